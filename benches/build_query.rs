@@ -0,0 +1,75 @@
+//! Measures how long it takes to build the query string of a large
+//! _Directions API_ request and a large _Distance Matrix API_ request.
+//!
+//! Run with `cargo bench --bench build_query`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use google_maps::prelude::*;
+
+// -----------------------------------------------------------------------------
+
+/// Returns `count` waypoints with a mix of addresses, coordinates and place
+/// IDs.
+fn waypoints(count: usize) -> Vec<Waypoint> {
+    (0..count)
+        .map(|index| match index % 3 {
+            0 => Waypoint::from_address(format!("{index} Wellington St, Ottawa, ON")),
+            1 => Waypoint::try_from_f64(45.42 + index as f64 / 1_000.0, -75.69).unwrap(),
+            _ => Waypoint::from_place_id(format!("ChIJrxNRX7IFzkwRCR5iKVZC-HA{index}")),
+        }) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Builds a directions request with 25 waypoints.
+fn directions(c: &mut Criterion) {
+    let client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE").unwrap();
+    let waypoints = waypoints(25);
+
+    c.bench_function("directions_25_waypoints", |b| {
+        b.iter(|| {
+            let mut request = client.directions(
+                Location::from_address("Parliament Hill, Ottawa, ON"),
+                Location::from_address("Rideau Hall, Ottawa, ON"),
+            ); // directions
+            request
+                .with_waypoints(black_box(&waypoints))
+                .with_travel_mode(TravelMode::Driving)
+                .with_language(Language::English)
+                .validate()
+                .unwrap()
+                .build()
+                .unwrap();
+            request.query_url().unwrap()
+        }) // iter
+    }); // bench_function
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Builds a distance matrix request with 25 origins and 25 destinations.
+fn distance_matrix(c: &mut Criterion) {
+    let client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE").unwrap();
+    let origins = waypoints(25);
+    let destinations = waypoints(25);
+
+    c.bench_function("distance_matrix_25x25", |b| {
+        b.iter(|| {
+            let mut request = client.distance_matrix(black_box(&origins), black_box(&destinations));
+            request
+                .with_travel_mode(TravelMode::Driving)
+                .with_language(Language::English)
+                .validate()
+                .unwrap()
+                .build()
+                .unwrap();
+            request.query_url().unwrap()
+        }) // iter
+    }); // bench_function
+} // fn
+
+// -----------------------------------------------------------------------------
+
+criterion_group!(benches, directions, distance_matrix);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+//! Compares encoding a 10,000 point polyline from the `Decimal` coordinates
+//! stored in `LatLng` against encoding it from the `f64` accessors.
+//!
+//! Run with `cargo bench --bench latlng_polyline`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use google_maps::prelude::*;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+
+// -----------------------------------------------------------------------------
+
+/// Returns `count` points along a gently curving path near Ottawa.
+fn points(count: usize) -> Vec<LatLng> {
+    (0..count)
+        .map(|index| {
+            let step = index as f64 / 10_000.0;
+            LatLng::from_f64(45.42 + step, -75.69 + step.sin()).unwrap()
+        }) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Appends one signed, already scaled, value to an encoded polyline using
+/// Google's [encoded polyline
+/// algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+fn encode_value(value: i64, polyline: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+    while value >= 0x20 {
+        polyline.push(char::from(((0x20 | (value & 0x1f)) + 63) as u8));
+        value >>= 5;
+    } // while
+    polyline.push(char::from((value + 63) as u8));
+} // fn
+
+/// Encodes a polyline from scaled `(lat, lng)` values.
+fn encode(scaled: impl Iterator<Item = (i64, i64)>) -> String {
+    let mut polyline = String::new();
+    let mut previous = (0, 0);
+    for (lat, lng) in scaled {
+        encode_value(lat - previous.0, &mut polyline);
+        encode_value(lng - previous.1, &mut polyline);
+        previous = (lat, lng);
+    } // for
+    polyline
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Encodes a 10,000 point polyline, scaling the coordinates as `Decimal` and
+/// as `f64`.
+fn polyline(c: &mut Criterion) {
+    let points = points(10_000);
+
+    // Both paths must produce the same polyline for the comparison to be fair:
+    let scale_decimal = |point: &LatLng| {
+        (
+            (point.lat * dec!(1e5)).round().to_i64().unwrap(),
+            (point.lng * dec!(1e5)).round().to_i64().unwrap(),
+        )
+    };
+    let scale_f64 = |point: &LatLng| {
+        (
+            (point.lat_f64() * 1e5).round() as i64,
+            (point.lng_f64() * 1e5).round() as i64,
+        )
+    };
+    assert_eq!(
+        encode(points.iter().map(scale_decimal)),
+        encode(points.iter().map(scale_f64)),
+    );
+
+    let mut group = c.benchmark_group("polyline_10k_points");
+    group.bench_function("decimal", |b| {
+        b.iter(|| encode(black_box(&points).iter().map(scale_decimal)))
+    }); // bench_function
+    group.bench_function("f64", |b| {
+        b.iter(|| encode(black_box(&points).iter().map(scale_f64)))
+    }); // bench_function
+    group.finish();
+} // fn
+
+// -----------------------------------------------------------------------------
+
+criterion_group!(benches, polyline);
+criterion_main!(benches);
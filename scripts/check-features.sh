@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+#
+# Checks that each Google Maps API can be compiled, and its tests pass, on its
+# own. This keeps the per-API feature flags from silently depending on each
+# other. Run from anywhere inside the repository:
+#
+#     scripts/check-features.sh
+#
+# Any extra arguments are passed to cargo, for example `--offline`.
+
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+apis=(
+    autocomplete
+    directions
+    distance_matrix
+    elevation
+    geocoding
+    places
+    roads
+    time_zone
+)
+
+run() {
+    echo "==> cargo $*"
+    cargo "$@"
+}
+
+# Each API alone, without an HTTP client:
+for api in "${apis[@]}"; do
+    run check --no-default-features --features "$api" "$@"
+done
+
+# Each API alone, with the `reqwest` HTTP client and either JSON parser
+# backend:
+for api in "${apis[@]}"; do
+    for backend in simd-json serde-json; do
+        run check --no-default-features --features "$api,reqwest,reqwest-default-tls,$backend" "$@"
+    done
+done
+
+# A typical subset, with tests:
+run test --no-default-features --features "geocoding" "$@"
+run test --no-default-features --features "geocoding,time_zone,reqwest,reqwest-default-tls,serde-json" "$@"
+
+# The default features:
+run test "$@"
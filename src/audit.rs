@@ -0,0 +1,391 @@
+//! Contains the `AuditSink` trait, which receives an `AuditEntry` for every
+//! request that the client makes to the Google Maps Platform. This may be used
+//! to keep an audit trail, for example to reconcile Google's bills.
+
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+// -----------------------------------------------------------------------------
+//
+/// A record of one request to the Google Maps Platform. An entry is recorded
+/// once the request has finished, after any automatic retries.
+///
+/// Entries never contain the API key or the response body.
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AuditEntry {
+    /// When the request was started.
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+
+    /// The API that was called, for example `directions` or `geocoding`.
+    pub api: String,
+
+    /// The request URL, with the API key removed.
+    pub url: String,
+
+    /// The HTTP status code of the last response. This is `None` if Google
+    /// could not be reached.
+    pub http_status: Option<u16>,
+
+    /// The status that Google returned in the last response body, for example
+    /// `OK` or `ZERO_RESULTS`. This is `None` if the body could not be read.
+    pub google_status: Option<String>,
+
+    /// The size of the last response body, in bytes. This is `None` if the
+    /// body was not read.
+    pub response_bytes: Option<usize>,
+
+    /// How long the request took, in milliseconds, including retries and the
+    /// time between them.
+    pub latency_ms: u64,
+
+    /// How many times the request was retried after its first attempt.
+    pub retries: u32,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to receive an `AuditEntry` for every request that the
+/// client makes. Use the `GoogleMapsClient::with_audit_sink` method to set it.
+///
+/// `record` is called from the task that made the request, so it should not
+/// block for long. The `FileAuditSink` and `ChannelAuditSink` implementations
+/// are provided. Any closure with the signature `Fn(AuditEntry)` may also be
+/// used as a sink:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+///     .with_audit_sink(|entry: AuditEntry| {
+///         println!("{} {} took {} ms", entry.api, entry.url, entry.latency_ms);
+///     })
+///     .build();
+/// ```
+
+pub trait AuditSink: Send + Sync {
+    /// Called once for each request, after any retries have finished.
+    fn record(&self, entry: AuditEntry);
+} // trait
+
+// -----------------------------------------------------------------------------
+
+impl<F> AuditSink for F
+where
+    F: Fn(AuditEntry) + Send + Sync,
+{
+    /// Calls the closure with the entry.
+    fn record(&self, entry: AuditEntry) {
+        self(entry);
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// An `AuditSink` that appends each entry to a file, as a line of JSON.
+
+#[derive(Debug)]
+pub struct FileAuditSink {
+    /// The path of the audit file.
+    path: PathBuf,
+
+    /// The audit file, opened for appending.
+    file: Mutex<std::fs::File>,
+} // struct
+
+impl FileAuditSink {
+    /// Opens the audit file for appending. The file is created if it does not
+    /// exist.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The path of the audit file.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file can not be opened.
+
+    pub fn new(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+        let file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        Ok(Self { path, file: Mutex::new(file) })
+    } // fn
+} // impl
+
+impl AuditSink for FileAuditSink {
+    /// Appends the entry to the file. If it can not be written, the error is
+    /// logged and the entry is lost.
+    fn record(&self, entry: AuditEntry) {
+        let written = crate::json::to_json_string(&entry)
+            .map_err(|error| error.to_string())
+            .and_then(|mut line| {
+                line.push('\n');
+                self.file
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)
+                    .write_all(line.as_bytes())
+                    .map_err(|error| error.to_string())
+            }); // and_then
+        if let Err(error) = written {
+            tracing::error!("could not write audit file `{}`: {error}", self.path.display());
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// An `AuditSink` that sends each entry down a `tokio` channel. This allows the
+/// entries to be processed, for example written to a database, in another
+/// task:
+///
+/// ```rust
+/// let (audit_sink, mut entries) = ChannelAuditSink::new();
+///
+/// tokio::spawn(async move {
+///     while let Some(entry) = entries.recv().await {
+///         // Store the entry...
+///     }
+/// });
+/// ```
+
+#[derive(Clone, Debug)]
+pub struct ChannelAuditSink {
+    /// The sending half of the channel.
+    sender: tokio::sync::mpsc::UnboundedSender<AuditEntry>,
+} // struct
+
+impl ChannelAuditSink {
+    /// Creates a channel, and returns the sink and the receiving half of the
+    /// channel.
+
+    #[must_use]
+    pub fn new() -> (Self, tokio::sync::mpsc::UnboundedReceiver<AuditEntry>) {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        (Self { sender }, receiver)
+    } // fn
+} // impl
+
+impl From<tokio::sync::mpsc::UnboundedSender<AuditEntry>> for ChannelAuditSink {
+    /// Creates a sink from the sending half of an existing channel.
+    fn from(sender: tokio::sync::mpsc::UnboundedSender<AuditEntry>) -> Self {
+        Self { sender }
+    } // fn
+} // impl
+
+impl AuditSink for ChannelAuditSink {
+    /// Sends the entry. If the receiver has been dropped, the entry is lost.
+    fn record(&self, entry: AuditEntry) {
+        if self.sender.send(entry).is_err() {
+            tracing::warn!("audit entry dropped, the channel's receiver has been closed");
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Collects the details of one request while it is being made, including its
+/// retries, and records them with the client's audit sink when it finishes.
+/// If the client has no audit sink, nothing is collected.
+
+pub(crate) struct Audit {
+    /// The client's audit sink, if it has one.
+    sink: Option<Arc<dyn AuditSink>>,
+
+    /// The API that is being called.
+    api: &'static str,
+
+    /// The request URL, with the API key removed.
+    url: String,
+
+    /// When the request was started.
+    timestamp: chrono::DateTime<chrono::Utc>,
+
+    /// When the request was started, for measuring latency.
+    started: Instant,
+
+    /// The details of the attempts so far.
+    attempts: Mutex<Attempts>,
+} // struct
+
+/// The details of a request's attempts. Later attempts overwrite the details
+/// of earlier ones.
+
+#[derive(Debug, Default)]
+struct Attempts {
+    /// How many attempts have been made.
+    count: u32,
+    /// The HTTP status code of the last attempt's response.
+    http_status: Option<u16>,
+    /// The status in the last attempt's response body.
+    google_status: Option<String>,
+    /// The size of the last attempt's response body.
+    response_bytes: Option<usize>,
+} // struct
+
+impl Audit {
+    /// Starts auditing a request.
+
+    pub fn new(sink: Option<Arc<dyn AuditSink>>, api: &'static str, url: &str) -> Self {
+        let url = if sink.is_some() { crate::error::sanitize_url(url) } else { String::new() };
+        Self {
+            sink,
+            api,
+            url,
+            timestamp: chrono::Utc::now(),
+            started: Instant::now(),
+            attempts: Mutex::default(),
+        } // struct
+    } // fn
+
+    /// Records that an attempt was made, and the HTTP status it got.
+
+    pub fn attempt(&self, response: &Result<reqwest::Response, crate::ReqError>) {
+        if self.sink.is_some() {
+            let mut attempts = self.lock();
+            attempts.count += 1;
+            attempts.http_status = response.as_ref().ok().map(|response| response.status().as_u16());
+            attempts.google_status = None;
+            attempts.response_bytes = None;
+        } // if
+    } // fn
+
+    /// Records the size of the response body that was read.
+
+    pub fn response_bytes<E>(&self, body: &Result<Vec<u8>, E>) {
+        if let (Some(_), Ok(body)) = (&self.sink, body) {
+            self.lock().response_bytes = Some(body.len());
+        } // if
+    } // fn
+
+    /// Records the status that Google returned in the response body.
+
+    pub fn google_status(&self, status: impl Into<String>) {
+        if self.sink.is_some() {
+            self.lock().google_status = Some(status.into());
+        } // if
+    } // fn
+
+    /// Records the request with the audit sink, now that it has finished.
+
+    pub fn finish(self) {
+        if let Some(sink) = self.sink {
+            let attempts = self.attempts.into_inner().unwrap_or_else(std::sync::PoisonError::into_inner);
+            sink.record(AuditEntry {
+                timestamp: self.timestamp,
+                api: self.api.to_string(),
+                url: self.url,
+                http_status: attempts.http_status,
+                google_status: attempts.google_status,
+                response_bytes: attempts.response_bytes,
+                latency_ms: u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+                retries: attempts.count.saturating_sub(1),
+            }); // record
+        } // if
+    } // fn
+
+    /// Locks the attempts. A poisoned lock is recovered, because the details
+    /// are always left consistent.
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Attempts> {
+        self.attempts.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "directions", feature = "geocoding"))]
+mod tests {
+    use super::{AuditEntry, ChannelAuditSink, FileAuditSink};
+    use crate::client::mock_server::MockServer;
+    use crate::directions::request::location::Location;
+    use crate::GoogleMapsClient;
+    use std::sync::{Arc, Mutex};
+
+    const KEY: &str = "AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000";
+
+    /// Makes a directions and a geocoding request against a mock server.
+    async fn make_requests(client: &GoogleMapsClient) {
+        let server = MockServer::replaying(&["directions", "geocoding"]);
+        let mut client = client.clone();
+        client.base_url = Some(server.base_url().to_string());
+
+        client
+            .directions(Location::from_address("Toronto"), Location::from_address("Montreal"))
+            .execute()
+            .await
+            .unwrap();
+        client.geocoding().with_address("Mountain View").execute().await.unwrap();
+    } // fn
+
+    #[tokio::test]
+    async fn records_each_request_without_the_key() {
+        let entries = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&entries);
+        let client = GoogleMapsClient::try_new(KEY)
+            .unwrap()
+            .with_audit_sink(move |entry: AuditEntry| recorded.lock().unwrap().push(entry))
+            .build();
+
+        let started = chrono::Utc::now();
+        make_requests(&client).await;
+
+        let entries = entries.lock().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].api, "directions");
+        assert_eq!(entries[1].api, "geocoding");
+        assert!(entries[0].url.starts_with("https://maps.googleapis.com/maps/api/directions/json?"));
+        assert!(entries[1].url.starts_with("https://maps.googleapis.com/maps/api/geocode/json?"));
+
+        for entry in entries.iter() {
+            assert!(!entry.url.contains(KEY), "{}", entry.url);
+            assert!(entry.url.contains("key=REDACTED"), "{}", entry.url);
+            assert_eq!(entry.http_status, Some(200));
+            assert_eq!(entry.google_status.as_deref(), Some("OK"));
+            assert!(entry.response_bytes.is_some_and(|bytes| bytes > 0), "{entry:?}");
+            assert_eq!(entry.retries, 0);
+            assert!(entry.timestamp >= started, "{entry:?}");
+        } // for
+    } // fn
+
+    #[tokio::test]
+    async fn writes_each_request_to_a_file_as_a_json_line() {
+        let path = std::env::temp_dir().join(format!("google_maps_audit_{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let client = GoogleMapsClient::try_new(KEY)
+            .unwrap()
+            .with_audit_sink(FileAuditSink::new(&path).unwrap())
+            .build();
+
+        make_requests(&client).await;
+
+        let lines = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let entries: Vec<AuditEntry> = lines
+            .lines()
+            .map(|line| crate::json::parse_json_str(line).unwrap())
+            .collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].api, "directions");
+        assert_eq!(entries[1].api, "geocoding");
+        assert!(!lines.contains(KEY));
+    } // fn
+
+    #[tokio::test]
+    async fn sends_each_request_down_a_channel() {
+        let (audit_sink, mut receiver) = ChannelAuditSink::new();
+        let client = GoogleMapsClient::try_new(KEY)
+            .unwrap()
+            .with_audit_sink(audit_sink)
+            .build();
+
+        make_requests(&client).await;
+
+        assert_eq!(receiver.recv().await.unwrap().api, "directions");
+        assert_eq!(receiver.recv().await.unwrap().api, "geocoding");
+        assert!(receiver.try_recv().is_err());
+    } // fn
+} // mod
@@ -0,0 +1,67 @@
+//! Allows requests to be cancelled with a `tokio_util` `CancellationToken`.
+//! See the _Cancellation_ section of the `GoogleMapsClient` documentation.
+
+use crate::error::Error as GoogleMapsError;
+use std::future::Future;
+use tokio_util::sync::CancellationToken;
+
+// -----------------------------------------------------------------------------
+//
+/// Runs a request until it completes or the token is cancelled, whichever
+/// happens first. If the token is cancelled first, the request's future is
+/// dropped, which aborts any HTTP request that is in flight, and a `Cancelled`
+/// error is returned.
+
+pub async fn until_cancelled<T>(
+    token: &CancellationToken,
+    request: impl Future<Output = Result<T, GoogleMapsError>>,
+) -> Result<T, GoogleMapsError> {
+    token
+        .run_until_cancelled(request)
+        .await
+        .unwrap_or(Err(GoogleMapsError::Cancelled))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use crate::error::Error as GoogleMapsError;
+    use crate::LatLng;
+    use std::time::Duration;
+    use tokio_util::sync::CancellationToken;
+
+    #[tokio::test]
+    async fn cancelling_during_a_backoff_stops_the_retries() {
+        // The cassette has 50 `503 Service Unavailable` responses, each of
+        // which is retried after a backoff of at least 250 milliseconds:
+        let client = replay_client("retry_budget");
+        let cassette = client.cassette.clone().unwrap();
+        let recorded = cassette.remaining();
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            canceller.cancel();
+        }); // spawn
+
+        let result = client
+            .time_zone(
+                LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+            )
+            .build()
+            .get_with_cancellation(token)
+            .await;
+
+        // Only the first attempt was made before the token was cancelled:
+        assert!(matches!(result, Err(GoogleMapsError::Cancelled)));
+        assert_eq!(recorded - cassette.remaining(), 1);
+
+        // No attempts are made after the backoff would have ended:
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(recorded - cassette.remaining(), 1);
+    } // fn
+} // mod
@@ -0,0 +1,329 @@
+//! Records responses from the Google Maps Platform to a cassette file, and
+//! replays them later without an API key or network access. This allows
+//! integration tests to be deterministic.
+//!
+//! A cassette is a JSON file with one interaction per request. Each
+//! interaction has the request URL, with the API key removed, and the HTTP
+//! status, content type and body of the response:
+//!
+//! ```json
+//! [
+//!   {
+//!     "url": "https://maps.googleapis.com/maps/api/geocode/json?key=REDACTED&address=Sydney",
+//!     "status": 200,
+//!     "content_type": "application/json; charset=UTF-8",
+//!     "body": "{ \"results\": [ ... ], \"status\": \"OK\" }"
+//!   }
+//! ]
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+// -----------------------------------------------------------------------------
+
+/// The environment variable that switches `Cassette::from_env` into record
+/// mode when it is set to `1`.
+const RECORD_VARIABLE: &str = "GOOGLE_MAPS_RECORD";
+
+// -----------------------------------------------------------------------------
+//
+/// Whether a cassette records real responses, or replays recorded ones.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CassetteMode {
+    /// Requests are sent to Google, and each request and response is appended
+    /// to the cassette file.
+    Record,
+    /// Requests are served from the cassette file. Nothing is sent to Google.
+    /// A request that was not recorded fails with a `CassetteMiss` error.
+    Replay,
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// A request and the response that Google gave to it.
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct Interaction {
+    /// The request URL, with the API key removed.
+    url: String,
+    /// The HTTP status code of the response.
+    status: u16,
+    /// The `Content-Type` header of the response, if it had one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    /// The response body.
+    body: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// A cassette file of recorded requests and responses. Use the
+/// `GoogleMapsClient::with_cassette` method to record or replay every request
+/// that a client makes:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+///     .with_cassette(Cassette::from_env("tests/cassettes/geocoding.json")?)
+///     .build();
+/// ```
+
+#[derive(Debug)]
+pub struct Cassette {
+    /// The path of the cassette file.
+    path: PathBuf,
+
+    /// Whether the cassette records or replays.
+    mode: CassetteMode,
+
+    /// In record mode, the interactions that have been recorded so far. In
+    /// replay mode, the interactions that have not been replayed yet.
+    interactions: Mutex<Vec<Interaction>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Cassette {
+    /// Opens a cassette file in the given mode.
+    ///
+    /// In record mode, the file does not need to exist yet. If it does, new
+    /// interactions are appended to the ones already in it. In replay mode,
+    /// the file must exist.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The path of the cassette file.
+    ///
+    /// * `mode` ‧ Whether to record or replay responses.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file can not be read or is not a cassette.
+
+    pub fn new(path: impl AsRef<Path>, mode: CassetteMode) -> Result<Self, crate::error::Error> {
+        let path = path.as_ref().to_path_buf();
+
+        let interactions = match std::fs::read_to_string(&path) {
+            Ok(json) => crate::json::parse_json_str(&json)
+                .map_err(|error| crate::error::Error::Cassette(path.display().to_string(), error.to_string()))?,
+            Err(error) if mode == CassetteMode::Record && error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(crate::error::Error::Cassette(path.display().to_string(), error.to_string())),
+        }; // match
+
+        Ok(Self { path, mode, interactions: Mutex::new(interactions) })
+    } // fn
+
+    /// Opens a cassette file in record mode. See `Cassette::new`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file exists but can not be read or is not a
+    ///   cassette.
+
+    pub fn record(path: impl AsRef<Path>) -> Result<Self, crate::error::Error> {
+        Self::new(path, CassetteMode::Record)
+    } // fn
+
+    /// Opens a cassette file in replay mode. See `Cassette::new`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file can not be read or is not a cassette.
+
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, crate::error::Error> {
+        Self::new(path, CassetteMode::Replay)
+    } // fn
+
+    /// Opens a cassette file in record mode if the `GOOGLE_MAPS_RECORD`
+    /// environment variable is set to `1`, and in replay mode otherwise. This
+    /// allows the same tests to re-record their cassettes with
+    /// `GOOGLE_MAPS_RECORD=1 cargo test`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file can not be read or is not a cassette.
+
+    pub fn from_env(path: impl AsRef<Path>) -> Result<Self, crate::error::Error> {
+        let mode = if std::env::var(RECORD_VARIABLE).is_ok_and(|record| record == "1") {
+            CassetteMode::Record
+        } else {
+            CassetteMode::Replay
+        }; // if
+        Self::new(path, mode)
+    } // fn
+
+    /// Returns whether the cassette records or replays.
+
+    #[must_use]
+    pub const fn mode(&self) -> CassetteMode {
+        self.mode
+    } // fn
+
+    /// In replay mode, returns a `CassetteMiss` error if there is no recorded
+    /// response left for the URL. Every request is checked before it is sent,
+    /// so that a missing recording is reported as such, rather than as an
+    /// error response from Google.
+    ///
+    /// In record mode, always returns `Ok`.
+
+    pub(crate) fn check(&self, url: &str) -> Result<(), crate::error::Error> {
+        if self.mode == CassetteMode::Record {
+            return Ok(());
+        } // if
+
+        let url = crate::error::sanitize_url(url);
+        let recorded = self
+            .interactions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .any(|interaction| interaction.url == url);
+
+        if recorded {
+            Ok(())
+        } else {
+            tracing::error!("cassette `{}` has no recorded response for `{url}`", self.path.display());
+            Err(crate::error::Error::CassetteMiss(self.path.display().to_string(), url))
+        } // if
+    } // fn
+
+    /// In replay mode, returns the recorded response for the URL. Each
+    /// recorded interaction is replayed once, in the order that they were
+    /// recorded. A request is checked with `check` before it is sent, but a
+    /// retry may still run out of recorded responses. In that case, a `404
+    /// Not Found` response that explains the problem is returned.
+    ///
+    /// In record mode, returns `None` so that the request is sent to Google.
+
+    pub(crate) fn play(&self, url: &str) -> Option<reqwest::Response> {
+        if self.mode == CassetteMode::Record {
+            return None;
+        } // if
+
+        let url = crate::error::sanitize_url(url);
+        let recorded = {
+            let mut interactions = self.interactions.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            interactions
+                .iter()
+                .position(|interaction| interaction.url == url)
+                .map(|index| interactions.remove(index))
+        }; // recorded
+
+        let interaction = recorded.unwrap_or_else(|| {
+            tracing::error!("cassette `{}` has no recorded response for `{url}`", self.path.display());
+            Interaction {
+                status: 404,
+                content_type: Some("text/plain".to_string()),
+                body: format!("cassette `{}` has no recorded response for `{url}`", self.path.display()),
+                url,
+            } // Interaction
+        }); // unwrap_or_else
+
+        Some(interaction.into_response())
+    } // fn
+
+    /// In record mode, reads the response from Google and appends it to the
+    /// cassette file. The response is then rebuilt so that it may be read by
+    /// the caller.
+    ///
+    /// If the cassette file can not be written, the error is logged and the
+    /// response is still returned.
+
+    pub(crate) async fn tape(
+        &self,
+        url: &str,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, crate::ReqError> {
+        let status = response.status().as_u16();
+        let content_type = crate::error::content_type(&response);
+        let body = response.bytes().await?;
+
+        let interaction = Interaction {
+            url: crate::error::sanitize_url(url),
+            status,
+            content_type,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }; // Interaction
+
+        let written = {
+            let mut interactions = self.interactions.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            interactions.push(interaction.clone());
+            crate::json::to_json_string_pretty(&*interactions)
+                .map_err(|error| error.to_string())
+                .and_then(|json| std::fs::write(&self.path, json).map_err(|error| error.to_string()))
+        }; // written
+        if let Err(error) = written {
+            tracing::error!("could not write cassette `{}`: {error}", self.path.display());
+        } // if
+
+        Ok(interaction.into_response())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Interaction {
+    /// Builds a `reqwest::Response` from the recorded response.
+
+    fn into_response(self) -> reqwest::Response {
+        let mut response = http::Response::new(self.body);
+        *response.status_mut() = http::StatusCode::from_u16(self.status)
+            .unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+        if let Some(content_type) = self
+            .content_type
+            .and_then(|content_type| http::HeaderValue::from_str(&content_type).ok())
+        {
+            response.headers_mut().insert(http::header::CONTENT_TYPE, content_type);
+        } // if
+        reqwest::Response::from(response)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::cassette::Cassette;
+    use crate::GoogleMapsClient;
+
+    /// Returns a client that replays the cassette with the given name from the
+    /// `tests/cassettes` directory. The cassettes were written by hand from the
+    /// sample responses in Google's documentation.
+
+    pub fn replay_client(cassette: &str) -> GoogleMapsClient {
+        let path = format!("{}/tests/cassettes/{cassette}.json", env!("CARGO_MANIFEST_DIR"));
+        GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000")
+            .unwrap()
+            .with_cassette(Cassette::replay(path).unwrap())
+            .build()
+    } // fn
+
+    impl Cassette {
+        /// Returns how many recorded responses have not been replayed yet.
+
+        pub fn remaining(&self) -> usize {
+            self.interactions.lock().unwrap_or_else(std::sync::PoisonError::into_inner).len()
+        } // fn
+    } // impl
+
+    #[tokio::test]
+    async fn unrecorded_request_is_a_cassette_miss() {
+        let client = replay_client("geocoding");
+        let result = client.geocoding().with_address("Nowhere").execute().await;
+        assert!(matches!(result, Err(crate::error::Error::CassetteMiss(_, url)) if url.ends_with("address=Nowhere")));
+    } // fn
+
+    #[tokio::test]
+    async fn each_response_is_replayed_once() {
+        let client = replay_client("geocoding");
+        let mut request = client.geocoding();
+        request.with_address("1600 Amphitheatre Parkway, Mountain View, CA");
+        assert!(request.clone().execute().await.is_ok());
+        assert!(matches!(
+            request.execute().await,
+            Err(crate::error::Error::CassetteMiss(..))
+        ));
+    } // fn
+} // mod
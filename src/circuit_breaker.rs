@@ -0,0 +1,292 @@
+//! Contains the `CircuitBreaker` struct, which stops the client from sending
+//! requests for a while when Google's servers keep failing. This avoids piling
+//! more load, and more retries, onto an outage.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// The state of a circuit breaker.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CircuitState {
+    /// Requests are sent as usual. Transient failures are being counted.
+    Closed,
+    /// Too many transient failures were seen. Requests are not sent, and fail
+    /// immediately with a `CircuitOpen` error, until the cooldown has passed.
+    Open,
+    /// The cooldown has passed and a single trial request has been let
+    /// through. If it succeeds the circuit closes, and if it fails the circuit
+    /// opens again.
+    HalfOpen,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+/// The state of a circuit breaker, with the bookkeeping for each state.
+
+#[derive(Clone, Copy, Debug)]
+enum State {
+    /// The number of consecutive transient failures, and when the first of
+    /// them happened.
+    Closed { failures: u32, since: Option<Instant> },
+    /// When the cooldown ends.
+    Open { until: Instant },
+    /// When another trial request may be let through, if the current one
+    /// has not reported back by then.
+    HalfOpen { until: Instant },
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// Stops the client from sending requests after repeated transient failures,
+/// such as HTTP `503 Service Unavailable` responses or connection errors. Use
+/// the `GoogleMapsClient::with_circuit_breaker` method to enable it.
+///
+/// * While the circuit is _closed_, requests are sent as usual. If
+///   `failure_threshold` consecutive attempts fail within `window`, the
+///   circuit _opens_.
+///
+/// * While the circuit is _open_, requests fail immediately with a
+///   `CircuitOpen` error, without being sent and without waiting on the rate
+///   limiter.
+///
+/// * Once `cooldown` has passed, the circuit is _half-open_: one trial request
+///   is let through. If it succeeds the circuit closes, otherwise it opens for
+///   another `cooldown`.
+///
+/// Every attempt counts, including automatic retries. Any response that is not
+/// a server error or `429 Too Many Requests` counts as a success, because it
+/// shows that Google's servers are answering.
+
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    /// How many consecutive transient failures open the circuit.
+    failure_threshold: u32,
+
+    /// The failures must all happen within this window of the first one.
+    window: Duration,
+
+    /// How long the circuit stays open before a trial request is let through.
+    cooldown: Duration,
+
+    /// The current state.
+    state: Mutex<State>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_threshold` ‧ How many consecutive transient failures open
+    ///   the circuit. A threshold of `0` is treated as `1`.
+    ///
+    /// * `window` ‧ The failures must all happen within this duration of the
+    ///   first one. Older failures are forgotten.
+    ///
+    /// * `cooldown` ‧ How long the circuit stays open before a trial request
+    ///   is let through.
+
+    #[must_use]
+    pub fn new(failure_threshold: u32, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            window,
+            cooldown,
+            state: Mutex::new(State::Closed { failures: 0, since: None }),
+        } // struct
+    } // fn
+
+    /// Returns the current state of the circuit.
+
+    #[must_use]
+    pub fn state(&self) -> CircuitState {
+        let state = *self.lock();
+        match state {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() < until => CircuitState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => CircuitState::HalfOpen,
+        } // match
+    } // fn
+
+    /// Returns whether a request may be sent now. If the cooldown has just
+    /// passed, this caller's request becomes the trial request.
+    ///
+    /// A trial request that never reports back, for example because its
+    /// request could not be built, does not hold the circuit half-open
+    /// forever: another trial is let through after a further `cooldown`.
+
+    pub(crate) fn allow_request(&self) -> bool {
+        let now = Instant::now();
+        let mut state = self.lock();
+        match *state {
+            State::Closed { .. } => true,
+            State::Open { until } | State::HalfOpen { until } if now < until => false,
+            State::Open { .. } | State::HalfOpen { .. } => {
+                tracing::info!("circuit breaker is half-open, letting a trial request through");
+                *state = State::HalfOpen { until: now + self.cooldown };
+                true
+            }, // Open | HalfOpen
+        } // match
+    } // fn
+
+    /// Records a successful attempt, which closes the circuit.
+
+    pub(crate) fn record_success(&self) {
+        let mut state = self.lock();
+        if !matches!(*state, State::Closed { .. }) {
+            tracing::info!("circuit breaker closed, Google is responding again");
+        } // if
+        *state = State::Closed { failures: 0, since: None };
+    } // fn
+
+    /// Records a transient failure, which may open the circuit.
+
+    pub(crate) fn record_failure(&self) {
+        let now = Instant::now();
+        let mut state = self.lock();
+        match *state {
+            State::Closed { failures, since } => {
+                // Start counting again if the first failure is too old:
+                let (failures, since) = match since {
+                    Some(since) if now.duration_since(since) <= self.window => (failures + 1, since),
+                    _ => (1, now),
+                }; // match
+                if failures >= self.failure_threshold {
+                    tracing::warn!(
+                        "circuit breaker opened after {failures} consecutive failures, \
+                        pausing requests for {:?}",
+                        self.cooldown,
+                    );
+                    *state = State::Open { until: now + self.cooldown };
+                } else {
+                    *state = State::Closed { failures, since: Some(since) };
+                } // if
+            }, // Closed
+            State::HalfOpen { .. } => {
+                tracing::warn!("circuit breaker trial request failed, pausing requests for {:?}", self.cooldown);
+                *state = State::Open { until: now + self.cooldown };
+            }, // HalfOpen
+            // Attempts that were already under way when the circuit opened do
+            // not extend the cooldown:
+            State::Open { .. } => {},
+        } // match
+    } // fn
+
+    /// Records the outcome of an attempt. Transport errors, server errors and
+    /// `429 Too Many Requests` responses are failures. Every other response is
+    /// a success.
+
+    pub(crate) fn record(&self, response: &Result<reqwest::Response, crate::ReqError>) {
+        match response {
+            Ok(response) if !crate::error::is_transient_http_status(response.status().as_str()) => self.record_success(),
+            _ => self.record_failure(),
+        } // match
+    } // fn
+
+    /// Locks the state. A poisoned lock is recovered, because the state is
+    /// always left consistent.
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The retry policy for requests. This is the `backoff` crate's default
+/// exponential backoff, except that automatic retries stop as soon as the
+/// circuit breaker opens, or when the retry budget can not pay for another
+/// retry. The request then fails with its last error, rather than continuing
+/// to retry against failing servers.
+
+pub(crate) struct CircuitBackoff {
+    /// The exponential backoff that decides when to retry.
+    pub(crate) backoff: backoff::ExponentialBackoff,
+
+    /// The client's circuit breaker, if it has one.
+    pub(crate) circuit_breaker: Option<Arc<CircuitBreaker>>,
+
+    /// The client's retry budget, if it has one.
+    pub(crate) retry_budget: Option<Arc<crate::retry_budget::RetryBudget>>,
+
+    /// Set if a retry was refused because the retry budget is exhausted.
+    pub(crate) budget_exhausted: Arc<AtomicBool>,
+} // struct
+
+impl backoff::backoff::Backoff for CircuitBackoff {
+    fn reset(&mut self) {
+        self.backoff.reset();
+    } // fn
+
+    fn next_backoff(&mut self) -> Option<Duration> {
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            if circuit_breaker.state() == CircuitState::Open {
+                return None;
+            } // if
+        } // if
+
+        // Only a retry that the backoff would make is paid for:
+        let next_backoff = self.backoff.next_backoff()?;
+
+        match &self.retry_budget {
+            Some(retry_budget) if !retry_budget.withdraw() => {
+                tracing::warn!("not retrying, the retry budget is exhausted");
+                self.budget_exhausted.store(true, Ordering::Relaxed);
+                None
+            }, // Some
+            _ => Some(next_backoff),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "time_zone", feature = "vcr"))]
+mod tests {
+    use super::CircuitState;
+    use crate::cassette::tests::replay_client;
+    use crate::{GoogleMapsClient, LatLng};
+    use std::time::Duration;
+
+    /// Sends the time zone request that the cassette has responses for.
+    async fn time_zone(client: &GoogleMapsClient) -> Result<(), crate::error::Error> {
+        client
+            .time_zone(
+                LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+            )
+            .execute()
+            .await
+            .map(|_response| ())
+    } // fn
+
+    #[tokio::test]
+    async fn opens_after_repeated_503s_and_closes_after_the_cooldown() {
+        // The cassette has three `503 Service Unavailable` responses, followed
+        // by a successful one:
+        let mut client = replay_client("circuit_breaker");
+        client.with_circuit_breaker(3, Duration::from_secs(60), Duration::from_millis(100));
+        let circuit_breaker = client.circuit_breaker.clone().unwrap();
+
+        // The first request is retried until the third failure opens the
+        // circuit:
+        assert!(time_zone(&client).await.is_err());
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+
+        // While the circuit is open, requests fail without being sent:
+        assert!(matches!(time_zone(&client).await, Err(crate::error::Error::CircuitOpen)));
+
+        // After the cooldown, a trial request is sent. It succeeds, which
+        // closes the circuit:
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+        assert!(time_zone(&client).await.is_ok());
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    } // fn
+} // mod
@@ -11,28 +11,11 @@ impl GoogleMapsClient {
     ///
     /// This method accepts no arguments.
 
-    #[cfg(feature = "reqwest")]
     #[must_use]
     pub fn build(&self) -> Self {
         self.clone()
     } // fn
 
-    // -------------------------------------------------------------------------
-    //
-    /// Completes the builder pattern into a final structure.
-    ///
-    /// ## Arguments
-    ///
-    /// This method accepts no arguments.
-
-    #[cfg(not(feature = "reqwest"))]
-    #[must_use]
-    pub fn build(&self) -> Self {
-        Self {
-            key: self.key.clone(),
-        } // GoogleMapsClient
-    } // fn
-
     // -------------------------------------------------------------------------
     //
     /// Completes the builder pattern into a final structure.
@@ -1,4 +1,10 @@
-use crate::client::GoogleMapsClient;
+use crate::client::{GoogleMapsClient, Inner};
+#[cfg(any(
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
 use crate::types::LatLng;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
@@ -26,20 +32,43 @@ impl GoogleMapsClient {
 
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!(
-                "RustGoogleMaps/{version}",
-                version = env!("CARGO_PKG_VERSION")
-            ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
+        let reqwest_client = Self::reqwest_client_builder().build()?;
 
         Ok(Self {
-            key: key.into(),
-            rate_limit: RequestRate::default(),
-            reqwest_client,
+            inner: std::sync::Arc::new(Inner {
+                key: key.into(),
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places",
+                    feature = "time_zone"
+                ))]
+                default_language: None,
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places"
+                ))]
+                default_region: None,
+                coordinate_precision: None,
+                rate_limit: RequestRate::default(),
+                capture_response_body: true,
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                default_headers: reqwest::header::HeaderMap::new(),
+                base_url: None,
+                reqwest_client_provided: false,
+                circuit_breaker: None,
+                retry_budget: None,
+                audit_sink: None,
+                #[cfg(feature = "vcr")]
+                cassette: None,
+                reqwest_client,
+            }), // Inner
         }) // GoogleMapsClient
     } // fn
 
@@ -55,20 +84,43 @@ impl GoogleMapsClient {
 
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
     pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!(
-                "RustGoogleMaps/{version}",
-                version = env!("CARGO_PKG_VERSION")
-            ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
+        let reqwest_client = Self::reqwest_client_builder().build()?;
 
         Ok(Self {
-            key: key.into(),
-            rate_limit: RequestRate::default(),
-            reqwest_client: crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client),
+            inner: std::sync::Arc::new(Inner {
+                key: key.into(),
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places",
+                    feature = "time_zone"
+                ))]
+                default_language: None,
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places"
+                ))]
+                default_region: None,
+                coordinate_precision: None,
+                rate_limit: RequestRate::default(),
+                capture_response_body: true,
+                pool_max_idle_per_host: None,
+                pool_idle_timeout: None,
+                default_headers: reqwest::header::HeaderMap::new(),
+                base_url: None,
+                reqwest_client_provided: false,
+                circuit_breaker: None,
+                retry_budget: None,
+                audit_sink: None,
+                #[cfg(feature = "vcr")]
+                cassette: None,
+                reqwest_client: crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client),
+            }), // Inner
         }) // GoogleMapsClient
     } // fn
 
@@ -95,6 +147,23 @@ impl GoogleMapsClient {
         Self::try_new(key).unwrap()
     }
 
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a `reqwest` client builder with the settings that the Google
+    /// Maps client uses for the `reqwest` clients that it builds itself.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn reqwest_client_builder() -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+            .user_agent(format!(
+                "RustGoogleMaps/{version}",
+                version = env!("CARGO_PKG_VERSION")
+            ))
+            .connect_timeout(std::time::Duration::from_secs(30))
+            .timeout(std::time::Duration::from_secs(30))
+            .read_timeout(std::time::Duration::from_secs(30))
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// Initialize the settings needed for a Google Cloud Maps API transaction.
@@ -107,7 +176,29 @@ impl GoogleMapsClient {
 
     #[cfg(not(feature = "reqwest"))]
     pub fn new(key: impl Into<String>) -> Self {
-        Self { key: key.into() }
+        Self {
+            inner: std::sync::Arc::new(Inner {
+                key: key.into(),
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places",
+                    feature = "time_zone"
+                ))]
+                default_language: None,
+                #[cfg(any(
+                    feature = "autocomplete",
+                    feature = "directions",
+                    feature = "distance_matrix",
+                    feature = "geocoding",
+                    feature = "places"
+                ))]
+                default_region: None,
+                coordinate_precision: None,
+            }), // Inner
+        } // GoogleMapsClient
     } // fn
 
     // -------------------------------------------------------------------------
@@ -130,6 +221,8 @@ impl GoogleMapsClient {
     ///     // Destination: Canada Science and Technology Museum
     ///     Location::try_from_f32(45.403_509, -75.618_904)?,
     /// )
+    /// .execute()
+    /// .await?;
     /// ```
 
     #[cfg(feature = "directions")]
@@ -142,6 +235,64 @@ impl GoogleMapsClient {
         crate::directions::request::Request::new(self, origin.into(), destination.into())
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Finds transit directions that arrive at the destination by a given
+    /// time, and returns the route that Google recommends. This is the common
+    /// "I need to arrive by 9 am" query. Returns `None` if Google could not
+    /// find a transit route (that is, the `ZERO_RESULTS` status).
+    ///
+    /// ## Arguments
+    ///
+    /// * `origin` ‧ The address, place ID or coordinates to depart from.
+    ///
+    /// * `destination` ‧ The address, place ID or coordinates to arrive at.
+    ///
+    /// * `arrival` ‧ The time that the passenger must arrive by, in any time
+    ///   zone.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_GOOGLE_API_KEY_HERE")?;
+    ///
+    /// if let Some(route) = google_maps_client
+    ///     .transit_arrive_by(
+    ///         Location::from_address("Union Station, Toronto"),
+    ///         Location::from_address("Toronto Pearson Airport"),
+    ///         Toronto.with_ymd_and_hms(2030, 1, 1, 9, 0, 0).unwrap(),
+    ///     )
+    ///     .await?
+    /// {
+    ///     println!("{:?}", route.total_duration());
+    /// }
+    /// ```
+
+    #[cfg(all(feature = "directions", feature = "reqwest"))]
+    pub async fn transit_arrive_by<Tz: chrono::TimeZone>(
+        &self,
+        origin: impl Into<Location>,
+        destination: impl Into<Location>,
+        arrival: chrono::DateTime<Tz>,
+    ) -> Result<Option<crate::directions::response::route::Route>, crate::GoogleMapsError> {
+        let mut request = self.directions(origin, destination);
+        request
+            .with_travel_mode(crate::directions::travel_mode::TravelMode::Transit)
+            .with_arrival_time(arrival.naive_utc());
+
+        match request.execute().await {
+            Ok(response) => Ok(response.routes.into_iter().next()),
+            // Google could not find a transit route:
+            Err(crate::GoogleMapsError::Directions(crate::directions::error::Error::GoogleMapsService(
+                crate::directions::response::status::Status::ZeroResults,
+                _,
+            ))) => Ok(None),
+            Err(error) => Err(error),
+        } // match
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Distance Matrix API is a service that provides travel distance and
@@ -176,22 +327,24 @@ impl GoogleMapsClient {
     ///
     /// # Generics
     ///
-    /// This method uses generics to improve ergonomics. The `C` generic is
-    /// intended to represent any collection that can be iterated over, and the
-    /// `W` generic is for any type that can be converted to a `Waypoint` type.
+    /// This method uses generics to improve ergonomics. The `O` and `D`
+    /// generics are intended to represent any collection that can be iterated
+    /// over, such as a `Vec` or an iterator chain, and their items may be any
+    /// type that can be converted to a `Waypoint` type. Origins and
+    /// destinations do not need to be the same type of collection.
 
     #[cfg(feature = "distance_matrix")]
     #[must_use]
-    pub fn distance_matrix<C, W>(
+    pub fn distance_matrix<O, D>(
         &self,
-        origins: C,
-        destinations: C
+        origins: O,
+        destinations: D
     ) -> crate::distance_matrix::request::Request
     where
-        C: IntoIterator<Item = W>,
-        W: Into<Waypoint> {
-        let origins: Vec<Waypoint> = origins.into_iter().map(Into::into).collect();
-        let destinations: Vec<Waypoint> = destinations.into_iter().map(Into::into).collect();
+        O: IntoIterator,
+        O::Item: Into<Waypoint>,
+        D: IntoIterator,
+        D::Item: Into<Waypoint> {
         crate::distance_matrix::request::Request::new(self, origins, destinations)
     } // fn
 
@@ -208,7 +361,7 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "elevation")]
     #[must_use]
-    pub const fn elevation(&self) -> crate::elevation::request::Request {
+    pub fn elevation(&self) -> crate::elevation::request::Request {
         crate::elevation::request::Request::new(self)
     } // fn
 
@@ -227,10 +380,143 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "geocoding")]
     #[must_use]
-    pub const fn geocoding(&self) -> crate::geocoding::forward::ForwardRequest {
+    pub fn geocoding(&self) -> crate::geocoding::forward::ForwardRequest {
         crate::geocoding::forward::ForwardRequest::new(self)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Geocodes a street address. This is a shortcut for `geocoding` followed
+    /// by `with_address`, and returns the same request type, so any other
+    /// parameters may still be set before it is executed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` ‧ The street address or plus code that you want to geocode.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let location = google_maps_client
+    ///     .geocode("10 Downing Street London")
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "geocoding")]
+    #[must_use]
+    pub fn geocode(
+        &self,
+        address: impl Into<String>
+    ) -> crate::geocoding::forward::ForwardRequest {
+        let mut request = crate::geocoding::forward::ForwardRequest::new(self);
+        request.with_address(address);
+        request
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Geocodes a street address, and returns the coordinates of the first
+    /// result. Returns `None` if Google could not find the address (that is,
+    /// the `ZERO_RESULTS` status).
+    ///
+    /// Google may return a result that is only a partial match for the
+    /// address, for example when the street exists but the street number
+    /// does not. Such a result is still returned, but a warning is logged
+    /// with `tracing`. Use `geocode` to inspect the `partial_match` field
+    /// yourself.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` ‧ The street address or plus code that you want to geocode.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// if let Some(latlng) = google_maps_client
+    ///     .geocode_to_latlng("10 Downing Street London")
+    ///     .await?
+    /// {
+    ///     println!("{latlng}");
+    /// }
+    /// ```
+
+    #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+    pub async fn geocode_to_latlng(
+        &self,
+        address: &str
+    ) -> Result<Option<LatLng>, crate::GoogleMapsError> {
+        self.geocode_to_latlng_with(address, None, None).await
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Geocodes a street address, and returns the coordinates of the first
+    /// result, optionally biased towards a bounding box and restricted to a
+    /// country. Returns `None` if Google could not find the address. See
+    /// `geocode_to_latlng` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `address` ‧ The street address or plus code that you want to geocode.
+    ///
+    /// * `bounds` ‧ A bounding box to bias the results towards. Results
+    ///   outside of the bounding box may still be returned.
+    ///
+    /// * `country` ‧ The country to restrict the results to.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let latlng = google_maps_client
+    ///     .geocode_to_latlng_with("Downing Street", None, Some(Country::UnitedKingdom))
+    ///     .await?;
+    /// ```
+
+    #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+    pub async fn geocode_to_latlng_with(
+        &self,
+        address: &str,
+        bounds: Option<crate::types::Bounds>,
+        country: Option<crate::types::Country>,
+    ) -> Result<Option<LatLng>, crate::GoogleMapsError> {
+        let mut request = self.geocode(address);
+
+        if let Some(bounds) = bounds {
+            request.with_bounds(bounds);
+        } // if
+
+        if let Some(country) = country {
+            request.with_component(crate::geocoding::forward::component::Component::Country(country));
+        } // if
+
+        match request.execute().await {
+            Ok(response) => Ok(response.results.into_iter().next().map(|result| {
+                if result.partial_match == Some(true) {
+                    tracing::warn!(
+                        "`{address}` is only a partial match for `{}`",
+                        result.formatted_address
+                    );
+                } // if
+                result.geometry.location
+            })),
+            // Google could not find the address:
+            Err(crate::GoogleMapsError::Geocoding(crate::geocoding::error::Error::GoogleMapsService(
+                crate::geocoding::response::status::Status::ZeroResults,
+                _,
+            ))) => Ok(None),
+            Err(error) => Err(error),
+        } // match
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Geocoding API is a service that provides geocoding and reverse
@@ -254,6 +540,8 @@ impl GoogleMapsClient {
     ///     // 10 Downing St, Westminster, London
     ///     LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?,
     /// )
+    /// .execute()
+    /// .await?;
     /// ```
 
     #[cfg(feature = "geocoding")]
@@ -265,6 +553,91 @@ impl GoogleMapsClient {
         crate::geocoding::reverse::ReverseRequest::new(self, location.into())
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Reverse geocodes a location, and returns the formatted address of the
+    /// result that best describes a street address. Returns `None` if Google
+    /// could not find an address for the location (that is, the
+    /// `ZERO_RESULTS` status).
+    ///
+    /// The request is filtered to the street address-like result types in
+    /// `geocoding::response::Response::ADDRESS_TYPES`. The best result is
+    /// chosen by `Response::best_address`: `ROOFTOP` results are preferred,
+    /// then results are ranked by their result type.
+    ///
+    /// ## Arguments
+    ///
+    /// * `latlng` ‧ The latitude and longitude values specifying the location
+    ///   for which you wish to obtain the closest, human-readable address.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// if let Some(address) = google_maps_client
+    ///     // 10 Downing St, Westminster, London
+    ///     .reverse_geocode_to_address(LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?)
+    ///     .await?
+    /// {
+    ///     println!("{address}");
+    /// }
+    /// ```
+
+    #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+    pub async fn reverse_geocode_to_address(
+        &self,
+        latlng: impl Into<LatLng>
+    ) -> Result<Option<String>, crate::GoogleMapsError> {
+        Ok(self
+            .reverse_geocode_best(latlng)
+            .await?
+            .map(|geocoding| geocoding.formatted_address))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reverse geocodes a location, and returns the whole result that best
+    /// describes a street address, for callers that need its address
+    /// components or geometry. Returns `None` if Google could not find an
+    /// address for the location. See `reverse_geocode_to_address` for more
+    /// information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `latlng` ‧ The latitude and longitude values specifying the location
+    ///   for which you wish to obtain the closest, human-readable address.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let geocoding = google_maps_client
+    ///     .reverse_geocode_best(LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?)
+    ///     .await?;
+    /// ```
+
+    #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+    pub async fn reverse_geocode_best(
+        &self,
+        latlng: impl Into<LatLng>
+    ) -> Result<Option<crate::geocoding::response::geocoding::Geocoding>, crate::GoogleMapsError> {
+        let mut request = self.reverse_geocoding(latlng);
+        request.with_result_types(crate::geocoding::response::Response::ADDRESS_TYPES);
+
+        match request.execute().await {
+            Ok(response) => Ok(response.best_address().cloned()),
+            // Google could not find an address for the location:
+            Err(crate::GoogleMapsError::Geocoding(crate::geocoding::error::Error::GoogleMapsService(
+                crate::geocoding::response::status::Status::ZeroResults,
+                _,
+            ))) => Ok(None),
+            Err(error) => Err(error),
+        } // match
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The **Time Zone API** provides time offset data for locations on the
@@ -294,6 +667,8 @@ impl GoogleMapsClient {
     ///      // Tuesday February 23, 2020 @ 6:00:00 pm
     ///      NaiveDate::from_ymd(2020, 2, 23).and_hms(18, 00, 0)
     /// )
+    /// .execute()
+    /// .await?;
     /// ```
 
     #[cfg(feature = "time_zone")]
@@ -306,6 +681,54 @@ impl GoogleMapsClient {
         crate::time_zone::request::Request::new(self, location.into(), timestamp.into())
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// Looks up the time zone in effect at a location at a specific time, and
+    /// returns its IANA ID, its total offset from UTC, and the parsed `Tz`.
+    /// Returns `None` if the location has no time zone, such as a point over
+    /// water (that is, the `ZERO_RESULTS` status).
+    ///
+    /// ## Arguments
+    ///
+    /// * `latlng` ‧ Latitude & longitude of the desired time zone location.
+    ///
+    /// * `when` ‧ The time is used to determine if Daylight Savings is
+    ///   applicable.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// if let Some(time_zone) = google_maps_client
+    ///     // St. Vitus Cathedral in Prague, Czechia
+    ///     .time_zone_at(LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512))?, Utc::now())
+    ///     .await?
+    /// {
+    ///     println!("{} is {} seconds from UTC", time_zone.time_zone_id, time_zone.offset.num_seconds());
+    /// }
+    /// ```
+
+    #[cfg(all(feature = "time_zone", feature = "reqwest"))]
+    pub async fn time_zone_at(
+        &self,
+        latlng: impl Into<LatLng>,
+        when: DateTime<Utc>,
+    ) -> Result<Option<crate::time_zone::response::local_time_zone::LocalTimeZone>, crate::GoogleMapsError> {
+        match self.time_zone(latlng, when).execute().await {
+            Ok(response) => Ok(response.local_time_zone()),
+            // The location has no time zone:
+            Err(crate::GoogleMapsError::TimeZone(crate::time_zone::error::Error::GoogleMapsService(
+                crate::time_zone::response::status::Status::ZeroResults,
+                _,
+            ))) => Ok(None),
+            Err(error) => Err(error),
+        } // match
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Places API **Place Autocomplete** service returns place predictions.
@@ -675,11 +1098,262 @@ impl GoogleMapsClient {
         crate::roads::snap_to_roads::request::Request::new(self, points)
     } // fn
 
+    /// Performs an HTTP get request for the URL using the client's
+    /// `reqwest_client`.
+    ///
+    /// If a circuit breaker or a retry budget has been set, the outcome of the
+    /// request is recorded with it.
+
     #[cfg(feature = "reqwest")]
     pub async fn get_request(&self, url: &str) -> Result<Response, crate::ReqError> {
-        match self.reqwest_client.get(url).build() {
+        self.get_request_with_language(url, None).await
+    } // fn
+
+    /// Performs an HTTP get request for the URL like `get_request`, and sends
+    /// the `language` code in the `Accept-Language` header. Some Google endpoints
+    /// honour the header rather than the `language` query parameter.
+    ///
+    /// Requests pass the same language that they put in the query string, that
+    /// is the language set with `with_language`, or else the client's
+    /// `default_language`. If there is no language, no header is sent.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn get_request_with_language(
+        &self,
+        url: &str,
+        language: Option<&str>,
+    ) -> Result<Response, crate::ReqError> {
+        let response = self.send_request(url, language).await;
+
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            circuit_breaker.record(&response);
+        } // if
+
+        if let Some(retry_budget) = &self.retry_budget {
+            retry_budget.record(&response);
+        } // if
+
+        response
+    } // fn
+
+    /// Returns a `CircuitOpen` error if a circuit breaker has been set and it
+    /// is not letting requests through. This is checked before the rate
+    /// limiter, so that a request that will not be sent does not wait.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn check_circuit_breaker(&self) -> Result<(), crate::error::Error> {
+        match &self.circuit_breaker {
+            Some(circuit_breaker) if !circuit_breaker.allow_request() => Err(crate::error::Error::CircuitOpen),
+            _ => Ok(()),
+        } // match
+    } // fn
+
+    /// Returns a `CassetteMiss` error if a cassette in replay mode has been set
+    /// and it has no recorded response for the URL. This is checked before the
+    /// rate limiter, so that a request that can not be replayed does not wait.
+
+    #[cfg(feature = "vcr")]
+    pub(crate) fn check_cassette(&self, url: &str) -> Result<(), crate::error::Error> {
+        self.cassette
+            .as_ref()
+            .map_or(Ok(()), |cassette| cassette.check(url))
+    } // fn
+
+    /// Runs `operation` until it succeeds, it fails with a permanent error, or
+    /// the retry policy gives up. The policy is exponential backoff, which
+    /// stops retrying if the circuit breaker opens or the retry budget is
+    /// exhausted. A transient error that was not retried because the retry
+    /// budget is exhausted is returned as a `RetryBudgetExhausted` error.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn retry<T, E, F, Fut>(&self, operation: F) -> Result<T, crate::error::Error>
+    where
+        E: Into<crate::error::Error>,
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, backoff::Error<E>>>,
+    {
+        let budget_exhausted = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+
+        let backoff = crate::circuit_breaker::CircuitBackoff {
+            backoff: backoff::ExponentialBackoff::default(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            retry_budget: self.retry_budget.clone(),
+            budget_exhausted: budget_exhausted.clone(),
+        }; // CircuitBackoff
+
+        backoff::future::retry(backoff, operation).await.map_err(|error| {
+            if budget_exhausted.load(std::sync::atomic::Ordering::Relaxed) {
+                crate::error::Error::RetryBudgetExhausted(Box::new(error.into()))
+            } else {
+                error.into()
+            } // if
+        }) // map_err
+    } // fn
+
+    /// Starts auditing a request to the `api` API. The details of each attempt
+    /// are collected, and recorded with the audit sink when the request
+    /// finishes. Does nothing if no audit sink has been set.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn audit(&self, api: &'static str, url: &str) -> crate::audit::Audit {
+        crate::audit::Audit::new(self.audit_sink.clone(), api, url)
+    } // fn
+
+    /// Sends an HTTP get request for the URL, or replays it from the cassette.
+    /// See `get_request`.
+
+    #[cfg(feature = "reqwest")]
+    async fn send_request(
+        &self,
+        url: &str,
+        language: Option<&str>,
+    ) -> Result<Response, crate::ReqError> {
+        // The request may be sent to another host, such as a local mock
+        // server, instead of Google:
+        let mut request = self.reqwest_client.get(&*rebase(self.base_url.as_deref(), url));
+
+        if let Some(language) = language {
+            request = request.header(reqwest::header::ACCEPT_LANGUAGE, language);
+        } // if
+
+        // A cassette in replay mode answers the request instead of Google:
+        #[cfg(feature = "vcr")]
+        if let Some(response) = self.cassette.as_ref().and_then(|cassette| cassette.play(url)) {
+            return Ok(response);
+        } // if
+
+        let response = match request.build() {
             Ok(request) => self.reqwest_client.execute(request).await,
             Err(error) => Err(crate::ReqError::from(error)),
-        }
-    }
+        }; // match
+
+        // A cassette in record mode keeps a copy of Google's response:
+        #[cfg(feature = "vcr")]
+        if let Some(cassette) = &self.cassette {
+            return cassette.tape(url, response?).await;
+        } // if
+
+        response
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Replaces the scheme and host of a request URL with `base_url`, if set.
+
+#[cfg(feature = "reqwest")]
+fn rebase<'a>(base_url: Option<&str>, url: &'a str) -> std::borrow::Cow<'a, str> {
+    let Some(base_url) = base_url else { return std::borrow::Cow::Borrowed(url) };
+    let path = url
+        .split_once("://")
+        .and_then(|(_scheme, rest)| rest.find('/').map(|start| &rest[start..]))
+        .unwrap_or("/");
+    std::borrow::Cow::Owned(format!("{base_url}{path}"))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest", feature = "geocoding"))]
+mod tests {
+    use crate::client::mock_server::MockServer;
+    use crate::{GoogleMapsClient, Language};
+
+    #[tokio::test]
+    async fn sends_the_language_in_the_query_and_the_header() {
+        let server = MockServer::replaying(&["geocoding"]);
+        let mut client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        client.base_url = Some(server.base_url().to_string());
+
+        // No language at all:
+        client.geocoding().with_address("Mountain View").execute().await.unwrap();
+
+        // A language for one request:
+        client
+            .geocoding()
+            .with_address("Mountain View")
+            .with_language(Language::PortugueseBrazil)
+            .execute()
+            .await
+            .unwrap();
+
+        // The client's default language, for every request:
+        client.with_default_language(Language::French);
+        client.geocoding().with_address("Mountain View").execute().await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+
+        assert!(!requests[0].path.contains("language="), "{}", requests[0].path);
+        assert_eq!(requests[0].header("accept-language"), None);
+
+        assert!(requests[1].path.contains("&language=pt-BR"), "{}", requests[1].path);
+        assert_eq!(requests[1].header("accept-language"), Some("pt-BR"));
+
+        assert!(requests[2].path.contains("&language=fr"), "{}", requests[2].path);
+        assert_eq!(requests[2].header("accept-language"), Some("fr"));
+    } // fn
+
+    #[cfg(all(feature = "directions", feature = "time_zone"))]
+    #[tokio::test]
+    async fn one_shot_requests_match_the_explicit_flow() {
+        use crate::directions::Location;
+        use crate::LatLng;
+
+        let server = MockServer::replaying(&["directions", "geocoding", "time_zone"]);
+        let mut client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        client.base_url = Some(server.base_url().to_string());
+        let downing_street = LatLng::try_from_f64(51.503_364, -0.127_625).unwrap();
+        let noon = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        // Each request is sent twice: with `execute`, and with the explicit
+        // `validate`, `build` and `get` chain that it replaces.
+        let one_shot = client
+            .directions(Location::from_address("Toronto"), Location::from_address("Montreal"))
+            .execute()
+            .await
+            .unwrap();
+        let explicit = client
+            .directions(Location::from_address("Toronto"), Location::from_address("Montreal"))
+            .validate()
+            .unwrap()
+            .build()
+            .unwrap()
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(one_shot, explicit);
+
+        let one_shot = client.geocode("10 Downing Street London").execute().await.unwrap();
+        let explicit = client
+            .geocoding()
+            .with_address("10 Downing Street London")
+            .validate()
+            .unwrap()
+            .build()
+            .unwrap()
+            .get()
+            .await
+            .unwrap();
+        assert_eq!(one_shot, explicit);
+
+        let one_shot = client.reverse_geocoding(downing_street).execute().await.unwrap();
+        let explicit = client.reverse_geocoding(downing_street).build().get().await.unwrap();
+        assert_eq!(one_shot, explicit);
+
+        let one_shot = client.time_zone(downing_street, noon).execute().await.unwrap();
+        let explicit = client.time_zone(downing_street, noon).build().get().await.unwrap();
+        assert_eq!(one_shot, explicit);
+
+        // Both flows sent the same URLs:
+        let requests = server.requests();
+        assert_eq!(requests.len(), 8);
+        for pair in requests.chunks(2) {
+            assert_eq!(pair[0].path, pair[1].path);
+        } // for
+        assert!(requests[0].path.starts_with("/maps/api/directions/json?"), "{}", requests[0].path);
+        assert!(requests[2].path.contains("&address=10%20Downing%20Street%20London"), "{}", requests[2].path);
+        assert!(requests[4].path.contains("&latlng=51.503364,-0.127625"), "{}", requests[4].path);
+        assert!(requests[6].path.contains("&timestamp=1700000000"), "{}", requests[6].path);
+    } // fn
+} // mod
@@ -0,0 +1,158 @@
+//! A mock HTTP server for tests that need to see the requests that the client
+//! sends, such as their headers. A client sends its requests to the mock
+//! server instead of Google once its `base_url` is set to the server's.
+
+use serde::Deserialize;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+// -----------------------------------------------------------------------------
+//
+/// A request that the mock server received.
+
+#[derive(Clone, Debug)]
+pub struct Request {
+    /// The request's path and query string, such as
+    /// `/maps/api/geocode/json?key=...`.
+    pub path: String,
+    /// The request's headers. Header names are lowercase.
+    pub headers: Vec<(String, String)>,
+} // struct
+
+impl Request {
+    /// Returns the value of the header with the given lowercase name.
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _value)| header == name)
+            .map(|(_header, value)| value.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A mock HTTP server, listening on a local port in a background thread. It
+/// records every request, and responds with the JSON body that its `respond`
+/// function returns for the request's path.
+
+pub struct MockServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+} // struct
+
+impl MockServer {
+    /// Starts a mock server that responds to each request with `200 OK` and
+    /// the JSON body returned by `respond` for the request's path.
+
+    pub fn start(respond: impl Fn(&str) -> String + Send + 'static) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().map_while(Result::ok) {
+                let Some(request) = read_request(&mut stream) else { continue };
+                let body = respond(&request.path);
+                recorded.lock().unwrap().push(request);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\n\
+                    Content-Type: application/json; charset=UTF-8\r\n\
+                    Content-Length: {}\r\n\
+                    Connection: close\r\n\r\n{body}",
+                    body.len(),
+                );
+            } // for
+        }); // spawn
+
+        Self { base_url, requests }
+    } // fn
+
+    /// Starts a mock server that responds with the bodies recorded in the
+    /// cassettes with the given names, from the `tests/cassettes` directory.
+    /// A request is answered with the first recorded response for the same
+    /// path, whatever its query string.
+
+    pub fn replaying(cassettes: &[&str]) -> Self {
+        /// The parts of a cassette's recorded interaction that are replayed.
+        #[derive(Deserialize)]
+        struct Interaction {
+            url: String,
+            body: String,
+        } // struct
+
+        let interactions: Vec<(String, String)> = cassettes
+            .iter()
+            .flat_map(|cassette| {
+                let path = format!("{}/tests/cassettes/{cassette}.json", env!("CARGO_MANIFEST_DIR"));
+                let json = std::fs::read_to_string(path).unwrap();
+                crate::json::parse_json_str::<Vec<Interaction>>(&json).unwrap()
+            }) // flat_map
+            .map(|interaction| (path_of(path_and_query(&interaction.url)).to_string(), interaction.body))
+            .collect();
+
+        Self::start(move |path| {
+            interactions
+                .iter()
+                .find(|(recorded, _body)| recorded == path_of(path))
+                .map(|(_path, body)| body.clone())
+                .unwrap_or_default()
+        }) // start
+    } // fn
+
+    /// Returns the mock server's scheme and host, such as
+    /// `http://127.0.0.1:12345`, for the client's `base_url`.
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    } // fn
+
+    /// Returns the requests that the mock server has received so far.
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a URL's path and query string, without its scheme and host.
+
+fn path_and_query(url: &str) -> &str {
+    url.split_once("://")
+        .and_then(|(_scheme, rest)| rest.find('/').map(|start| &rest[start..]))
+        .unwrap_or("/")
+} // fn
+
+/// Returns a request path without its query string.
+
+fn path_of(path: &str) -> &str {
+    path.split_once('?').map_or(path, |(path, _query)| path)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the request line and headers of an HTTP request. The client only
+/// sends `GET` requests, so there is no body to read.
+
+fn read_request(stream: &mut impl Read) -> Option<Request> {
+    let mut head = Vec::new();
+    let mut byte = [0; 1];
+    while !head.ends_with(b"\r\n\r\n") {
+        stream.read_exact(&mut byte).ok()?;
+        head.push(byte[0]);
+    } // while
+
+    let head = String::from_utf8(head).ok()?;
+    let mut lines = head.split("\r\n");
+    let path = lines.next()?.split(' ').nth(1)?.to_string();
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    Some(Request { path, headers })
+} // fn
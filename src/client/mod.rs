@@ -6,13 +6,51 @@
 
 mod build;
 mod impls;
+#[cfg(all(test, feature = "reqwest", feature = "geocoding"))]
+pub mod mock_server;
+mod with_coordinate_precision;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places",
+    feature = "time_zone"
+))]
+mod with_default_language;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places"
+))]
+mod with_default_region;
+#[cfg(feature = "reqwest")]
+mod with_audit_sink;
+#[cfg(feature = "vcr")]
+mod with_cassette;
+#[cfg(feature = "reqwest")]
+mod with_circuit_breaker;
+#[cfg(feature = "reqwest")]
+mod with_default_header;
+#[cfg(feature = "reqwest")]
+mod with_pool;
 #[cfg(feature = "reqwest")]
 mod with_rate;
 #[cfg(feature = "reqwest")]
+mod with_response_body_capture;
+#[cfg(feature = "reqwest")]
+mod with_retry_budget;
+#[cfg(feature = "reqwest")]
 mod with_reqwest_client;
+#[cfg(feature = "reqwest")]
+mod with_throttle_hook;
 
 // -----------------------------------------------------------------------------
 
+pub use crate::client::with_coordinate_precision::Coordinates;
+
 #[cfg(feature = "reqwest")]
 use crate::request_rate::RequestRate;
 
@@ -35,18 +73,138 @@ use crate::request_rate::RequestRate;
 ///     .with_rate(&Api::All, 1, std::time::Duration::from_secs(2))
 ///     .build();
 /// ```
+///
+/// ## Sharing the client
+///
+/// The client is `Clone + Send + Sync`. Cloning it is cheap: the API key, the
+/// `reqwest` client, the rate limiters and every other setting sit behind a
+/// single `Arc` that all clones share, so rate limits are observed across all
+/// of them. Requests own a clone of the client rather than borrowing it, so
+/// neither the client nor a request forces a lifetime onto your code. A client may be kept in your web
+/// framework's application state and cloned into each handler or task:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?.build();
+///
+/// let handles: Vec<_> = ["Sydney", "Melbourne", "Brisbane"]
+///     .into_iter()
+///     .map(|city| {
+///         let google_maps_client = google_maps_client.clone();
+///         tokio::spawn(async move {
+///             google_maps_client.geocoding().with_address(city).execute().await
+///         })
+///     })
+///     .collect();
+/// ```
+///
+/// ## Cancellation
+///
+/// Requests may be cancelled by dropping their future, for example when a
+/// `tokio::time::timeout` expires or an HTTP handler is abandoned. Nothing
+/// keeps running in the background: the client spawns no tasks, the HTTP
+/// request in flight is aborted, any wait for a retry ends, and a request's
+/// turn at the rate limiter is handed to the next waiter.
+///
+/// With the `cancellation` feature, each request also has a
+/// `get_with_cancellation` method that takes a `tokio_util`
+/// `CancellationToken`. It returns an `Error::Cancelled` error when the token
+/// is cancelled.
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct GoogleMapsClient {
+    /// The client's settings and shared state. Every clone of the client
+    /// points to the same `Inner`, so cloning the client and creating requests
+    /// is cheap. Changing a setting on a clone, for example with `with_rate()`,
+    /// gives that clone its own copy of the settings first.
+    inner: std::sync::Arc<Inner>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The settings and shared state of a `GoogleMapsClient`. The client
+/// dereferences to this structure, so these fields may be read directly from
+/// the client, for example `google_maps_client.key`.
+
+#[derive(Clone)]
+pub struct Inner {
     /// Your application's API key. This key identifies your application for
     /// purposes of quota management. Learn how to [get a
     /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
     pub key: String,
 
+    /// The language that results are returned in when a request does not set
+    /// one. Defaults to `None`, which leaves the choice to Google. A language
+    /// set on the request itself overrides this client default. See the
+    /// `with_default_language()` method for more information.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places",
+        feature = "time_zone"
+    ))]
+    pub default_language: Option<crate::types::Language>,
+
+    /// The region that results are biased towards when a request does not set
+    /// one. Defaults to `None`, which is no region bias. A region set on the
+    /// request itself overrides this client default. See the
+    /// `with_default_region()` method for more information.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places"
+    ))]
+    pub default_region: Option<crate::types::Region>,
+
+    /// The number of decimal places that coordinates are rounded to when they
+    /// are used in a query. Defaults to `None`, which is full precision. See
+    /// the `with_coordinate_precision()` method for more information.
+    pub coordinate_precision: Option<u8>,
+
     /// Rate limits for each of the Google Cloud Maps Platform APIs.
     #[cfg(feature = "reqwest")]
     pub rate_limit: RequestRate,
 
+    /// Whether the beginning of the response body should be included in errors
+    /// when a response can not be parsed. Defaults to `true`. See the
+    /// `with_response_body_capture()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub capture_response_body: bool,
+
+    /// The maximum number of idle connections to keep open to each host, for
+    /// the `reqwest` client that the Google Maps client builds. Defaults to
+    /// `None`, which is `reqwest`'s default. See the
+    /// `with_pool_max_idle_per_host()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+
+    /// How long idle connections are kept open, for the `reqwest` client that
+    /// the Google Maps client builds. Defaults to `None`, which is `reqwest`'s
+    /// default. See the `with_pool_idle_timeout()` method for more
+    /// information.
+    #[cfg(feature = "reqwest")]
+    pub(crate) pool_idle_timeout: Option<std::time::Duration>,
+
+    /// Headers that are sent with every request, by the `reqwest` client that
+    /// the Google Maps client builds. Defaults to empty. See the
+    /// `with_default_header()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub(crate) default_headers: reqwest::header::HeaderMap,
+
+    /// The scheme and host that requests are sent to instead of Google's, such
+    /// as `http://127.0.0.1:12345`. Defaults to `None`, which sends requests to
+    /// Google. Tests set this to send requests to a local mock server.
+    #[cfg(feature = "reqwest")]
+    pub(crate) base_url: Option<String>,
+
+    /// Whether the `reqwest` client was provided by the user, rather than
+    /// built by the Google Maps client. A provided client is never rebuilt.
+    #[cfg(feature = "reqwest")]
+    pub(crate) reqwest_client_provided: bool,
+
     /// Allows you to optionally provide your own pre-configured reqwest client
     /// that will be used by the Google Maps client.
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
@@ -56,4 +214,185 @@ pub struct GoogleMapsClient {
     /// that will be used by the Google Maps client.
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub reqwest_client: reqwest::Client,
+
+    /// Stops requests from being sent for a while after repeated failures
+    /// from Google's servers, if set. Defaults to `None`. See the
+    /// `with_circuit_breaker()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub circuit_breaker: Option<std::sync::Arc<crate::circuit_breaker::CircuitBreaker>>,
+
+    /// Limits the automatic retries of every request, if set. Defaults to
+    /// `None`. See the `with_retry_budget()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub retry_budget: Option<std::sync::Arc<crate::retry_budget::RetryBudget>>,
+
+    /// Receives an audit entry for every request that the client makes, if
+    /// set. Defaults to `None`. See the `with_audit_sink()` method for more
+    /// information.
+    #[cfg(feature = "reqwest")]
+    pub audit_sink: Option<std::sync::Arc<dyn crate::audit::AuditSink>>,
+
+    /// Records or replays every request that the client makes, if set. See
+    /// the `with_cassette()` method for more information.
+    #[cfg(feature = "vcr")]
+    pub cassette: Option<std::sync::Arc<crate::cassette::Cassette>>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::ops::Deref for GoogleMapsClient {
+    type Target = Inner;
+    /// Returns the client's settings and shared state.
+    fn deref(&self) -> &Inner {
+        &self.inner
+    } // fn
+} // impl
+
+impl std::ops::DerefMut for GoogleMapsClient {
+    /// Returns the client's settings and shared state for changing. If other
+    /// clones of this client share them, this client gets its own copy first,
+    /// so the other clones are not changed.
+    fn deref_mut(&mut self) -> &mut Inner {
+        std::sync::Arc::make_mut(&mut self.inner)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for GoogleMapsClient {
+    /// Formats the client for debugging. The API key is redacted so that it
+    /// does not end up in logs.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug_struct = f.debug_struct("GoogleMapsClient");
+        debug_struct.field("key", &"REDACTED");
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places",
+            feature = "time_zone"
+        ))]
+        debug_struct.field("default_language", &self.default_language);
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places"
+        ))]
+        debug_struct.field("default_region", &self.default_region);
+        debug_struct.field("coordinate_precision", &self.coordinate_precision);
+        #[cfg(feature = "reqwest")]
+        debug_struct
+            .field("rate_limit", &self.rate_limit)
+            .field("capture_response_body", &self.capture_response_body)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout)
+            .field("default_headers", &self.default_headers)
+            .field("base_url", &self.base_url)
+            .field("reqwest_client_provided", &self.reqwest_client_provided)
+            .field("reqwest_client", &self.reqwest_client)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("retry_budget", &self.retry_budget)
+            .field("audit_sink", &self.audit_sink.as_ref().map(|_audit_sink| "AuditSink"));
+        #[cfg(feature = "vcr")]
+        debug_struct.field("cassette", &self.cassette);
+        debug_struct.finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+// The client is shared across threads and tasks. Fail to compile if a field
+// ever stops it from being `Clone + Send + Sync`:
+
+const _: () = {
+    const fn assert_shareable<T: Clone + Send + Sync>() {}
+    assert_shareable::<GoogleMapsClient>();
+};
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::GoogleMapsClient;
+
+    #[test]
+    fn clones_share_their_settings_until_one_is_changed() {
+        let client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000")
+            .unwrap()
+            .build();
+        let mut clone = client.clone();
+        assert!(std::sync::Arc::ptr_eq(&client.inner, &clone.inner));
+
+        clone.with_coordinate_precision(5);
+        assert!(!std::sync::Arc::ptr_eq(&client.inner, &clone.inner));
+        assert_eq!(client.coordinate_precision, None);
+        assert_eq!(clone.coordinate_precision, Some(5));
+    } // fn
+
+    #[cfg(all(feature = "time_zone", feature = "vcr"))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn one_client_is_shared_across_spawned_tasks() {
+        // The cassette has four responses to the same time zone request, and
+        // each is replayed once:
+        let client = crate::cassette::tests::replay_client("shared_client");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_task| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    client
+                        .time_zone(
+                            crate::LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                            chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+                        )
+                        .execute()
+                        .await
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let response = handle.await.unwrap().unwrap();
+            assert_eq!(response.time_zone_id.unwrap().name(), "America/Los_Angeles");
+        } // for
+
+        // Every task used the same cassette as the original client:
+        assert_eq!(client.cassette.as_ref().unwrap().remaining(), 0);
+    } // fn
+
+    #[cfg(all(feature = "time_zone", feature = "vcr"))]
+    #[tokio::test(flavor = "multi_thread")]
+    async fn clones_in_spawned_tasks_share_the_rate_limiter() {
+        use crate::request_rate::api::Api;
+        use std::time::{Duration, Instant};
+
+        // One request per 100 milliseconds, for all clones together:
+        let mut client = crate::cassette::tests::replay_client("shared_client");
+        client.with_rate(&Api::All, 1, Duration::from_millis(100));
+
+        let start = Instant::now();
+        let handles: Vec<_> = (0..3)
+            .map(|_task| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    client
+                        .time_zone(
+                            crate::LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                            chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+                        )
+                        .execute()
+                        .await
+                })
+            })
+            .collect();
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
+        } // for
+
+        // The second and third requests waited for the first and second:
+        assert!(start.elapsed() >= Duration::from_millis(180), "{:?}", start.elapsed());
+    } // fn
+} // mod
@@ -0,0 +1,38 @@
+use crate::{audit::AuditSink, client::GoogleMapsClient};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a sink that receives an `AuditEntry` for every request the client
+    /// makes. This may be used to keep an audit trail, for example to
+    /// reconcile Google's bills.
+    ///
+    /// Each entry has the time, API, URL, HTTP status, Google status, response
+    /// size, latency and retry count of one request. It is recorded once the
+    /// request has finished, after any automatic retries. Entries never
+    /// contain the API key or the response body.
+    ///
+    /// The sink is shared by clones of the client.
+    ///
+    /// ## Arguments
+    ///
+    /// * `audit_sink` ‧ Any type that implements the `AuditSink` trait, such
+    ///   as `FileAuditSink` or `ChannelAuditSink`, or a closure with the
+    ///   signature `Fn(AuditEntry)`.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_audit_sink(FileAuditSink::new("google_maps_audit.jsonl")?)
+    ///     .build();
+    /// ```
+
+    pub fn with_audit_sink(&mut self, audit_sink: impl AuditSink + 'static) -> &mut Self {
+        self.audit_sink = Some(Arc::new(audit_sink));
+        self
+    } // fn
+} // impl
@@ -0,0 +1,35 @@
+use crate::{cassette::Cassette, client::GoogleMapsClient};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a cassette that records or replays every request the client
+    /// makes. In record mode, requests are sent to Google as usual, and each
+    /// request URL (with the API key removed) and response is appended to the
+    /// cassette file. In replay mode, requests are answered from the cassette
+    /// file, so that tests may run without an API key or network access.
+    ///
+    /// Rate limiting and retries still apply in replay mode. For fast tests,
+    /// consider leaving the client's rate limits at their defaults.
+    ///
+    /// ## Arguments
+    ///
+    /// * `cassette` ‧ The cassette to record to or replay from. See
+    ///   `Cassette::from_env` to choose the mode with the `GOOGLE_MAPS_RECORD`
+    ///   environment variable.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_cassette(Cassette::replay("tests/cassettes/geocoding.json")?)
+    ///     .build();
+    /// ```
+
+    pub fn with_cassette(&mut self, cassette: Cassette) -> &mut Self {
+        self.cassette = Some(std::sync::Arc::new(cassette));
+        self
+    } // fn
+} // impl
@@ -0,0 +1,49 @@
+use crate::{circuit_breaker::CircuitBreaker, client::GoogleMapsClient};
+use std::{sync::Arc, time::Duration};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a circuit breaker that stops requests from being sent for a while
+    /// when Google's servers keep failing. Without one, every request burns
+    /// its full retry budget during an outage, which adds to the load.
+    ///
+    /// After `failure_threshold` consecutive transient failures within
+    /// `window`, requests fail immediately with a `CircuitOpen` error for
+    /// `cooldown`. Then a single trial request is let through: if it succeeds
+    /// requests resume, otherwise they are paused for another `cooldown`. See
+    /// `CircuitBreaker` for more information.
+    ///
+    /// The circuit breaker is shared by clones of the client.
+    ///
+    /// ## Arguments
+    ///
+    /// * `failure_threshold` ‧ How many consecutive transient failures open
+    ///   the circuit. Automatic retries count as attempts.
+    ///
+    /// * `window` ‧ The failures must all happen within this duration of the
+    ///   first one.
+    ///
+    /// * `cooldown` ‧ How long requests are paused for before a trial request
+    ///   is let through.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_circuit_breaker(5, Duration::from_secs(30), Duration::from_secs(60))
+    ///     .build();
+    /// ```
+
+    pub fn with_circuit_breaker(
+        &mut self,
+        failure_threshold: u32,
+        window: Duration,
+        cooldown: Duration,
+    ) -> &mut Self {
+        self.circuit_breaker = Some(Arc::new(CircuitBreaker::new(failure_threshold, window, cooldown)));
+        self
+    } // fn
+} // impl
@@ -0,0 +1,61 @@
+use crate::client::GoogleMapsClient;
+use std::borrow::Cow;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the number of decimal places that coordinates are rounded to when
+    /// they are used in a query. By default, coordinates are sent with their
+    /// full precision.
+    ///
+    /// Rounding keeps URLs short, and makes requests for nearby points
+    /// identical, which increases cache hits. Five decimal places is roughly
+    /// one metre of precision. Coordinates given as encoded polylines, or as
+    /// `geo` crate types, are sent unchanged.
+    ///
+    /// ## Arguments
+    ///
+    /// * `decimals` ‧ The number of decimal places to round latitudes and
+    ///   longitudes to.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_coordinate_precision(5)
+    ///     .build();
+    /// ```
+
+    pub fn with_coordinate_precision(&mut self, decimals: u8) -> &mut Self {
+        self.coordinate_precision = Some(decimals);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// Returns the coordinates rounded to the client's coordinate precision,
+    /// or unchanged if no coordinate precision has been set. Unchanged
+    /// coordinates are borrowed rather than copied.
+
+    pub(crate) fn coordinates<'c, C: Coordinates>(&self, coordinates: &'c C) -> Cow<'c, C> {
+        self.coordinate_precision
+            .map_or(Cow::Borrowed(coordinates), |decimals| Cow::Owned(coordinates.rounded(decimals)))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// This trait is not for public consumption. It is for internal use only.
+///
+/// Implemented by types that contain coordinates, so that the client's
+/// coordinate precision can be applied to them when building a query.
+
+pub trait Coordinates: Clone {
+    /// Returns a copy with every latitude & longitude rounded to `decimals`
+    /// decimal places.
+    fn rounded(&self, decimals: u8) -> Self;
+} // trait
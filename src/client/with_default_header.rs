@@ -0,0 +1,138 @@
+use crate::client::GoogleMapsClient;
+use reqwest::header::{HeaderName, HeaderValue};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a header that is sent with every request, including retries. This
+    /// may be used to authenticate with an egress proxy, for example.
+    ///
+    /// The header is applied by rebuilding the `reqwest` client that the
+    /// Google Maps client built when it was created, so the connection pool
+    /// settings are kept. It is ignored if a `reqwest` client has been
+    /// provided with `with_reqwest_client()` or a similar method. In that
+    /// case, set the header with `reqwest::ClientBuilder::default_headers()`
+    /// on the provided client instead.
+    ///
+    /// Setting the same header twice replaces the earlier value. The value is
+    /// marked as sensitive, so it is not shown when the client is printed with
+    /// `{:?}`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` ‧ The name of the header.
+    ///
+    /// * `value` ‧ The value of the header.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// use reqwest::header::{HeaderName, HeaderValue};
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_default_header(
+    ///         HeaderName::from_static("proxy-authorization"),
+    ///         HeaderValue::from_str(&proxy_token)?,
+    ///     )?
+    ///     .build();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the `reqwest` client can not be rebuilt. The
+    ///   client and its default headers are then left unchanged.
+
+    pub fn with_default_header(
+        &mut self,
+        name: HeaderName,
+        mut value: HeaderValue
+    ) -> Result<&mut Self, crate::error::Error> {
+        value.set_sensitive(true);
+        let previous = self.default_headers.insert(name.clone(), value);
+        if let Err(error) = self.rebuild_reqwest_client() {
+            match previous {
+                Some(previous) => self.default_headers.insert(name, previous),
+                None => self.default_headers.remove(name),
+            }; // match
+            return Err(error);
+        } // if
+        Ok(self)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    #[test]
+    fn rebuilds_the_client_with_the_header() {
+        let client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000")
+            .unwrap()
+            .with_default_header(
+                HeaderName::from_static("x-egress-token"),
+                HeaderValue::from_static("secret-token"),
+            )
+            .unwrap()
+            .with_pool_max_idle_per_host(4)
+            .unwrap()
+            .build();
+        let reqwest_client = format!("{:?}", client.reqwest_client);
+        // The header survives a later pool setting, next to the user agent:
+        assert!(reqwest_client.contains("x-egress-token"));
+        assert!(reqwest_client.contains("RustGoogleMaps/"));
+        // The value is not shown when the client is printed:
+        assert!(!format!("{client:?}").contains("secret-token"));
+    } // fn
+
+    #[cfg(all(feature = "directions", feature = "geocoding", feature = "roads"))]
+    #[tokio::test]
+    async fn sends_the_header_with_every_api() {
+        use crate::client::mock_server::MockServer;
+        use crate::directions::request::location::Location;
+        use crate::LatLng;
+
+        let server = MockServer::replaying(&["directions", "geocoding", "roads"]);
+        let mut client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000")
+            .unwrap()
+            .with_default_header(
+                HeaderName::from_static("x-egress-token"),
+                HeaderValue::from_static("secret-token"),
+            )
+            .unwrap()
+            .build();
+
+        client.base_url = Some(server.base_url().to_string());
+
+        client
+            .directions(Location::from_address("Toronto"), Location::from_address("Montreal"))
+            .execute()
+            .await
+            .unwrap();
+        client.geocoding().with_address("Mountain View").execute().await.unwrap();
+        client
+            .snap_to_roads(vec![
+                LatLng::try_from_f64(-35.278_01, 149.129_58).unwrap(),
+                LatLng::try_from_f64(-35.280_32, 149.129_07).unwrap(),
+            ])
+            .execute()
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        let paths: Vec<&str> = requests.iter().map(|request| request.path.as_str()).collect();
+        assert_eq!(requests.len(), 3);
+        assert!(paths[0].starts_with("/maps/api/directions/json?"), "{paths:?}");
+        assert!(paths[1].starts_with("/maps/api/geocode/json?"), "{paths:?}");
+        assert!(paths[2].starts_with("/v1/snapToRoads/?"), "{paths:?}");
+        for request in &requests {
+            assert_eq!(request.header("x-egress-token"), Some("secret-token"), "{}", request.path);
+            // The header is sent next to the client's own headers:
+            assert!(request.header("user-agent").is_some_and(|agent| agent.starts_with("RustGoogleMaps/")));
+        } // for
+    } // fn
+} // mod
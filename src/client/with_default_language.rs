@@ -0,0 +1,34 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Language;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a default language for all requests made with this client.
+    ///
+    /// The default language is used by every request that accepts a language
+    /// (for example, _Directions_, _Geocoding_, _Places_ and _Time Zone_)
+    /// unless that request has its own language set with `with_language`. A
+    /// language set on the request always takes precedence over the client's
+    /// default language.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which to return results by default. See
+    ///   the `Language` enum for the list of supported languages.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_default_language(Language::French)
+    ///     .build();
+    /// ```
+
+    pub fn with_default_language(&mut self, language: impl Into<Language>) -> &mut Self {
+        self.default_language = Some(language.into());
+        self
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Region;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a default region bias for all requests made with this client.
+    ///
+    /// The default region is used by every request that accepts a region (for
+    /// example, _Directions_, _Geocoding_ and _Places_) unless that request
+    /// has its own region set with `with_region`. A region set on the request
+    /// always takes precedence over the client's default region.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region code to bias results towards by default. See
+    ///   the `Region` enum for the list of supported regions.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_default_region(Region::Canada)
+    ///     .build();
+    /// ```
+
+    pub fn with_default_region(&mut self, region: impl Into<Region>) -> &mut Self {
+        self.default_region = Some(region.into());
+        self
+    } // fn
+} // impl
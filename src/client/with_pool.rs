@@ -0,0 +1,157 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of idle connections that are kept open to each
+    /// host. Raising this may help high-throughput batch jobs, which would
+    /// otherwise open new connections to Google's servers.
+    ///
+    /// The setting is applied by rebuilding the `reqwest` client that the
+    /// Google Maps client built when it was created. It is ignored if a
+    /// `reqwest` client has been provided with `with_reqwest_client()` or a
+    /// similar method. In that case, configure the provided client's pool
+    /// instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool_max_idle_per_host` ‧ The maximum number of idle connections to
+    ///   keep open to each host.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_pool_max_idle_per_host(32)?
+    ///     .build();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the `reqwest` client can not be rebuilt. The
+    ///   client and its settings are then left unchanged.
+
+    pub fn with_pool_max_idle_per_host(
+        &mut self,
+        pool_max_idle_per_host: usize
+    ) -> Result<&mut Self, crate::error::Error> {
+        let previous = self.pool_max_idle_per_host.replace(pool_max_idle_per_host);
+        if let Err(error) = self.rebuild_reqwest_client() {
+            self.pool_max_idle_per_host = previous;
+            return Err(error);
+        } // if
+        Ok(self)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how long idle connections are kept open before they are closed.
+    ///
+    /// The setting is applied by rebuilding the `reqwest` client that the
+    /// Google Maps client built when it was created. It is ignored if a
+    /// `reqwest` client has been provided with `with_reqwest_client()` or a
+    /// similar method. In that case, configure the provided client's pool
+    /// instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `pool_idle_timeout` ‧ How long an idle connection is kept open.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_pool_idle_timeout(std::time::Duration::from_secs(90))?
+    ///     .build();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the `reqwest` client can not be rebuilt. The
+    ///   client and its settings are then left unchanged.
+
+    pub fn with_pool_idle_timeout(
+        &mut self,
+        pool_idle_timeout: std::time::Duration
+    ) -> Result<&mut Self, crate::error::Error> {
+        let previous = self.pool_idle_timeout.replace(pool_idle_timeout);
+        if let Err(error) = self.rebuild_reqwest_client() {
+            self.pool_idle_timeout = previous;
+            return Err(error);
+        } // if
+        Ok(self)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Rebuilds the `reqwest` client with the current connection pool and
+    /// default header settings, unless the client was provided by the user.
+    /// If the client can not be built, the error is returned and the current
+    /// client is kept.
+
+    pub(super) fn rebuild_reqwest_client(&mut self) -> Result<(), crate::error::Error> {
+        if self.reqwest_client_provided {
+            tracing::warn!("connection pool and default header settings are ignored for a provided `reqwest` client");
+            return Ok(());
+        } // if
+
+        let mut builder = Self::reqwest_client_builder()
+            .default_headers(self.default_headers.clone());
+
+        if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+        } // if
+
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        } // if
+
+        let reqwest_client = builder.build()?;
+
+        #[cfg(feature = "reqwest-middleware")]
+        let reqwest_client = crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client);
+
+        self.reqwest_client = reqwest_client;
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::GoogleMapsClient;
+    use std::time::Duration;
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap()
+    } // fn
+
+    #[test]
+    fn builds_with_custom_pool_settings() {
+        let client = client()
+            .with_pool_max_idle_per_host(32)
+            .unwrap()
+            .with_pool_idle_timeout(Duration::from_secs(90))
+            .unwrap()
+            .build();
+        assert_eq!(client.pool_max_idle_per_host, Some(32));
+        assert_eq!(client.pool_idle_timeout, Some(Duration::from_secs(90)));
+    } // fn
+
+    #[test]
+    fn keeps_a_provided_client() {
+        let reqwest_client = reqwest::Client::builder()
+            .user_agent("pool-test")
+            .build()
+            .unwrap();
+        let client = client()
+            .with_reqwest_client(reqwest_client)
+            .with_pool_max_idle_per_host(32)
+            .unwrap()
+            .build();
+        assert!(format!("{:?}", client.reqwest_client).contains("pool-test"));
+    } // fn
+} // mod
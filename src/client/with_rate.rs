@@ -8,6 +8,11 @@ impl GoogleMapsClient {
     //
     /// Sets the rate limit for the specified API.
     ///
+    /// Requests that are waiting on the same API's rate limit are sent in the
+    /// order that they were made: first-in, first-out. A request is never
+    /// overtaken by a newer request to the same API, so none are starved under
+    /// sustained load.
+    ///
     /// ## Arguments
     ///
     /// * `api` ‧ Which Google Maps API are you setting the rate limit for? For
@@ -9,6 +9,11 @@ impl GoogleMapsClient {
     /// use. This allows the you to have more control over the how the Google
     /// Maps client connects to the Google Maps server.
     ///
+    /// The provided client is used for every request to every API, including
+    /// retries. Connection pool settings and default headers set on the Google
+    /// Maps client are not applied to it, so configure them on the provided
+    /// client instead.
+    ///
     /// [Mause](https://github.com/Mause) mentioned that this feature could be
     /// useful for writing tests. Thanks for the suggestion!
     ///
@@ -32,12 +37,14 @@ impl GoogleMapsClient {
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
     pub fn with_reqwest_client(&mut self, reqwest_client: reqwest::Client) -> &mut Self {
         self.reqwest_client = crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client);
+        self.reqwest_client_provided = true;
         self
     } // fn
 
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub fn with_reqwest_client(&mut self, reqwest_client: reqwest::Client) -> &mut Self {
         self.reqwest_client = reqwest_client;
+        self.reqwest_client_provided = true;
         self
     } // fn
 
@@ -47,6 +54,7 @@ impl GoogleMapsClient {
         reqwest_client: reqwest_middleware::ClientWithMiddleware
     ) -> &mut Self {
         self.reqwest_client = crate::reqwest_maybe_middleware::Client::Middleware(reqwest_client);
+        self.reqwest_client_provided = true;
         self
     } // fn
 
@@ -56,6 +64,7 @@ impl GoogleMapsClient {
         reqwest_client: crate::reqwest_maybe_middleware::Client
     ) -> &mut Self {
         self.reqwest_client = reqwest_client;
+        self.reqwest_client_provided = true;
         self
     } // fn
 } // impl
@@ -0,0 +1,33 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets whether the beginning of the response body (up to 2 KB) is
+    /// included in the error when a response from Google can not be parsed.
+    /// This is helpful for debugging, but the response body may contain data
+    /// you consider private. Response body capture is enabled by default.
+    ///
+    /// The API key is always removed from the request URL that is included in
+    /// the error.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capture_response_body` ‧ Set to `true` to include a snippet of the
+    ///   response body in parsing errors, or `false` to omit it.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_response_body_capture(false)
+    ///     .build();
+    /// ```
+
+    pub fn with_response_body_capture(&mut self, capture_response_body: bool) -> &mut Self {
+        self.capture_response_body = capture_response_body;
+        self
+    } // fn
+} // impl
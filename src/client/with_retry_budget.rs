@@ -0,0 +1,43 @@
+use crate::{client::GoogleMapsClient, retry_budget::RetryBudget};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a retry budget that limits the automatic retries of every request
+    /// made with this client. Without one, each request retries on its own,
+    /// and a partial outage at Google multiplies the client's traffic just
+    /// when Google's servers are struggling.
+    ///
+    /// Each retry takes a token from the budget, and each successful attempt
+    /// puts `retry_ratio` tokens back, up to `capacity`. A transient failure
+    /// that can not be paid for is not retried: the request fails with a
+    /// `RetryBudgetExhausted` error, which contains the last attempt's error.
+    /// See `RetryBudget` for more information.
+    ///
+    /// The retry budget is shared by clones of the client.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ How many retries may be made in a burst, and in total
+    ///   while every attempt is failing.
+    ///
+    /// * `retry_ratio` ‧ How many retries each successful attempt pays for.
+    ///   For example, `0.1` allows one retry for every 10 successful
+    ///   attempts.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_retry_budget(10, 0.1)
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_budget(&mut self, capacity: u32, retry_ratio: f64) -> &mut Self {
+        self.retry_budget = Some(Arc::new(RetryBudget::new(capacity, retry_ratio)));
+        self
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::{client::GoogleMapsClient, request_rate::throttle_hook::ThrottleHook};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets a hook that is called each time the rate limiter delays a request.
+    /// This may be used to observe how often, and for how long, requests are
+    /// being throttled. For example, to emit Prometheus metrics.
+    ///
+    /// ## Arguments
+    ///
+    /// * `throttle_hook` ‧ Any type that implements the `ThrottleHook` trait,
+    ///   or a closure with the signature `Fn(&Api, Duration)`. The hook is
+    ///   passed the API that delayed the request, and the time spent waiting.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+    ///     .with_rate(&Api::Directions, 1, std::time::Duration::from_secs(2))
+    ///     .with_throttle_hook(|api: &Api, waited: std::time::Duration| {
+    ///         tracing::info!("`{api}` request was throttled for {waited:?}");
+    ///     })
+    ///     .build();
+    /// ```
+
+    pub fn with_throttle_hook(&mut self, throttle_hook: impl ThrottleHook + 'static) -> &mut Self {
+        self.rate_limit.with_throttle_hook(throttle_hook);
+        self
+    } // fn
+} // impl
@@ -0,0 +1,114 @@
+//! Renders requests as equivalent `curl` commands, so that a request may be
+//! reproduced from a terminal or pasted into a bug report.
+
+// -----------------------------------------------------------------------------
+
+/// The environment variable that the API key is read from, in place of the
+/// key itself, in rendered commands.
+const API_KEY_VARIABLE: &str = "$GOOGLE_MAPS_API_KEY";
+
+// -----------------------------------------------------------------------------
+//
+/// Renders an HTTP request as a `curl` command that is safe to share. The API
+/// key in the URL is replaced with a reference to the `GOOGLE_MAPS_API_KEY`
+/// environment variable, and every argument is quoted for POSIX shells.
+///
+/// A `GET` request without headers or a body is rendered as only
+/// `curl 'url'`. Otherwise the method, each header and the body are included:
+///
+/// ```text
+/// curl -X POST 'url' -H 'Content-Type: application/json' --data '{...}'
+/// ```
+
+pub fn to_curl(method: &str, url: &str, headers: &[(&str, &str)], body: Option<&str>) -> String {
+    let mut command = String::from("curl");
+
+    if method != "GET" {
+        command.push_str(" -X ");
+        command.push_str(method);
+    } // if
+
+    command.push(' ');
+    command.push_str(&quote_url(url));
+
+    for (name, value) in headers {
+        command.push_str(" -H ");
+        command.push_str(&quote(&format!("{name}: {value}")));
+    } // for
+
+    if let Some(body) = body {
+        command.push_str(" --data ");
+        command.push_str(&quote(body));
+    } // if
+
+    command
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Quotes a URL for a POSIX shell, replacing the value of its `key` parameter
+/// with a reference to the API key environment variable. The reference is
+/// left outside of the single quotes, so that the shell expands it.
+
+fn quote_url(url: &str) -> String {
+    let url = crate::error::replace_key(url, API_KEY_VARIABLE);
+
+    // Query strings are percent-encoded, so `$` can only appear in the URL as
+    // part of the reference that was just inserted:
+    quote(&url).replace(API_KEY_VARIABLE, &format!("'\"{API_KEY_VARIABLE}\"'"))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Wraps a string in single quotes for a POSIX shell. Single quotes within
+/// the string are closed, escaped, and re-opened.
+
+fn quote(string: &str) -> String {
+    format!("'{}'", string.replace('\'', r"'\''"))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "directions", feature = "reqwest"))]
+mod tests {
+    use super::to_curl;
+    use crate::directions::{request::Request, Location};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn renders_a_directions_request() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let mut request = Request::new(
+            &client,
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // new
+
+        assert_eq!(
+            request.to_curl().unwrap(),
+            "curl 'https://maps.googleapis.com/maps/api/directions/json?key='\"$GOOGLE_MAPS_API_KEY\"'&origin=Toronto&destination=Montreal'",
+        );
+    } // fn
+
+    #[test]
+    fn renders_a_post_request_with_headers_and_a_body() {
+        let command = to_curl(
+            "POST",
+            "https://places.googleapis.com/v1/places:searchText?key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000",
+            &[("Content-Type", "application/json"), ("X-Goog-FieldMask", "places.displayName")],
+            Some(r#"{"textQuery":"Joe's Pizza"}"#),
+        );
+
+        assert_eq!(
+            command,
+            concat!(
+                "curl -X POST ",
+                "'https://places.googleapis.com/v1/places:searchText?key='\"$GOOGLE_MAPS_API_KEY\"'' ",
+                "-H 'Content-Type: application/json' ",
+                "-H 'X-Goog-FieldMask: places.displayName' ",
+                r#"--data '{"textQuery":"Joe'\''s Pizza"}'"#,
+            ),
+        );
+    } // fn
+} // mod
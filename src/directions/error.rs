@@ -24,6 +24,9 @@ pub enum Error {
     EitherRestrictionsOrWaypoints(usize, String),
     /// Waypoints may not be specified in Transit travel mode.
     EitherWaypointsOrTransitMode(usize),
+    /// Waypoints were specified, but every one of them was empty. Contains the
+    /// number of waypoints that were discarded.
+    EmptyWaypoints(usize),
     /// Google Maps Directions API service generated an error. See the `Status`
     /// enum for more information.
     GoogleMapsService(Status, Option<String>),
@@ -92,6 +95,9 @@ pub enum Error {
     /// Google Maps Directions API service.
     QueryNotBuilt,
     /// The request must be validated before a query string may be built.
+    ///
+    /// Neither this error nor `QueryNotBuilt` can occur when using the
+    /// typestate `Builder`, see `Request::builder`.
     RequestNotValidated,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
@@ -100,14 +106,42 @@ pub enum Error {
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
-    /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    /// The JSON parser generated an error.
+    Json(crate::json::Error),
+    /// The response from the Google Maps service could not be parsed. Contains
+    /// the sanitized request URL, the error generated by the JSON parser, and
+    /// the beginning of the response body (if response body capture is
+    /// enabled in the client).
+    InvalidJsonResponse(String, crate::json::Error, Option<String>),
+    /// Google responded with an `OK` status, but the response is missing data
+    /// that an `OK` response always contains. Contains the sanitized request
+    /// URL and the name of the missing field.
+    MalformedResponse(String, String),
+    /// Google responded with a body that is not JSON, such as an HTML error
+    /// page. This usually means that the API key is invalid, or that the
+    /// service is misconfigured. Contains the HTTP status, and the beginning
+    /// of the response body (if response body capture is enabled in the
+    /// client).
+    NonJsonResponse { status: String, snippet: String },
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
+    /// A traffic model may only be specified when a departure time is
+    /// specified.
+    TrafficModelRequiresDepartureTime(String),
     /// Transit mode may only be specified in Transit travel mode.
     TransitModeIsForTransitOnly(String, String),
     /// Transit routing preference may only be specified in Transit travel mode.
     TransitRoutePreferenceIsForTransitOnly(String, String),
+    /// The route's `waypoint_order` could not be applied to the provided
+    /// waypoints because their lengths differ.
+    WaypointOrderMismatch(usize, usize),
+    /// The route's `waypoint_order` contained an index that is out of range
+    /// for the provided waypoints.
+    WaypointOrderOutOfRange(u8, usize),
+    /// The request failed validation for more than one reason. Contains each
+    /// of the validation errors, in the order they were detected. A request
+    /// that fails validation for only one reason returns that error directly.
+    ValidationFailed(Vec<Self>),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -142,6 +176,10 @@ impl std::fmt::Display for Error {
                 The with_waypoints() method cannot be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 {waypoint_count} waypoint(s) are set. \
                 Try again either with a different travel mode or no waypoints."),
+            Self::EmptyWaypoints(waypoint_count) => write!(f,
+                "Google Maps Directions API client: \
+                The with_waypoints() method was given {waypoint_count} waypoint(s), but all of them were empty. \
+                Try again with at least one non-empty address, place ID, polyline or coordinate, or no waypoints."),
             Self::GoogleMapsService(status, error_message) => match error_message {
                 // If the Google Maps Directions API service generated an error
                 // message, return that:
@@ -265,7 +303,32 @@ impl std::fmt::Display for Error {
             Self::Reqwest(error) => write!(f, "Google Maps Directions API client in the Reqwest library: {error}"),
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
-            Self::SimdJson(error) => write!(f, "Google Maps Directions API client in the Serde JSON library: {error}"),
+            Self::Json(error) => write!(f, "Google Maps Directions API client in the JSON parser: {error}"),
+            Self::InvalidJsonResponse(url, error, body) => match body {
+                Some(body) => write!(f,
+                    "Google Maps Directions API client in the JSON parser: {error}. \
+                    Request URL: `{url}`. \
+                    Response body: `{body}`"),
+                None => write!(f,
+                    "Google Maps Directions API client in the JSON parser: {error}. \
+                    Request URL: `{url}`."),
+            }, // match
+            Self::MalformedResponse(url, field) => write!(f,
+                "Google Maps Directions API client: \
+                Google responded with an `OK` status, but the response has no `{field}`. \
+                Request URL: `{url}`."),
+            Self::NonJsonResponse { status, snippet } => if snippet.is_empty() {
+                write!(f,
+                    "Google Maps Directions API client: \
+                    Google responded with a `{status}` status and a body that is not JSON, such as an HTML error page. \
+                    Ensure that the API key is valid, and that the API is enabled for it.")
+            } else {
+                write!(f,
+                    "Google Maps Directions API client: \
+                    Google responded with a `{status}` status and a body that is not JSON, such as an HTML error page. \
+                    Ensure that the API key is valid, and that the API is enabled for it. \
+                    Response body: `{snippet}`")
+            }, // if
             Self::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Directions API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -273,6 +336,11 @@ impl std::fmt::Display for Error {
                 Try again with {} fewer waypoint(s).",
                 waypoint_count,
                 waypoint_count - 25),
+            Self::TrafficModelRequiresDepartureTime(traffic_model) => write!(f,
+                "Google Maps Directions API client: \
+                The with_traffic_model() method may only be used when with_departure_time() has been set. \
+                The traffic model is set to `{traffic_model}` and no departure time is set. \
+                Try again either with a departure time of `DepartureTime::Now` or a future time, or no traffic model."),
             Self::TransitModeIsForTransitOnly(travel_mode, transit_modes) => write!(f,
                 "Google Maps Directions API client: \
                 The with_transit_modes() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
@@ -283,6 +351,25 @@ impl std::fmt::Display for Error {
                 The with_transit_route_preference() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 The travel mode is set to `{travel_mode}` and the transit route preference is set to `{transit_route_preference}`. \
                 Try again either with a travel mode of `TravelMode::Transit` or no transit route preference."),
+            Self::WaypointOrderMismatch(waypoint_count, waypoint_order_count) => write!(f,
+                "Google Maps Directions API client: \
+                The route's waypoint order contains {waypoint_order_count} waypoint(s) \
+                but {waypoint_count} waypoint(s) were provided. \
+                Provide the same waypoints, in the same order, that were used in the request."),
+            Self::WaypointOrderOutOfRange(index, waypoint_count) => write!(f,
+                "Google Maps Directions API client: \
+                The route's waypoint order refers to waypoint index {index} \
+                but only {waypoint_count} waypoint(s) were provided."),
+            Self::ValidationFailed(errors) => {
+                write!(f,
+                    "Google Maps Directions API client: \
+                    The request failed validation for {} reasons:",
+                    errors.len())?;
+                errors
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(index, error)| write!(f, "\n{}. {error}", index + 1))
+            }, // ValidationFailed
         } // match
     } // fn
 } // impl
@@ -302,12 +389,131 @@ impl From<crate::ReqError> for Error {
 
 // -----------------------------------------------------------------------------
 
-impl From<simd_json::Error> for Error {
-    /// This trait converts from an Serde JSON (`simd_json::Error`)
+impl From<crate::json::Error> for Error {
+    /// This trait converts from a JSON parser (`crate::json::Error`)
     /// error type into a Google Maps Directions API error type
     /// (`google_maps::directions::error::Error`) by wrapping it inside. This
     /// function is required to use the `?` operator.
-    fn from(error: simd_json::Error) -> Self {
-        Self::SimdJson(error)
+    fn from(error: crate::json::Error) -> Self {
+        Self::Json(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Returns `true` if the error is temporary and the request may succeed if
+    /// it is retried. Network errors, HTTP "500 Server Errors", HTTP "429 Too
+    /// Many Requests", and the Google Maps Directions API's `UNKNOWN_ERROR` status
+    /// are transient. All other errors are permanent.
+    ///
+    /// This is the same classification that the client uses when deciding
+    /// whether a request is eligible for automatic retries.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::GoogleMapsService(status, _) => status == &Status::UnknownError,
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::is_transient_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) => true,
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns `true` if the error was caused by exceeding a usage quota or a
+    /// request rate limit.
+    #[must_use]
+    pub fn is_quota_error(&self) -> bool {
+        match self {
+            Self::GoogleMapsService(status, _) => matches!(status, Status::OverDailyLimit | Status::OverQueryLimit),
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::is_quota_http_status(status),
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns the HTTP status code that the server responded with, if the
+    /// error was caused by an unsuccessful HTTP response.
+    #[cfg(feature = "reqwest")]
+    #[must_use]
+    pub fn http_status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::http_status(status),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the status that the Google Maps Platform responded with, if the
+    /// error was reported by Google's service. This allows branching on the
+    /// precise status without parsing the error message.
+    #[must_use]
+    pub const fn google_status(&self) -> Option<&Status> {
+        match self {
+            Self::GoogleMapsService(status, _) => Some(status),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the error message that the Google Maps Platform responded with,
+    /// if the error was reported by Google's service and a message was
+    /// included.
+    #[must_use]
+    pub fn google_message(&self) -> Option<&str> {
+        match self {
+            Self::GoogleMapsService(_, message) => message.as_deref(),
+            _ => None,
+        } // match
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Converts the errors collected while validating a request into a
+    /// `Result`. No errors is a success, a single error is returned as-is, and
+    /// multiple errors are returned together in `Error::ValidationFailed`.
+    pub(crate) fn from_validation_errors(mut errors: Vec<Self>) -> Result<(), Self> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Self::ValidationFailed(errors)),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::{Error, Status};
+    use reqwest::StatusCode;
+
+    fn non_json_response(status: &str) -> Error {
+        Error::NonJsonResponse { status: status.to_string(), snippet: String::new() }
+    } // fn
+
+    // `ReqError` is `reqwest::Error` itself, unless `reqwest-middleware` is
+    // enabled:
+    #[cfg_attr(not(feature = "reqwest-middleware"), allow(clippy::useless_conversion))]
+    #[test]
+    fn classifies_each_error_variant() {
+        // Each error, and whether it is transient, whether it is a quota
+        // error, and its HTTP status:
+        let cases = [
+            (Error::GoogleMapsService(Status::UnknownError, None), true, false, None),
+            (Error::GoogleMapsService(Status::OverQueryLimit, None), false, true, None),
+            (Error::GoogleMapsService(Status::RequestDenied, None), false, false, None),
+            (Error::HttpUnsuccessful("429 Too Many Requests".to_string()), true, true, Some(StatusCode::TOO_MANY_REQUESTS)),
+            (Error::HttpUnsuccessful("503 Service Unavailable".to_string()), true, false, Some(StatusCode::SERVICE_UNAVAILABLE)),
+            (Error::HttpUnsuccessful("404 Not Found".to_string()), false, false, Some(StatusCode::NOT_FOUND)),
+            (non_json_response("503 Service Unavailable"), true, false, Some(StatusCode::SERVICE_UNAVAILABLE)),
+            (non_json_response("403 Forbidden"), false, false, Some(StatusCode::FORBIDDEN)),
+            (Error::Reqwest(crate::error::tests::reqwest_error().into()), true, false, None),
+        ]; // cases
+
+        for (error, is_transient, is_quota_error, http_status) in cases {
+            assert_eq!(error.is_transient(), is_transient, "{error:?}");
+            assert_eq!(error.is_quota_error(), is_quota_error, "{error:?}");
+            assert_eq!(error.http_status(), http_status, "{error:?}");
+        } // for
+    } // fn
+} // mod
@@ -94,9 +94,27 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 // -----------------------------------------------------------------------------
 
+/// Normalizes a code before it is looked up, so that codes can be parsed
+/// case-insensitively. Surrounding whitespace is removed, letters are
+/// lower-cased, and hyphens & spaces are replaced with underscores. For
+/// example, `Best-Guess` becomes `best_guess`.
+
+pub(crate) fn normalize_code(code: &str) -> String {
+    code.trim()
+        .chars()
+        .map(|character| match character {
+            '-' | ' ' => '_',
+            character => character.to_ascii_lowercase(),
+        }) // map
+        .collect()
+} // fn
+
+// -----------------------------------------------------------------------------
+
 pub use crate::directions::{
     request::{
-        avoid::Avoid, departure_time::DepartureTime, location::Location,
+        avoid::Avoid, builder::Builder as DirectionsBuilder, departure_time::DepartureTime,
+        location::Location, ready_request::ReadyRequest as DirectionsReadyRequest,
         traffic_model::TrafficModel, transit_mode::TransitMode,
         transit_route_preference::TransitRoutePreference, unit_system::UnitSystem,
         waypoint::Waypoint, Request as DirectionsRequest,
@@ -113,3 +131,118 @@ pub use crate::directions::{
     travel_mode::TravelMode,
     vehicle_type::VehicleType,
 }; // use
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{Avoid, TrafficModel, TransitMode, TransitRoutePreference, TravelMode, UnitSystem};
+    use std::fmt::{Debug, Display};
+    use std::str::FromStr;
+
+    /// Asserts that each code parses into the expected variant, and that each
+    /// invalid code is rejected with an error that quotes it.
+    #[track_caller]
+    fn assert_parses<T>(valid: &[(&str, T)], invalid: &[&str])
+    where
+        T: FromStr + Debug + PartialEq,
+        T::Err: Display,
+    {
+        for (code, expected) in valid {
+            match code.parse::<T>() {
+                Ok(parsed) => assert_eq!(&parsed, expected, "`{code}`"),
+                Err(error) => panic!("`{code}` was rejected: {error}"),
+            } // match
+        } // for
+
+        for code in invalid {
+            match code.parse::<T>() {
+                Ok(parsed) => panic!("`{code}` was parsed as {parsed:?}"),
+                Err(error) => assert!(error.to_string().contains(&format!("`{code}`")), "{error}"),
+            } // match
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_travel_modes_in_any_case() {
+        assert_parses(
+            &[
+                ("DRIVING", TravelMode::Driving),
+                ("driving", TravelMode::Driving),
+                ("Walking", TravelMode::Walking),
+                ("bIcYcLiNg", TravelMode::Bicycling),
+                (" transit ", TravelMode::Transit),
+            ],
+            &["", "drive", "DRIVING_MODE", "flying"],
+        );
+    } // fn
+
+    #[test]
+    fn parses_restrictions_in_any_case() {
+        assert_parses(
+            &[
+                ("tolls", Avoid::Tolls),
+                ("TOLLS", Avoid::Tolls),
+                ("Highways", Avoid::Highways),
+                ("fErRiEs", Avoid::Ferries),
+                ("indoor\n", Avoid::Indoor),
+            ],
+            &["", "toll", "motorways", "tolls|ferries"],
+        );
+    } // fn
+
+    #[test]
+    fn parses_unit_systems_in_any_case() {
+        assert_parses(
+            &[
+                ("metric", UnitSystem::Metric),
+                ("METRIC", UnitSystem::Metric),
+                ("Imperial", UnitSystem::Imperial),
+            ],
+            &["", "si", "us customary"],
+        );
+    } // fn
+
+    #[test]
+    fn parses_traffic_models_in_any_case() {
+        assert_parses(
+            &[
+                ("best_guess", TrafficModel::BestGuess),
+                ("BEST_GUESS", TrafficModel::BestGuess),
+                ("Best-Guess", TrafficModel::BestGuess),
+                ("best guess", TrafficModel::BestGuess),
+                ("Optimistic", TrafficModel::Optimistic),
+                ("PESSIMISTIC", TrafficModel::Pessimistic),
+            ],
+            &["", "bestguess", "best__guess", "realistic"],
+        );
+    } // fn
+
+    #[test]
+    fn parses_transit_modes_in_any_case() {
+        assert_parses(
+            &[
+                ("bus", TransitMode::Bus),
+                ("BUS", TransitMode::Bus),
+                ("Rail", TransitMode::Rail),
+                ("SubWay", TransitMode::Subway),
+                ("TRAIN", TransitMode::Train),
+                ("tram", TransitMode::Tram),
+            ],
+            &["", "ferry", "light rail"],
+        );
+    } // fn
+
+    #[test]
+    fn parses_transit_route_preferences_in_any_case() {
+        assert_parses(
+            &[
+                ("fewer_transfers", TransitRoutePreference::FewerTransfers),
+                ("FEWER_TRANSFERS", TransitRoutePreference::FewerTransfers),
+                ("Fewer-Transfers", TransitRoutePreference::FewerTransfers),
+                ("less walking", TransitRoutePreference::LessWalking),
+            ],
+            &["", "fewertransfers", "more_walking"],
+        );
+    } // fn
+} // mod
@@ -136,9 +136,12 @@ impl std::convert::TryFrom<&str> for Avoid {
     /// Gets an `Avoid` enum from a `String` that contains a valid
     /// [restrictions](https://developers.google.com/maps/documentation/directions/intro#Restrictions)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(restriction_code: &str) -> Result<Self, Self::Error> {
         Ok(RESTRICTIONS_BY_CODE
-            .get(restriction_code)
+            .get(crate::directions::normalize_code(restriction_code).as_str())
             .cloned()
             .ok_or_else(|| DirectionsError::InvalidAvoidCode(restriction_code.to_string()))?)
     } // fn
@@ -153,9 +156,12 @@ impl std::str::FromStr for Avoid {
     /// Gets an `Avoid` enum from a `String` that contains a valid
     /// [restrictions](https://developers.google.com/maps/documentation/directions/intro#Restrictions)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(restriction_code: &str) -> Result<Self, Self::Err> {
         Ok(RESTRICTIONS_BY_CODE
-            .get(restriction_code)
+            .get(crate::directions::normalize_code(restriction_code).as_str())
             .cloned()
             .ok_or_else(|| DirectionsError::InvalidAvoidCode(restriction_code.to_string()))?)
     } // fn
@@ -1,120 +1,124 @@
+use crate::directions::request::query_string::{push_joined, push_waypoints, WAYPOINT_CAPACITY};
 use crate::directions::{error::Error, request::Request};
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::fmt::Write;
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Builds the query string for the Google Maps Directions API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
+    /// The query string is written into a single `String` that is sized up
+    /// front, so that building many requests allocates as little as possible.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&'a mut self) -> Result<&'a mut Self, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
         if !self.validated {
             return Err(Error::RequestNotValidated);
         }
 
+        let mut query = String::with_capacity(
+            256 + self.client.key.len() + WAYPOINT_CAPACITY * self.waypoints.len()
+        ); // with_capacity
+
         // Builds the "required parameters" portion of the query string:
-        let mut query = format!(
-            "key={}&origin={}&destination={}",
-            self.client.key,
-            String::from(&self.origin), // URL-encoding performed by From trait
-            String::from(&self.destination), // URL-encoding performed by From trait
-        ); // format!
+        query.push_str("key=");
+        query.push_str(&self.client.key);
+        query.push_str("&origin=");
+        query.push_str(&String::from(&*self.client.coordinates(&self.origin))); // URL-encoding performed by From trait
+        query.push_str("&destination=");
+        query.push_str(&String::from(&*self.client.coordinates(&self.destination))); // URL-encoding performed by From trait
 
         // Builds the "optional parameters" portion of the query string:
 
         // Alternatives key/value pair:
         if let Some(alternatives) = &self.alternatives {
             query.push_str("&alternatives=");
-            query.push_str(&alternatives.to_string());
+            query.push_str(if *alternatives { "true" } else { "false" });
         } // if
 
         // Arrival time key/value pair:
         if let Some(arrival_time) = &self.arrival_time {
-            query.push_str("&arrival_time=");
-            query.push_str(&arrival_time.and_utc().timestamp().to_string());
+            // Writing to a `String` cannot fail:
+            let _ = write!(query, "&arrival_time={}", arrival_time.and_utc().timestamp());
         } // if
 
         // Avoid key/value pair:
         if !self.restrictions.is_empty() {
             query.push_str("&avoid=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .restrictions
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut restrictions = self.restrictions.iter().map(<&str>::from).collect::<Vec<&str>>();
+            restrictions.sort_unstable();
+            restrictions.dedup();
+            push_joined(&mut query, restrictions);
         } // if
 
         // Departure time key/value pair:
         if let Some(departure_time) = &self.departure_time {
-            query.push_str("&departure_time=");
-            query.push_str(&String::from(departure_time));
+            // Writing to a `String` cannot fail:
+            let _ = write!(query, "&departure_time={departure_time}");
         } // if
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(language.into());
         } // if
 
         // Travel mode key/value pair:
         if let Some(travel_mode) = &self.travel_mode {
             query.push_str("&mode=");
-            query.push_str(&String::from(travel_mode).to_lowercase());
+            query.extend(<&str>::from(travel_mode).chars().map(|c| c.to_ascii_lowercase()));
         } // if
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if none
+        // was set for this request:
+        if let Some(region) = self.region.as_ref().or(self.client.default_region.as_ref()) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(region.into());
         } // if
 
         // Traffic model key/value pair:
         if let Some(traffic_model) = &self.traffic_model {
             query.push_str("&traffic_model=");
-            query.push_str(&String::from(traffic_model));
+            query.push_str(traffic_model.into());
         } // if
 
         // Transit mode key/value pair:
         if !self.transit_modes.is_empty() {
             query.push_str("&transit_mode=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .transit_modes
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut transit_modes = self.transit_modes.iter().map(<&str>::from).collect::<Vec<&str>>();
+            transit_modes.sort_unstable();
+            transit_modes.dedup();
+            push_joined(&mut query, transit_modes);
         } // if
 
         // Transit route preference key/value pair:
         if let Some(transit_route_preference) = &self.transit_route_preference {
             query.push_str("&transit_routing_preference=");
-            query.push_str(&String::from(transit_route_preference));
+            query.push_str(transit_route_preference.into());
         } // if
 
         // Unit system key/value pair:
         if let Some(unit_system) = &self.unit_system {
             query.push_str("&units=");
-            query.push_str(&String::from(unit_system));
+            query.push_str(unit_system.into());
         } // if
 
         // Waypoints key/value pair:
@@ -123,18 +127,7 @@ impl<'a> Request<'a> {
             if self.waypoint_optimization {
                 query.push_str("optimize:true|");
             } // if
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .waypoints
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            push_waypoints(&mut query, &self.client, &self.waypoints);
         } // if
 
         // Set query string in Request struct.
@@ -144,3 +137,79 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{DepartureTime, Location, TrafficModel, TravelMode, UnitSystem, Waypoint};
+    use crate::types::{Language, Region};
+    use crate::GoogleMapsClient;
+
+    /// Returns `count` waypoints with a mix of addresses, coordinates and
+    /// place IDs, the same as the `build_query` benchmark.
+    fn waypoints(count: usize) -> Vec<Waypoint> {
+        (0..count)
+            .map(|index| match index % 3 {
+                0 => Waypoint::from_address(format!("{index} Wellington St, Ottawa, ON")),
+                1 => Waypoint::try_from_f64(45.42 + index as f64 / 1_000.0, -75.69).unwrap(),
+                _ => Waypoint::from_place_id(format!("ChIJrxNRX7IFzkwRCR5iKVZC-HA{index}")),
+            }) // map
+            .collect()
+    } // fn
+
+    #[test]
+    fn builds_the_same_url_for_25_waypoints() {
+        // The expected URL was generated by the query string builder that
+        // allocated a `String` for each parameter, before it was rewritten to
+        // write into a single buffer:
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let eight_am = chrono::NaiveDate::from_ymd_opt(2024, 1, 15)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+
+        let mut request = client.directions(
+            Location::from_address("Parliament Hill, Ottawa, ON"),
+            Location::from_address("Rideau Hall, Ottawa, ON"),
+        ); // directions
+        request
+            .with_waypoints(waypoints(25))
+            .with_waypoint_optimization(true)
+            .with_travel_mode(TravelMode::Driving)
+            .with_departure_time(DepartureTime::At(eight_am))
+            .with_traffic_model(TrafficModel::Pessimistic)
+            .with_unit_system(UnitSystem::Imperial)
+            .with_language(Language::French)
+            .with_region(Region::Canada);
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            include_str!("../../../tests/urls/directions_25_waypoints.txt").trim_end(),
+        );
+    } // fn
+
+    #[test]
+    fn sends_now_as_the_literal_departure_time() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request
+            .with_departure_time(DepartureTime::Now)
+            .with_traffic_model(TrafficModel::BestGuess);
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/directions/json\
+            ?key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000\
+            &origin=Toronto\
+            &destination=Montreal\
+            &departure_time=now\
+            &traffic_model=best_guess",
+        );
+    } // fn
+} // mod
@@ -0,0 +1,303 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::{
+    error::Error,
+    request::{
+        avoid::Avoid, departure_time::DepartureTime, location::Location,
+        ready_request::ReadyRequest, traffic_model::TrafficModel, transit_mode::TransitMode,
+        transit_route_preference::TransitRoutePreference, unit_system::UnitSystem,
+        waypoint::Waypoint, Request,
+    }, // crate::directions::request
+    travel_mode::TravelMode,
+}; // use crate::directions
+use crate::types::{Language, Region};
+use chrono::NaiveDateTime;
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for _Directions API_ requests. Unlike the mutable
+/// `Request` builder, the request can only be sent once `build` has returned a
+/// `ReadyRequest`, which has already been validated and has its query string
+/// built. Calling the methods in the wrong order is a compile-time error
+/// rather than a `QueryNotBuilt` or `RequestNotValidated` error at run-time.
+///
+/// ```rust
+/// let directions = DirectionsRequest::builder(
+///     &google_maps_client,
+///     Location::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+///     Location::from_address("1867 St Laurent Blvd, Ottawa, ON K1G 5A3"),
+/// )
+/// .with_travel_mode(TravelMode::Transit)
+/// .build()?
+/// .get()
+/// .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let directions = DirectionsRequest::builder(
+///     &google_maps_client,
+///     Location::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+///     Location::from_address("1867 St Laurent Blvd, Ottawa, ON K1G 5A3"),
+/// )
+/// .with_travel_mode(TravelMode::Transit)
+/// .get()
+/// .await?;
+/// ```
+///
+/// Doc-tests are disabled for this crate, so the examples in the
+/// `tests/compile_fail` directory check this for every typestate builder.
+
+#[derive(Debug)]
+pub struct Builder {
+    request: Request,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Initializes a typestate builder. See `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `origin` ‧ The address, textual latitude/longitude value, or place ID
+    ///   from which you wish to calculate directions.
+    ///
+    /// * `destination` ‧ The address, textual latitude/longitude value, or
+    ///   place ID to which you wish to calculate directions.
+
+    #[must_use]
+    pub fn builder(
+        client: &GoogleMapsClient,
+        origin: impl Into<Location>,
+        destination: impl Into<Location>
+    ) -> Builder {
+        Builder {
+            request: Self::new(client, origin.into(), destination.into()),
+        } // Builder
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Validates the request and builds its query string. The returned
+    /// `ReadyRequest` may then be sent to the Google Maps Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the combination of parameters is not accepted by
+    ///   the Google Maps Directions API. For example, an arrival time and a
+    ///   departure time in the same request.
+
+    pub fn build(mut self) -> Result<ReadyRequest, Error> {
+        self.request.validate()?.build()?;
+        Ok(ReadyRequest {
+            client: self.request.client,
+            query: self.request.query.unwrap_or_default(),
+            language: self.request.language,
+        }) // ReadyRequest
+    } // fn
+
+    /// Specifies whether the service may provide more than one route
+    /// alternative in the response. See `Request::with_alternatives`.
+
+    #[must_use]
+    pub fn with_alternatives(mut self, alternatives: impl Into<bool>) -> Self {
+        self.request.with_alternatives(alternatives);
+        self
+    } // fn
+
+    /// Specifies the desired time of arrival. See
+    /// `Request::with_arrival_time`.
+
+    #[must_use]
+    pub fn with_arrival_time(mut self, arrival_time: impl Into<NaiveDateTime>) -> Self {
+        self.request.with_arrival_time(arrival_time);
+        self
+    } // fn
+
+    /// Specifies the desired time of departure. See
+    /// `Request::with_departure_time`.
+
+    #[must_use]
+    pub fn with_departure_time(mut self, departure_time: impl Into<DepartureTime>) -> Self {
+        self.request.with_departure_time(departure_time);
+        self
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `Request::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.with_language(language);
+        self
+    } // fn
+
+    /// Specifies the region bias. See `Request::with_region`.
+
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<Region>) -> Self {
+        self.request.with_region(region);
+        self
+    } // fn
+
+    /// Specifies a feature that routes should avoid. See
+    /// `Request::with_restriction`.
+
+    #[must_use]
+    pub fn with_restriction(mut self, restriction: impl Into<Avoid>) -> Self {
+        self.request.with_restriction(restriction);
+        self
+    } // fn
+
+    /// Specifies features that routes should avoid. See
+    /// `Request::with_restrictions`.
+
+    #[must_use]
+    pub fn with_restrictions<C, A>(mut self, restrictions: C) -> Self
+    where
+        C: IntoIterator<Item = A>,
+        A: Into<Avoid> {
+        self.request.with_restrictions(restrictions);
+        self
+    } // fn
+
+    /// Specifies the assumptions to use when calculating time in traffic. See
+    /// `Request::with_traffic_model`.
+
+    #[must_use]
+    pub fn with_traffic_model(mut self, traffic_model: impl Into<TrafficModel>) -> Self {
+        self.request.with_traffic_model(traffic_model);
+        self
+    } // fn
+
+    /// Specifies a preferred mode of transit. See
+    /// `Request::with_transit_mode`.
+
+    #[must_use]
+    pub fn with_transit_mode(mut self, transit_mode: impl Into<TransitMode>) -> Self {
+        self.request.with_transit_mode(transit_mode);
+        self
+    } // fn
+
+    /// Specifies preferred modes of transit. See
+    /// `Request::with_transit_modes`.
+
+    #[must_use]
+    pub fn with_transit_modes<C, T>(mut self, transit_modes: C) -> Self
+    where
+        C: IntoIterator<Item = T>,
+        T: Into<TransitMode> {
+        self.request.with_transit_modes(transit_modes);
+        self
+    } // fn
+
+    /// Specifies preferences for transit routes. See
+    /// `Request::with_transit_route_preference`.
+
+    #[must_use]
+    pub fn with_transit_route_preference(
+        mut self,
+        transit_route_preference: impl Into<TransitRoutePreference>
+    ) -> Self {
+        self.request.with_transit_route_preference(transit_route_preference);
+        self
+    } // fn
+
+    /// Specifies the mode of transportation. See
+    /// `Request::with_travel_mode`.
+
+    #[must_use]
+    pub fn with_travel_mode(mut self, travel_mode: impl Into<TravelMode>) -> Self {
+        self.request.with_travel_mode(travel_mode);
+        self
+    } // fn
+
+    /// Specifies the unit system to use when displaying results. See
+    /// `Request::with_unit_system`.
+
+    #[must_use]
+    pub fn with_unit_system(mut self, unit_system: impl Into<UnitSystem>) -> Self {
+        self.request.with_unit_system(unit_system);
+        self
+    } // fn
+
+    /// Allows the Directions service to optimize the provided route by
+    /// rearranging the waypoints. See `Request::with_waypoint_optimization`.
+
+    #[must_use]
+    pub fn with_waypoint_optimization(mut self, waypoint_optimization: impl Into<bool>) -> Self {
+        self.request.with_waypoint_optimization(waypoint_optimization);
+        self
+    } // fn
+
+    /// Specifies an intermediate location to visit. See
+    /// `Request::with_waypoint`.
+
+    #[must_use]
+    pub fn with_waypoint(mut self, waypoint: impl Into<Waypoint>) -> Self {
+        self.request.with_waypoint(waypoint);
+        self
+    } // fn
+
+    /// Specifies intermediate locations to visit. See
+    /// `Request::with_waypoints`.
+
+    #[must_use]
+    pub fn with_waypoints<C, W>(mut self, waypoints: C) -> Self
+    where
+        C: IntoIterator<Item = W>,
+        W: Into<Waypoint> {
+        self.request.with_waypoints(waypoints);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{request::Request, Location, TravelMode, UnitSystem, Waypoint};
+    use crate::types::Language;
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let consuming = Request::builder(
+            &client,
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ) // builder
+        .with_travel_mode(TravelMode::Driving)
+        .with_waypoint(Waypoint::from_address("Kingston"))
+        .with_unit_system(UnitSystem::Metric)
+        .with_language(Language::French)
+        .build()
+        .unwrap();
+
+        let mut mutable = Request::new(
+            &client,
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // new
+        mutable
+            .with_travel_mode(TravelMode::Driving)
+            .with_waypoint(Waypoint::from_address("Kingston"))
+            .with_unit_system(UnitSystem::Metric)
+            .with_language(Language::French);
+
+        assert_eq!(consuming.query_url(), mutable.query_url().unwrap());
+    } // fn
+} // mod
@@ -4,8 +4,7 @@
 
 use crate::directions::error::Error as DirectionsError;
 use crate::error::Error as GoogleMapsError;
-use chrono::DateTime;
-use chrono::NaiveDateTime;
+use chrono::{DateTime, NaiveDateTime, TimeZone};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -63,11 +62,61 @@ impl std::convert::From<&DepartureTime> for String {
 
 // -----------------------------------------------------------------------------
 
+impl std::convert::From<NaiveDateTime> for DepartureTime {
+    /// Converts a `NaiveDateTime`, which is interpreted as UTC, to a
+    /// `DepartureTime::At` enum.
+    fn from(departure_time: NaiveDateTime) -> Self {
+        Self::At(departure_time)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<Tz: TimeZone> std::convert::From<DateTime<Tz>> for DepartureTime {
+    /// Converts a time zone-aware `DateTime` to a `DepartureTime::At` enum.
+    fn from(departure_time: DateTime<Tz>) -> Self {
+        Self::At(departure_time.naive_utc())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<std::time::SystemTime> for DepartureTime {
+    // Error definitions are contained in the
+    // `google_maps\src\directions\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Converts a `std::time::SystemTime` to a `DepartureTime::At` enum. This
+    /// allows a departure time to be given without using `chrono` types.
+    /// Fractions of a second are truncated.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the time is before the Unix epoch, or too far in
+    ///   the future to be represented.
+    fn try_from(departure_time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        match departure_time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_secs())
+                .ok()
+                .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+                .map(|date_time| Self::At(date_time.naive_utc()))
+                .ok_or_else(|| DirectionsError::InvalidDepartureTime(duration.as_secs().to_string()).into()),
+            Err(error) => Err(DirectionsError::InvalidDepartureTime(
+                format!("-{}", error.duration().as_secs_f64()),
+            ))?,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::fmt::Display for DepartureTime {
     /// Converts a `DepartureTime` enum to a `String` that contains a [departure
     /// time](https://developers.google.com/maps/documentation/directions/intro#optional-parameters).
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{departure_time}", departure_time = String::from(self))
+        match self {
+            Self::Now => f.write_str("now"),
+            Self::At(departure_time) => write!(f, "{}", departure_time.and_utc().timestamp()),
+        } // match
     } // fn
 } // impl
 
@@ -5,7 +5,7 @@ use crate::error::Error as GoogleMapsError; // crate::directions
 
 // =============================================================================
 
-impl<'a> DirectionsRequest<'a> {
+impl DirectionsRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> DirectionsRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<DirectionsResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<DirectionsResponse, GoogleMapsError> {
         self.validate()?.build()?.get().await
     } // fn
 } // impl
@@ -1,34 +1,141 @@
 use crate::directions::{
-    error::Error as DirectionsError, request::Request as DirectionsRequest,
-    response::status::Status as DirectionsStatus, response::Response as DirectionsResponse,
-    OUTPUT_FORMAT, SERVICE_URL,
+    error::Error as DirectionsError, request::ready_request::ReadyRequest,
+    request::Request as DirectionsRequest, response::status::Status as DirectionsStatus,
+    response::Response as DirectionsResponse, OUTPUT_FORMAT, SERVICE_URL,
 }; // use crate::directions
 use crate::error::Error as GoogleMapsError;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> DirectionsRequest<'a> {
+impl DirectionsRequest {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
     pub async fn get(&mut self) -> Result<DirectionsResponse, GoogleMapsError> {
-        // Build the URL stem for the HTTP get request:
-        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+        self.ready()?.get().await
+    } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<DirectionsResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
+
+    /// Performs the HTTP get request and returns the response to the caller,
+    /// along with the untouched JSON body that Google returned. This may be
+    /// used to keep the exact response for auditing or reprocessing.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn get_raw(&mut self) -> Result<(DirectionsResponse, String), GoogleMapsError> {
+        self.ready()?.get_raw().await
+    } // fn
+
+    /// Converts the built request into a `ReadyRequest`, which is used to
+    /// perform the HTTP get request.
 
-        match &self.query {
-            // If query string built, append it to the URL stem.
-            Some(query) => url.push_str(query.as_ref()),
-            // If query string not built, return an error.
-            None => return Err(DirectionsError::QueryNotBuilt)?,
-        } // match
+    fn ready(&self) -> Result<ReadyRequest, DirectionsError> {
+        // If the query string has not been built, return an error:
+        self.query
+            .as_ref()
+            .map(|query| ReadyRequest {
+                client: self.client.clone(),
+                query: query.clone(),
+                language: self.language.clone(),
+            }) // map
+            .ok_or(DirectionsError::QueryNotBuilt)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ReadyRequest {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn get(&self) -> Result<DirectionsResponse, GoogleMapsError> {
+        self.get_response(false)
+            .await
+            .map(|(response, _raw)| response)
+    } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<DirectionsResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
+
+    /// Performs the HTTP get request and returns the response to the caller,
+    /// along with the untouched JSON body that Google returned. This may be
+    /// used to keep the exact response for auditing or reprocessing.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn get_raw(&self) -> Result<(DirectionsResponse, String), GoogleMapsError> {
+        self.get_response(true)
+            .await
+            .map(|(response, raw)| (response, raw.unwrap_or_default()))
+    } // fn
+
+    /// Performs the HTTP get request. If `keep_raw` is `true`, the untouched
+    /// JSON body is returned alongside the parsed response.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(
+            otel.kind = "client",
+            http.method = "GET",
+            http.url = tracing::field::Empty,
+            google.api = "directions",
+            google.status = tracing::field::Empty,
+        )
+    )]
+    async fn get_response(
+        &self,
+        keep_raw: bool,
+    ) -> Result<(DirectionsResponse, Option<String>), GoogleMapsError> {
+        // Build the URL for the HTTP get request:
+        let url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{}", self.query);
+
+        // Fail fast if the circuit breaker is not letting requests through:
+        self.client.check_circuit_breaker()?;
+
+        // Fail fast if a replaying cassette has no response for the request:
+        #[cfg(feature = "vcr")]
+        self.client.check_cassette(&url)?;
 
         // Observe any rate limiting before executing request:
         tracing::info!("making HTTP GET request to Google Maps Directions API");
@@ -39,15 +146,24 @@ impl<'a> DirectionsRequest<'a> {
             .await;
 
         tracing::debug!("{url}");
+        tracing::Span::current().record("http.url", crate::error::sanitize_url(&url).as_str());
+
+        // The language is also sent in the `Accept-Language` header:
+        let language = self.language.as_ref().or(self.client.default_language.as_ref()).map(<&str>::from);
+
+        // Collect the details of the request for the audit trail:
+        let audit = self.client.audit("directions", &url);
 
         // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        // retries is returned, we have reached the maximum retries, or the
+        // circuit breaker has opened. Note: errors wrapped in `Transient()`
+        // will retried by the `backoff` crate while errors wrapped in
+        // `Permanent()` will exit the retry loop.
+        let response = self.client.retry(|| async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+            let response = self.client.get_request_with_language(&url, language).await;
+            audit.attempt(&response);
 
             // Check response from the HTTP client:
             match response {
@@ -55,35 +171,51 @@ impl<'a> DirectionsRequest<'a> {
                     // HTTP client was successful getting a response from the
                     // server. Check the HTTP status code:
                     if response.status().is_success() {
+                        // Capture the status and content type before the
+                        // response is consumed, in case the body is not JSON:
+                        let status = response.status().to_string();
+                        let content_type = crate::error::content_type(&response);
                         // If the HTTP GET request was successful, get the
                         // response text:
                         let text = response.text().await;
                         let bytes = text.map(String::into_bytes);
+                        audit.response_bytes(&bytes);
                         match bytes {
+                            Ok(bytes) if crate::error::is_non_json(content_type.as_deref(), &bytes) => {
+                                // Google responded with something other than JSON,
+                                // such as an HTML error page. This will not be
+                                // fixed by retrying:
+                                let error = DirectionsError::NonJsonResponse {
+                                    status,
+                                    snippet: crate::error::non_json_snippet(&bytes, self.client.capture_response_body),
+                                };
+                                tracing::error!("{}", error);
+                                Err(Permanent(error))
+                            } // Ok(bytes)
                             Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<DirectionsResponse>(&mut bytes) {
+                                // Keep a copy of the untouched response body
+                                // if requested. The JSON parser may modify the
+                                // buffer in-place:
+                                let raw = keep_raw.then(|| String::from_utf8_lossy(&bytes).into_owned());
+                                match crate::json::parse_json_response::<DirectionsResponse>(&mut bytes, self.client.capture_response_body) {
                                     Ok(deserialized) => {
+                                        tracing::Span::current().record("google.status", deserialized.status.as_str());
+                                        audit.google_status(deserialized.status.as_str());
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == DirectionsStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
+                                        if deserialized.status != DirectionsStatus::Ok {
+                                            // Google API returned an error. This
+                                            // indicates an issue with the request.
+                                            // In most cases, retrying will not
+                                            // help:
                                             let error = DirectionsError::GoogleMapsService(
                                                 deserialized.status.clone(),
                                                 deserialized.error_message,
                                             );
                                             // Check Google API response status
                                             // for error type:
-                                            if deserialized.status == DirectionsStatus::UnknownError
-                                            {
+                                            if error.is_transient() {
                                                 // Only Google's "Unknown Error"
                                                 // is eligible for retries:
                                                 tracing::warn!("{}", error);
@@ -98,11 +230,29 @@ impl<'a> DirectionsRequest<'a> {
                                                 tracing::error!("{}", error);
                                                 Err(Permanent(error))
                                             } // if
+                                        // Google's response was "Ok" but it is missing
+                                        // data that an "Ok" response always has. This
+                                        // will not be fixed by retrying:
+                                        } else if let Some(field) = deserialized.missing_field() {
+                                            let error = DirectionsError::MalformedResponse(
+                                                crate::error::sanitize_url(&url),
+                                                field.to_string(),
+                                            );
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
+                                        } else {
+                                            // If Google's response was "Ok" return the
+                                            // struct deserialized from JSON:
+                                            Ok((deserialized, raw))
                                         } // if
                                     } // Ok(deserialized)
-                                    Err(error) => {
+                                    Err((error, body)) => {
                                         tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(DirectionsError::SimdJson(error)))
+                                        Err(Permanent(DirectionsError::InvalidJsonResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            body,
+                                        )))
                                     } // Err
                                 } // match
                             } // Ok(text)
@@ -116,19 +266,28 @@ impl<'a> DirectionsRequest<'a> {
                     // We got a response from the server but it was not OK.
                     // Only HTTP "500 Server Errors", and HTTP "429 Too Many
                     // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: DirectionsError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
                     } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(DirectionsError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
+                        let status = response.status();
+                        // Google may respond with an HTML error page, for example
+                        // when the API key is entirely wrong:
+                        let error = crate::error::non_json_body(response, self.client.capture_response_body)
+                            .await
+                            .map_or_else(
+                                || DirectionsError::HttpUnsuccessful(status.to_string()),
+                                |snippet| DirectionsError::NonJsonResponse { status: status.to_string(), snippet },
+                            ); // map_or_else
+                        if error.is_transient() {
+                            tracing::warn!("HTTP client returned: {}", status);
+                            Err(Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        // Not a 500 Server Error or "429 Too Many Requests"
+                        // error. The error is permanent, do not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}", status);
+                            Err(Permanent(error))
+                        } // if
                     } // if
                 } // case
                 // HTTP client did not get a response from the server. Retry:
@@ -141,9 +300,58 @@ impl<'a> DirectionsRequest<'a> {
                 } // case
             } // match
         })
-        .await?;
+        .await;
+
+        // Record the request in the audit trail, now that retries are over:
+        audit.finish();
+        let response = response?;
 
         // Return response to caller:
         Ok(response)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use crate::directions::error::Error as DirectionsError;
+    use crate::directions::request::location::Location;
+
+    #[tokio::test]
+    async fn replays_directions() {
+        let client = replay_client("directions");
+        let response = client
+            .directions(
+                Location::from_address("Toronto"),
+                Location::from_address("Montreal")
+            )
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(response.routes.len(), 1);
+        assert_eq!(response.routes[0].summary, "ON-401 E");
+        assert_eq!(response.routes[0].total_distance(), 541_043);
+    } // fn
+
+    #[tokio::test]
+    async fn rejects_an_ok_response_without_routes() {
+        // Google's response is "OK", but it has no `routes`:
+        let client = replay_client("directions_malformed");
+        let result = client
+            .directions(
+                Location::from_address("Toronto"),
+                Location::from_address("Montreal")
+            )
+            .execute()
+            .await;
+
+        let Err(crate::error::Error::Directions(DirectionsError::MalformedResponse(url, field))) = result else {
+            panic!("expected a malformed response, got {result:?}");
+        };
+        assert_eq!(field, "routes");
+        assert!(url.contains("origin=Toronto&destination=Montreal"), "{url}");
+        assert!(!url.contains("AIzaSy"), "{url}");
+    } // fn
+} // mod
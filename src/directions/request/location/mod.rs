@@ -153,6 +153,20 @@ impl std::convert::From<&Location> for String {
 
 // -----------------------------------------------------------------------------
 
+impl crate::client::Coordinates for Location {
+    /// Rounds the coordinates of the `LatLng` variant. All other variants are
+    /// returned unchanged.
+    fn rounded(&self, decimals: u8) -> Self {
+        if let Self::LatLng(latlng) = self {
+            Self::LatLng(latlng.rounded(decimals))
+        } else {
+            self.clone()
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl Location {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to a latitude/longitude coordinate to calculate directions.
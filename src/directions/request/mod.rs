@@ -5,6 +5,7 @@
 
 pub mod avoid;
 mod build;
+pub mod builder;
 pub mod departure_time;
 #[cfg(feature = "reqwest")]
 mod execute;
@@ -12,7 +13,9 @@ mod execute;
 mod get;
 pub mod location;
 mod new;
+pub(crate) mod query_string;
 mod query_url;
+pub mod ready_request;
 pub mod traffic_model;
 pub mod transit_mode;
 pub mod transit_route_preference;
@@ -32,6 +35,8 @@ mod with_travel_mode;
 mod with_unit_system;
 mod with_waypoint_optimization;
 mod with_waypoints;
+#[cfg(feature = "polyline")]
+mod with_waypoints_from_polyline;
 
 // -----------------------------------------------------------------------------
 
@@ -51,13 +56,13 @@ use chrono::NaiveDateTime;
 /// _Directions API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The address, latitude/longitude, or place ID to which you wish to
     /// calculate directions.
@@ -128,6 +133,79 @@ pub struct Request<'a> {
     /// Directions API through cURL.
     query: Option<String>,
 
+    /// The number of empty waypoints that were discarded by `with_waypoints()`.
+    /// If every waypoint was empty, validation fails rather than silently
+    /// sending the request without waypoints.
+    discarded_waypoints: usize,
+
     /// Has the request been validated?
     validated: bool,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("destination", &self.destination)
+            .field("origin", &self.origin)
+            .field("alternatives", &self.alternatives)
+            .field("arrival_time", &self.arrival_time)
+            .field("departure_time", &self.departure_time)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("restrictions", &self.restrictions)
+            .field("traffic_model", &self.traffic_model)
+            .field("transit_modes", &self.transit_modes)
+            .field("transit_route_preference", &self.transit_route_preference)
+            .field("travel_mode", &self.travel_mode)
+            .field("unit_system", &self.unit_system)
+            .field("waypoint_optimization", &self.waypoint_optimization)
+            .field("waypoints", &self.waypoints)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .field("discarded_waypoints", &self.discarded_waypoints)
+            .field("validated", &self.validated)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{Avoid, Location, TravelMode};
+    use crate::types::Language;
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn clones_of_a_half_built_request_are_independent() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request.with_travel_mode(TravelMode::Driving);
+
+        let mut french = request.clone();
+        french.with_language(Language::French);
+        request.with_restriction(Avoid::Tolls);
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/directions/json?\
+            key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000&origin=Toronto\
+            &destination=Montreal&avoid=tolls&mode=driving",
+        );
+        assert_eq!(
+            french.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/directions/json?\
+            key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000&origin=Toronto\
+            &destination=Montreal&language=fr&mode=driving",
+        );
+    } // fn
+} // mod
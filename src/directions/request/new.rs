@@ -5,7 +5,7 @@ use crate::{
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the data structure for the builder pattern.
@@ -15,14 +15,14 @@ impl<'a> Request<'a> {
     /// This method accepts no arguments.
 
     #[must_use]
-    pub const fn new(
-        client: &'a GoogleMapsClient,
+    pub fn new(
+        client: &GoogleMapsClient,
         origin: Location,
         destination: Location
     ) -> Self {
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             destination,
             origin,
             // Optional parameters:
@@ -41,6 +41,7 @@ impl<'a> Request<'a> {
             waypoints: Vec::new(),
             // Internal use only:
             query: None,
+            discarded_waypoints: 0,
             validated: false,
         } // struct
     } // fn
@@ -0,0 +1,77 @@
+//! Writes the parts of a query string that are shared by the _Directions API_
+//! and the _Distance Matrix API_. Values are percent-encoded straight into the
+//! query string, rather than into a `String` of their own first.
+
+use crate::client::GoogleMapsClient;
+use crate::directions::request::waypoint::Waypoint;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+/// The `|` that separates the values of a multi-valued parameter,
+/// percent-encoded.
+pub const SEPARATOR: &str = "%7C";
+
+/// The `place_id:` prefix of a place ID waypoint, percent-encoded.
+const PLACE_ID_PREFIX: &str = "place%5Fid%3A";
+
+/// The `enc:` prefix of an encoded polyline waypoint, percent-encoded.
+const POLYLINE_PREFIX: &str = "enc%3A";
+
+/// The `:` suffix of an encoded polyline waypoint, percent-encoded.
+const POLYLINE_SUFFIX: &str = "%3A";
+
+/// A rough guess of how long a percent-encoded waypoint is, used to size the
+/// query string before it is written.
+pub const WAYPOINT_CAPACITY: usize = 48;
+
+// -----------------------------------------------------------------------------
+//
+/// Appends `value` to the query string, percent-encoded.
+
+pub fn push_encoded(query: &mut String, value: &str) {
+    query.extend(utf8_percent_encode(value, NON_ALPHANUMERIC));
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends `values` to the query string, separated by an encoded `|`. The
+/// values must already be safe to use in a URL, such as the codes of an enum.
+
+pub fn push_joined<'v>(query: &mut String, values: impl IntoIterator<Item = &'v str>) {
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            query.push_str(SEPARATOR);
+        } // if
+        query.push_str(value);
+    } // for
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends `waypoints` to the query string, percent-encoded and separated by an
+/// encoded `|`. The client's coordinate precision is applied to coordinates.
+///
+/// Addresses, place IDs and polylines are encoded straight from the waypoint.
+/// Other waypoints are converted to a `String` first.
+
+pub fn push_waypoints(query: &mut String, client: &GoogleMapsClient, waypoints: &[Waypoint]) {
+    for (index, waypoint) in waypoints.iter().enumerate() {
+        if index > 0 {
+            query.push_str(SEPARATOR);
+        } // if
+        match waypoint {
+            Waypoint::Address(address) => push_encoded(query, address),
+            Waypoint::PlaceId(place_id) => {
+                query.push_str(PLACE_ID_PREFIX);
+                push_encoded(query, place_id);
+            }, // PlaceId
+            Waypoint::Polyline(polyline) => {
+                query.push_str(POLYLINE_PREFIX);
+                push_encoded(query, polyline);
+                query.push_str(POLYLINE_SUFFIX);
+            }, // Polyline
+            _ => push_encoded(query, &String::from(&*client.coordinates(waypoint))),
+        } // match
+    } // for
+} // fn
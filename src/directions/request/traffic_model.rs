@@ -126,9 +126,12 @@ impl std::convert::TryFrom<&str> for TrafficModel {
     /// [traffic
     /// model](https://developers.google.com/maps/documentation/javascript/reference/directions#TrafficModel)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(traffic_model_code: &str) -> Result<Self, Self::Error> {
         Ok(TRAFFIC_MODELS_BY_CODE
-            .get(traffic_model_code)
+            .get(crate::directions::normalize_code(traffic_model_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTrafficModelCode(traffic_model_code.to_string())
@@ -146,9 +149,12 @@ impl std::str::FromStr for TrafficModel {
     /// [traffic
     /// model](https://developers.google.com/maps/documentation/javascript/reference/directions#TrafficModel)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(traffic_model_code: &str) -> Result<Self, Self::Err> {
         Ok(TRAFFIC_MODELS_BY_CODE
-            .get(traffic_model_code)
+            .get(crate::directions::normalize_code(traffic_model_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTrafficModelCode(traffic_model_code.to_string())
@@ -131,9 +131,12 @@ impl std::convert::TryFrom<&str> for TransitMode {
     /// Gets a `TransitMode` enum from a `String` that contains a valid [transit
     /// mode](https://developers.google.com/maps/documentation/javascript/reference/directions#TransitMode)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(transit_mode_code: &str) -> Result<Self, Self::Error> {
         Ok(TRANSIT_MODES_BY_CODE
-            .get(transit_mode_code)
+            .get(crate::directions::normalize_code(transit_mode_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTransitModeCode(transit_mode_code.to_string())
@@ -150,9 +153,12 @@ impl std::str::FromStr for TransitMode {
     /// Gets a `TransitMode` enum from a `String` that contains a valid [transit
     /// mode](https://developers.google.com/maps/documentation/javascript/reference/directions#TransitMode)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(transit_mode_code: &str) -> Result<Self, Self::Err> {
         Ok(TRANSIT_MODES_BY_CODE
-            .get(transit_mode_code)
+            .get(crate::directions::normalize_code(transit_mode_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTransitModeCode(transit_mode_code.to_string())
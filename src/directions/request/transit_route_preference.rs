@@ -111,9 +111,12 @@ impl std::convert::TryFrom<&str> for TransitRoutePreference {
     /// valid [transit route
     /// preference](https://developers.google.com/maps/documentation/javascript/reference/directions#TransitRoutePreference)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(transit_route_preference_code: &str) -> Result<Self, Self::Error> {
         Ok(TRANSIT_ROUTE_PREFERENCE_BY_CODE
-            .get(transit_route_preference_code)
+            .get(crate::directions::normalize_code(transit_route_preference_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTransitRoutePreferenceCode(
@@ -133,9 +136,12 @@ impl std::str::FromStr for TransitRoutePreference {
     /// valid [transit route
     /// preference](https://developers.google.com/maps/documentation/javascript/reference/directions#TransitRoutePreference)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(transit_route_preference_code: &str) -> Result<Self, Self::Err> {
         Ok(TRANSIT_ROUTE_PREFERENCE_BY_CODE
-            .get(transit_route_preference_code)
+            .get(crate::directions::normalize_code(transit_route_preference_code).as_str())
             .cloned()
             .ok_or_else(|| {
                 DirectionsError::InvalidTransitRoutePreferenceCode(
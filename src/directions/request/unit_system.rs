@@ -116,9 +116,12 @@ impl std::convert::TryFrom<&str> for UnitSystem {
     /// Gets a `UnitSystem` enum from a `String` that contains a valid [unit
     /// system](https://developers.google.com/maps/documentation/directions/intro#UnitSystems)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(unit_system_code: &str) -> Result<Self, Self::Error> {
         Ok(UNIT_SYSTEMS_BY_CODE
-            .get(unit_system_code)
+            .get(crate::directions::normalize_code(unit_system_code).as_str())
             .cloned()
             .ok_or_else(|| DirectionsError::InvalidUnitSystemCode(unit_system_code.to_string()))?)
     } // fn
@@ -133,9 +136,12 @@ impl std::str::FromStr for UnitSystem {
     /// Gets a `UnitSystem` enum from a `String` that contains a valid [unit
     /// system](https://developers.google.com/maps/documentation/directions/intro#UnitSystems)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(unit_system_code: &str) -> Result<Self, Self::Err> {
         Ok(UNIT_SYSTEMS_BY_CODE
-            .get(unit_system_code)
+            .get(crate::directions::normalize_code(unit_system_code).as_str())
             .cloned()
             .ok_or_else(|| DirectionsError::InvalidUnitSystemCode(unit_system_code.to_string()))?)
     } // fn
@@ -1,6 +1,6 @@
 use crate::directions::{error::Error, request::Request, travel_mode::TravelMode}; // crate::directions
 
-impl<'a> Request<'a> {
+impl Request {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -8,47 +8,52 @@ impl<'a> Request<'a> {
     /// not check parameter values for validity - i.e. it will not ensure
     /// Polylines or Place ID's are valid and well-formed.
     ///
+    /// All of the combinations are checked. If more than one problem is found,
+    /// they are all returned together in `Error::ValidationFailed`.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&'a mut self) -> Result<&'a mut Self, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+        let mut errors = Vec::new();
+
         if let Some(travel_mode) = &self.travel_mode {
             // If the travel mode has been set to TravelMode::Transit...
             if *travel_mode == TravelMode::Transit {
                 // ...waypoints cannot be set:
                 if !self.waypoints.is_empty() {
-                    return Err(Error::EitherWaypointsOrTransitMode(self.waypoints.len()));
+                    errors.push(Error::EitherWaypointsOrTransitMode(self.waypoints.len()));
                 } // if
 
             // If the transit mode is not set to TravelMode::Transit...
             } else {
                 // ...an arrival time cannot be set:
                 if let Some(arrival_time) = &self.arrival_time {
-                    return Err(Error::ArrivalTimeIsForTransitOnly(
+                    errors.push(Error::ArrivalTimeIsForTransitOnly(
                         travel_mode.to_string(),
                         arrival_time.format("%F %r").to_string(),
-                    )); // Err
+                    )); // push
                 } // if
 
                 // ...a transit mode cannot be set:
                 if !self.transit_modes.is_empty() {
-                    return Err(Error::TransitModeIsForTransitOnly(
+                    errors.push(Error::TransitModeIsForTransitOnly(
                         travel_mode.to_string(),
                         self.transit_modes
                             .iter()
                             .map(std::string::ToString::to_string)
                             .collect::<Vec<String>>()
                             .join("|"),
-                    )); // Err
+                    )); // push
                 } // if
 
                 // ...a transit route preference cannot be set:
                 if let Some(transit_route_preference) = &self.transit_route_preference {
-                    return Err(Error::TransitRoutePreferenceIsForTransitOnly(
+                    errors.push(Error::TransitRoutePreferenceIsForTransitOnly(
                         travel_mode.to_string(),
                         transit_route_preference.to_string(),
-                    )); // Err
+                    )); // push
                 } // if
             } // if
         } // if
@@ -57,41 +62,59 @@ impl<'a> Request<'a> {
         if !self.waypoints.is_empty() {
             // ...alternatives cannot be set to true:
             if let Some(alternatives) = &self.alternatives {
-                if !alternatives {
-                    return Err(Error::EitherAlternativesOrWaypoints(self.waypoints.len()));
-                    // Err
+                if *alternatives {
+                    errors.push(Error::EitherAlternativesOrWaypoints(self.waypoints.len()));
                 } // if
             } // if
 
             // ...restrictions cannot be set:
-            if self.restrictions.is_empty() {
-                return Err(Error::EitherRestrictionsOrWaypoints(
+            if !self.restrictions.is_empty() {
+                errors.push(Error::EitherRestrictionsOrWaypoints(
                     self.waypoints.len(),
                     self.restrictions
                         .iter()
                         .map(std::string::ToString::to_string)
                         .collect::<Vec<String>>()
                         .join("|"),
-                )); // Err
+                )); // push
             } // if
 
             // ...ensure that the number of waypoints is equal to or less than 25:
             if self.waypoints.len() > 25 {
-                return Err(Error::TooManyWaypoints(self.waypoints.len()));
+                errors.push(Error::TooManyWaypoints(self.waypoints.len()));
             } // if
         } // if
 
+        // If waypoints were given but all of them were empty, report it rather
+        // than quietly routing without them:
+        if self.waypoints.is_empty() && self.discarded_waypoints > 0 {
+            errors.push(Error::EmptyWaypoints(self.discarded_waypoints));
+        } // if
+
         // If an arrival time has been set...
         if let Some(arrival_time) = &self.arrival_time {
             // ...a departure time cannot be set:
             if let Some(departure_time) = &self.departure_time {
-                return Err(Error::EitherDepartureTimeOrArrivalTime(
+                errors.push(Error::EitherDepartureTimeOrArrivalTime(
                     arrival_time.format("%F %r").to_string(),
                     departure_time.to_string(),
-                )); // Err
+                )); // push
+            } // if
+        } // if
+
+        // If a traffic model has been set...
+        if let Some(traffic_model) = &self.traffic_model {
+            // ...a departure time must also be set:
+            if self.departure_time.is_none() {
+                errors.push(Error::TrafficModelRequiresDepartureTime(
+                    traffic_model.to_string(),
+                )); // push
             } // if
         } // if
 
+        // Report every problem found, rather than only the first one:
+        Error::from_validation_errors(errors)?;
+
         // Indicate that the request passed validation.
         self.validated = true;
 
@@ -99,3 +122,128 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{
+        error::Error,
+        request::{location::Location, waypoint::Waypoint},
+        Avoid, DepartureTime, TrafficModel
+    };
+    use crate::GoogleMapsClient;
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap()
+    } // fn
+
+    #[test]
+    fn waypoints_alone_are_valid() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request.with_waypoints(vec![Waypoint::from_address("Ottawa")]);
+        assert!(request.validate().is_ok());
+    } // fn
+
+    #[test]
+    fn waypoints_with_alternatives_disabled_are_valid() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request
+            .with_waypoints(vec![Waypoint::from_address("Ottawa")])
+            .with_alternatives(false);
+        assert!(request.validate().is_ok());
+    } // fn
+
+    #[test]
+    fn waypoints_with_alternatives_enabled_are_rejected() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request
+            .with_waypoints(vec![Waypoint::from_address("Ottawa")])
+            .with_alternatives(true);
+        assert!(matches!(
+            request.validate(),
+            Err(Error::EitherAlternativesOrWaypoints(1))
+        ));
+    } // fn
+
+    #[test]
+    fn waypoints_with_restrictions_are_rejected() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request
+            .with_waypoints(vec![Waypoint::from_address("Ottawa")])
+            .with_restrictions(vec![Avoid::Tolls]);
+        assert!(matches!(
+            request.validate(),
+            Err(Error::EitherRestrictionsOrWaypoints(1, _))
+        ));
+    } // fn
+
+    #[test]
+    fn traffic_model_requires_a_departure_time() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request.with_traffic_model(TrafficModel::Pessimistic);
+        assert!(matches!(
+            request.validate(),
+            Err(Error::TrafficModelRequiresDepartureTime(traffic_model)) if traffic_model == "pessimistic"
+        ));
+
+        request.with_departure_time(DepartureTime::Now);
+        assert!(request.validate().is_ok());
+    } // fn
+
+    #[test]
+    fn reports_every_problem_together() {
+        let client = client();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal")
+        );
+        request
+            .with_waypoints(vec![Waypoint::from_address("Ottawa")])
+            .with_alternatives(true)
+            .with_restrictions(vec![Avoid::Tolls])
+            .with_traffic_model(TrafficModel::BestGuess);
+
+        let error = request.validate().unwrap_err();
+        let Error::ValidationFailed(errors) = &error else {
+            panic!("expected `ValidationFailed`, got `{error:?}`");
+        }; // let
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                Error::EitherAlternativesOrWaypoints(1),
+                Error::EitherRestrictionsOrWaypoints(1, _),
+                Error::TrafficModelRequiresDepartureTime(_),
+            ]
+        ));
+
+        // The problems are listed one per line, and numbered:
+        let message = error.to_string();
+        let lines: Vec<&str> = message.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("The request failed validation for 3 reasons:"));
+        assert!(lines[1].starts_with("1. ") && lines[1].contains("with_alternatives()"));
+        assert!(lines[2].starts_with("2. ") && lines[2].contains("with_restrictions()"));
+        assert!(lines[3].starts_with("3. ") && lines[3].contains("with_traffic_model()"));
+    } // fn
+} // mod
@@ -47,6 +47,11 @@ pub enum Waypoint {
     /// See also: the Google Encoded Polyline encoding & decoding crate called
     /// [polyline](https://crates.io/crates/polyline).
     Polyline(String),
+    /// A location that the route passes through without stopping. The route
+    /// is not split into legs at a pass-through location, so it may be used
+    /// to make a route follow a path. Pass-through locations are only
+    /// supported by the _Directions API_.
+    Via(LatLng),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -62,6 +67,7 @@ impl std::convert::From<&Waypoint> for String {
             Waypoint::LatLng(latlng) => Self::from(latlng),
             Waypoint::PlaceId(place_id) => format!("place_id:{place_id}"),
             Waypoint::Polyline(polyline) => format!("enc:{polyline}:"),
+            Waypoint::Via(latlng) => format!("via:{latlng}"),
         } // match
     } // fn
 } // impl
@@ -110,6 +116,11 @@ pub enum Waypoint {
     /// See also: the Google Encoded Polyline encoding & decoding crate called
     /// [polyline](https://crates.io/crates/polyline).
     Polyline(String),
+    /// A location that the route passes through without stopping. The route
+    /// is not split into legs at a pass-through location, so it may be used
+    /// to make a route follow a path. Pass-through locations are only
+    /// supported by the _Directions API_.
+    Via(LatLng),
     /// If you pass coordinates, they are used unchanged to calculate
     /// directions. This variant supports the
     /// [geo](https://crates.io/crates/geo) crate's
@@ -139,6 +150,8 @@ impl std::convert::From<&Waypoint> for String {
 
             Waypoint::Polyline(polyline) => format!("enc:{polyline}:"),
 
+            Waypoint::Via(latlng) => format!("via:{latlng}"),
+
             Waypoint::Coord(coordinate) => format!(
                 "{latitude},{longitude}",
                 latitude = coordinate.y,
@@ -156,6 +169,20 @@ impl std::convert::From<&Waypoint> for String {
 
 // -----------------------------------------------------------------------------
 
+impl crate::client::Coordinates for Waypoint {
+    /// Rounds the coordinates of the `LatLng` and `Via` variants. All other
+    /// variants are returned unchanged.
+    fn rounded(&self, decimals: u8) -> Self {
+        match self {
+            Self::LatLng(latlng) => Self::LatLng(latlng.rounded(decimals)),
+            Self::Via(latlng) => Self::Via(latlng.rounded(decimals)),
+            _ => self.clone(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl Waypoint {
     /// If you pass an address, the Directions service geocodes the string and
     /// converts it to latitude & longitude coordinates to calculate directions.
@@ -240,6 +267,22 @@ impl Waypoint {
 
 // -----------------------------------------------------------------------------
 
+impl Waypoint {
+    /// Returns whether the waypoint is an address, place ID or polyline that
+    /// is empty or only whitespace. Google can not route through an empty
+    /// waypoint, so `Request::with_waypoints` discards them.
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Address(text) | Self::PlaceId(text) | Self::Polyline(text) => text.trim().is_empty(),
+            _ => false,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl From<LatLng> for Waypoint {
     /// Converts an owned `LatLng` coordinates type into a `Waypoint` type.
      fn from(latlng: LatLng) -> Self {
@@ -255,3 +298,47 @@ impl From<&LatLng> for Waypoint {
         Self::LatLng(*latlng)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&str> for Waypoint {
+    /// Converts a borrowed `&str` into a `Waypoint` type. The string is
+    /// treated as an address. Use `Waypoint::from_place_id` or
+    /// `Waypoint::from_polyline` for place IDs or encoded polylines.
+    fn from(address: &str) -> Self {
+        Self::Address(address.to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&String> for Waypoint {
+    /// Converts a borrowed `&String` into a `Waypoint` type. The string is
+    /// treated as an address.
+    fn from(address: &String) -> Self {
+        Self::Address(address.clone())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<String> for Waypoint {
+    /// Converts an owned `String` into a `Waypoint` type. The string is
+    /// treated as an address.
+    fn from(address: String) -> Self {
+        Self::Address(address)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<V: TryInto<Decimal>> TryFrom<(V, V)> for Waypoint {
+    type Error = GoogleMapsError;
+    /// Attempts to get a `Waypoint` from a `(lat, lng)` tuple that contains a
+    /// `0` latitude and a `1` longitude (in that order). If either the
+    /// latitude (-90.0 to +90.0) or longitude (-180.0 to +180.0) are out of
+    /// range, this function will return an error.
+    fn try_from(coordinates: (V, V)) -> Result<Self, Self::Error> {
+        Ok(Self::LatLng(LatLng::try_from(coordinates)?))
+    } // fn
+} // impl
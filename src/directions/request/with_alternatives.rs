@@ -1,6 +1,6 @@
 use crate::directions::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify whether service may provide more than one route alternative in
     /// the response.
     ///
@@ -24,9 +24,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_alternatives(
-        &'a mut self,
+        &mut self,
         alternatives: impl Into<bool>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.alternatives = Some(alternatives.into());
         self
     } // fn
@@ -1,6 +1,6 @@
 use crate::directions::request::{departure_time::DepartureTime, Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the desired departure time.
     ///
     /// ## Arguments
@@ -35,6 +35,13 @@ impl<'a> Request<'a> {
     ///   distributed nature of the service. Results may also vary between
     ///   nearly-equivalent routes at any time or frequency.
     ///
+    /// * Billing: a driving request that includes a departure time returns
+    ///   `duration_in_traffic` and is billed at the _Directions Advanced_ SKU.
+    ///   This crate never sends a departure time (or traffic model) unless one
+    ///   has been set with this method, so plain requests stay on the basic
+    ///   _Directions_ SKU. Use `Route::has_traffic_data` to check whether the
+    ///   response carries traffic-aware durations.
+    ///
     /// ## Examples:
     ///
     /// * Departing now:
@@ -42,6 +49,16 @@ impl<'a> Request<'a> {
     /// .with_departure_time(DepartureTime::Now)
     /// ```
     ///
+    /// `DepartureTime::Now` is sent to Google as the literal `now` rather than
+    /// as a timestamp, so the departure time is not affected by the local
+    /// clock being wrong. Prefer it over `Utc::now()`.
+    ///
+    /// * Departing at a `chrono` date & time. A `NaiveDateTime` is interpreted
+    ///   as UTC:
+    /// ```rust
+    /// .with_departure_time(Utc.with_ymd_and_hms(2030, 1, 1, 12, 30, 0).unwrap())
+    /// ```
+    ///
     /// * Departing on Tuesday February 22, 2022 at 1:00:00 PM:
     /// ```rust
     /// .with_departure_time(DepartureTime::At(
@@ -57,10 +74,68 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_departure_time(
-        &'a mut self,
+        &mut self,
         departure_time: impl Into<DepartureTime>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.departure_time = Some(departure_time.into());
         self
     } // fn
+
+    /// Specifies the desired departure time as a `std::time::SystemTime`. This
+    /// is the same as `with_departure_time`, for callers that do not use
+    /// `chrono`. Fractions of a second are truncated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_time` ‧ The soonest time the passenger intends to depart.
+    ///
+    /// ## Example:
+    ///
+    /// * Departing in one hour:
+    /// ```rust
+    /// .with_departure_system_time(SystemTime::now() + Duration::from_secs(3_600))?
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the time is before the Unix epoch, or too far in
+    ///   the future to be represented.
+
+    pub fn with_departure_system_time(
+        &mut self,
+        departure_time: std::time::SystemTime
+    ) -> Result<&mut Self, crate::error::Error> {
+        self.departure_time = Some(DepartureTime::try_from(departure_time)?);
+        Ok(self)
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{Location, TravelMode};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn plain_requests_send_no_departure_time() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request.with_travel_mode(TravelMode::Driving);
+
+        // Without a departure time or a traffic model, the request is billed
+        // at the basic rate:
+        assert_eq!(
+            request.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/directions/json\
+            ?key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000\
+            &origin=Toronto\
+            &destination=Montreal\
+            &mode=driving",
+        );
+    } // fn
+} // mod
@@ -1,6 +1,6 @@
 use crate::{directions::request::Request, types::Language};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify the language in which to return results.
     ///
     /// ## Arguments
@@ -45,9 +45,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.language = Some(language.into());
         self
     } // fn
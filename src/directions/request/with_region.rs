@@ -1,6 +1,6 @@
 use crate::{directions::request::Request, types::Region};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the region bias. There is a London in Canada and there is a
     /// London in England. By biasing the region, you help the directions
     /// service choose the London you intended.
@@ -32,9 +32,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_region(
-        &'a mut self,
+        &mut self,
         region: impl Into<Region>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.region = Some(region.into());
         self
     } // fn
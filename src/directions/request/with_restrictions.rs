@@ -1,6 +1,6 @@
 use crate::directions::request::{avoid::Avoid, Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify a feature that routes should avoid.
     ///
     /// ## Arguments
@@ -58,9 +58,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_restriction(
-        &'a mut self,
+        &mut self,
         restriction: impl Into<Avoid>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Add restriction to Request struct.
         self.restrictions = vec![restriction.into()];
         // Return modified Request struct to caller.
@@ -89,9 +89,9 @@ impl<'a> Request<'a> {
     /// type.
 
     pub fn with_restrictions<C, A>(
-        &'a mut self,
+        &mut self,
         restrictions: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = A>,
         A: Into<Avoid> {
@@ -1,6 +1,6 @@
 use crate::directions::request::{transit_mode::TransitMode, Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify the preferred mode of transit.
     ///
     /// ## Arguments
@@ -48,9 +48,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_mode(
-        &'a mut self,
+        &mut self,
         transit_mode: impl Into<TransitMode>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Add restiction to Request struct.
         self.transit_modes = vec![transit_mode.into()];
         // Return modified Request struct to caller.
@@ -80,9 +80,9 @@ impl<'a> Request<'a> {
     /// type.
 
     pub fn with_transit_modes<C, T>(
-        &'a mut self,
+        &mut self,
         transit_modes: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = T>,
         T: Into<TransitMode> {
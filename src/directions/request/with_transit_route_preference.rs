@@ -1,6 +1,6 @@
 use crate::directions::request::{transit_route_preference::TransitRoutePreference, Request}; // crate::directions::request
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the preferences for transit routes.
     ///
     /// ## Arguments
@@ -32,9 +32,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_route_preference(
-        &'a mut self,
+        &mut self,
         transit_route_preference: impl Into<TransitRoutePreference>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.transit_route_preference = Some(transit_route_preference.into());
         self
     } // fn
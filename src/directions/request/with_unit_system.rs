@@ -1,6 +1,6 @@
 use crate::directions::request::{unit_system::UnitSystem, Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the unit system to use when displaying results.
     ///
     /// ## Arguments
@@ -41,9 +41,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_unit_system(
-        &'a mut self,
+        &mut self,
         unit_system: impl Into<UnitSystem>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.unit_system = Some(unit_system.into());
         self
     } // fn
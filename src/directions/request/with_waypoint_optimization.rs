@@ -1,6 +1,6 @@
 use crate::directions::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies whether the waypoint order should be optimized or not.
     ///
     /// ## Arguments
@@ -42,9 +42,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_waypoint_optimization(
-        &'a mut self,
+        &mut self,
         waypoint_optimization: impl Into<bool>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.waypoint_optimization = waypoint_optimization.into();
         self
     } // fn
@@ -1,6 +1,6 @@
 use crate::directions::request::{waypoint::Waypoint, Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify pass throughs or stopovers at intermediate locations.
     ///
     /// ## Arguments
@@ -29,6 +29,16 @@ impl<'a> Request<'a> {
     /// * `Waypoint::Polyline`: Encoded polyline that can be specified by a set
     ///   of any of the above. (`lexeF{~wsZejrPjtye@:`)
     ///
+    /// Empty or whitespace-only addresses, place IDs and polylines are
+    /// discarded, and a waypoint that repeats the one before it is dropped. If
+    /// every waypoint is discarded, `validate()` returns an `EmptyWaypoints`
+    /// error rather than sending the request without waypoints.
+    ///
+    /// A `&str` or `String` converts into a `Waypoint::Address`, and a `LatLng`
+    /// into a `Waypoint::LatLng`. A `(lat, lng)` tuple, in that order, may be
+    /// converted with `Waypoint::try_from`, which checks that the coordinates
+    /// are in range.
+    ///
     /// [Waypoints](https://developers.google.com/maps/documentation/directions/intro#Waypoints)
     /// ----------------------------------------------------------------------------------------
     ///
@@ -94,14 +104,10 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_waypoint(
-        &'a mut self,
+        &mut self,
         waypoint: impl Into<Waypoint>
-    ) -> &'a mut Self {
-        let waypoint: Waypoint = waypoint.into();
-        // Add waypoint to Request struct.
-        self.waypoints = vec![waypoint];
-        // Return modified Request struct to caller.
-        self
+    ) -> &mut Self {
+        self.with_waypoints([waypoint])
     } // fn
 
     /// ## Example
@@ -131,15 +137,122 @@ impl<'a> Request<'a> {
     /// `W` generic is for any type that can be converted to a `Waypoint` type.
 
     pub fn with_waypoints<C, W>(
-        &'a mut self,
+        &mut self,
         waypoints: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = W>,
         W: Into<Waypoint> {
+        // Discard empty waypoints, and count them so that validation can
+        // report a list that was entirely empty:
+        let mut discarded_waypoints = 0;
+        let mut cleaned: Vec<Waypoint> = waypoints
+            .into_iter()
+            .map(Into::into)
+            .filter(|waypoint| {
+                let empty = waypoint.is_empty();
+                discarded_waypoints += usize::from(empty);
+                !empty
+            }) // filter
+            .collect();
+        // Visiting the same location twice in a row adds nothing to the route:
+        cleaned.dedup();
         // Add waypoints to Request struct.
-        self.waypoints = waypoints.into_iter().map(Into::into).collect();
+        self.waypoints = cleaned;
+        self.discarded_waypoints = discarded_waypoints;
         // Return modified Request struct to caller.
         self
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{Location, Waypoint};
+    use crate::types::LatLng;
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn accepts_three_kinds_of_waypoints_in_one_call() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request.with_waypoints([
+            Waypoint::from("Kingston, ON"),
+            Waypoint::try_from((45.40453, -75.682_107)).unwrap(),
+            Waypoint::from(LatLng::try_from_f64(44.231_172, -76.485_954).unwrap()),
+        ]); // with_waypoints
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/directions/json?\
+            key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000&origin=Toronto\
+            &destination=Montreal&waypoints=Kingston%2C%20ON\
+            %7C45%2E40453%2C%2D75%2E682107%7C44%2E231172%2C%2D76%2E485954",
+        );
+    } // fn
+
+    #[test]
+    fn drops_blank_and_repeated_waypoints() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request.with_waypoints([
+            Waypoint::from("Kingston, ON"),
+            // A blank between two copies of a waypoint makes them consecutive:
+            Waypoint::from("  "),
+            Waypoint::from("Kingston, ON"),
+            Waypoint::from("Ottawa, ON"),
+            Waypoint::PlaceId(String::new()),
+            Waypoint::from("Ottawa, ON"),
+            Waypoint::Polyline("\t".to_string()),
+            // Visiting a waypoint again later in the route is kept:
+            Waypoint::from("Kingston, ON"),
+        ]); // with_waypoints
+
+        assert_eq!(
+            request.waypoints,
+            [
+                Waypoint::from("Kingston, ON"),
+                Waypoint::from("Ottawa, ON"),
+                Waypoint::from("Kingston, ON"),
+            ],
+        );
+        assert!(request.validate().is_ok());
+        assert!(request
+            .query_url()
+            .unwrap()
+            .ends_with("&waypoints=Kingston%2C%20ON%7COttawa%2C%20ON%7CKingston%2C%20ON"));
+    } // fn
+
+    #[test]
+    fn rejects_waypoints_that_are_all_blank() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let mut request = client.directions(
+            Location::from_address("Toronto"),
+            Location::from_address("Montreal"),
+        ); // directions
+        request.with_waypoints([
+            Waypoint::from(""),
+            Waypoint::from("   "),
+            Waypoint::PlaceId(" ".to_string()),
+        ]); // with_waypoints
+
+        assert!(request.waypoints.is_empty());
+        assert!(matches!(
+            request.validate(),
+            Err(crate::directions::error::Error::EmptyWaypoints(3))
+        ));
+    } // fn
+} // mod
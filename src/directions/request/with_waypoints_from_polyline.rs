@@ -0,0 +1,68 @@
+use crate::directions::request::{waypoint::Waypoint, Request};
+use crate::types::LatLng;
+
+impl Request {
+    /// Makes the route follow a previously computed path, by passing through
+    /// points sampled from an encoded polyline.
+    ///
+    /// ## Arguments
+    ///
+    /// * `encoded` ‧ The path to follow, as an [encoded
+    ///   polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    ///   with a precision of 5. This is the format of the `overview_polyline`
+    ///   in a _Directions API_ response.
+    ///
+    /// * `max_points` ‧ The maximum number of points to pass through.
+    ///
+    /// ## Description
+    ///
+    /// The polyline is decoded, and its first and last points are dropped,
+    /// because they are the origin and destination of the route. If more than
+    /// `max_points` points remain, they are downsampled to `max_points` evenly
+    /// spaced points. The points are set as `Waypoint::Via` pass-through
+    /// waypoints, replacing any waypoints that have already been set.
+    ///
+    /// A request may have at most 25 waypoints, so `max_points` should not
+    /// exceed 25.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_waypoints_from_polyline(&route.overview_polyline.points, 20)?
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid, or if a decoded point is
+    ///   out of range.
+
+    pub fn with_waypoints_from_polyline(
+        &mut self,
+        encoded: &str,
+        max_points: usize
+    ) -> Result<&mut Self, crate::GoogleMapsError> {
+        let path = polyline::decode_polyline(encoded, 5)?;
+
+        // The first and last points are the origin and destination:
+        let points = path.0.get(1..path.0.len().saturating_sub(1)).unwrap_or_default();
+
+        // Pick `max_points` evenly spaced points, if there are more:
+        let indices: Vec<usize> = if points.len() > max_points {
+            (0..max_points)
+                .map(|index| index * points.len() / max_points)
+                .collect()
+        } else {
+            (0..points.len()).collect()
+        }; // if
+
+        self.waypoints = indices
+            .into_iter()
+            .map(|index| {
+                LatLng::try_from_f64(points[index].y, points[index].x).map(Waypoint::Via)
+            }) // map
+            .collect::<Result<Vec<Waypoint>, crate::GoogleMapsError>>()?;
+        self.discarded_waypoints = 0;
+
+        Ok(self)
+    } // fn
+} // impl
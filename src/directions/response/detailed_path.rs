@@ -0,0 +1,207 @@
+//! Decodes the full-resolution geometry of a `Route` or `Leg` from the
+//! polylines of its steps.
+
+use crate::directions::response::{leg::Leg, route::Route, step::Step};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+/// Polylines in a _Directions API_ response are encoded with a precision of 5
+/// decimal places.
+const PRECISION: u8 = 5;
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Returns the full-resolution path of the leg, by decoding the polyline
+    /// of every step in order. Consecutive duplicate points, such as the point
+    /// where one step ends and the next begins, are removed.
+    ///
+    /// The path starts and ends approximately at the leg's `start_location`
+    /// and `end_location`. Polylines are encoded with 5 decimal places, which
+    /// is about one meter, so the points may differ from the locations in the
+    /// response by that much.
+    ///
+    /// Unlike the route's `overview_polyline`, the step polylines are not
+    /// simplified. A long leg may have tens of thousands of points, each of
+    /// which is a `LatLng` of two `Decimal`s. To avoid holding the whole path
+    /// in memory, see `Leg::detailed_path_iter`.
+    /// ```rust
+    /// let path = leg.detailed_path()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a polyline is invalid or if the decoded
+    ///   coordinates are out of bounds.
+
+    pub fn detailed_path(&self) -> Result<Vec<LatLng>, crate::GoogleMapsError> {
+        self.detailed_path_iter().collect()
+    } // fn
+
+    /// Returns an iterator over the full-resolution path of the leg. This is
+    /// the same path as `Leg::detailed_path` returns, but only one step's
+    /// polyline is decoded at a time.
+    ///
+    /// If a polyline is invalid, the iterator yields the error and then ends.
+    /// ```rust
+    /// for point in leg.detailed_path_iter() {
+    ///     let point = point?;
+    /// }
+    /// ```
+
+    pub fn detailed_path_iter(
+        &self
+    ) -> impl Iterator<Item = Result<LatLng, crate::GoogleMapsError>> + '_ {
+        dedup(self.steps.iter().flat_map(decode))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Returns the full-resolution path of the route, by decoding the polyline
+    /// of every step of every leg in order. Consecutive duplicate points, such
+    /// as the point where one step or leg ends and the next begins, are
+    /// removed.
+    ///
+    /// Unlike the `overview_polyline`, the step polylines are not simplified.
+    /// A long route may have tens of thousands of points, each of which is a
+    /// `LatLng` of two `Decimal`s. To avoid holding the whole path in memory,
+    /// see `Route::detailed_path_iter`.
+    /// ```rust
+    /// let path = route.detailed_path()?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a polyline is invalid or if the decoded
+    ///   coordinates are out of bounds.
+
+    pub fn detailed_path(&self) -> Result<Vec<LatLng>, crate::GoogleMapsError> {
+        self.detailed_path_iter().collect()
+    } // fn
+
+    /// Returns an iterator over the full-resolution path of the route. This is
+    /// the same path as `Route::detailed_path` returns, but only one step's
+    /// polyline is decoded at a time.
+    ///
+    /// If a polyline is invalid, the iterator yields the error and then ends.
+    /// ```rust
+    /// for point in route.detailed_path_iter() {
+    ///     let point = point?;
+    /// }
+    /// ```
+
+    pub fn detailed_path_iter(
+        &self
+    ) -> impl Iterator<Item = Result<LatLng, crate::GoogleMapsError>> + '_ {
+        dedup(self.legs.iter().flat_map(|leg| &leg.steps).flat_map(decode))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a step's polyline into its points. If the polyline is invalid, a
+/// single error is returned in place of the points.
+
+fn decode(step: &Step) -> Vec<Result<LatLng, crate::GoogleMapsError>> {
+    match polyline::decode_polyline(&step.polyline.points, u32::from(PRECISION)) {
+        Ok(path) => path
+            .0
+            .into_iter()
+            .map(|coordinate| {
+                LatLng::try_from_f64(coordinate.y, coordinate.x)
+                    .map(|point| point.rounded(PRECISION))
+            }) // map
+            .collect(),
+        Err(error) => vec![Err(error.into())],
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Removes consecutive duplicate points from a path, and ends the path after
+/// the first error.
+
+fn dedup(
+    points: impl Iterator<Item = Result<LatLng, crate::GoogleMapsError>>
+) -> impl Iterator<Item = Result<LatLng, crate::GoogleMapsError>> {
+    let mut previous: Option<LatLng> = None;
+    let mut failed = false;
+
+    points.filter_map(move |point| {
+        if failed {
+            return None;
+        } // if
+        match point {
+            Ok(point) if previous.as_ref() == Some(&point) => None,
+            Ok(point) => {
+                previous = Some(point);
+                Some(Ok(point))
+            }, // Ok
+            Err(error) => {
+                failed = true;
+                Some(Err(error))
+            }, // Err
+        } // match
+    }) // filter_map
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::PRECISION;
+    use crate::directions::response::tests::route_through_kingston;
+
+    #[test]
+    fn decodes_every_step_of_a_leg() {
+        let route = route_through_kingston();
+
+        let overview = polyline::decode_polyline(&route.overview_polyline.points, PRECISION.into()).unwrap();
+
+        for leg in &route.legs {
+            let path = leg.detailed_path().unwrap();
+            assert!(path.len() > overview.0.len(), "{} points", path.len());
+
+            // Polylines have 5 decimal places, so the path starts and ends at
+            // the leg's locations rounded to 5 decimal places:
+            assert_eq!(path.first(), Some(&leg.start_location.rounded(PRECISION)));
+            assert_eq!(path.last(), Some(&leg.end_location.rounded(PRECISION)));
+        } // for
+
+        // Steps of 4 and 5 points, and of 4 and 4 points, that share their
+        // first and last points:
+        assert_eq!(route.legs[0].detailed_path().unwrap().len(), 8);
+        assert_eq!(route.legs[1].detailed_path().unwrap().len(), 7);
+    } // fn
+
+    #[test]
+    fn decodes_every_leg_of_a_route() {
+        let route = route_through_kingston();
+        let path = route.detailed_path().unwrap();
+
+        // The legs share Kingston, which is kept once:
+        assert_eq!(path.len(), 14);
+        assert_eq!(path[7], route.legs[1].start_location.rounded(PRECISION));
+        assert_ne!(path[8], path[7]);
+        assert_eq!(path.first(), Some(&route.legs[0].start_location.rounded(PRECISION)));
+        assert_eq!(path.last(), Some(&route.legs[1].end_location.rounded(PRECISION)));
+
+        let iterated: Vec<_> = route.detailed_path_iter().map(Result::unwrap).collect();
+        assert_eq!(iterated, path);
+    } // fn
+
+    #[test]
+    fn stops_at_an_invalid_polyline() {
+        let mut route = route_through_kingston();
+        route.legs[0].steps[1].polyline.points = "!!!".to_string();
+
+        let points: Vec<_> = route.detailed_path_iter().collect();
+        assert_eq!(points.len(), 5);
+        assert!(points[..4].iter().all(Result::is_ok));
+        assert!(points[4].is_err());
+        assert!(route.detailed_path().is_err());
+    } // fn
+} // mod
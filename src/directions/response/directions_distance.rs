@@ -1,5 +1,6 @@
 //! A representation of distance as a numeric value and a display string.
 
+use crate::serde::number_or_string::number_or_string;
 use serde::{Deserialize, Serialize};
 
 /// A representation of distance as a numeric value and a display string.
@@ -10,5 +11,94 @@ pub struct DirectionsDistance {
     /// `with_unit_system()` specified in the request.
     pub text: String,
     /// Indicates the distance in meters.
+    #[serde(deserialize_with = "number_or_string")]
     pub value: u32,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsDistance {
+    /// Formats the distance in metric units, from the `value` field rather
+    /// than Google's `text`. Distances under a kilometre are given in whole
+    /// meters, longer distances in kilometers with `precision` decimal
+    /// places.
+    /// ```rust
+    /// // "23.5 km"
+    /// let distance = leg.distance.format_metric(1);
+    /// ```
+
+    #[must_use]
+    pub fn format_metric(&self, precision: usize) -> String {
+        if self.value < 1_000 {
+            format!("{} m", self.value)
+        } else {
+            format!("{:.precision$} km", f64::from(self.value) / 1_000.0)
+        } // if
+    } // fn
+
+    /// Formats the distance in imperial units, from the `value` field rather
+    /// than Google's `text`. Distances under a tenth of a mile are given in
+    /// whole feet, longer distances in miles with `precision` decimal places.
+    /// ```rust
+    /// // "14.6 mi"
+    /// let distance = leg.distance.format_imperial(1);
+    /// ```
+
+    #[must_use]
+    pub fn format_imperial(&self, precision: usize) -> String {
+        let miles = f64::from(self.value) / METERS_PER_MILE;
+        if miles < 0.1 {
+            format!("{:.0} ft", f64::from(self.value) / METERS_PER_FOOT)
+        } else {
+            format!("{miles:.precision$} mi")
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// The length of an international mile, in meters.
+const METERS_PER_MILE: f64 = 1_609.344;
+
+/// The length of an international foot, in meters.
+const METERS_PER_FOOT: f64 = 0.3048;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::DirectionsDistance;
+
+    /// Returns a distance of `meters`, with no text.
+    fn distance(meters: u32) -> DirectionsDistance {
+        DirectionsDistance { text: String::new(), value: meters }
+    } // fn
+
+    #[test]
+    fn formats_metric_distances() {
+        for (meters, precision, expected) in [
+            (0, 1, "0 m"),
+            (999, 1, "999 m"),
+            (1_000, 1, "1.0 km"),
+            (1_000, 0, "1 km"),
+            (23_456, 1, "23.5 km"),
+            (23_456, 2, "23.46 km"),
+        ] {
+            assert_eq!(distance(meters).format_metric(precision), expected, "{meters} m");
+        } // for
+    } // fn
+
+    #[test]
+    fn formats_imperial_distances() {
+        for (meters, precision, expected) in [
+            (0, 1, "0 ft"),
+            (160, 1, "525 ft"),
+            (161, 1, "0.1 mi"),
+            (1_609, 1, "1.0 mi"),
+            (23_496, 1, "14.6 mi"),
+            (23_496, 0, "15 mi"),
+        ] {
+            assert_eq!(distance(meters).format_imperial(precision), expected, "{meters} m");
+        } // for
+    } // fn
+} // mod
@@ -18,3 +18,140 @@ pub struct DirectionsDuration {
     )]
     pub value: Duration,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsDuration {
+    /// Returns the duration as a `chrono::Duration`. This is the same as the
+    /// `value` field.
+    /// ```rust
+    /// let duration = leg.duration.as_chrono();
+    /// ```
+
+    #[must_use]
+    pub const fn as_chrono(&self) -> Duration {
+        self.value
+    } // fn
+
+    /// Returns the duration as a `std::time::Duration`.
+    ///
+    /// A `std::time::Duration` can not be negative. If Google returns a
+    /// negative duration, this function saturates and returns a zero duration
+    /// rather than failing.
+    /// ```rust
+    /// tokio::time::sleep(leg.duration.as_std()).await;
+    /// ```
+
+    #[must_use]
+    pub fn as_std(&self) -> std::time::Duration {
+        self.value.to_std().unwrap_or_default()
+    } // fn
+
+    /// Formats the duration as hours, minutes and seconds, such as `1:12:05`,
+    /// from the `value` field rather than Google's `text`. Hours are not
+    /// limited to 24, and negative durations are prefixed with `-`.
+    /// ```rust
+    /// // "1:12:05"
+    /// let duration = leg.duration.format_hms();
+    /// ```
+
+    #[must_use]
+    pub fn format_hms(&self) -> String {
+        let sign = if self.value < Duration::zero() { "-" } else { "" };
+        let seconds = self.value.num_seconds().unsigned_abs();
+        format!("{sign}{}:{:02}:{:02}", seconds / 3_600, seconds / 60 % 60, seconds % 60)
+    } // fn
+
+    /// Formats the duration in words, such as `1 h 12 min`, from the `value`
+    /// field rather than Google's `text`.
+    ///
+    /// At most `precision` units are shown, starting from the largest unit in
+    /// the duration. The duration is rounded to the smallest unit shown, and
+    /// units that round to zero are left out. Days, hours, minutes and seconds
+    /// are used. A `precision` of `0` is treated as `1`.
+    /// ```rust
+    /// // "1 h 12 min"
+    /// let duration = leg.duration.format_verbose(2);
+    /// ```
+
+    #[must_use]
+    pub fn format_verbose(&self, precision: usize) -> String {
+        const UNITS: [(u64, &str); 4] = [(86_400, "d"), (3_600, "h"), (60, "min"), (1, "s")];
+
+        let sign = if self.value < Duration::zero() { "-" } else { "" };
+        let mut seconds = self.value.num_seconds().unsigned_abs();
+
+        // Round to the smallest unit that will be shown:
+        let largest = UNITS.iter().position(|(unit, _)| seconds >= *unit).unwrap_or(UNITS.len() - 1);
+        let (smallest, _) = UNITS[largest.saturating_add(precision.max(1) - 1).min(UNITS.len() - 1)];
+        seconds = (seconds + smallest / 2) / smallest * smallest;
+
+        let parts: Vec<String> = UNITS
+            .iter()
+            .filter(|(unit, _)| *unit >= smallest)
+            .filter_map(|(unit, name)| {
+                let count = seconds / unit;
+                seconds %= unit;
+                (count > 0).then(|| format!("{count} {name}"))
+            }) // filter_map
+            .collect();
+
+        if parts.is_empty() {
+            format!("0 {}", UNITS[UNITS.len() - 1].1)
+        } else {
+            format!("{sign}{}", parts.join(" "))
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::DirectionsDuration;
+    use chrono::Duration;
+
+    /// Returns a duration of `seconds`, with no text.
+    fn duration(seconds: i64) -> DirectionsDuration {
+        DirectionsDuration { text: String::new(), value: Duration::seconds(seconds) }
+    } // fn
+
+    #[test]
+    fn formats_hours_minutes_and_seconds() {
+        for (seconds, expected) in [
+            (0, "0:00:00"),
+            (59, "0:00:59"),
+            (60, "0:01:00"),
+            (5_400, "1:30:00"),
+            (90_061, "25:01:01"),
+            (-90, "-0:01:30"),
+        ] {
+            assert_eq!(duration(seconds).format_hms(), expected, "{seconds} s");
+        } // for
+    } // fn
+
+    #[test]
+    fn formats_durations_in_words() {
+        for (seconds, precision, expected) in [
+            (0, 2, "0 s"),
+            (59, 2, "59 s"),
+            (60, 2, "1 min"),
+            (89, 1, "1 min"),
+            (90, 1, "2 min"),
+            (90, 2, "1 min 30 s"),
+            (5_400, 2, "1 h 30 min"),
+            (5_400, 1, "2 h"),
+            (5_400, 0, "2 h"),
+            (90_061, 4, "1 d 1 h 1 min 1 s"),
+            (-90, 2, "-1 min 30 s"),
+        ] {
+            assert_eq!(duration(seconds).format_verbose(precision), expected, "{seconds} s");
+        } // for
+    } // fn
+
+    #[test]
+    fn formats_durations_in_words_with_any_precision() {
+        assert_eq!(duration(5_400).format_verbose(usize::MAX), "1 h 30 min");
+        assert_eq!(duration(59).format_verbose(usize::MAX), "59 s");
+    } // fn
+} // mod
@@ -0,0 +1,270 @@
+//! Exports a `Route` as [GPX](https://www.topografix.com/gpx.asp) or
+//! [KML](https://developers.google.com/kml/documentation/kmlreference), so that
+//! it may be loaded into handheld GPS units, Google Earth, and other mapping
+//! software.
+
+use crate::directions::response::route::Route;
+use std::fmt::Write;
+
+// -----------------------------------------------------------------------------
+
+/// Polylines in a _Directions API_ response are encoded with a precision of 5
+/// decimal places.
+const PRECISION: u32 = 5;
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Exports the route as a [GPX 1.1](https://www.topografix.com/GPX/1/1/)
+    /// document. The document contains one track, named after the route's
+    /// `summary`, with a single track segment that follows the route's
+    /// `overview_polyline`.
+    ///
+    /// The overview polyline is smoothed. For a more detailed track, see
+    /// `Route::to_gpx_by_step`.
+    /// ```rust
+    /// std::fs::write("route.gpx", route.to_gpx()?)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid or if the decoded
+    ///   coordinates are out of bounds.
+
+    pub fn to_gpx(&self) -> Result<String, crate::error::Error> {
+        let segment = decode(&self.overview_polyline.points)?;
+        Ok(self.gpx(&[segment]))
+    } // fn
+
+    /// Exports the route as a [GPX 1.1](https://www.topografix.com/GPX/1/1/)
+    /// document. The document contains one track, named after the route's
+    /// `summary`, with one track segment for each step of each leg. Each
+    /// segment follows the step's `polyline`, which is more detailed than the
+    /// route's overview polyline.
+    /// ```rust
+    /// std::fs::write("route.gpx", route.to_gpx_by_step()?)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a polyline is invalid or if the decoded
+    ///   coordinates are out of bounds.
+
+    pub fn to_gpx_by_step(&self) -> Result<String, crate::error::Error> {
+        let segments = self
+            .legs
+            .iter()
+            .flat_map(|leg| &leg.steps)
+            .map(|step| decode(&step.polyline.points))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(self.gpx(&segments))
+    } // fn
+
+    /// Exports the route as a [KML
+    /// 2.2](https://developers.google.com/kml/documentation/kmlreference)
+    /// document. The document contains one placemark, named after the route's
+    /// `summary` and described by its `copyrights`, with a `LineString` that
+    /// follows the route's `overview_polyline`.
+    /// ```rust
+    /// std::fs::write("route.kml", route.to_kml()?)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid or if the decoded
+    ///   coordinates are out of bounds.
+
+    pub fn to_kml(&self) -> Result<String, crate::error::Error> {
+        let path = decode(&self.overview_polyline.points)?;
+
+        // KML coordinates are `longitude,latitude` tuples, separated by
+        // whitespace:
+        let coordinates = path
+            .iter()
+            .map(|(longitude, latitude)| format!("{longitude:.5},{latitude:.5}"))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        Ok(format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <kml xmlns=\"http://www.opengis.net/kml/2.2\">\n\
+            \x20 <Placemark>\n\
+            \x20   <name>{name}</name>\n\
+            \x20   <description>{description}</description>\n\
+            \x20   <LineString>\n\
+            \x20     <tessellate>1</tessellate>\n\
+            \x20     <coordinates>{coordinates}</coordinates>\n\
+            \x20   </LineString>\n\
+            \x20 </Placemark>\n\
+            </kml>\n",
+            name = escape(&self.summary),
+            description = escape(&self.copyrights),
+        ))
+    } // fn
+
+    /// Writes a GPX document with one track, named after the route's
+    /// `summary`, containing the given track segments.
+
+    fn gpx(&self, segments: &[Vec<(f64, f64)>]) -> String {
+        let mut gpx = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <gpx version=\"1.1\" creator=\"google_maps\" xmlns=\"http://www.topografix.com/GPX/1/1\">\n\
+            \x20 <trk>\n\
+            \x20   <name>{name}</name>\n",
+            name = escape(&self.summary),
+        );
+
+        for segment in segments {
+            gpx.push_str("    <trkseg>\n");
+            for (longitude, latitude) in segment {
+                // Writing to a `String` cannot fail:
+                let _ = writeln!(
+                    gpx,
+                    "      <trkpt lat=\"{latitude:.5}\" lon=\"{longitude:.5}\"/>",
+                );
+            } // for
+            gpx.push_str("    </trkseg>\n");
+        } // for
+
+        gpx.push_str("  </trk>\n</gpx>\n");
+        gpx
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes an encoded polyline into `(longitude, latitude)` pairs.
+
+fn decode(points: &str) -> Result<Vec<(f64, f64)>, crate::error::Error> {
+    Ok(polyline::decode_polyline(points, PRECISION)?
+        .0
+        .into_iter()
+        .map(|coordinate| (coordinate.x, coordinate.y))
+        .collect())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Escapes the characters that have a special meaning in XML text and
+/// attribute values.
+
+fn escape(text: &str) -> String {
+    text.chars().fold(String::with_capacity(text.len()), |mut escaped, character| {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(character),
+        } // match
+        escaped
+    }) // fold
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::response::tests::route_through_kingston;
+
+    /// Returns the text between the first `<tag>` and `</tag>` in a document.
+    fn text_of<'a>(xml: &'a str, tag: &str) -> &'a str {
+        let start = xml.find(&format!("<{tag}>")).unwrap() + tag.len() + 2;
+        let end = xml.find(&format!("</{tag}>")).unwrap();
+        &xml[start..end]
+    } // fn
+
+    #[test]
+    fn exports_the_overview_polyline_as_one_gpx_segment() {
+        let gpx = route_through_kingston().to_gpx().unwrap();
+
+        assert_eq!(gpx.matches("<trkseg>").count(), 1);
+        assert_eq!(gpx.matches("<trkpt ").count(), 5);
+        assert!(gpx.contains("<trkpt lat=\"43.65331\" lon=\"-79.38342\"/>"));
+        assert!(gpx.contains("<trkpt lat=\"45.50192\" lon=\"-73.56739\"/>"));
+        assert_eq!(text_of(&gpx, "name"), "ON-401 E and A-20 E");
+    } // fn
+
+    #[test]
+    fn exports_one_gpx_segment_per_step() {
+        let gpx = route_through_kingston().to_gpx_by_step().unwrap();
+
+        // The step polylines have 4, 5, 4 and 4 points:
+        assert_eq!(gpx.matches("<trkseg>").count(), 4);
+        assert_eq!(gpx.matches("</trkseg>").count(), 4);
+        assert_eq!(gpx.matches("<trkpt ").count(), 17);
+    } // fn
+
+    #[test]
+    fn exports_kml_coordinates_as_longitude_then_latitude() {
+        let kml = route_through_kingston().to_kml().unwrap();
+        let coordinates: Vec<&str> = text_of(&kml, "coordinates").split_whitespace().collect();
+
+        assert_eq!(coordinates.len(), 5);
+        assert_eq!(coordinates[0], "-79.38342,43.65331");
+        assert_eq!(coordinates[2], "-76.48595,44.23117");
+        assert_eq!(coordinates[4], "-73.56739,45.50192");
+        assert_eq!(text_of(&kml, "description"), "Map data ©2024 Google");
+    } // fn
+
+    #[test]
+    fn escapes_the_route_summary() {
+        let mut route = route_through_kingston();
+        route.summary = "Tom & Jerry's <Highway> \"1\"".to_string();
+        let escaped = "Tom &amp; Jerry&apos;s &lt;Highway&gt; &quot;1&quot;";
+
+        assert_eq!(text_of(&route.to_gpx().unwrap(), "name"), escaped);
+        assert_eq!(text_of(&route.to_gpx_by_step().unwrap(), "name"), escaped);
+        assert_eq!(text_of(&route.to_kml().unwrap(), "name"), escaped);
+    } // fn
+
+    /// Parses every exported document with an XML parser, which checks that
+    /// each element is closed and that the escaped summary reads back as the
+    /// original.
+    #[cfg(feature = "gpx")]
+    #[test]
+    fn exports_well_formed_xml() {
+        use quick_xml::events::Event;
+
+        let mut route = route_through_kingston();
+        route.summary = "Tom & Jerry's <Highway> \"1\"".to_string();
+
+        let documents = [
+            route.to_gpx().unwrap(),
+            route.to_gpx_by_step().unwrap(),
+            route.to_kml().unwrap(),
+        ]; // documents
+
+        for document in &documents {
+            let mut reader = quick_xml::Reader::from_str(document);
+            let mut depth = 0_usize;
+            let mut in_name = false;
+            let mut name = String::new();
+            loop {
+                match reader.read_event().unwrap() {
+                    Event::Start(element) => {
+                        depth += 1;
+                        in_name = element.local_name().as_ref() == b"name";
+                    }, // Start
+                    Event::End(_) => {
+                        depth -= 1;
+                        in_name = false;
+                    }, // End
+                    Event::Text(text) if in_name => name.push_str(&text.unescape().unwrap()),
+                    Event::Eof => break,
+                    _ => {},
+                } // match
+            } // loop
+            assert_eq!(depth, 0, "{document}");
+            assert_eq!(name, route.summary, "{document}");
+        } // for
+
+        // The GPX track points read back as the step polylines' points, with
+        // the latitude and longitude the right way around:
+        let points = crate::types::LatLng::vec_from_gpx_track(documents[1].as_bytes()).unwrap();
+        assert_eq!(points.len(), 17);
+        assert_eq!(points.first(), Some(&route.legs[0].start_location.rounded(5)));
+        assert_eq!(points.last(), Some(&route.legs[1].end_location.rounded(5)));
+    } // fn
+} // mod
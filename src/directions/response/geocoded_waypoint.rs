@@ -1,11 +1,20 @@
-use crate::{directions::response::geocoder_status::GeocoderStatus, types::PlaceType}; // use
+use crate::{
+    directions::response::geocoder_status::GeocoderStatus,
+    types::{MaybeKnown, PlaceType},
+}; // use
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// Details about the geocoding of the origin, the destination, or one of the
+/// waypoints of a request. A response's `geocoded_waypoints` are in the same
+/// order as the request: the origin, then each waypoint, then the destination.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct GeocodedWaypoint {
     /// Indicates the status code resulting from the geocoding operation.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub geocoder_status: Option<GeocoderStatus>,
+    pub geocoder_status: Option<MaybeKnown<GeocoderStatus>>,
     /// Whether the geocoder did not return an exact match for the original
     /// waypoint, though it was able to match part of the requested address.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -22,5 +31,25 @@ pub struct GeocodedWaypoint {
     /// section of the Developer's Guide.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub types: Vec<PlaceType>,
+    pub types: Vec<MaybeKnown<PlaceType>>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl GeocodedWaypoint {
+    /// Returns whether Google may have interpreted this waypoint differently
+    /// than intended: either it only matched part of the requested address, or
+    /// its geocoder status is something other than `OK`.
+    ///
+    /// For example, a request for "Springfield" may be partially matched to
+    /// one of the many places with that name.
+
+    #[must_use]
+    pub fn is_ambiguous(&self) -> bool {
+        self.partial_match == Some(true)
+            || self
+                .geocoder_status
+                .as_ref()
+                .is_some_and(|status| *status != GeocoderStatus::Ok)
+    } // fn
+} // impl
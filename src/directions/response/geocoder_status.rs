@@ -15,15 +15,15 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub enum GeocoderStatus {
     /// Indicates that no errors occurred; the address was successfully parsed
     /// and at least one geocode was returned.
-    #[serde(alias = "OK")]
+    #[serde(rename = "OK")]
     Ok,
     /// Indicates that the geocode was successful but returned no results. This
     /// may occur if the geocoder was passed a non-existent `address`.
-    #[serde(alias = "ZERO_RESULTS")]
+    #[serde(rename = "ZERO_RESULTS")]
     ZeroResults,
     /// Indicates that the request could not be processed due to a server error.
     /// The request may succeed if you try again.
-    #[serde(alias = "UNKNOWN_ERROR")]
+    #[serde(rename = "UNKNOWN_ERROR")]
     UnknownError,
 } // struct
 
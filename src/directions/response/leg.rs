@@ -5,6 +5,7 @@ use crate::directions::response::{
     directions_distance::DirectionsDistance, directions_duration::DirectionsDuration, step::Step,
     transit_time::TransitTime,
 }; // crate::directions::response
+use crate::serde::decimal_as_number::latlng_as_numbers;
 use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
 
@@ -44,6 +45,7 @@ pub struct Leg {
     /// locations. `end_location` indicates the actual geocoded destination,
     /// which may be different than the `end_location` of the last step if, for
     /// example, the road is not near the destination of this leg.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub end_location: LatLng,
     /// The address of the origin of this leg.
     pub start_address: String,
@@ -52,6 +54,7 @@ pub struct Leg {
     /// locations. `start_location` indicates the actual geocoded origin, which
     /// may be different than the `start_location` of the first step if, for
     /// example, the road is not near the origin of this leg.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub start_location: LatLng,
     /// An array of `Steps`, each of which contains information about the
     /// individual steps in this leg.
@@ -178,4 +181,45 @@ impl Leg {
             .as_ref()
             .map(|time| time.time_zone.name().to_string())
     } // fn
+
+    /// Returns the geocoded origin and destination of this leg, as a
+    /// `(start_location, end_location)` pair. These are the locations that
+    /// Google snapped the leg's endpoints to, which may differ from the
+    /// requested waypoints.
+    /// ```rust
+    /// let (start, end) = leg.start_end();
+    /// ```
+
+    #[must_use]
+    pub const fn start_end(&self) -> (LatLng, LatLng) {
+        (self.start_location, self.end_location)
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::response::tests::route_through_kingston;
+    use crate::types::LatLng;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn returns_where_each_leg_starts_and_ends() {
+        let route = route_through_kingston();
+        let toronto = LatLng::try_from_dec(dec!(43.6533096), dec!(-79.3834186)).unwrap();
+        let kingston = LatLng::try_from_dec(dec!(44.2311717), dec!(-76.4859544)).unwrap();
+        let montreal = LatLng::try_from_dec(dec!(45.5019196), dec!(-73.5673914)).unwrap();
+
+        assert_eq!(route.legs[0].start_end(), (toronto, kingston));
+        assert_eq!(route.legs[1].start_end(), (kingston, montreal));
+
+        // Each leg starts where its first step starts, and ends where its last
+        // step ends:
+        for leg in &route.legs {
+            let (start, end) = leg.start_end();
+            assert_eq!(start, leg.steps.first().unwrap().start_location);
+            assert_eq!(end, leg.steps.last().unwrap().end_location);
+        } // for
+    } // fn
+} // mod
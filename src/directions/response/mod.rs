@@ -2,9 +2,13 @@
 //! the Google Maps Platform. Look in here for more information about the data
 //! returned from Google's server and how to parse it with your program.
 
+#[cfg(feature = "polyline")]
+mod detailed_path;
 pub mod directions_distance;
 pub mod directions_duration;
 pub mod driving_maneuver;
+#[cfg(feature = "polyline")]
+mod export;
 pub mod geocoded_waypoint;
 pub mod geocoder_status;
 pub mod leg;
@@ -26,6 +30,8 @@ use crate::directions::{
     response::{geocoded_waypoint::GeocodedWaypoint, route::Route, status::Status}, // response
     travel_mode::TravelMode,
 }; // crate::directions
+use crate::types::MaybeKnown;
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 /// Directions responses contain the following root elements.
@@ -39,7 +45,7 @@ pub struct Response {
     /// are `via:` waypoints.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub available_travel_modes: Vec<TravelMode>,
+    pub available_travel_modes: Vec<MaybeKnown<TravelMode>>,
 
     /// When the status code is other than `OK`, there may be an additional
     /// `error_message` field within the Directions response object. This field
@@ -70,14 +76,334 @@ pub struct Response {
     /// Contains metadata on the request. See [Status
     /// Codes](https://developers.google.com/maps/documentation/directions/intro#StatusCodes).
     pub status: Status,
+
+    /// Contains an array of warnings that apply to the whole response, if
+    /// Google returned any. Warnings are usually returned for each route
+    /// instead, in `Route::warnings`, alongside the route's `copyrights`. You
+    /// must handle and display these warnings yourself.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the route with the shortest total duration, as calculated by
+    /// `Route::total_duration`. This is useful for choosing between several
+    /// routes when `alternatives` is requested. If several routes are equally
+    /// fast, the first one is returned.
+    ///
+    /// Returns `None` if the response contains no routes.
+    /// ```rust
+    /// let fastest_route = directions.fastest();
+    /// ```
+
+    #[must_use]
+    pub fn fastest(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.total_duration())
+    } // fn
+
+    /// Returns the route with the shortest total distance, as calculated by
+    /// `Route::total_distance`. If several routes are equally short, the first
+    /// one is returned.
+    ///
+    /// Returns `None` if the response contains no routes.
+    /// ```rust
+    /// let shortest_route = directions.shortest();
+    /// ```
+
+    #[must_use]
+    pub fn shortest(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| route.total_distance())
+    } // fn
+
+    /// Returns the transit route that requires the fewest transfers, that is
+    /// the route with the fewest steps that have `transit_details`. If several
+    /// routes have as few transfers, the first one is returned.
+    ///
+    /// This is only meaningful for `TravelMode::Transit` requests. For other
+    /// travel modes, the first route is returned.
+    ///
+    /// Returns `None` if the response contains no routes.
+    /// ```rust
+    /// let simplest_route = directions.fewest_transfers();
+    /// ```
+
+    #[must_use]
+    pub fn fewest_transfers(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| {
+            route
+                .legs
+                .iter()
+                .flat_map(|leg| &leg.steps)
+                .filter(|step| step.transit_details.is_some())
+                .count()
+        }) // min_by_key
+    } // fn
+
+    /// Returns the indices of the `geocoded_waypoints` that Google may have
+    /// interpreted differently than intended, as determined by
+    /// `GeocodedWaypoint::is_ambiguous`. Index `0` is the origin and the last
+    /// index is the destination; the indices in between are the waypoints.
+    ///
+    /// Returns an empty `Vec` if every waypoint was geocoded exactly.
+    /// ```rust
+    /// for index in directions.ambiguous_waypoints() {
+    ///     println!("waypoint {index} may not be what you meant");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn ambiguous_waypoints(&self) -> Vec<usize> {
+        self.geocoded_waypoints
+            .iter()
+            .enumerate()
+            .filter(|(_index, waypoint)| waypoint.is_ambiguous())
+            .map(|(index, _waypoint)| index)
+            .collect()
+    } // fn
+
+    /// Returns the recognized travel modes from `available_travel_modes`.
+    /// When a request gets `ZERO_RESULTS` for its travel mode, these are the
+    /// modes that the user could switch to. Travel modes that this client
+    /// does not recognize are skipped.
+    ///
+    /// Returns an empty `Vec` if Google did not suggest any travel modes.
+    /// ```rust
+    /// for travel_mode in directions.known_available_travel_modes() {
+    ///     println!("try {travel_mode} instead");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn known_available_travel_modes(&self) -> Vec<TravelMode> {
+        self.available_travel_modes
+            .iter()
+            .filter_map(MaybeKnown::known)
+            .cloned()
+            .collect()
+    } // fn
+
+    /// Returns an iterator over the routes of the response, in the order that
+    /// Google returned them. This is the same as `routes.iter()`.
+    /// ```rust
+    /// let summaries: Vec<&str> = directions.iter().map(|route| route.summary.as_str()).collect();
+    /// ```
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Route> {
+        self.routes.iter()
+    } // fn
+
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.routes.is_empty().then_some("routes")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Directions API JSON `String` response into a
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl<'r> IntoIterator for &'r Response {
+    type Item = &'r Route;
+    type IntoIter = std::slice::Iter<'r, Route>;
+
+    /// Iterates over the routes of the response, in the order that Google
+    /// returned them. This is the same as iterating over `routes`.
+    /// ```rust
+    /// for route in &directions {
+    ///     println!("{}", route.summary);
+    /// }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::ops::Index<usize> for Response {
+    type Output = Route;
+
+    /// Returns the route at `index`. This is the same as indexing `routes`.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if `index` is out of range, for example when the response has
+    ///   no routes. Use `routes.get(index)` to check first.
+    fn index(&self, index: usize) -> &Route {
+        &self.routes[index]
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+pub(crate) mod tests {
+    use super::{Response, Route};
+    use crate::json::tests::{assert_round_trip, cassette_body, fixture};
+
+    /// Returns the route of the `directions_waypoints` fixture: from Toronto to
+    /// Montreal with a stop in Kingston, in two legs of two steps each.
+
+    pub fn route_through_kingston() -> Route {
+        let response: Response = fixture("directions_waypoints").parse().unwrap();
+        response.routes.into_iter().next().unwrap()
+    } // fn
+
+    /// Returns the `directions_alternatives` fixture: three transit routes from
+    /// Toronto to Montreal. The second route is the fastest, the third is the
+    /// shortest, and the first and third each take a single transit vehicle.
+    fn alternatives() -> Response {
+        fixture("directions_alternatives").parse().unwrap()
+    } // fn
+
+    #[test]
+    fn directions_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("directions", 0));
+    } // fn
+
+    #[test]
+    fn parses_a_route_with_two_legs() {
+        assert_round_trip::<Response>(&fixture("directions_waypoints"));
+
+        let route = route_through_kingston();
+        assert_eq!(route.legs.len(), 2);
+        assert_eq!(route.legs[0].end_address, route.legs[1].start_address);
+        assert!(route.legs.iter().all(|leg| leg.steps.len() == 2));
+    } // fn
+
+    #[test]
+    fn transit_alternatives_round_trip() {
+        assert_round_trip::<Response>(&fixture("directions_alternatives"));
+    } // fn
+
+    #[test]
+    fn picks_the_fastest_and_the_shortest_route() {
+        let response = alternatives();
+
+        let fastest = response.fastest().unwrap();
+        assert_eq!(fastest.summary, "GO Transit and VIA Rail");
+        assert_eq!(fastest.total_duration(), chrono::Duration::seconds(18_900));
+
+        let shortest = response.shortest().unwrap();
+        assert_eq!(shortest.summary, "Megabus");
+        assert_eq!(shortest.total_distance(), 538_000);
+    } // fn
+
+    #[test]
+    fn picks_the_first_of_the_routes_with_the_fewest_transfers() {
+        let mut response = alternatives();
+        assert_eq!(response.fewest_transfers().unwrap().summary, "VIA Rail");
+
+        response.routes.reverse();
+        assert_eq!(response.fewest_transfers().unwrap().summary, "Megabus");
+    } // fn
+
+    #[test]
+    fn picks_the_first_of_equally_fast_or_short_routes() {
+        let mut response = alternatives();
+        for route in &mut response.routes {
+            route.legs[0].duration.value = chrono::Duration::seconds(20_000);
+            route.legs[0].distance.value = 540_000;
+        } // for
+
+        assert_eq!(response.fastest().unwrap().summary, "VIA Rail");
+        assert_eq!(response.shortest().unwrap().summary, "VIA Rail");
+    } // fn
+
+    #[test]
+    fn picks_no_route_from_an_empty_response() {
+        let mut response = alternatives();
+        response.routes.clear();
+
+        assert!(response.fastest().is_none());
+        assert!(response.shortest().is_none());
+        assert!(response.fewest_transfers().is_none());
+    } // fn
+
+    #[test]
+    fn suggests_other_travel_modes_when_there_are_no_results() {
+        use crate::directions::response::status::Status;
+        use crate::directions::travel_mode::TravelMode;
+
+        let json = fixture("directions_zero_results");
+        assert_round_trip::<Response>(&json);
+
+        let response: Response = json.parse().unwrap();
+        assert_eq!(response.status, Status::ZeroResults);
+        assert!(response.routes.is_empty());
+        assert_eq!(response.known_available_travel_modes(), [TravelMode::Driving, TravelMode::Transit]);
+    } // fn
+
+    #[test]
+    fn iterates_over_and_indexes_the_routes() {
+        let response = alternatives();
+        let summaries = ["VIA Rail", "GO Transit and VIA Rail", "Megabus"];
+
+        let iterated: Vec<&str> = response.iter().map(|route| route.summary.as_str()).collect();
+        assert_eq!(iterated, summaries);
+
+        let mut looped = Vec::new();
+        for route in &response {
+            looped.push(route.summary.as_str());
+        } // for
+        assert_eq!(looped, summaries);
+
+        assert_eq!(response[1].summary, "GO Transit and VIA Rail");
+        assert_eq!(response[2], response.routes[2]);
+    } // fn
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn panics_when_indexing_past_the_last_route() {
+        let _route = &alternatives()[3];
+    } // fn
+
+    #[test]
+    fn lists_the_waypoints_that_were_not_geocoded_exactly() {
+        let json = r#"{
+            "geocoded_waypoints": [
+                { "geocoder_status": "OK", "place_id": "ChIJpTvG15DL1IkRd8S0KlBVNTI" },
+                { "geocoder_status": "OK", "partial_match": true, "place_id": "ChIJlbBzLqCv0kwRPaFEUQwJ6cE" },
+                { "geocoder_status": "ZERO_RESULTS" },
+                { "geocoder_status": "OK", "partial_match": false, "place_id": "ChIJDbdkHFQayUwR7-8fITgxTmU" }
+            ],
+            "routes": [],
+            "status": "NOT_FOUND",
+            "warnings": ["Walking directions are in beta. Use caution."]
+        }"#;
+        assert_round_trip::<Response>(json);
+
+        let response: Response = json.parse().unwrap();
+        assert_eq!(response.ambiguous_waypoints(), [1, 2]);
+        assert_eq!(response.warnings, ["Walking directions are in beta. Use caution."]);
+
+        let response = alternatives();
+        assert!(response.ambiguous_waypoints().is_empty());
+        assert!(response.warnings.is_empty());
+    } // fn
+} // mod
@@ -1,11 +1,15 @@
 //! A single route containing a set of legs.
 
+use crate::directions::error::Error as DirectionsError;
 use crate::directions::response::{
+    directions_duration::DirectionsDuration,
     leg::Leg,
     overview_polyline::OverviewPolyline,
     transit_fare::TransitFare
 };
+use crate::serde::decimal_as_number::bounds_as_numbers;
 use crate::types::Bounds;
+use chrono::Duration;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +23,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Route {
     /// The bounds for this route.
+    #[serde(serialize_with = "bounds_as_numbers")]
     pub bounds: Bounds,
     /// Copyrights text to be displayed for this route.
     pub copyrights: String,
@@ -172,10 +177,161 @@ impl Route {
             )
         } // if
     } // fn
+
+    /// Returns the total duration of the route, that is the sum of the
+    /// durations of all of its legs.
+    ///
+    /// Negative leg durations are treated as zero, and the total saturates at
+    /// the maximum `chrono::Duration` rather than overflowing.
+    /// ```rust
+    /// let total_duration = route.total_duration();
+    /// ```
+
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        sum_durations(self.legs.iter().map(|leg| &leg.duration))
+    } // fn
+
+    /// Returns the total duration of the route taking traffic conditions into
+    /// account, that is the sum of the `duration_in_traffic` of all of its
+    /// legs. If any leg is missing a `duration_in_traffic`, this function will
+    /// return `None`.
+    ///
+    /// Negative leg durations are treated as zero, and the total saturates at
+    /// the maximum `chrono::Duration` rather than overflowing.
+    /// ```rust
+    /// let total_duration_in_traffic = route.total_duration_in_traffic();
+    /// ```
+
+    #[must_use]
+    pub fn total_duration_in_traffic(&self) -> Option<Duration> {
+        self.legs
+            .iter()
+            .map(|leg| leg.duration_in_traffic.as_ref())
+            .collect::<Option<Vec<&DirectionsDuration>>>()
+            .map(sum_durations)
+    } // fn
+
+    /// Returns whether Google returned traffic-aware durations for this route,
+    /// that is whether every leg has a `duration_in_traffic`.
+    ///
+    /// Google only returns `duration_in_traffic` when the request specified a
+    /// `departure_time` (which bills at the higher _Advanced_ SKU). This may be
+    /// used to check whether `total_duration_in_traffic` is available.
+    /// ```rust
+    /// if route.has_traffic_data() { /* ... */ }
+    /// ```
+
+    #[must_use]
+    pub fn has_traffic_data(&self) -> bool {
+        !self.legs.is_empty()
+            && self
+                .legs
+                .iter()
+                .all(|leg| leg.duration_in_traffic.is_some())
+    } // fn
+
+    /// Returns the total distance of the route in meters, that is the sum of
+    /// the distances of all of its legs.
+    /// ```rust
+    /// let total_distance = route.total_distance();
+    /// ```
+
+    #[must_use]
+    pub fn total_distance(&self) -> u64 {
+        self.legs
+            .iter()
+            .map(|leg| u64::from(leg.distance.value))
+            .sum()
+    } // fn
+
+    /// Returns the route's `bounds` grown by `padding_degrees` on every side.
+    /// This may be used to frame the entire route on a map in one call. See
+    /// `Bounds::expand` for details.
+    /// ```rust
+    /// let viewport = route.fit_bounds(0.01);
+    /// ```
+
+    #[must_use]
+    pub fn fit_bounds(&self, padding_degrees: f64) -> Bounds {
+        self.bounds.expand(padding_degrees)
+    } // fn
+
+    /// Returns the route without its geometry: the `overview_polyline` is
+    /// emptied and the `steps` of every leg are removed, along with their
+    /// polylines. The legs' distances, durations, addresses and locations, and
+    /// the route's `bounds`, are kept.
+    ///
+    /// The Directions API has no parameter for requesting a route without its
+    /// geometry. When only the distance or the time of arrival is needed, this
+    /// may be used to reduce the size of a route that is kept in memory, cached
+    /// or serialized.
+    /// ```rust
+    /// let eta_only = route.without_geometry();
+    /// ```
+
+    #[must_use]
+    pub fn without_geometry(mut self) -> Self {
+        self.overview_polyline.points.clear();
+        for leg in &mut self.legs {
+            leg.steps.clear();
+        } // for
+        self
+    } // fn
+
+    /// Applies the `waypoint_order` of an optimized route to the caller's own
+    /// waypoint data. The `original` slice should contain one element (for
+    /// example, a label) per waypoint, in the order that the waypoints were
+    /// given in the request. The elements are returned in the optimized order.
+    /// ```rust
+    /// let stops = route.reorder(&["Phoenix", "Bangor", "Dallas"])?;
+    /// // If `waypoint_order` is [0, 2, 1], `stops` is now
+    /// // ["Phoenix", "Dallas", "Bangor"]
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the number of elements in `original` does not
+    ///   match the length of `waypoint_order`. Note that `waypoint_order` is
+    ///   empty if waypoint optimization was not requested.
+    ///
+    /// * Returns an error if `waypoint_order` refers to an element that is
+    ///   out of range.
+    pub fn reorder<T: Clone>(&self, original: &[T]) -> Result<Vec<T>, DirectionsError> {
+        if original.len() != self.waypoint_order.len() {
+            return Err(DirectionsError::WaypointOrderMismatch(
+                original.len(),
+                self.waypoint_order.len(),
+            ));
+        } // if
+
+        self.waypoint_order
+            .iter()
+            .map(|index| {
+                original
+                    .get(usize::from(*index))
+                    .cloned()
+                    .ok_or(DirectionsError::WaypointOrderOutOfRange(*index, original.len()))
+            })
+            .collect()
+    } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
 
+/// Sums durations defensively: negative durations are treated as zero, and the
+/// total saturates at the maximum `chrono::Duration`.
+
+fn sum_durations<'a>(durations: impl IntoIterator<Item = &'a DirectionsDuration>) -> Duration {
+    durations.into_iter().fold(Duration::zero(), |total, duration| {
+        total
+            .checked_add(&duration.value.max(Duration::zero()))
+            .unwrap_or(Duration::MAX)
+    })
+} // fn
+
+// -----------------------------------------------------------------------------
+
 #[cfg(all(feature = "polyline", feature = "geo"))]
 impl Route {
     /// Attempts to convert a borrowed `&Route` struct to a
@@ -239,4 +395,149 @@ impl TryFrom<Route> for geo_types::geometry::LineString<f64> {
     fn try_from(route: Route) -> Result<Self, Self::Error> {
         route.decode_polyline(5)
     } // fn
-} // impl
\ No newline at end of file
+} // impl
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::error::Error as DirectionsError;
+    use crate::directions::response::directions_duration::DirectionsDuration;
+    use crate::directions::response::tests::route_through_kingston;
+    use crate::types::LatLng;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    /// Parses a leg duration as Google would return it, in seconds.
+    fn duration(seconds: i64) -> DirectionsDuration {
+        crate::json::parse_json_str(&format!(r#"{{"text": "", "value": {seconds}}}"#)).unwrap()
+    } // fn
+
+    #[test]
+    fn totals_the_legs_of_the_route() {
+        let route = route_through_kingston();
+
+        // 263.142 km in 2 hours 40 mins, then 286.556 km in 2 hours 54 mins:
+        assert_eq!(route.total_distance(), 549_698);
+        assert_eq!(route.total_duration(), Duration::seconds(20_040));
+        assert_eq!(route.total_duration(), route.legs[0].duration.value + route.legs[1].duration.value);
+    } // fn
+
+    #[test]
+    fn totals_the_durations_in_traffic_only_when_every_leg_has_one() {
+        let mut route = route_through_kingston();
+        assert!(!route.has_traffic_data());
+        assert_eq!(route.total_duration_in_traffic(), None);
+
+        route.legs[0].duration_in_traffic = Some(duration(10_800));
+        assert!(!route.has_traffic_data());
+        assert_eq!(route.total_duration_in_traffic(), None);
+
+        route.legs[1].duration_in_traffic = Some(duration(11_400));
+        assert!(route.has_traffic_data());
+        assert_eq!(route.total_duration_in_traffic(), Some(Duration::seconds(22_200)));
+
+        route.legs.clear();
+        assert!(!route.has_traffic_data());
+        assert_eq!(route.total_duration(), Duration::zero());
+    } // fn
+
+    #[test]
+    fn treats_negative_durations_as_zero() {
+        let mut route = route_through_kingston();
+        route.legs[0].duration = duration(-600);
+        route.legs[0].duration_in_traffic = Some(duration(-600));
+        route.legs[1].duration_in_traffic = Some(duration(11_400));
+
+        assert_eq!(route.total_duration(), Duration::seconds(10_440));
+        assert_eq!(route.total_duration_in_traffic(), Some(Duration::seconds(11_400)));
+    } // fn
+
+    #[test]
+    fn saturates_absurdly_long_durations() {
+        // The longest duration that `chrono` can represent, in seconds:
+        let longest = i64::MAX / 1_000;
+
+        let mut route = route_through_kingston();
+        route.legs[0].duration = duration(longest);
+        route.legs[1].duration = duration(longest);
+        assert_eq!(route.total_duration(), Duration::MAX);
+
+        route.legs[1].duration = duration(-longest);
+        assert_eq!(route.total_duration(), Duration::seconds(longest));
+    } // fn
+
+    #[test]
+    fn reorders_waypoints_into_the_optimized_order() {
+        let mut route = route_through_kingston();
+        route.waypoint_order = vec![2, 0, 3, 1];
+
+        let stops = route.reorder(&["Kingston", "Ottawa", "Belleville", "Cornwall"]).unwrap();
+        assert_eq!(stops, ["Belleville", "Kingston", "Cornwall", "Ottawa"]);
+    } // fn
+
+    #[test]
+    fn rejects_waypoints_that_do_not_match_the_order() {
+        // The fixture's route has one waypoint:
+        let mut route = route_through_kingston();
+        assert_eq!(route.reorder(&["Kingston"]).unwrap(), ["Kingston"]);
+        assert!(matches!(
+            route.reorder(&["Kingston", "Ottawa"]),
+            Err(DirectionsError::WaypointOrderMismatch(2, 1))
+        ));
+
+        // Without waypoint optimization, the order is empty:
+        route.waypoint_order.clear();
+        assert!(matches!(
+            route.reorder(&["Kingston"]),
+            Err(DirectionsError::WaypointOrderMismatch(1, 0))
+        ));
+
+        route.waypoint_order = vec![0, 4];
+        assert!(matches!(
+            route.reorder(&["Kingston", "Ottawa"]),
+            Err(DirectionsError::WaypointOrderOutOfRange(4, 2))
+        ));
+    } // fn
+
+    #[test]
+    fn fits_the_route_with_padding() {
+        let route = route_through_kingston();
+        let viewport = route.fit_bounds(0.5);
+
+        assert_eq!(viewport.southwest, LatLng::try_from_dec(dec!(43.1533096), dec!(-79.8834186)).unwrap());
+        assert_eq!(viewport.northeast, LatLng::try_from_dec(dec!(46.0019196), dec!(-73.0673914)).unwrap());
+        for leg in &route.legs {
+            assert!(viewport.contains(&leg.start_location));
+            assert!(viewport.contains(&leg.end_location));
+        } // for
+
+        // A negative or `NaN` padding leaves the bounds as they are:
+        assert_eq!(route.fit_bounds(-1.0), route.bounds);
+        assert_eq!(route.fit_bounds(f64::NAN), route.bounds);
+    } // fn
+
+    #[test]
+    fn strips_the_geometry_but_keeps_the_totals() {
+        let route = route_through_kingston();
+        let eta_only = route.clone().without_geometry();
+
+        assert!(eta_only.overview_polyline.points.is_empty());
+        assert!(eta_only.legs.iter().all(|leg| leg.steps.is_empty()));
+
+        // Everything but the geometry is kept:
+        assert_eq!(eta_only.legs.len(), 2);
+        assert_eq!(eta_only.total_distance(), route.total_distance());
+        assert_eq!(eta_only.total_duration(), route.total_duration());
+        assert_eq!(eta_only.bounds, route.bounds);
+        assert_eq!(eta_only.summary, route.summary);
+        assert_eq!(eta_only.waypoint_order, route.waypoint_order);
+        for (stripped, leg) in eta_only.legs.iter().zip(&route.legs) {
+            assert_eq!(stripped.distance, leg.distance);
+            assert_eq!(stripped.duration, leg.duration);
+            assert_eq!(stripped.start_address, leg.start_address);
+            assert_eq!(stripped.end_address, leg.end_address);
+            assert_eq!(stripped.start_location, leg.start_location);
+            assert_eq!(stripped.end_location, leg.end_location);
+        } // for
+    } // fn
+} // mod
@@ -97,17 +97,29 @@ impl std::convert::From<&Status> for String {
     /// [status](https://developers.google.com/maps/documentation/directions/intro#StatusCodes)
     /// code.
     fn from(status: &Status) -> Self {
-        match status {
-            Status::InvalidRequest => Self::from("INVALID_REQUEST"),
-            Status::MaxRouteLengthExceeded => Self::from("MAX_ROUTE_LENGTH_EXCEEDED"),
-            Status::MaxWaypointsExceeded => Self::from("MAX_WAYPOINTS_EXCEEDED"),
-            Status::NotFound => Self::from("NOT_FOUND"),
-            Status::Ok => Self::from("OK"),
-            Status::OverDailyLimit => Self::from("OVER_DAILY_LIMIT"),
-            Status::OverQueryLimit => Self::from("OVER_QUERY_LIMIT"),
-            Status::RequestDenied => Self::from("REQUEST_DENIED"),
-            Status::UnknownError => Self::from("UNKNOWN_ERROR"),
-            Status::ZeroResults => Self::from("ZERO_RESULTS"),
+        status.as_str().to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns the status code exactly as documented and returned by Google,
+    /// for example `OVER_QUERY_LIMIT`. Unlike `Display`, which is meant for
+    /// people, this is suitable for logs or as a metrics label.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest => "INVALID_REQUEST",
+            Self::MaxRouteLengthExceeded => "MAX_ROUTE_LENGTH_EXCEEDED",
+            Self::MaxWaypointsExceeded => "MAX_WAYPOINTS_EXCEEDED",
+            Self::NotFound => "NOT_FOUND",
+            Self::Ok => "OK",
+            Self::OverDailyLimit => "OVER_DAILY_LIMIT",
+            Self::OverQueryLimit => "OVER_QUERY_LIMIT",
+            Self::RequestDenied => "REQUEST_DENIED",
+            Self::UnknownError => "UNKNOWN_ERROR",
+            Self::ZeroResults => "ZERO_RESULTS",
         } // match
     } // fn
 } // impl
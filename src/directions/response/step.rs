@@ -9,7 +9,8 @@ use crate::directions::response::{
     transit_details::TransitDetails
 };
 use crate::directions::travel_mode::TravelMode;
-use crate::types::LatLng;
+use crate::serde::decimal_as_number::latlng_as_numbers;
+use crate::types::{LatLng, MaybeKnown};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -44,6 +45,7 @@ pub struct Step {
     /// form. This property may be undefined as the duration may be unknown.
     pub duration: DirectionsDuration,
     /// The ending location of this step.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub end_location: LatLng,
     /// Instructions for this step.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -51,13 +53,14 @@ pub struct Step {
     /// Contains the action to take for the current step (turn left, merge,
     /// straight, etc.). This field is used to determine which icon to display.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub maneuver: Option<DrivingManeuver>,
+    pub maneuver: Option<MaybeKnown<DrivingManeuver>>,
     /// Contains a single `points` object that holds an [encoded polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm) representation of the step. This polyline is an approximate (smoothed) path of the step. (Corresponds to `path` in the [Directions.Step interface](https://developers.google.com/maps/documentation/javascript/reference/directions#DirectionsStep).)
     ///
     /// See also: the Google Encoded Polyline encoding & decoding crate called
     /// [polyline](https://crates.io/crates/polyline).
     pub polyline: Polyline,
     /// The starting location of this step.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub start_location: LatLng,
     /// Contains detailed directions for walking or driving steps in transit
     /// directions. Substeps are only available when `travel_mode` is set to
@@ -70,7 +73,7 @@ pub struct Step {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transit_details: Option<TransitDetails>,
     /// The mode of travel used in this step.
-    pub travel_mode: TravelMode,
+    pub travel_mode: MaybeKnown<TravelMode>,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -85,7 +88,7 @@ impl Step {
     /// ```
 
     pub fn get_maneuver(&self) -> Option<String> {
-        self.maneuver.as_ref().map(String::from)
+        self.maneuver.as_ref().map(ToString::to_string)
     } // fn
 } // impl
 
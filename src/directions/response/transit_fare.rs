@@ -1,6 +1,8 @@
 //! Contains the total fare (that is, the total ticket costs) on this route.
 
 use crate::directions::response::transit_currency::TransitCurrency;
+use crate::serde::decimal_as_number::decimal_as_number;
+use crate::types::MaybeKnown;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -12,9 +14,10 @@ use serde::{Deserialize, Serialize};
 pub struct TransitFare {
     /// An [ISO 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217)
     /// indicating the currency that the amount is expressed in.
-    pub currency: TransitCurrency,
+    pub currency: MaybeKnown<TransitCurrency>,
     /// The total fare amount, formatted in the requested language.
     pub text: String,
     /// The total fare amount, in the currency specified above.
+    #[serde(serialize_with = "decimal_as_number")]
     pub value: Decimal,
 } // struct
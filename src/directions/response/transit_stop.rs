@@ -1,5 +1,6 @@
 //! Contains information about the stop/station for this part of the trip.
 
+use crate::serde::decimal_as_number::latlng_as_numbers;
 use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
 
@@ -11,5 +12,6 @@ pub struct TransitStop {
     pub name: String,
     /// The location of the transit station/stop, represented as a `lat` and
     /// `lng` field.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub location: LatLng,
 } // struct
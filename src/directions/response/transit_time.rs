@@ -1,7 +1,7 @@
 //! A representation of time as a Date object, a localized string, and a time
 //! zone.
 
-use chrono::{naive::serde::ts_seconds, NaiveDateTime};
+use chrono::{naive::serde::ts_seconds, DateTime, NaiveDateTime, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
@@ -21,3 +21,70 @@ pub struct TransitTime {
     #[serde(with = "ts_seconds")]
     pub value: NaiveDateTime,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl TransitTime {
+    /// Returns the time of this departure or arrival in UTC.
+    /// ```rust
+    /// let departure_utc = transit_details.departure_time.utc();
+    /// ```
+
+    #[must_use]
+    pub const fn utc(&self) -> DateTime<Utc> {
+        self.value.and_utc()
+    } // fn
+
+    /// Returns the time of this departure or arrival in the time zone of the
+    /// transit stop. This is the same time zone that the `text` field is
+    /// displayed in. The `time_zone` is checked against the IANA Time Zone
+    /// Database when the response is parsed, so this conversion can't fail.
+    /// ```rust
+    /// let departure_local = transit_details.departure_time.local();
+    /// ```
+
+    #[must_use]
+    pub fn local(&self) -> DateTime<Tz> {
+        self.utc().with_timezone(&self.time_zone)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::response::Response;
+    use crate::json::tests::{assert_round_trip, fixture};
+    use chrono::{TimeZone, Utc};
+    use chrono_tz::America::{Chicago, Detroit};
+
+    #[test]
+    fn converts_times_on_either_side_of_a_time_zone_boundary() {
+        // A train from Chicago, on Central time, to Detroit, on Eastern time:
+        let json = fixture("directions_transit_time_zones");
+        assert_round_trip::<Response>(&json);
+        let response: Response = json.parse().unwrap();
+        let leg = &response.routes[0].legs[0];
+
+        // Google separates the time from AM or PM with a narrow no-break space:
+        let departure = leg.departure_time.as_ref().unwrap();
+        assert_eq!(departure.utc(), Utc.with_ymd_and_hms(2024, 3, 18, 12, 20, 0).unwrap());
+        assert_eq!(departure.local(), Chicago.with_ymd_and_hms(2024, 3, 18, 7, 20, 0).unwrap());
+        assert_eq!(departure.local().format("%-I:%M\u{202f}%p").to_string(), departure.text);
+
+        let arrival = leg.arrival_time.as_ref().unwrap();
+        assert_eq!(arrival.utc(), Utc.with_ymd_and_hms(2024, 3, 18, 18, 25, 0).unwrap());
+        assert_eq!(arrival.local(), Detroit.with_ymd_and_hms(2024, 3, 18, 14, 25, 0).unwrap());
+        assert_eq!(arrival.local().format("%-I:%M\u{202f}%p").to_string(), arrival.text);
+
+        // The clocks read 7:20 AM and 2:25 PM, but the trip takes an hour
+        // less than that, because Detroit is an hour ahead of Chicago:
+        assert_eq!(arrival.utc() - departure.utc(), leg.duration.value);
+        assert_eq!(leg.duration.value, chrono::Duration::minutes(6 * 60 + 5));
+
+        let train = leg.steps[1].transit_details.as_ref().unwrap();
+        assert_eq!(train.departure_time.local().format("%-I:%M\u{202f}%p").to_string(), "7:25\u{202f}AM");
+        assert_eq!(train.arrival_time.local().format("%-I:%M\u{202f}%p").to_string(), "2:19\u{202f}PM");
+        assert_eq!(train.arrival_time.utc() - train.departure_time.utc(), leg.steps[1].duration.value);
+    } // fn
+} // mod
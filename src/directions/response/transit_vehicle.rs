@@ -1,6 +1,7 @@
 //! Contains the type of vehicle used on this line.
 
 use crate::directions::vehicle_type::VehicleType;
+use crate::types::MaybeKnown;
 use serde::{Deserialize, Serialize};
 
 /// Contains the type of vehicle used on this line.
@@ -18,6 +19,6 @@ pub struct TransitVehicle {
     /// Contains the type of vehicle that runs on this line. See the [Vehicle
     /// Type](https://developers.google.com/maps/documentation/directions/intro#VehicleType)
     /// documentation for a complete list of supported values.
-    #[serde(alias = "type")]
-    pub vehicle_type: VehicleType,
+    #[serde(rename = "type", alias = "vehicle_type")]
+    pub vehicle_type: MaybeKnown<VehicleType>,
 } // struct
@@ -105,10 +105,10 @@ impl std::convert::From<&TravelMode> for String {
 // -----------------------------------------------------------------------------
 
 static TRAVEL_MODES_BY_CODE: phf::Map<&'static str, TravelMode> = phf_map! {
-    "BICYCLING" => TravelMode::Bicycling,
-    "DRIVING" => TravelMode::Driving,
-    "TRANSIT" => TravelMode::Transit,
-    "WALKING" => TravelMode::Walking,
+    "bicycling" => TravelMode::Bicycling,
+    "driving" => TravelMode::Driving,
+    "transit" => TravelMode::Transit,
+    "walking" => TravelMode::Walking,
 };
 
 // -----------------------------------------------------------------------------
@@ -120,9 +120,12 @@ impl std::convert::TryFrom<&str> for TravelMode {
     /// Gets a `TravelMode` enum from a `String` that contains a valid [travel
     /// mode](https://developers.google.com/maps/documentation/directions/intro#TravelModes)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn try_from(travel_mode_code: &str) -> Result<Self, Self::Error> {
         TRAVEL_MODES_BY_CODE
-            .get(travel_mode_code)
+            .get(crate::directions::normalize_code(travel_mode_code).as_str())
             .cloned()
             .ok_or_else(|| Error::InvalidTravelModeCode(travel_mode_code.to_string()))
     } // fn
@@ -137,9 +140,12 @@ impl std::str::FromStr for TravelMode {
     /// Gets a `TravelMode` enum from a `String` that contains a valid [travel
     /// mode](https://developers.google.com/maps/documentation/directions/intro#TravelModes)
     /// code.
+    ///
+    /// The code is matched case-insensitively, and hyphens or spaces may be
+    /// used in place of underscores.
     fn from_str(travel_mode_code: &str) -> Result<Self, Self::Err> {
         TRAVEL_MODES_BY_CODE
-            .get(travel_mode_code)
+            .get(crate::directions::normalize_code(travel_mode_code).as_str())
             .cloned()
             .ok_or_else(|| Error::InvalidTravelModeCode(travel_mode_code.to_string()))
     } // fn
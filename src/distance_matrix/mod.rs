@@ -106,6 +106,10 @@ pub use crate::directions::{
 }; // crate::directions
 
 pub use crate::distance_matrix::{
-    request::Request as DistanceMatrixRequest, response::status::Status as DistanceMatrixStatus,
+    request::{
+        builder::Builder as DistanceMatrixBuilder,
+        ready_request::ReadyRequest as DistanceMatrixReadyRequest, Request as DistanceMatrixRequest,
+    }, // request
+    response::status::Status as DistanceMatrixStatus,
     response::Response as DistanceMatrixResponse,
 }; // crate::distance_matrix
@@ -1,131 +1,120 @@
+use crate::directions::request::query_string::{push_joined, push_waypoints, WAYPOINT_CAPACITY};
 use crate::distance_matrix::{error::Error, request::Request};
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use std::fmt::Write;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Directions API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
+    /// The query string is written into a single `String` that is sized up
+    /// front, so that building many requests allocates as little as possible.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
             return Err(Error::RequestNotValidated);
         }
 
+        let mut query = String::with_capacity(
+            256 + self.client.key.len()
+                + WAYPOINT_CAPACITY * (self.origins.len() + self.destinations.len())
+        ); // with_capacity
+
         // Builds the "required parameters" portion of the query string:
 
-        let mut query = format!(
-            "key={}&origins={}&destinations={}",
-            // Key:
-            self.client.key,
-            // Origins:
-            utf8_percent_encode(
-                &self
-                    .origins
-                    .iter()
-                    .map(String::from)
-                    .collect::<Vec<String>>()
-                    .join("|"),
-                NON_ALPHANUMERIC
-            ),
-            // Destinations:
-            utf8_percent_encode(
-                &self
-                    .destinations
-                    .iter()
-                    .map(String::from)
-                    .collect::<Vec<String>>()
-                    .join("|"),
-                NON_ALPHANUMERIC
-            ),
-        ); // format!
+        // Key:
+        query.push_str("key=");
+        query.push_str(&self.client.key);
+        // Origins:
+        query.push_str("&origins=");
+        push_waypoints(&mut query, &self.client, &self.origins);
+        // Destinations:
+        query.push_str("&destinations=");
+        push_waypoints(&mut query, &self.client, &self.destinations);
 
         // Builds the "optional parameters" portion of the query string:
 
         // Arrival time key/value pair:
         if let Some(arrival_time) = &self.arrival_time {
-            query.push_str("&arrival_time=");
-            query.push_str(&arrival_time.and_utc().timestamp().to_string());
+            // Writing to a `String` cannot fail:
+            let _ = write!(query, "&arrival_time={}", arrival_time.and_utc().timestamp());
         } // if
 
         // Avoid key/value pair:
         if !self.restrictions.is_empty() {
             query.push_str("&avoid=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .restrictions
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut restrictions = self.restrictions.iter().map(<&str>::from).collect::<Vec<&str>>();
+            restrictions.sort_unstable();
+            restrictions.dedup();
+            push_joined(&mut query, restrictions);
         } // if
 
         // Departure time key/value pair:
         if let Some(departure_time) = &self.departure_time {
-            query.push_str("&departure_time=");
-            query.push_str(&String::from(departure_time));
+            // Writing to a `String` cannot fail:
+            let _ = write!(query, "&departure_time={departure_time}");
         } // if
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(language.into());
         } // if
 
         // Travel mode key/value pair:
         if let Some(travel_mode) = &self.travel_mode {
             query.push_str("&mode=");
-            query.push_str(&String::from(travel_mode).to_lowercase());
+            query.extend(<&str>::from(travel_mode).chars().map(|c| c.to_ascii_lowercase()));
         } // if
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if none
+        // was set for this request:
+        if let Some(region) = self.region.as_ref().or(self.client.default_region.as_ref()) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(region.into());
         } // if
 
         // Traffic model key/value pair:
         if let Some(traffic_model) = &self.traffic_model {
             query.push_str("&traffic_model=");
-            query.push_str(&String::from(traffic_model));
+            query.push_str(traffic_model.into());
         } // if
 
         // Transit mode key/value pair:
         if !self.transit_modes.is_empty() {
             query.push_str("&transit_mode=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .transit_modes
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut transit_modes = self.transit_modes.iter().map(<&str>::from).collect::<Vec<&str>>();
+            transit_modes.sort_unstable();
+            transit_modes.dedup();
+            push_joined(&mut query, transit_modes);
         } // if
 
         // Transit route preference key/value pair:
         if let Some(transit_route_preference) = &self.transit_route_preference {
             query.push_str("&transit_routing_preference=");
-            query.push_str(&String::from(transit_route_preference));
+            query.push_str(transit_route_preference.into());
         } // if
 
         // Unit system key/value pair:
         if let Some(unit_system) = &self.unit_system {
             query.push_str("&units=");
-            query.push_str(&String::from(unit_system));
+            query.push_str(unit_system.into());
         } // if
 
         // Set query string in Request struct.
@@ -135,3 +124,75 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::directions::{Avoid, TransitMode, TransitRoutePreference, TravelMode, UnitSystem, Waypoint};
+    use crate::types::{Language, Region};
+    use crate::GoogleMapsClient;
+
+    /// Returns `count` waypoints, starting from `first`, with a mix of
+    /// addresses, coordinates and place IDs.
+    fn waypoints(first: usize, count: usize) -> Vec<Waypoint> {
+        (first..first + count)
+            .map(|index| match index % 3 {
+                0 => Waypoint::from_address(format!("{index} Wellington St, Ottawa, ON")),
+                1 => Waypoint::try_from_f64(45.42 + index as f64 / 1_000.0, -75.69).unwrap(),
+                _ => Waypoint::from_place_id(format!("ChIJrxNRX7IFzkwRCR5iKVZC-HA{index}")),
+            }) // map
+            .collect()
+    } // fn
+
+    #[test]
+    fn builds_the_same_url_for_a_25_by_25_matrix() {
+        // The expected URL was generated by the query string builder that
+        // allocated a `String` for each parameter, before it was rewritten to
+        // write into a single buffer:
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let eight_am = chrono::NaiveDate::from_ymd_opt(2024, 1, 15)
+            .unwrap()
+            .and_hms_opt(8, 0, 0)
+            .unwrap();
+
+        let mut request = client.distance_matrix(waypoints(0, 25), waypoints(25, 25));
+        request
+            .with_travel_mode(TravelMode::Transit)
+            .with_restrictions([Avoid::Tolls, Avoid::Ferries])
+            .with_transit_modes([TransitMode::Bus, TransitMode::Rail])
+            .with_transit_route_preference(TransitRoutePreference::FewerTransfers)
+            .with_arrival_time(eight_am)
+            .with_unit_system(UnitSystem::Metric)
+            .with_language(Language::English)
+            .with_region(Region::Canada);
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            include_str!("../../../tests/urls/distance_matrix_25x25.txt").trim_end(),
+        );
+    } // fn
+
+    #[test]
+    fn sends_now_as_the_literal_departure_time() {
+        use crate::directions::{DepartureTime, TrafficModel};
+
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let mut request = client.distance_matrix(waypoints(0, 1), waypoints(1, 1));
+        request
+            .with_departure_time(DepartureTime::Now)
+            .with_traffic_model(TrafficModel::BestGuess);
+
+        assert_eq!(
+            request.query_url().unwrap(),
+            "https://maps.googleapis.com/maps/api/distancematrix/json\
+            ?key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000\
+            &origins=0%20Wellington%20St%2C%20Ottawa%2C%20ON\
+            &destinations=45%2E421%2C%2D75%2E69\
+            &departure_time=now\
+            &traffic_model=best_guess",
+        );
+    } // fn
+} // mod
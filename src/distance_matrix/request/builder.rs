@@ -0,0 +1,267 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::{
+    request::{
+        avoid::Avoid, departure_time::DepartureTime, traffic_model::TrafficModel,
+        transit_mode::TransitMode, transit_route_preference::TransitRoutePreference,
+        unit_system::UnitSystem, waypoint::Waypoint,
+    }, // crate::directions::request
+    travel_mode::TravelMode,
+}; // use crate::directions
+use crate::distance_matrix::{
+    error::Error,
+    request::{ready_request::ReadyRequest, Request},
+}; // use crate::distance_matrix
+use crate::types::{Language, Region};
+use chrono::NaiveDateTime;
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for _Distance Matrix API_ requests. Each method takes
+/// the builder by value and returns it, so that a request may be built in a
+/// single expression, or returned from a function. The request can only be
+/// sent once `build` has returned a `ReadyRequest`, which has already been
+/// validated and has its query string built:
+///
+/// ```rust
+/// let distance_matrix = DistanceMatrixRequest::builder(
+///     &google_maps_client,
+///     vec![Waypoint::from_address("Ottawa, ON")],
+///     vec![Waypoint::from_address("Montréal, QC")],
+/// )
+/// .with_travel_mode(TravelMode::Driving)
+/// .build()?
+/// .get()
+/// .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let distance_matrix = DistanceMatrixRequest::builder(
+///     &google_maps_client,
+///     vec![Waypoint::from_address("Ottawa, ON")],
+///     vec![Waypoint::from_address("Montréal, QC")],
+/// )
+/// .with_travel_mode(TravelMode::Driving)
+/// .get()
+/// .await?;
+/// ```
+///
+/// The builder produces exactly the same query string as the mutable
+/// `Request` builder with the same parameters.
+
+#[derive(Debug)]
+pub struct Builder {
+    request: Request,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Initializes a typestate builder. See `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `origins` ‧ The starting points for calculating travel distance and
+    ///   time.
+    ///
+    /// * `destinations` ‧ The finishing points for calculating travel distance
+    ///   and time.
+    ///
+    /// Origins and destinations may be any collection or iterator of values
+    /// that can be converted to a `Waypoint`. See `Request::new` for more
+    /// information.
+
+    #[must_use]
+    pub fn builder<O, D>(
+        client: &GoogleMapsClient,
+        origins: O,
+        destinations: D
+    ) -> Builder
+    where
+        O: IntoIterator,
+        O::Item: Into<Waypoint>,
+        D: IntoIterator,
+        D::Item: Into<Waypoint> {
+        Builder {
+            request: Self::new(client, origins, destinations),
+        } // Builder
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Validates the request and builds its query string. The returned
+    /// `ReadyRequest` may then be sent to the Google Maps Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the combination of parameters is not accepted by
+    ///   the Google Maps Distance Matrix API. For example, an arrival time and
+    ///   a departure time in the same request.
+
+    pub fn build(mut self) -> Result<ReadyRequest, Error> {
+        self.request.validate()?.build()?;
+        Ok(ReadyRequest {
+            client: self.request.client,
+            query: self.request.query.unwrap_or_default(),
+            language: self.request.language,
+        }) // ReadyRequest
+    } // fn
+
+    /// Specifies the desired time of arrival. See
+    /// `Request::with_arrival_time`.
+
+    #[must_use]
+    pub fn with_arrival_time(mut self, arrival_time: impl Into<NaiveDateTime>) -> Self {
+        self.request.with_arrival_time(arrival_time);
+        self
+    } // fn
+
+    /// Specifies the desired time of departure. See
+    /// `Request::with_departure_time`.
+
+    #[must_use]
+    pub fn with_departure_time(mut self, departure_time: impl Into<DepartureTime>) -> Self {
+        self.request.with_departure_time(departure_time);
+        self
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `Request::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.with_language(language);
+        self
+    } // fn
+
+    /// Specifies the region bias. See `Request::with_region`.
+
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<Region>) -> Self {
+        self.request.with_region(region);
+        self
+    } // fn
+
+    /// Specifies a feature that routes should avoid. See
+    /// `Request::with_restriction`.
+
+    #[must_use]
+    pub fn with_restriction(mut self, restriction: impl Into<Avoid>) -> Self {
+        self.request.with_restriction(restriction);
+        self
+    } // fn
+
+    /// Specifies features that routes should avoid. See
+    /// `Request::with_restrictions`.
+
+    #[must_use]
+    pub fn with_restrictions<C, A>(mut self, restrictions: C) -> Self
+    where
+        C: IntoIterator<Item = A>,
+        A: Into<Avoid> {
+        self.request.with_restrictions(restrictions);
+        self
+    } // fn
+
+    /// Specifies the assumptions to use when calculating time in traffic. See
+    /// `Request::with_traffic_model`.
+
+    #[must_use]
+    pub fn with_traffic_model(mut self, traffic_model: impl Into<TrafficModel>) -> Self {
+        self.request.with_traffic_model(traffic_model);
+        self
+    } // fn
+
+    /// Specifies a preferred mode of transit. See
+    /// `Request::with_transit_mode`.
+
+    #[must_use]
+    pub fn with_transit_mode(mut self, transit_mode: impl Into<TransitMode>) -> Self {
+        self.request.with_transit_mode(transit_mode);
+        self
+    } // fn
+
+    /// Specifies preferred modes of transit. See
+    /// `Request::with_transit_modes`.
+
+    #[must_use]
+    pub fn with_transit_modes<C, T>(mut self, transit_modes: C) -> Self
+    where
+        C: IntoIterator<Item = T>,
+        T: Into<TransitMode> {
+        self.request.with_transit_modes(transit_modes);
+        self
+    } // fn
+
+    /// Specifies preferences for transit routes. See
+    /// `Request::with_transit_route_preference`.
+
+    #[must_use]
+    pub fn with_transit_route_preference(
+        mut self,
+        transit_route_preference: impl Into<TransitRoutePreference>
+    ) -> Self {
+        self.request.with_transit_route_preference(transit_route_preference);
+        self
+    } // fn
+
+    /// Specifies the mode of transportation. See
+    /// `Request::with_travel_mode`.
+
+    #[must_use]
+    pub fn with_travel_mode(mut self, travel_mode: impl Into<TravelMode>) -> Self {
+        self.request.with_travel_mode(travel_mode);
+        self
+    } // fn
+
+    /// Specifies the unit system to use when displaying results. See
+    /// `Request::with_unit_system`.
+
+    #[must_use]
+    pub fn with_unit_system(mut self, unit_system: impl Into<UnitSystem>) -> Self {
+        self.request.with_unit_system(unit_system);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::distance_matrix::{request::Request, Avoid, TravelMode, Waypoint};
+    use crate::types::Language;
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let origins = [Waypoint::from_address("Vancouver BC"), Waypoint::from_address("Seattle")];
+        let destinations = [Waypoint::from_address("San Francisco")];
+
+        let consuming = Request::builder(&client, origins.clone(), destinations.clone())
+            .with_travel_mode(TravelMode::Driving)
+            .with_restriction(Avoid::Ferries)
+            .with_language(Language::English)
+            .build()
+            .unwrap();
+
+        let mut mutable = Request::new(&client, origins, destinations);
+        mutable
+            .with_travel_mode(TravelMode::Driving)
+            .with_restriction(Avoid::Ferries)
+            .with_language(Language::English);
+
+        assert_eq!(consuming.query_url(), mutable.query_url().unwrap());
+    } // fn
+} // mod
@@ -5,7 +5,7 @@ use crate::error::Error as GoogleMapsError; // crate::distance_matrix
 
 // =============================================================================
 
-impl<'a> DistanceMatrixRequest<'a> {
+impl DistanceMatrixRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> DistanceMatrixRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<DistanceMatrixResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<DistanceMatrixResponse, GoogleMapsError> {
         self.validate()?.build()?.get().await
     } // fn
 } // impl
@@ -4,12 +4,14 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+pub mod builder;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
 mod query_url;
+pub mod ready_request;
 mod validate;
 mod with_arrival_time;
 mod with_departure_time;
@@ -40,13 +42,13 @@ use chrono::NaiveDateTime;
 /// _Distance Matrix API_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// One or more locations to use as the finishing point for calculating
     /// travel distance and time. You can pass an address, latitude/longitude,
@@ -109,3 +111,29 @@ pub struct Request<'a> {
     /// Has the request been validated?
     validated: bool,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("destinations", &self.destinations)
+            .field("origins", &self.origins)
+            .field("arrival_time", &self.arrival_time)
+            .field("departure_time", &self.departure_time)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("restrictions", &self.restrictions)
+            .field("traffic_model", &self.traffic_model)
+            .field("transit_modes", &self.transit_modes)
+            .field("transit_route_preference", &self.transit_route_preference)
+            .field("travel_mode", &self.travel_mode)
+            .field("unit_system", &self.unit_system)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .field("validated", &self.validated)
+            .finish()
+    } // fn
+} // impl
@@ -5,7 +5,7 @@ use crate::{
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Distance Matrix API query with the
@@ -14,18 +14,36 @@ impl Request<'_> {
     /// ## Arguments
     ///
     /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `origins` ‧ The starting points for calculating travel distance and
+    ///   time.
+    ///
+    /// * `destinations` ‧ The finishing points for calculating travel distance
+    ///   and time.
+    ///
+    /// # Generics
+    ///
+    /// The `O` and `D` generics are intended to represent any collection that
+    /// can be iterated over, such as a `Vec` or an iterator chain. Their items
+    /// may be any type that can be converted to a `Waypoint` type. Origins and
+    /// destinations do not need to be the same type of collection.
 
     #[must_use]
-    pub const fn new(
+    pub fn new<O, D>(
         client: &GoogleMapsClient,
-        origins: Vec<Waypoint>,
-        destinations: Vec<Waypoint>
-    ) -> Request {
-        Request {
+        origins: O,
+        destinations: D
+    ) -> Self
+    where
+        O: IntoIterator,
+        O::Item: Into<Waypoint>,
+        D: IntoIterator,
+        D::Item: Into<Waypoint> {
+        Self {
             // Required parameters:
-            client,
-            destinations,
-            origins,
+            client: client.clone(),
+            destinations: destinations.into_iter().map(Into::into).collect(),
+            origins: origins.into_iter().map(Into::into).collect(),
             // Optional parameters:
             arrival_time: None,
             departure_time: None,
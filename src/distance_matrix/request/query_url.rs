@@ -3,7 +3,7 @@ use std::borrow::Cow;
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     /// Returns the URL query string that represents the query you've built.
     ///
     /// ## Description
@@ -17,7 +17,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn query_url(&'a mut self) -> Result<String, Error> {
+    pub fn query_url(&mut self) -> Result<String, Error> {
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => Cow::from(query_string),
@@ -27,4 +27,18 @@ impl<'a> Request<'a> {
 
         Ok(format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}"))
     } // fn
+
+    /// Returns a `curl` command that sends the request you've built. This
+    /// could be useful for reproducing a problem from a terminal. The API key
+    /// is replaced with a reference to the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, so the command may be safely shared, for example in a bug
+    /// report.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn to_curl(&mut self) -> Result<String, Error> {
+        Ok(crate::curl::to_curl("GET", &self.query_url()?, &[], None))
+    } // fn
 } // impl
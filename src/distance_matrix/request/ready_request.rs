@@ -0,0 +1,82 @@
+use crate::client::GoogleMapsClient;
+use crate::distance_matrix::{OUTPUT_FORMAT, SERVICE_URL};
+use crate::types::Language;
+
+// -----------------------------------------------------------------------------
+//
+/// A _Distance Matrix API_ request that has been validated and whose query
+/// string has been built. It is returned by `Builder::build` and may be sent to
+/// the Google Maps Platform with `get` without any possibility of
+/// `QueryNotBuilt` or `RequestNotValidated` errors.
+
+#[derive(Clone)]
+pub struct ReadyRequest {
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    pub(super) client: GoogleMapsClient,
+
+    /// The URL query string that will be sent to Google.
+    pub(super) query: String,
+
+    /// The language that was set on the request, if any. It is sent in the
+    /// `Accept-Language` header, as well as in the query string.
+    pub(super) language: Option<Language>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for ReadyRequest {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReadyRequest")
+            .field("client", &self.client)
+            .field("query", &crate::error::sanitize_url(&self.query))
+            .field("language", &self.language)
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl ReadyRequest {
+    /// Returns the URL query string that will be sent to the Google Maps
+    /// Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn query(&self) -> &str {
+        &self.query
+    } // fn
+
+    /// Returns the URL that will be requested from the Google Maps Platform.
+    /// This could be useful for records or logging. It could also be used for
+    /// passing to your HTTP client of choice and executing the HTTP GET
+    /// request yourself.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn query_url(&self) -> String {
+        format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{}", self.query)
+    } // fn
+
+    /// Returns a `curl` command that sends the request. The API key is
+    /// replaced with a reference to the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, so the command may be safely shared, for example in a bug
+    /// report.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn to_curl(&self) -> String {
+        crate::curl::to_curl("GET", &self.query_url(), &[], None)
+    } // fn
+} // impl
@@ -1,7 +1,7 @@
 use crate::directions::travel_mode::TravelMode;
 use crate::distance_matrix::{error::Error, request::Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -9,40 +9,55 @@ impl<'a> Request<'a> {
     /// not check parameter values for validity - i.e. it will not ensure
     /// Polylines or Place ID's are valid and well-formed.
     ///
+    /// All of the combinations are checked. If more than one problem is found,
+    /// they are all returned together in `Error::ValidationFailed`.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+        let mut errors = Vec::new();
+
+        // A request may have at most 25 origins...
+        if self.origins.len() > 25 {
+            errors.push(Error::TooManyOrigins(self.origins.len()));
+        } // if
+
+        // ...and at most 25 destinations:
+        if self.destinations.len() > 25 {
+            errors.push(Error::TooManyDestinations(self.destinations.len()));
+        } // if
+
         if let Some(travel_mode) = &self.travel_mode {
             // If the transit mode is not set to TravelMode::Transit...
             if *travel_mode != TravelMode::Transit {
                 // ...an arrival time cannot be set:
                 if let Some(arrival_time) = &self.arrival_time {
-                    return Err(Error::ArrivalTimeIsForTransitOnly(
+                    errors.push(Error::ArrivalTimeIsForTransitOnly(
                         travel_mode.to_string(),
                         arrival_time.format("%F %r").to_string(),
-                    )); // Err
+                    )); // push
                 } // if
 
                 // ...a transit mode cannot be set:
                 if !self.transit_modes.is_empty() {
-                    return Err(Error::TransitModeIsForTransitOnly(
+                    errors.push(Error::TransitModeIsForTransitOnly(
                         travel_mode.to_string(),
                         self.transit_modes
                             .iter()
                             .map(std::string::ToString::to_string)
                             .collect::<Vec<String>>()
                             .join("|"),
-                    )); // Err
+                    )); // push
                 } // if
 
                 // ...a transit route preference cannot be set:
                 if let Some(transit_route_preference) = &self.transit_route_preference {
-                    return Err(Error::TransitRoutePreferenceIsForTransitOnly(
+                    errors.push(Error::TransitRoutePreferenceIsForTransitOnly(
                         travel_mode.to_string(),
                         transit_route_preference.to_string(),
-                    )); // Err
+                    )); // push
                 } // if
             } // if
         } // if
@@ -51,13 +66,26 @@ impl<'a> Request<'a> {
         if let Some(arrival_time) = &self.arrival_time {
             // ...a departure time cannot be set:
             if let Some(departure_time) = &self.departure_time {
-                return Err(Error::EitherDepartureTimeOrArrivalTime(
+                errors.push(Error::EitherDepartureTimeOrArrivalTime(
                     arrival_time.format("%F %r").to_string(),
                     departure_time.to_string(),
-                )); // Err
+                )); // push
             } // if
         } // if
 
+        // If a traffic model has been set...
+        if let Some(traffic_model) = &self.traffic_model {
+            // ...a departure time must also be set:
+            if self.departure_time.is_none() {
+                errors.push(Error::TrafficModelRequiresDepartureTime(
+                    traffic_model.to_string(),
+                )); // push
+            } // if
+        } // if
+
+        // Report every problem found, rather than only the first one:
+        Error::from_validation_errors(errors)?;
+
         // Indicate that the request passed validation.
         self.validated = true;
 
@@ -65,3 +93,65 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::distance_matrix::{error::Error, DepartureTime, TrafficModel, Waypoint};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn traffic_model_requires_a_departure_time() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let mut request = client.distance_matrix(
+            vec![Waypoint::from_address("Toronto")],
+            vec![Waypoint::from_address("Montreal")],
+        ); // distance_matrix
+        request.with_traffic_model(TrafficModel::Pessimistic);
+        assert!(matches!(
+            request.validate(),
+            Err(Error::TrafficModelRequiresDepartureTime(traffic_model)) if traffic_model == "pessimistic"
+        ));
+
+        request.with_departure_time(DepartureTime::Now);
+        assert!(request.validate().is_ok());
+    } // fn
+
+    #[test]
+    fn reports_every_problem_together() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let places = |count: usize| {
+            (0..count)
+                .map(|index| Waypoint::from_address(format!("{index} Wellington St, Ottawa, ON")))
+                .collect::<Vec<Waypoint>>()
+        }; // places
+
+        let mut request = client.distance_matrix(places(26), places(30));
+        request.with_traffic_model(TrafficModel::BestGuess);
+
+        let error = request.validate().unwrap_err();
+        let Error::ValidationFailed(errors) = &error else {
+            panic!("expected `ValidationFailed`, got `{error:?}`");
+        }; // let
+        assert!(matches!(
+            errors.as_slice(),
+            [
+                Error::TooManyOrigins(26),
+                Error::TooManyDestinations(30),
+                Error::TrafficModelRequiresDepartureTime(_),
+            ]
+        ));
+
+        // The problems are listed one per line, and numbered:
+        let message = error.to_string();
+        let lines: Vec<&str> = message.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with("The request failed validation for 3 reasons:"));
+        assert!(lines[1].starts_with("1. ") && lines[1].contains("26 origins are set"));
+        assert!(lines[2].starts_with("2. ") && lines[2].contains("30 destinations are set"));
+        assert!(lines[3].starts_with("3. ") && lines[3].contains("with_traffic_model()"));
+    } // fn
+} // mod
@@ -1,7 +1,7 @@
 use crate::distance_matrix::request::Request;
 use chrono::NaiveDateTime;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the desired arrival time.
     ///
     /// ## Arguments
@@ -23,9 +23,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_arrival_time(
-        &'a mut self,
+        &mut self,
         arrival_time: impl Into<NaiveDateTime>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         let arrival_time: NaiveDateTime = arrival_time.into();
         self.arrival_time = Some(arrival_time);
         self
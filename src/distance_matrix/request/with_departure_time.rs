@@ -1,7 +1,7 @@
 use crate::directions::request::departure_time::DepartureTime;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the desired departure time.
     ///
     /// ## Arguments
@@ -43,6 +43,16 @@ impl<'a> Request<'a> {
     /// .with_departure_time(DepartureTime::Now)
     /// ```
     ///
+    /// `DepartureTime::Now` is sent to Google as the literal `now` rather than
+    /// as a timestamp, so the departure time is not affected by the local
+    /// clock being wrong. Prefer it over `Utc::now()`.
+    ///
+    /// * Departing at a `chrono` date & time. A `NaiveDateTime` is interpreted
+    ///   as UTC:
+    /// ```rust
+    /// .with_departure_time(Utc.with_ymd_and_hms(2030, 1, 1, 12, 30, 0).unwrap())
+    /// ```
+    ///
     /// * Departing on Tuesday February 22, 2022 at 1:00:00 PM:
     /// ```rust
     /// .with_departure_time(DepartureTime::At(
@@ -58,10 +68,38 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_departure_time(
-        &'a mut self,
+        &mut self,
         departure_time: impl Into<DepartureTime>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.departure_time = Some(departure_time.into());
         self
     } // fn
+
+    /// Specifies the desired departure time as a `std::time::SystemTime`. This
+    /// is the same as `with_departure_time`, for callers that do not use
+    /// `chrono`. Fractions of a second are truncated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_time` ‧ The soonest time the passenger intends to depart.
+    ///
+    /// ## Example:
+    ///
+    /// * Departing in one hour:
+    /// ```rust
+    /// .with_departure_system_time(SystemTime::now() + Duration::from_secs(3_600))?
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the time is before the Unix epoch, or too far in
+    ///   the future to be represented.
+
+    pub fn with_departure_system_time(
+        &mut self,
+        departure_time: std::time::SystemTime
+    ) -> Result<&mut Self, crate::error::Error> {
+        self.departure_time = Some(DepartureTime::try_from(departure_time)?);
+        Ok(self)
+    } // fn
 } // impl
@@ -1,7 +1,7 @@
 use crate::distance_matrix::request::Request;
 use crate::types::Language;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify the language in which to return results.
     ///
     /// ## Arguments
@@ -46,9 +46,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.language = Some(language.into());
         self
     } // fn
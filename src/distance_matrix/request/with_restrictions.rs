@@ -1,7 +1,7 @@
 use crate::directions::request::avoid::Avoid;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify a feature that routes should avoid.
     ///
     /// ## Arguments
@@ -59,9 +59,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_restriction(
-        &'a mut self,
+        &mut self,
         restriction: impl Into<Avoid>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Add restriction to Request struct.
         self.restrictions = vec![restriction.into()];
         // Return modified Request struct to caller.
@@ -90,9 +90,9 @@ impl<'a> Request<'a> {
     /// type.
 
     pub fn with_restrictions<C, A>(
-        &'a mut self,
+        &mut self,
         restrictions: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = A>,
         A: Into<Avoid> {
@@ -1,7 +1,7 @@
 use crate::directions::request::traffic_model::TrafficModel;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the assumptions to use when calculating time in traffic.
     ///
     /// ## Arguments
@@ -18,7 +18,8 @@ impl<'a> Request<'a> {
     /// historical averages. The `traffic_model` parameter may only be specified
     /// for driving directions where the request includes a `departure_time`,
     /// and only if the request includes an API key or a Google Maps Platform
-    /// Premium Plan client ID. The available values for this parameter are:
+    /// Premium Plan client ID. Validation fails if a traffic model is set
+    /// without a departure time. The available values for this parameter are:
     ///
     /// * `TrafficModel::BestGuess` (default) indicates that the returned
     ///   `duration_in_traffic` should be the best estimate of travel time given
@@ -50,9 +51,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_traffic_model(
-        &'a mut self,
+        &mut self,
         traffic_model: impl Into<TrafficModel>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.traffic_model = Some(traffic_model.into());
         self
     } // fn
@@ -1,7 +1,7 @@
 use crate::directions::request::transit_route_preference::TransitRoutePreference;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the preference for transit routes.
     ///
     /// ## Arguments
@@ -33,9 +33,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_transit_route_preference(
-        &'a mut self,
+        &mut self,
         transit_route_preference: impl Into<TransitRoutePreference>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.transit_route_preference = Some(transit_route_preference.into());
         self
     } // fn
@@ -1,7 +1,7 @@
 use crate::directions::travel_mode::TravelMode;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specify the mode of transportation.
     ///
     /// ## Arguments
@@ -47,9 +47,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_travel_mode(
-        &'a mut self,
+        &mut self,
         travel_mode: impl Into<TravelMode>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.travel_mode = Some(travel_mode.into());
         self
     } // fn
@@ -1,7 +1,7 @@
 use crate::directions::request::unit_system::UnitSystem;
 use crate::distance_matrix::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the unit system to use when displaying results.
     ///
     /// ## Arguments
@@ -42,9 +42,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_unit_system(
-        &'a mut self,
+        &mut self,
         unit_system: impl Into<UnitSystem>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.unit_system = Some(unit_system.into());
         self
     } // fn
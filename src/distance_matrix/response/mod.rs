@@ -11,6 +11,7 @@ pub mod row;
 pub mod status;
 
 use crate::distance_matrix::response::{row::Row, status::Status};
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 /// Distance Matrix responses contain the following root elements.
@@ -52,12 +53,46 @@ pub struct Response {
     pub status: Status,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.rows.is_empty().then_some("rows")
+    } // fn
+} // impl
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Distance Matrix API JSON `String` response into a
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn distance_matrix_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("distance_matrix", 0));
+    } // fn
+} // mod
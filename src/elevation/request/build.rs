@@ -1,20 +1,37 @@
 use crate::elevation::{error::Error, request::Request}; // crate::elevation
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Elevation API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
             return Err(Error::RequestNotValidated);
         }
 
+        // Set query string in Request struct.
+        self.query = Some(self.query_string());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+
+    /// Returns the query string for the request. Only one of the positional
+    /// request and the sampled path request should have been set.
+
+    pub(super) fn query_string(&self) -> String {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -27,7 +44,7 @@ impl<'a> Request<'a> {
         // Locations key/value pair:
         if let Some(locations) = &self.locations {
             query.push_str("&locations=");
-            query.push_str(&String::from(locations));
+            query.push_str(&String::from(&*self.client.coordinates(locations)));
         } // if
 
         // This section builds the "sampled path request" portion of the query
@@ -36,7 +53,7 @@ impl<'a> Request<'a> {
         // Path key/value pair:
         if let Some(path) = &self.path {
             query.push_str("&path=");
-            query.push_str(&String::from(path));
+            query.push_str(&String::from(&*self.client.coordinates(path)));
         } // if
 
         // Samples key/value pair:
@@ -45,10 +62,6 @@ impl<'a> Request<'a> {
             query.push_str(&samples.to_string());
         } // if
 
-        // Set query string in Request struct.
-        self.query = Some(query);
-
-        // Return modified Request struct to caller.
-        Ok(self)
+        query
     } // fn
 } // impl
@@ -0,0 +1,138 @@
+use crate::client::GoogleMapsClient;
+use crate::elevation::request::{locations::Locations, ready_request::ReadyRequest, Request};
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for _Elevation API_ requests. A builder is started as
+/// either a positional request or a sampled path request, so a request with
+/// both can not be written. The request can only be sent once `build` has
+/// returned a `ReadyRequest`:
+///
+/// ```rust
+/// let elevation = ElevationRequest::positional_builder(
+///     &google_maps_client,
+///     ElevationLocations::LatLngs(vec![
+///         // Denver, Colorado, the "Mile High City"
+///         LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034))?,
+///     ]),
+/// )
+/// .build()
+/// .get()
+/// .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let elevation = ElevationRequest::positional_builder(
+///     &google_maps_client,
+///     ElevationLocations::LatLngs(vec![
+///         LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034))?,
+///     ]),
+/// )
+/// .get()
+/// .await?;
+/// ```
+
+#[derive(Debug)]
+pub struct Builder {
+    request: Request,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Initializes a typestate builder for a _positional request_. See
+    /// `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `locations` ‧ The location(s) on the earth from which to return
+    ///   elevation data. See `Request::for_positional_requests`.
+
+    #[must_use]
+    pub fn positional_builder(
+        client: &GoogleMapsClient,
+        locations: impl Into<Locations>
+    ) -> Builder {
+        let mut request = Self::new(client);
+        request.for_positional_requests(locations);
+        Builder { request }
+    } // fn
+
+    /// Initializes a typestate builder for a _sampled path request_. See
+    /// `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `path` ‧ The path on the earth for which to return elevation data.
+    ///
+    /// * `samples` ‧ The number of sample points along the path. See
+    ///   `Request::for_sampled_path_request`.
+
+    #[must_use]
+    pub fn sampled_path_builder(
+        client: &GoogleMapsClient,
+        path: impl Into<Locations>,
+        samples: impl Into<u8>
+    ) -> Builder {
+        let mut request = Self::new(client);
+        request.for_sampled_path_request(path, samples);
+        Builder { request }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Builds the query string. The returned `ReadyRequest` may then be sent
+    /// to the Google Maps Platform. The builder only ever holds one kind of
+    /// request, so there is nothing to validate.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn build(self) -> ReadyRequest {
+        ReadyRequest {
+            query: self.request.query_string(),
+            client: self.request.client,
+        } // ReadyRequest
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::elevation::request::{locations::Locations, Request};
+    use crate::types::LatLng;
+    use crate::GoogleMapsClient;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let denver = LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034)).unwrap();
+        let death_valley = LatLng::try_from_dec(dec!(36.23998), dec!(-116.83171)).unwrap();
+
+        let positional =
+            Request::positional_builder(&client, Locations::LatLngs(vec![denver])).build();
+        let mut mutable = Request::new(&client);
+        mutable.for_positional_request(denver);
+        assert_eq!(positional.query_url(), mutable.query_url().unwrap());
+
+        let path = Locations::LatLngs(vec![denver, death_valley]);
+        let sampled_path = Request::sampled_path_builder(&client, path.clone(), 4).build();
+        let mut mutable = Request::new(&client);
+        mutable.for_sampled_path_request(path, 4);
+        assert_eq!(sampled_path.query_url(), mutable.query_url().unwrap());
+    } // fn
+} // mod
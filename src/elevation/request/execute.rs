@@ -5,7 +5,7 @@ use crate::error::Error as GoogleMapsError; // crate::elevation
 
 // =============================================================================
 
-impl<'a> ElevationRequest<'a> {
+impl ElevationRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> ElevationRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<ElevationResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<ElevationResponse, GoogleMapsError> {
         self.validate()?.build()?.get().await
     } // fn
 } // impl
@@ -3,7 +3,7 @@ use crate::types::LatLng;
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Adds the _positional request_ parameter to the Elevation API query.
@@ -22,9 +22,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn for_positional_request(
-        &'a mut self,
+        &mut self,
         location: impl Into<LatLng>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         let location: LatLng = location.into();
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![location]));
@@ -59,9 +59,9 @@ impl<'a> Request<'a> {
     /// [polyline](https://crates.io/crates/polyline).
 
     pub fn for_positional_requests(
-        &'a mut self,
+        &mut self,
         locations: impl Into<Locations>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         let locations: Locations = locations.into();
         // Set the path in Request struct.
         self.locations = Some(locations);
@@ -89,9 +89,9 @@ impl<'a> Request<'a> {
         it's suggested to use the `for_positional_request` method instead"
     )]
     pub fn for_coordinate_request(
-        &'a mut self,
+        &mut self,
         coordinate: &geo_types::Coord
-    ) -> Result<&'a mut Self, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![LatLng::try_from(coordinate)?]));
         // Return modified Request struct to caller.
@@ -118,9 +118,9 @@ impl<'a> Request<'a> {
         it's suggested to use the `for_positional_request` method instead"
     )]
     pub fn try_point_request(
-        &'a mut self,
+        &mut self,
         point: &geo_types::Point
-    ) -> Result<&'a mut Self, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LatLngs(vec![LatLng::try_from(point)?]));
         // Return modified Request struct to caller.
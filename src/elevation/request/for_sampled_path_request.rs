@@ -2,7 +2,7 @@ use crate::elevation::request::{locations::Locations, Request};
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Adds the _sampled path request_ parameters to the Elevation API query.
@@ -45,10 +45,10 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn for_sampled_path_request(
-        &'a mut self,
+        &mut self,
         path: impl Into<Locations>,
         samples: impl Into<u8>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         let path: Locations = path.into();
         let samples: u8 = samples.into();
         // Set the path in Request struct.
@@ -81,9 +81,9 @@ impl<'a> Request<'a> {
         it's suggested to use the `for_sampled_path_request` method instead"
     )]
     pub fn for_line_string_request(
-        &'a mut self,
+        &mut self,
         line_string: geo_types::LineString
-    ) -> Result<&'a mut Self, crate::error::Error> {
+    ) -> Result<&mut Self, crate::error::Error> {
         // Set the path in Request struct.
         self.locations = Some(Locations::LineString(line_string));
         // Return modified Request struct to caller.
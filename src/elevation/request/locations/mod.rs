@@ -132,6 +132,20 @@ impl std::convert::From<&Locations> for String {
 
 // -----------------------------------------------------------------------------
 
+impl crate::client::Coordinates for Locations {
+    /// Rounds the coordinates of the `LatLngs` variant. All other variants are
+    /// returned unchanged.
+    fn rounded(&self, decimals: u8) -> Self {
+        if let Self::LatLngs(latlngs) = self {
+            Self::LatLngs(latlngs.iter().map(|latlng| latlng.rounded(decimals)).collect())
+        } else {
+            self.clone()
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::default::Default for Locations {
     /// Returns a reasonable default variant for the `Locations` enum type.
     fn default() -> Self {
@@ -4,6 +4,7 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+pub mod builder;
 #[cfg(feature = "reqwest")]
 mod execute;
 mod for_positional_request;
@@ -13,6 +14,7 @@ mod get;
 pub mod locations;
 mod new;
 mod query_url;
+pub mod ready_request;
 mod validate;
 
 // -----------------------------------------------------------------------------
@@ -24,14 +26,17 @@ use crate::{client::GoogleMapsClient, elevation::request::locations::Locations};
 /// **Look at this `Request` struct for documentation on how to build your
 /// _Elevation API_ query**. The methods implemented for this struct are what's
 /// used to build your request.
+///
+/// The Elevation API does not accept `language` or `region` parameters, so this
+/// request has no `with_language` or `with_region` methods.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     // Positional Requests:
     // --------------------
@@ -63,3 +68,20 @@ pub struct Request<'a> {
     /// Has the request been validated?
     validated: bool,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("locations", &self.locations)
+            .field("path", &self.path)
+            .field("samples", &self.samples)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .field("validated", &self.validated)
+            .finish()
+    } // fn
+} // impl
@@ -3,7 +3,7 @@ use crate::elevation::request::Request;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Elevation API query with the
@@ -14,11 +14,11 @@ impl Request<'_> {
     /// * `client` ‧ Your application's Google Maps API client struct.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient) -> Request {
+    pub fn new(client: &GoogleMapsClient) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             // Positional requests:
             locations: None,
             // Sampled path requests:
@@ -1,6 +1,6 @@
 use crate::elevation::{error::Error, request::Request};
 
-impl<'a> Request<'a> {
+impl Request {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Directions API will accept them - i.e. it will not allow both a
@@ -8,19 +8,27 @@ impl<'a> Request<'a> {
     /// function does not check parameter values for validity - i.e. it will not
     /// ensure Polylines or Latitudes/Longitudes are valid and well-formed.
     ///
+    /// All of the combinations are checked. If more than one problem is found,
+    /// they are all returned together in `Error::ValidationFailed`.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+        let mut errors = Vec::new();
+
         // If a "for positional request" has been set...
         if let Some(_locations) = &self.locations {
             // ...a "for sampled path request" cannot be set.
             if let Some(_path) = &self.path {
-                return Err(Error::EitherPositionalOrSampledPath);
+                errors.push(Error::EitherPositionalOrSampledPath);
             }
         } // if
 
+        // Report every problem found, rather than only the first one:
+        Error::from_validation_errors(errors)?;
+
         // Indicated that the request passed validation.
         self.validated = true;
 
@@ -28,3 +36,28 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::elevation::{error::Error, request::{locations::Locations, Request}};
+    use crate::types::LatLng;
+    use crate::GoogleMapsClient;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn positional_and_sampled_path_request_is_rejected() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let denver = LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034)).unwrap();
+
+        // The only problem that can be found is returned as-is, rather than
+        // in a `ValidationFailed` list of one:
+        let mut request = Request::new(&client);
+        request
+            .for_positional_request(denver)
+            .for_sampled_path_request(Locations::LatLngs(vec![denver, denver]), 4);
+        assert!(matches!(request.validate(), Err(Error::EitherPositionalOrSampledPath)));
+    } // fn
+} // mod
@@ -0,0 +1,158 @@
+//! Provides `GeoJSON` export for the _Elevation API_ response, using the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::elevation::response::{point::Point, Response};
+use ::geojson::{Feature, FeatureCollection, Geometry, JsonObject, JsonValue, Value};
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the sample points as a `GeoJSON` `FeatureCollection`, with one
+    /// `Point` feature for each sample point. Each position is ordered
+    /// `[longitude, latitude]`.
+    ///
+    /// Each feature has an `elevation` property, in meters. It also has a
+    /// `resolution` property, in meters, if Google returned one for the
+    /// point.
+    ///
+    /// ```rust
+    /// let feature_collection = elevation.to_feature_collection();
+    /// ```
+
+    #[must_use]
+    pub fn to_feature_collection(&self) -> FeatureCollection {
+        FeatureCollection {
+            bbox: None,
+            features: self.results.iter().map(point_feature).collect(),
+            foreign_members: None,
+        } // FeatureCollection
+    } // fn
+
+    /// Returns the sample points as a single `GeoJSON` `Feature` with a
+    /// `LineString` geometry, which is useful for a sampled path request.
+    /// Each position is ordered `[longitude, latitude]`.
+    ///
+    /// The feature has an `elevations` property, with the elevation of each
+    /// sample point in meters, in the same order as the positions.
+    ///
+    /// A `GeoJSON` `LineString` should have at least two positions. If the
+    /// response has fewer than two sample points, the geometry has fewer
+    /// positions.
+    ///
+    /// ```rust
+    /// let feature = elevation.to_line_string_feature();
+    /// ```
+
+    #[must_use]
+    pub fn to_line_string_feature(&self) -> Feature {
+        let line_string = self
+            .results
+            .iter()
+            .map(|sample| sample.location.to_geojson_position())
+            .collect();
+
+        let elevations = self
+            .results
+            .iter()
+            .map(|sample| JsonValue::from(sample.elevation))
+            .collect();
+
+        let mut properties = JsonObject::new();
+        properties.insert("elevations".to_string(), JsonValue::Array(elevations));
+
+        Feature {
+            bbox: None,
+            geometry: Some(Geometry::new(Value::LineString(line_string))),
+            id: None,
+            properties: Some(properties),
+            foreign_members: None,
+        } // Feature
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a sample point as a `GeoJSON` `Point` feature, with its elevation
+/// and resolution as properties.
+
+fn point_feature(sample: &Point) -> Feature {
+    let mut properties = JsonObject::new();
+    properties.insert("elevation".to_string(), JsonValue::from(sample.elevation));
+    if let Some(resolution) = sample.resolution {
+        properties.insert("resolution".to_string(), JsonValue::from(resolution));
+    } // if
+
+    Feature {
+        bbox: None,
+        geometry: Some(sample.location.to_geojson_point()),
+        id: None,
+        properties: Some(properties),
+        foreign_members: None,
+    } // Feature
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::elevation::response::{point::Point, status::Status, Response};
+    use crate::types::LatLng;
+    use ::geojson::GeoJson;
+    use rust_decimal_macros::dec;
+
+    /// Returns a response with two sample points, the second of which has no
+    /// resolution.
+    fn response() -> Response {
+        Response {
+            error_message: None,
+            results: vec![
+                Point {
+                    elevation: 1_608.5,
+                    location: LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034)).unwrap(),
+                    resolution: Some(4.75),
+                },
+                Point {
+                    elevation: 1_590.25,
+                    location: LatLng::try_from_dec(dec!(39.7458), dec!(-105.0011)).unwrap(),
+                    resolution: None,
+                },
+            ],
+            status: Status::Ok,
+        } // Response
+    } // fn
+
+    #[test]
+    fn exports_each_sample_as_a_point_feature() {
+        let expected: GeoJson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-104.9847034, 39.7391536] },
+                    "properties": { "elevation": 1608.5, "resolution": 4.75 }
+                },
+                {
+                    "type": "Feature",
+                    "geometry": { "type": "Point", "coordinates": [-105.0011, 39.7458] },
+                    "properties": { "elevation": 1590.25 }
+                }
+            ]
+        }"#.parse().unwrap();
+
+        assert_eq!(GeoJson::from(response().to_feature_collection()), expected);
+    } // fn
+
+    #[test]
+    fn exports_the_samples_as_a_line_string() {
+        let expected: GeoJson = r#"{
+            "type": "Feature",
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [[-104.9847034, 39.7391536], [-105.0011, 39.7458]]
+            },
+            "properties": { "elevations": [1608.5, 1590.25] }
+        }"#.parse().unwrap();
+
+        assert_eq!(GeoJson::from(response().to_line_string_feature()), expected);
+    } // fn
+} // mod
@@ -2,13 +2,19 @@
 //! from the Google Maps Platform. Look in here for more information about the
 //! data returned from Google's server and how to parse it with your program.
 
+#[cfg(feature = "geojson")]
+mod geojson;
 pub mod point;
 pub mod status;
 
 use crate::elevation::response::{point::Point, status::Status};
+use crate::types::{GoogleStatus, HasStatus, LatLng};
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Elevation API is stored in this structure.
+///
+/// `PartialEq` compares the `f64` fields of each sample point exactly. Use
+/// `approx_eq` to compare them within a tolerance instead.
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Response {
@@ -34,12 +40,222 @@ pub struct Response {
     pub status: Status,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.results.is_empty().then_some("results")
+    } // fn
+
+    /// Returns the elevation of the sample point nearest to `point`, and the
+    /// distance from `point` to that sample in metres. This is useful for
+    /// looking up the elevation at an arbitrary position along a sampled
+    /// path. Distances are great circle distances, see
+    /// `LatLng::haversine_distance`.
+    ///
+    /// Returns `None` if the response has no sample points.
+    /// ```rust
+    /// if let Some((elevation, distance)) = elevation.elevation_near(&latlng) {
+    ///     println!("{elevation} m, sampled {distance} m away");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn elevation_near(&self, point: &LatLng) -> Option<(f64, f64)> {
+        self.results
+            .iter()
+            .map(|sample| (sample.elevation, sample.location.haversine_distance(point)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    } // fn
+
+    /// Returns the elevation at a position along the sampled path, given as a
+    /// fraction `t` of the path's length. `0.0` is the first sample point and
+    /// `1.0` is the last. The elevation is interpolated linearly between the
+    /// two sample points on either side of the position. The path's length is
+    /// measured with great circle distances between consecutive samples.
+    ///
+    /// `t` is clamped to the `0.0` to `1.0` range. A response with a single
+    /// sample point returns its elevation for any `t`.
+    ///
+    /// Returns `None` if the response has no sample points, or if `t` is
+    /// `NaN`.
+    /// ```rust
+    /// let halfway = elevation.elevation_at_fraction(0.5);
+    /// ```
+
+    #[must_use]
+    pub fn elevation_at_fraction(&self, t: f64) -> Option<f64> {
+        if self.results.is_empty() || t.is_nan() {
+            return None;
+        } // if
+
+        let segments: Vec<f64> = self
+            .results
+            .windows(2)
+            .map(|pair| pair[0].location.haversine_distance(&pair[1].location))
+            .collect();
+
+        let mut remaining = t.clamp(0.0, 1.0) * segments.iter().sum::<f64>();
+
+        for (pair, length) in self.results.windows(2).zip(segments) {
+            if remaining <= length {
+                let fraction = if length > 0.0 { remaining / length } else { 0.0 };
+                return Some((pair[1].elevation - pair[0].elevation).mul_add(fraction, pair[0].elevation));
+            } // if
+            remaining -= length;
+        } // for
+
+        // Rounding errors may leave a tiny distance past the last sample:
+        self.results.last().map(|last| last.elevation)
+    } // fn
+
+    /// Returns the largest `resolution` of the sample points, in meters. This
+    /// is the coarsest spacing of the data that any of the elevations were
+    /// interpolated from, so it shows how trustworthy the least accurate
+    /// elevation in the response is.
+    ///
+    /// Sample points without a resolution are skipped. Returns `None` if no
+    /// sample point has a resolution.
+    /// ```rust
+    /// if let Some(resolution) = elevation.worst_resolution() {
+    ///     println!("elevations are accurate to within {resolution} m");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn worst_resolution(&self) -> Option<f64> {
+        self.results
+            .iter()
+            .filter_map(|sample| sample.resolution)
+            .max_by(f64::total_cmp)
+    } // fn
+
+    /// Compares two responses, allowing the `elevation` and `resolution` of
+    /// each sample point to differ by up to `epsilon` meters. All other fields
+    /// are compared exactly.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.status == other.status
+            && self.error_message == other.error_message
+            && self.results.len() == other.results.len()
+            && self
+                .results
+                .iter()
+                .zip(&other.results)
+                .all(|(a, b)| a.approx_eq(b, epsilon))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Elevation API JSON `String` into a `Response`
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::{point::Point, status::Status, Response};
+    use crate::json::tests::{assert_round_trip, cassette_body};
+    use crate::types::LatLng;
+
+    /// Returns a response with a sample point for each elevation, one degree
+    /// of longitude apart along the equator, so that every segment of the path
+    /// is the same length.
+    fn path(elevations: &[f64]) -> Response {
+        Response {
+            error_message: None,
+            results: elevations
+                .iter()
+                .zip(0_u32..)
+                .map(|(elevation, longitude)| Point {
+                    elevation: *elevation,
+                    location: LatLng::try_from_f64(0.0, f64::from(longitude)).unwrap(),
+                    resolution: Some(9.543_951_988_220_215),
+                }) // map
+                .collect(),
+            status: Status::Ok,
+        } // Response
+    } // fn
+
+    /// Asserts that an interpolated elevation is the expected one, give or take
+    /// rounding errors.
+    #[track_caller]
+    fn assert_elevation(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!((actual - expected).abs() < 1e-6, "{actual} is not {expected}");
+    } // fn
+
+    #[test]
+    fn elevation_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("elevation", 0));
+    } // fn
+
+    #[test]
+    fn finds_the_elevation_near_a_point() {
+        let response = path(&[100.0, 200.0, 150.0, 150.0, 300.0]);
+        let point = LatLng::try_from_f64(0.1, 2.2).unwrap();
+
+        let (elevation, distance) = response.elevation_near(&point).unwrap();
+        assert_elevation(Some(elevation), 150.0);
+        let expected = response.results[2].location.haversine_distance(&point);
+        assert!((distance - expected).abs() < 1e-6, "{distance} is not {expected}");
+
+        assert_eq!(path(&[]).elevation_near(&point), None);
+    } // fn
+
+    #[test]
+    fn interpolates_the_elevation_along_the_path() {
+        let response = path(&[100.0, 200.0, 150.0, 150.0, 300.0]);
+
+        assert_elevation(response.elevation_at_fraction(0.0), 100.0);
+        assert_elevation(response.elevation_at_fraction(0.125), 150.0);
+        assert_elevation(response.elevation_at_fraction(0.25), 200.0);
+        assert_elevation(response.elevation_at_fraction(0.5), 150.0);
+        assert_elevation(response.elevation_at_fraction(0.625), 150.0);
+        assert_elevation(response.elevation_at_fraction(0.875), 225.0);
+        assert_elevation(response.elevation_at_fraction(1.0), 300.0);
+    } // fn
+
+    #[test]
+    fn clamps_the_fraction_of_the_path() {
+        let response = path(&[100.0, 200.0, 150.0, 150.0, 300.0]);
+
+        assert_elevation(response.elevation_at_fraction(-0.5), 100.0);
+        assert_elevation(response.elevation_at_fraction(f64::NEG_INFINITY), 100.0);
+        assert_elevation(response.elevation_at_fraction(1.5), 300.0);
+        assert_elevation(response.elevation_at_fraction(f64::INFINITY), 300.0);
+        assert_eq!(response.elevation_at_fraction(f64::NAN), None);
+    } // fn
+
+    #[test]
+    fn interpolates_a_path_of_one_or_no_samples() {
+        let response = path(&[42.0]);
+        for t in [0.0, 0.5, 1.0, -1.0, 2.0] {
+            assert_elevation(response.elevation_at_fraction(t), 42.0);
+        } // for
+        assert_eq!(response.elevation_at_fraction(f64::NAN), None);
+
+        assert_eq!(path(&[]).elevation_at_fraction(0.5), None);
+    } // fn
+} // mod
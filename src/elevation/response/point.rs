@@ -1,7 +1,11 @@
+use crate::serde::decimal_as_number::latlng_as_numbers;
 use crate::types::LatLng;
 use serde::{Deserialize, Serialize};
 
 /// Structure for an elevation sample point.
+///
+/// `PartialEq` compares the `f64` elevation and resolution exactly. Use
+/// `approx_eq` to compare them within a tolerance instead.
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Point {
@@ -10,6 +14,7 @@ pub struct Point {
     /// Position for which elevation data is being computed. Note that for path
     /// requests, the set of `location` elements will contain the sampled points
     /// along the path.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub location: LatLng,
     /// Maximum distance between data points from which the elevation was
     /// interpolated, in meters. This property will be missing if the
@@ -20,3 +25,24 @@ pub struct Point {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resolution: Option<f64>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl Point {
+    /// Compares two sample points, allowing the `elevation` and `resolution`
+    /// to differ by up to `epsilon` meters. The `location` is compared exactly.
+    /// This is useful when comparing a parsed response against an expected
+    /// value in tests.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let resolution_eq = match (self.resolution, other.resolution) {
+            (Some(a), Some(b)) => (a - b).abs() <= epsilon,
+            (None, None) => true,
+            _ => false,
+        }; // match
+
+        self.location == other.location
+            && (self.elevation - other.elevation).abs() <= epsilon
+            && resolution_eq
+    } // fn
+} // impl
@@ -85,4 +85,376 @@ pub enum Error {
     #[error(transparent)]
     #[diagnostic(code(google_maps::polyline))]
     Polyline(#[from] polyline::errors::PolylineError),
+
+    /// A cassette file could not be read, or is not a cassette. Contains the
+    /// path of the file and the underlying error message.
+    #[cfg(feature = "vcr")]
+    #[error("Google Maps client: could not read cassette `{0}`: {1}")]
+    #[diagnostic(code(google_maps::cassette))]
+    Cassette(String, String),
+
+    /// A cassette in replay mode has no recorded response for the request, so
+    /// the request was not sent. Contains the path of the cassette file and
+    /// the request URL, with the API key removed.
+    #[cfg(feature = "vcr")]
+    #[error("Google Maps client: cassette `{0}` has no recorded response for `{1}`")]
+    #[diagnostic(code(google_maps::cassette_miss))]
+    CassetteMiss(String, String),
+
+    /// The client's circuit breaker is open after repeated failures from
+    /// Google's servers, so the request was not sent. See the
+    /// `GoogleMapsClient::with_circuit_breaker` method.
+    #[cfg(feature = "reqwest")]
+    #[error("Google Maps client: request not sent, the circuit breaker is open after repeated failures")]
+    #[diagnostic(code(google_maps::circuit_open))]
+    CircuitOpen,
+
+    /// A request failed with a transient error, and was not retried because
+    /// the client's retry budget is exhausted. Contains the error of the last
+    /// attempt. See the `GoogleMapsClient::with_retry_budget` method.
+    #[cfg(feature = "reqwest")]
+    #[error("Google Maps client: request not retried, the retry budget is exhausted: {0}")]
+    #[diagnostic(code(google_maps::retry_budget_exhausted))]
+    RetryBudgetExhausted(Box<Self>),
+
+    /// The request was cancelled with its `CancellationToken` before it
+    /// completed. See the `get_with_cancellation` methods.
+    #[cfg(feature = "cancellation")]
+    #[error("Google Maps client: request cancelled")]
+    #[diagnostic(code(google_maps::cancelled))]
+    Cancelled,
 } // enum Error
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Returns `true` if the error is temporary and the request may succeed if
+    /// it is retried. This is the same classification that the client uses
+    /// when deciding whether a request is eligible for automatic retries.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(error) => error.is_transient(),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(error) => error.is_transient(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(error) => error.is_transient(),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(error) => error.is_transient(),
+            #[cfg(feature = "places")]
+            Self::Places(error) => error.is_transient(),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(error) => error.is_transient(),
+            #[cfg(feature = "roads")]
+            Self::Roads(error) => error.is_transient(),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(error) => error.is_transient(),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) | Self::CircuitOpen => true,
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns `true` if the error was caused by exceeding a usage quota or a
+    /// request rate limit. For example, Google's `OVER_QUERY_LIMIT` status or
+    /// an HTTP `429 Too Many Requests` response.
+    #[must_use]
+    pub fn is_quota_error(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(error) => error.is_quota_error(),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(error) => error.is_quota_error(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(error) => error.is_quota_error(),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(error) => error.is_quota_error(),
+            #[cfg(feature = "places")]
+            Self::Places(error) => error.is_quota_error(),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(error) => error.is_quota_error(),
+            #[cfg(feature = "roads")]
+            Self::Roads(error) => error.is_quota_error(),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(error) => error.is_quota_error(),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(error) => error.status() == Some(reqwest::StatusCode::TOO_MANY_REQUESTS),
+            #[cfg(feature = "reqwest")]
+            Self::RetryBudgetExhausted(error) => error.is_quota_error(),
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns the HTTP status code that the server responded with, if the
+    /// error was caused by an unsuccessful HTTP response.
+    #[cfg(feature = "reqwest")]
+    #[must_use]
+    pub fn http_status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(error) => error.http_status(),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(error) => error.http_status(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(error) => error.http_status(),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(error) => error.http_status(),
+            #[cfg(feature = "places")]
+            Self::Places(error) => error.http_status(),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(error) => error.http_status(),
+            #[cfg(feature = "roads")]
+            Self::Roads(error) => error.http_status(),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(error) => error.http_status(),
+            Self::Reqwest(error) => error.status(),
+            Self::RetryBudgetExhausted(error) => error.http_status(),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Extracts the numeric status code from an HTTP status string, such as the
+/// `429 Too Many Requests` strings that are stored in the `HttpUnsuccessful`
+/// error variants.
+
+pub(crate) fn http_status_code(status: &str) -> Option<u16> {
+    status.split_whitespace().next()?.parse().ok()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if an HTTP status string indicates that the request may
+/// succeed if it is retried. Only HTTP "500 Server Errors", and HTTP "429 Too
+/// Many Requests" are eligible for retries.
+///
+/// This is the only classification of HTTP statuses. The error accessors, the
+/// circuit breaker and the retry budget all use it, so that they agree on
+/// which responses are failures. A `reqwest::StatusCode` may be passed with
+/// its `as_str()` method.
+
+pub(crate) fn is_transient_http_status(status: &str) -> bool {
+    http_status_code(status).is_some_and(|code| code == 429 || (500..600).contains(&code))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if an HTTP status string indicates that a rate limit has
+/// been exceeded. That is, an HTTP "429 Too Many Requests" response.
+
+pub(crate) fn is_quota_http_status(status: &str) -> bool {
+    http_status_code(status) == Some(429)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts an HTTP status string into a `reqwest::StatusCode`.
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn http_status(status: &str) -> Option<reqwest::StatusCode> {
+    http_status_code(status).and_then(|code| reqwest::StatusCode::from_u16(code).ok())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// The maximum number of response body bytes that will be captured in JSON
+/// parsing errors.
+
+#[cfg(feature = "reqwest")]
+pub(crate) const BODY_SNIPPET_LENGTH: usize = 2_048;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the beginning of an HTTP response body as a (lossy) UTF-8 string.
+/// This is used to help debug JSON parsing errors.
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn body_snippet(body: &[u8]) -> String {
+    let end = body.len().min(BODY_SNIPPET_LENGTH);
+    String::from_utf8_lossy(&body[..end]).into_owned()
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the `Content-Type` header of an HTTP response, if it has one.
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn content_type(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .map(str::to_ascii_lowercase)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns `true` if a response body is not JSON. For example, an HTML error
+/// page that Google may respond with when the API key is entirely wrong or an
+/// endpoint is misconfigured.
+///
+/// A body is not JSON if its content type is known and is not a JSON content
+/// type, or if it starts with `<`.
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn is_non_json(content_type: Option<&str>, body: &[u8]) -> bool {
+    let non_json_content_type = content_type.is_some_and(|content_type| !content_type.contains("json"));
+    let markup = body.iter().find(|byte| !byte.is_ascii_whitespace()) == Some(&b'<');
+    non_json_content_type || markup
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the body of an unsuccessful HTTP response. If the body is not JSON,
+/// returns its beginning (or an empty string, if response body capture is
+/// disabled). Otherwise, returns `None`.
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn non_json_body(response: reqwest::Response, capture: bool) -> Option<String> {
+    let content_type = content_type(&response);
+    let body = response.bytes().await.ok()?;
+    is_non_json(content_type.as_deref(), &body).then(|| non_json_snippet(&body, capture))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the beginning of a response body that is not JSON, or an empty
+/// string if response body capture is disabled.
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn non_json_snippet(body: &[u8], capture: bool) -> String {
+    if capture {
+        body_snippet(body)
+    } else {
+        String::new()
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Removes the API key from a request URL so that it may safely be included in
+/// error messages and logs.
+
+#[cfg(any(
+    feature = "reqwest",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
+pub(crate) fn sanitize_url(url: &str) -> String {
+    replace_key(url, "REDACTED")
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Replaces the value of the `key` parameter in a request URL with
+/// `replacement`. Used to redact the API key from logs, and to refer to it by
+/// an environment variable in `curl` commands.
+
+#[cfg(any(
+    feature = "reqwest",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
+pub(crate) fn replace_key(url: &str, replacement: &str) -> String {
+    url.split('&')
+        .map(|parameter| {
+            parameter
+                .split_once("key=")
+                .filter(|(prefix, _key)| prefix.is_empty() || prefix.ends_with('?'))
+                .map_or_else(
+                    || parameter.to_string(),
+                    |(prefix, _key)| format!("{prefix}key={replacement}"),
+                )
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+pub(crate) mod tests {
+    use super::Error;
+    use reqwest::StatusCode;
+
+    /// Returns a `reqwest` error, without sending a request.
+
+    pub fn reqwest_error() -> reqwest::Error {
+        reqwest::Client::new().get("not a url").build().unwrap_err()
+    } // fn
+
+    #[test]
+    fn classifies_each_error_variant() {
+        // Each error, and whether it is transient, whether it is a quota
+        // error, and its HTTP status:
+        let cases = [
+            (Error::Reqwest(reqwest_error()), true, false, None),
+            (Error::CircuitOpen, true, false, None),
+            (Error::RetryBudgetExhausted(Box::new(Error::CircuitOpen)), false, false, None),
+        ]; // cases
+
+        for (error, is_transient, is_quota_error, http_status) in cases {
+            assert_eq!(error.is_transient(), is_transient, "{error:?}");
+            assert_eq!(error.is_quota_error(), is_quota_error, "{error:?}");
+            assert_eq!(error.http_status(), http_status, "{error:?}");
+        } // for
+    } // fn
+
+    #[cfg(feature = "time_zone")]
+    #[test]
+    fn classifies_the_errors_of_each_api() {
+        use crate::time_zone::{error::Error as TimeZoneError, response::status::Status};
+
+        let cases = [
+            (TimeZoneError::GoogleMapsService(Status::UnknownError, None), true, false, None),
+            (TimeZoneError::GoogleMapsService(Status::OverQueryLimit, None), false, true, None),
+            (TimeZoneError::GoogleMapsService(Status::RequestDenied, None), false, false, None),
+            (TimeZoneError::HttpUnsuccessful("429 Too Many Requests".to_string()), true, true, Some(StatusCode::TOO_MANY_REQUESTS)),
+            (TimeZoneError::HttpUnsuccessful("503 Service Unavailable".to_string()), true, false, Some(StatusCode::SERVICE_UNAVAILABLE)),
+            (TimeZoneError::HttpUnsuccessful("404 Not Found".to_string()), false, false, Some(StatusCode::NOT_FOUND)),
+        ]; // cases
+
+        for (error, is_transient, is_quota_error, http_status) in cases {
+            let error = Error::from(error);
+            assert_eq!(error.is_transient(), is_transient, "{error:?}");
+            assert_eq!(error.is_quota_error(), is_quota_error, "{error:?}");
+            assert_eq!(error.http_status(), http_status, "{error:?}");
+
+            // A request that is not retried because the retry budget is
+            // exhausted is not transient, but keeps its cause's quota error
+            // and HTTP status:
+            let error = Error::RetryBudgetExhausted(Box::new(error));
+            assert!(!error.is_transient(), "{error:?}");
+            assert_eq!(error.is_quota_error(), is_quota_error, "{error:?}");
+            assert_eq!(error.http_status(), http_status, "{error:?}");
+        } // for
+    } // fn
+
+    #[test]
+    fn detects_bodies_that_are_not_json() {
+        use super::is_non_json;
+
+        assert!(!is_non_json(Some("application/json; charset=utf-8"), br#"{"status": "OK"}"#));
+        assert!(!is_non_json(None, b"  [1, 2, 3]"));
+        // Markup is not JSON, whatever its content type says:
+        assert!(is_non_json(Some("application/json"), b"\n  <html><body>Error</body></html>"));
+        assert!(is_non_json(None, b"<!DOCTYPE html>"));
+        // Neither is a body with an HTML content type:
+        assert!(is_non_json(Some("text/html; charset=utf-8"), b"Service misconfigured"));
+    } // fn
+} // mod
@@ -1,15 +1,21 @@
 use crate::{geocoding::error::Error, geocoding::forward::ForwardRequest};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Builds the query string for the Google Maps Geocoding API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn build(&mut self) -> Result<&mut Self, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
@@ -44,35 +50,37 @@ impl<'a> ForwardRequest<'a> {
         if let Some(bounds) = &self.bounds {
             query.push_str("&bounds=");
             query.push_str(
-                &utf8_percent_encode(&String::from(bounds), NON_ALPHANUMERIC).to_string(),
+                &utf8_percent_encode(&String::from(&*self.client.coordinates(bounds)), NON_ALPHANUMERIC).to_string(),
             );
         }
 
         // Components key/value pair:
         if !self.components.is_empty() {
             query.push_str("&components=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .components
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut components = self.components.iter().map(String::from).collect::<Vec<String>>();
+            components.sort();
+            components.dedup();
+            query.push_str(&utf8_percent_encode(&components.join("|"), NON_ALPHANUMERIC).to_string());
         }
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if none
+        // was set for this request, except for place ID lookups, which can not
+        // be biased:
+        let default_region = self
+            .client
+            .default_region
+            .as_ref()
+            .filter(|_default_region| self.place_id.is_none());
+        if let Some(region) = self.region.as_ref().or(default_region) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         }
@@ -0,0 +1,186 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::{
+    error::Error,
+    forward::{component::Component, ForwardRequest},
+    ready_request::ReadyRequest,
+}; // use crate::geocoding
+use crate::types::{Bounds, Language, Region, ResponseFormat};
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for forward _Geocoding API_ requests. Each method takes
+/// the builder by value and returns it, so that a request may be built in a
+/// single expression, or returned from a function. The request can only be
+/// sent once `build` has returned a `ReadyRequest`:
+///
+/// ```rust
+/// let location = GeocodingRequest::builder(&google_maps_client)
+///     .with_address("10 Downing Street London")
+///     .build()?
+///     .get()
+///     .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let location = GeocodingRequest::builder(&google_maps_client)
+///     .with_address("10 Downing Street London")
+///     .get()
+///     .await?;
+/// ```
+///
+/// The builder produces exactly the same query string as the mutable
+/// `ForwardRequest` builder with the same parameters.
+
+#[derive(Debug)]
+pub struct Builder {
+    request: ForwardRequest,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ForwardRequest {
+    /// Initializes a typestate builder. See `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+
+    #[must_use]
+    pub fn builder(client: &GoogleMapsClient) -> Builder {
+        Builder {
+            request: Self::new(client),
+        } // Builder
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Validates the request and builds its query string. The returned
+    /// `ReadyRequest` may then be sent to the Google Maps Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the combination of parameters is not accepted by
+    ///   the Google Maps Geocoding API. For example, if none of an address, a
+    ///   place ID or components were specified.
+
+    pub fn build(mut self) -> Result<ReadyRequest, Error> {
+        self.request.validate()?.build()?;
+        Ok(ReadyRequest {
+            client: self.request.client,
+            query: self.request.query.unwrap_or_default(),
+            language: self.request.language,
+            format: self.request.format,
+        }) // ReadyRequest
+    } // fn
+
+    /// Specifies the street address to geocode. See
+    /// `ForwardRequest::with_address`.
+
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.request.with_address(address);
+        self
+    } // fn
+
+    /// Specifies a bounding box to bias the results towards. See
+    /// `ForwardRequest::with_bounds`.
+
+    #[must_use]
+    pub fn with_bounds(mut self, bounds: impl Into<Bounds>) -> Self {
+        self.request.with_bounds(bounds);
+        self
+    } // fn
+
+    /// Specifies a component filter. See `ForwardRequest::with_component`.
+
+    #[must_use]
+    pub fn with_component(mut self, component: impl Into<Component>) -> Self {
+        self.request.with_component(component);
+        self
+    } // fn
+
+    /// Specifies component filters. See `ForwardRequest::with_components`.
+
+    #[must_use]
+    pub fn with_components<C, O>(mut self, components: C) -> Self
+    where
+        C: IntoIterator<Item = O>,
+        O: Into<Component> {
+        self.request.with_components(components);
+        self
+    } // fn
+
+    /// Specifies the format that Google responds in. See
+    /// `ForwardRequest::with_format`.
+
+    #[must_use]
+    pub fn with_format(mut self, format: impl Into<ResponseFormat>) -> Self {
+        self.request.with_format(format);
+        self
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `ForwardRequest::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.with_language(language);
+        self
+    } // fn
+
+    /// Specifies the place ID to geocode. See
+    /// `ForwardRequest::with_place_id`.
+
+    #[must_use]
+    pub fn with_place_id(mut self, place_id: impl Into<String>) -> Self {
+        self.request.with_place_id(place_id);
+        self
+    } // fn
+
+    /// Specifies the region bias. See `ForwardRequest::with_region`.
+
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<Region>) -> Self {
+        self.request.with_region(region);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::geocoding::forward::{component::Component, ForwardRequest};
+    use crate::types::{Country, Language};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let consuming = ForwardRequest::builder(&client)
+            .with_address("10 Downing Street London")
+            .with_component(Component::Country(Country::UnitedKingdom))
+            .with_language(Language::English)
+            .build()
+            .unwrap();
+
+        let mut mutable = ForwardRequest::new(&client);
+        mutable
+            .with_address("10 Downing Street London")
+            .with_component(Component::Country(Country::UnitedKingdom))
+            .with_language(Language::English);
+
+        assert_eq!(consuming.query_url(), mutable.query_url().unwrap());
+    } // fn
+} // mod
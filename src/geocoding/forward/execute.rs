@@ -5,7 +5,7 @@ use crate::geocoding::{
 
 // =============================================================================
 
-impl<'a> GeocodingForwardRequest<'a> {
+impl GeocodingForwardRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> GeocodingForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<GeocodingResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<GeocodingResponse, GoogleMapsError> {
         self.validate()?.build()?.get().await
     } // fn
 } // impl
@@ -1,146 +1,73 @@
 use crate::error::Error as GoogleMapsError;
 use crate::geocoding::{
-    error::Error as GeocodingError, forward::ForwardRequest as FordwardGeocodingRequest,
-    response::status::Status as GeocodingStatus, response::Response as GeocodingResponse,
-    OUTPUT_FORMAT, SERVICE_URL,
+    error::Error as GeocodingError, ready_request::ReadyRequest,
+    response::Response as GeocodingResponse, forward::ForwardRequest as FordwardGeocodingRequest,
 };
-use crate::request_rate::api::Api;
-use backoff::future::retry;
-use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> FordwardGeocodingRequest<'a> {
+impl FordwardGeocodingRequest {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
     pub async fn get(&mut self) -> Result<GeocodingResponse, GoogleMapsError> {
-        // Build the URL stem for the HTTP get request:
-        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+        self.ready()?.get().await
+    } // fn
 
-        match &self.query {
-            // If query string built, append it to the URL stem.
-            Some(query) => url.push_str(query.as_ref()),
-            // If query string not built, return an error.
-            None => return Err(GeocodingError::QueryNotBuilt)?,
-        } // match
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
 
-        // Observe any rate limiting before executing request:
-        tracing::info!("making HTTP GET request to Google Maps Geocoding API");
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<GeocodingResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
 
-        self.client
-            .rate_limit
-            .limit_apis(vec![&Api::All, &Api::Geocoding])
-            .await;
+    /// Converts the built request into a `ReadyRequest`, which is used to
+    /// perform the HTTP get request.
 
-        tracing::debug!("{url}");
+    fn ready(&self) -> Result<ReadyRequest, GeocodingError> {
+        // If the query string has not been built, return an error:
+        self.query
+            .as_ref()
+            .map(|query| ReadyRequest {
+                client: self.client.clone(),
+                query: query.clone(),
+                language: self.language.clone(),
+                format: self.format,
+            }) // map
+            .ok_or(GeocodingError::QueryNotBuilt)
+    } // fn
+} // impl
 
-        // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
-            // Query the Google Cloud Maps Platform using using an HTTP get
-            // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+// -----------------------------------------------------------------------------
 
-            // Check response from the HTTP client:
-            match response {
-                Ok(response) => {
-                    // HTTP client was successful getting a response from the
-                    // server. Check the HTTP status code:
-                    if response.status().is_success() {
-                        // If the HTTP GET request was successful, get the
-                        // response text:
-                        let bytes = response.text().await.map(String::into_bytes);
-                        match bytes {
-                            Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<GeocodingResponse>(&mut bytes) {
-                                    Ok(deserialized) => {
-                                        // If the response JSON was successfully
-                                        // parsed, check the Google API status
-                                        // before returning it to the caller:
-                                        if deserialized.status == GeocodingStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
-                                            let error = GeocodingError::GoogleMapsService(
-                                                deserialized.status.clone(),
-                                                deserialized.error_message,
-                                            );
-                                            // Check Google API response status
-                                            // for error type:
-                                            if deserialized.status == GeocodingStatus::UnknownError
-                                            {
-                                                // Only Google's "Unknown Error"
-                                                // is eligible for retries:
-                                                tracing::warn!("{}", error);
-                                                Err(Transient {
-                                                    err: error,
-                                                    retry_after: None,
-                                                })
-                                            } else {
-                                                // Not an "Unknown Error." The
-                                                // error is permanent, do not
-                                                // retry:
-                                                tracing::error!("{}", error);
-                                                Err(Permanent(error))
-                                            } // if
-                                        } // if
-                                    } // Ok(deserialized)
-                                    Err(error) => {
-                                        tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(GeocodingError::SimdJson(error)))
-                                    } // Err
-                                } // match
-                            } // Ok(text)
-                            Err(error) => {
-                                tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(GeocodingError::ReqwestMessage(error.to_string())))
-                            } // Err
-                        } // match
-                          // We got a response from the server but it was not OK.
-                          // Only HTTP "500 Server Errors", and HTTP "429 Too Many
-                          // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: GeocodingError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
-                    } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(GeocodingError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
-                    } // if
-                } // case
-                // HTTP client did not get a response from the server. Retry:
-                Err(error) => {
-                    tracing::warn!("HTTP client returned: {}", error);
-                    Err(Transient {
-                        err: GeocodingError::Reqwest(error),
-                        retry_after: None,
-                    })
-                } // case
-            } // match
-        })
-        .await?;
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
 
-        // Return response to caller:
-        Ok(response)
+    #[tokio::test]
+    async fn replays_forward_geocoding() {
+        let client = replay_client("geocoding");
+        let response = client
+            .geocoding()
+            .with_address("1600 Amphitheatre Parkway, Mountain View, CA")
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(
+            response.results[0].formatted_address,
+            "1600 Amphitheatre Pkwy, Mountain View, CA 94043, USA"
+        );
     } // fn
-} // impl
+} // mod
@@ -5,17 +5,20 @@
 //! geocoding converts a street address to latitude & longitude coordinates.
 
 mod build;
+pub mod builder;
 pub mod component;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod parameters;
 mod query_url;
 mod validate;
 mod with_address;
 mod with_bounds;
 mod with_components;
+mod with_format;
 mod with_language;
 mod with_place_id;
 mod with_region;
@@ -24,7 +27,7 @@ mod with_region;
 
 use crate::client::GoogleMapsClient;
 use crate::geocoding::forward::component::Component;
-use crate::types::{Bounds, Language, Region};
+use crate::types::{Bounds, Language, Region, ResponseFormat};
 
 // -----------------------------------------------------------------------------
 
@@ -32,14 +35,23 @@ use crate::types::{Bounds, Language, Region};
 /// _Geocoding API_ query**. The methods implemented for this struct are what's
 /// used to build your request. Forward geocoding looks up a longitude &
 /// latitude coordinates from a street address.
+///
+/// ## Location biasing
+///
+/// The _Geocoding API_ may be biased towards a viewport with `with_bounds`,
+/// and towards a country with `with_region`. It does not support the
+/// `locationbias` parameter of the _Places API_, biasing to a point and
+/// radius, or biasing to the location of the caller's IP address. Biasing
+/// only applies to `address` and `components` searches; validation rejects a
+/// biased `place_id` lookup.
 
-#[derive(Debug)]
-pub struct ForwardRequest<'a> {
+#[derive(Clone)]
+pub struct ForwardRequest {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     // Optional parameters:
     // --------------------
@@ -84,6 +96,10 @@ pub struct ForwardRequest<'a> {
     /// below.)
     region: Option<Region>,
 
+    /// The format that Google responds in. The response is parsed into the
+    /// same `Response` struct either way. Defaults to JSON.
+    format: ResponseFormat,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
@@ -92,3 +108,24 @@ pub struct ForwardRequest<'a> {
     /// Has the request been validated?
     validated: bool,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for ForwardRequest {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ForwardRequest")
+            .field("client", &self.client)
+            .field("address", &self.address)
+            .field("place_id", &self.place_id)
+            .field("bounds", &self.bounds)
+            .field("components", &self.components)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("format", &self.format)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .field("validated", &self.validated)
+            .finish()
+    } // fn
+} // impl
@@ -1,8 +1,8 @@
-use crate::{client::GoogleMapsClient, geocoding::forward::ForwardRequest};
+use crate::{client::GoogleMapsClient, geocoding::forward::ForwardRequest, types::ResponseFormat};
 
 // =============================================================================
 
-impl ForwardRequest<'_> {
+impl ForwardRequest {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Geolocation API query with the
@@ -13,11 +13,11 @@ impl ForwardRequest<'_> {
     /// * `client` ‧ Your application's Google Maps API client struct.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient) -> ForwardRequest {
+    pub fn new(client: &GoogleMapsClient) -> Self {
         // Instantiate struct and return it to caller:
-        ForwardRequest {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             // Optional parameters:
             address: None,
             place_id: None,
@@ -25,6 +25,7 @@ impl ForwardRequest<'_> {
             components: Vec::new(),
             language: None,
             region: None,
+            format: ResponseFormat::default(),
             // Internal use only:
             validated: false,
             query: None,
@@ -0,0 +1,127 @@
+//! Contains the `Parameters` struct, which holds the parameters of a forward
+//! _Geocoding API_ request without the client that will send it.
+
+use crate::client::GoogleMapsClient;
+use crate::geocoding::forward::{component::Component, ForwardRequest};
+use crate::types::{Bounds, Language, Region};
+
+// -----------------------------------------------------------------------------
+//
+/// The parameters of a forward _Geocoding API_ request. Every parameter is
+/// optional, so simple requests may be written with struct-update syntax and
+/// turned into a request with `ForwardRequest::from_parameters`:
+///
+/// ```rust
+/// let location = ForwardRequest::from_parameters(
+///     &google_maps_client,
+///     GeocodingParameters {
+///         address: Some("10 Downing Street London".to_string()),
+///         ..Default::default()
+///     },
+/// )
+/// .execute()
+/// .await?;
+/// ```
+///
+/// The fields have the same meaning as the `ForwardRequest` methods of the
+/// same names. See `ForwardRequest::with_address` and so on for more
+/// information.
+///
+/// Only the _Geocoding API_ requests have a parameters struct. Forward
+/// geocoding is the only request in this crate where every parameter is
+/// optional, and reverse geocoding has a single required coordinate. The
+/// requests of the other APIs have required parameters, such as an origin and
+/// a destination, that have no sensible default. Build those with the
+/// `GoogleMapsClient` methods and the `with_*` methods, or with their
+/// consuming `builder` where there is one.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Parameters {
+    /// The street address or plus code that you want to geocode.
+    pub address: Option<String>,
+
+    /// The place ID of the place for which you wish to obtain the
+    /// human-readable address.
+    pub place_id: Option<String>,
+
+    /// The bounding box of the viewport within which to bias geocode results
+    /// more prominently.
+    pub bounds: Option<Bounds>,
+
+    /// The component filters. Each element in the components filter consists
+    /// of a `component:value` pair, and fully restricts the results from the
+    /// geocoder.
+    pub components: Vec<Component>,
+
+    /// The language in which to return results.
+    pub language: Option<Language>,
+
+    /// The region code, specified as a ccTLD ("top-level domain") two-character
+    /// value.
+    pub region: Option<Region>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ForwardRequest {
+    /// Initializes a request from its parameters. The request is not validated
+    /// or built until `execute`, `query_url` or `build` is called, exactly as
+    /// if the parameters had been set with the `with_*` methods.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `parameters` ‧ The parameters of the request. See `Parameters` for
+    ///   more information.
+
+    #[must_use]
+    pub fn from_parameters(client: &GoogleMapsClient, parameters: Parameters) -> Self {
+        Self {
+            address: parameters.address,
+            place_id: parameters.place_id,
+            bounds: parameters.bounds,
+            components: parameters.components,
+            language: parameters.language,
+            region: parameters.region,
+            ..Self::new(client)
+        } // ForwardRequest
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Parameters;
+    use crate::geocoding::forward::ForwardRequest;
+    use crate::types::{Language, Region};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn struct_update_syntax_matches_the_with_methods() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+
+        let mut from_parameters = ForwardRequest::from_parameters(
+            &client,
+            Parameters {
+                address: Some("10 Downing Street London".to_string()),
+                language: Some(Language::English),
+                region: Some(Region::UnitedKingdom),
+                ..Default::default()
+            },
+        ); // from_parameters
+
+        let mut with_methods = ForwardRequest::new(&client);
+        with_methods
+            .with_address("10 Downing Street London")
+            .with_language(Language::English)
+            .with_region(Region::UnitedKingdom);
+
+        assert_eq!(
+            from_parameters.query_url().unwrap(),
+            with_methods.query_url().unwrap(),
+        );
+    } // fn
+} // mod
@@ -1,6 +1,6 @@
 use crate::geocoding::{error::Error, forward::ForwardRequest};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Ensures the built query is valid. This function checks the combination
     /// of parameters to ensure that they make sense together and that Google
     /// Maps Geocoding API will accept them - i.e. require an address or
@@ -12,14 +12,92 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
         // If a positional request has been set...
         if self.address.is_none() && self.place_id.is_none() && self.components.is_empty() {
             return Err(Error::AddressOrComponentsRequired);
         } // if
+
+        // A place ID lookup is not a search, so it may not be combined with
+        // search parameters, and it can not be biased:
+        if self.place_id.is_some() {
+            if self.address.is_some() || !self.components.is_empty() {
+                return Err(Error::PlaceIdWithAddressOrComponents);
+            } // if
+            if self.bounds.is_some() {
+                return Err(Error::BiasWithPlaceId("bounds".to_string()));
+            } // if
+            if self.region.is_some() {
+                return Err(Error::BiasWithPlaceId("region".to_string()));
+            } // if
+        } // if
+
           // Indicate that the request passed validation.
         self.validated = true;
         // Return modified Request struct to caller.
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::geocoding::{error::Error, forward::component::Component};
+    use crate::types::{Bounds, LatLng, Region};
+    use crate::GoogleMapsClient;
+
+    const PLACE_ID: &str = "ChIJeRpOeF67j4AR9ydy_PIzPuM";
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap()
+    } // fn
+
+    #[test]
+    fn rejects_place_id_with_address() {
+        let client = client();
+        let mut request = client.geocoding();
+        request.with_place_id(PLACE_ID).with_address("Mountain View");
+        assert!(matches!(request.validate(), Err(Error::PlaceIdWithAddressOrComponents)));
+    } // fn
+
+    #[test]
+    fn rejects_place_id_with_components() {
+        let client = client();
+        let mut request = client.geocoding();
+        request
+            .with_place_id(PLACE_ID)
+            .with_component(Component::PostalCode("94043".to_string()));
+        assert!(matches!(request.validate(), Err(Error::PlaceIdWithAddressOrComponents)));
+    } // fn
+
+    #[test]
+    fn rejects_place_id_with_bounds() {
+        let client = client();
+        let mut request = client.geocoding();
+        request.with_place_id(PLACE_ID).with_bounds(Bounds {
+            southwest: LatLng::try_from_f64(37.0, -123.0).unwrap(),
+            northeast: LatLng::try_from_f64(38.0, -122.0).unwrap(),
+        });
+        assert!(matches!(request.validate(), Err(Error::BiasWithPlaceId(parameter)) if parameter == "bounds"));
+    } // fn
+
+    #[test]
+    fn rejects_place_id_with_region() {
+        let client = client();
+        let mut request = client.geocoding();
+        request.with_place_id(PLACE_ID).with_region(Region::Canada);
+        assert!(matches!(request.validate(), Err(Error::BiasWithPlaceId(parameter)) if parameter == "region"));
+    } // fn
+
+    #[test]
+    fn skips_the_default_region_for_place_id() {
+        let client = client().with_default_region(Region::Canada).build();
+
+        let url = client.geocoding().with_place_id(PLACE_ID).query_url().unwrap();
+        assert!(!url.contains("region="));
+
+        let url = client.geocoding().with_address("Mountain View").query_url().unwrap();
+        assert!(url.contains("&region=ca"));
+    } // fn
+} // mod
@@ -1,6 +1,6 @@
 use crate::geocoding::forward::ForwardRequest;
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Specifies the street address to geocode.
     ///
     /// ## Arguments
@@ -21,9 +21,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_address(
-        &'a mut self,
+        &mut self,
         address: impl Into<String>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set address in ForwardRequest struct.
         self.address = Some(address.into());
         // Return modified ForwardRequest struct to caller.
@@ -3,7 +3,7 @@ use crate::types::Bounds;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Specifies a bounding box for biasing results.
     ///
     /// ## Arguments
@@ -31,6 +31,10 @@ impl<'a> ForwardRequest<'a> {
     /// the San Fernando Valley of Los Angeles results in this geocode returning
     /// the neighborhood named "Winnetka" in that location.
     ///
+    /// The Geocoding API has no point-and-radius `locationbias` or IP address
+    /// biasing. To bias towards a point, pass a small bounding box around it.
+    /// Bounds may not be used with `with_place_id`.
+    ///
     /// ## Example
     ///
     /// * Specify bounding box for search area:
@@ -42,9 +46,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_bounds(
-        &'a mut self,
+        &mut self,
         bounds: impl Into<Bounds>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set bounds in ForwardRequest struct.
         self.bounds = Some(bounds.into());
         // Return modified ForwardRequest struct to caller.
@@ -1,6 +1,6 @@
 use crate::geocoding::forward::{component::Component, ForwardRequest};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Restricts the results from the geocoder to the specified component
     /// type(s).
     ///
@@ -64,9 +64,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_component(
-        &'a mut self,
+        &mut self,
         component: impl Into<Component>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Add component to ForwardRequest struct.
         self.components = vec![component.into()];
         // Return modified ForwardRequest struct to caller.
@@ -97,9 +97,9 @@ impl<'a> ForwardRequest<'a> {
     /// type.
 
     pub fn with_components<C, O>(
-        &'a mut self,
+        &mut self,
         components: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = O>,
         O: Into<Component> {
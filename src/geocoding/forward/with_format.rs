@@ -0,0 +1,33 @@
+use crate::{geocoding::forward::ForwardRequest, types::ResponseFormat};
+
+impl ForwardRequest {
+    /// Specifies the format that Google responds in.
+    ///
+    /// ## Arguments
+    ///
+    /// * `format` ‧ The output format of the response. Either format is
+    ///   parsed into the same `Response` struct.
+    ///
+    /// ## Description
+    ///
+    /// Google recommends JSON, which is the default. XML may be requested with
+    /// `ResponseFormat::Xml` for integrations that require it. XML responses
+    /// are only available with the `xml` feature.
+    ///
+    /// ## Example
+    ///
+    /// * Request an XML response:
+    /// ```rust
+    /// .with_format(ResponseFormat::Xml)
+    /// ```
+
+    pub fn with_format(
+        &mut self,
+        format: impl Into<ResponseFormat>
+    ) -> &mut Self {
+        // Set format in ForwardRequest struct.
+        self.format = format.into();
+        // Return modified ForwardRequest struct to caller.
+        self
+    } // fn
+} // impl
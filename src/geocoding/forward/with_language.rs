@@ -1,6 +1,6 @@
 use crate::{geocoding::forward::ForwardRequest, types::Language};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Specifies the language in which to return results.
     ///
     /// ## Arguments
@@ -45,9 +45,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set language in ForwardRequest struct.
         self.language = Some(language.into());
         // Return modified ForwardRequest struct to caller.
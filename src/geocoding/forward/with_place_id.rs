@@ -1,6 +1,6 @@
 use crate::geocoding::forward::ForwardRequest;
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Specifies the place id to geocode.
     ///
     /// ## Arguments
@@ -23,9 +23,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_place_id(
-        &'a mut self,
+        &mut self,
         place_id: impl Into<String>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set address in ForwardRequest struct.
         self.place_id = Some(place_id.into());
         // Return modified ForwardRequest struct to caller.
@@ -1,6 +1,6 @@
 use crate::{geocoding::forward::ForwardRequest, types::Region};
 
-impl<'a> ForwardRequest<'a> {
+impl ForwardRequest {
     /// Specifies the region bias.
     ///
     /// ## Arguments
@@ -36,9 +36,9 @@ impl<'a> ForwardRequest<'a> {
     /// ```
 
     pub fn with_region(
-        &'a mut self,
+        &mut self,
         region: impl Into<Region>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set region in ForwardRequest struct.
         self.region = Some(region.into());
         // Return modified ForwardRequest struct to caller.
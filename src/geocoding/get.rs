@@ -0,0 +1,239 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    error::Error as GeocodingError, ready_request::ReadyRequest,
+    response::status::Status as GeocodingStatus, response::Response as GeocodingResponse,
+    SERVICE_URL,
+};
+use crate::request_rate::api::Api;
+use crate::types::ResponseFormat;
+use backoff::Error::{Permanent, Transient};
+
+// -----------------------------------------------------------------------------
+
+impl ReadyRequest {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(
+            otel.kind = "client",
+            http.method = "GET",
+            http.url = tracing::field::Empty,
+            google.api = "geocoding",
+            google.status = tracing::field::Empty,
+        )
+    )]
+    pub async fn get(&self) -> Result<GeocodingResponse, GoogleMapsError> {
+        // Build the URL for the HTTP get request:
+        let url = format!("{SERVICE_URL}/{}?{}", self.format, self.query);
+
+        // Fail fast if the circuit breaker is not letting requests through:
+        self.client.check_circuit_breaker()?;
+
+        // Fail fast if a replaying cassette has no response for the request:
+        #[cfg(feature = "vcr")]
+        self.client.check_cassette(&url)?;
+
+        // Observe any rate limiting before executing request:
+        tracing::info!("making HTTP GET request to Google Maps Geocoding API");
+
+        self.client
+            .rate_limit
+            .limit_apis(vec![&Api::All, &Api::Geocoding])
+            .await;
+
+        tracing::debug!("{url}");
+        tracing::Span::current().record("http.url", crate::error::sanitize_url(&url).as_str());
+
+        // The language is also sent in the `Accept-Language` header:
+        let language = self.language.as_ref().or(self.client.default_language.as_ref()).map(<&str>::from);
+
+        // Collect the details of the request for the audit trail:
+        let audit = self.client.audit("geocoding", &url);
+
+        // Retries the get request until successful, an error ineligible for
+        // retries is returned, we have reached the maximum retries, or the
+        // circuit breaker has opened. Note: errors wrapped in `Transient()`
+        // will retried by the `backoff` crate while errors wrapped in
+        // `Permanent()` will exit the retry loop.
+        let response = self.client.retry(|| async {
+            // Query the Google Cloud Maps Platform using using an HTTP get
+            // request, and return result to caller:
+            let response = self.client.get_request_with_language(&url, language).await;
+            audit.attempt(&response);
+
+            // Check response from the HTTP client:
+            match response {
+                Ok(response) => {
+                    // HTTP client was successful getting a response from the
+                    // server. Check the HTTP status code:
+                    if response.status().is_success() {
+                        // Capture the status and content type before the
+                        // response is consumed, in case the body is not JSON:
+                        let status = response.status().to_string();
+                        let content_type = crate::error::content_type(&response);
+                        // If the HTTP GET request was successful, get the
+                        // response text:
+                        let bytes = response.text().await.map(String::into_bytes);
+                        audit.response_bytes(&bytes);
+                        match bytes {
+                            Ok(bytes) if self.format == ResponseFormat::Json
+                                && crate::error::is_non_json(content_type.as_deref(), &bytes) => {
+                                // Google responded with something other than JSON,
+                                // such as an HTML error page. This will not be
+                                // fixed by retrying:
+                                let error = GeocodingError::NonJsonResponse {
+                                    status,
+                                    snippet: crate::error::non_json_snippet(&bytes, self.client.capture_response_body),
+                                };
+                                tracing::error!("{}", error);
+                                Err(Permanent(error))
+                            } // Ok(bytes)
+                            Ok(mut bytes) => {
+                                // Parse the response in the format that was
+                                // requested:
+                                let parsed = match self.format {
+                                    ResponseFormat::Json => crate::json::parse_json_response::<GeocodingResponse>(&mut bytes, self.client.capture_response_body)
+                                        .map_err(|(error, body)| GeocodingError::InvalidJsonResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            body,
+                                        )), // map_err
+                                    #[cfg(feature = "xml")]
+                                    ResponseFormat::Xml => crate::xml::parse_xml::<GeocodingResponse>(&bytes)
+                                        .map_err(|error| GeocodingError::InvalidXmlResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            self.client
+                                                .capture_response_body
+                                                .then(|| crate::error::body_snippet(&bytes)),
+                                        )), // map_err
+                                }; // match
+                                match parsed {
+                                    Ok(deserialized) => {
+                                        tracing::Span::current().record("google.status", deserialized.status.as_str());
+                                        audit.google_status(deserialized.status.as_str());
+                                        // If the response JSON was successfully
+                                        // parsed, check the Google API status
+                                        // before returning it to the caller:
+                                        if deserialized.status != GeocodingStatus::Ok {
+                                            // Google API returned an error. This
+                                            // indicates an issue with the request.
+                                            // In most cases, retrying will not
+                                            // help:
+                                            let error = GeocodingError::GoogleMapsService(
+                                                deserialized.status.clone(),
+                                                deserialized.error_message,
+                                            );
+                                            // Check Google API response status
+                                            // for error type:
+                                            if error.is_transient() {
+                                                // Only Google's "Unknown Error"
+                                                // is eligible for retries:
+                                                tracing::warn!("{}", error);
+                                                Err(Transient {
+                                                    err: error,
+                                                    retry_after: None,
+                                                })
+                                            } else {
+                                                // Not an "Unknown Error." The
+                                                // error is permanent, do not
+                                                // retry:
+                                                tracing::error!("{}", error);
+                                                Err(Permanent(error))
+                                            } // if
+                                        // Google's response was "Ok" but it is missing
+                                        // data that an "Ok" response always has. This
+                                        // will not be fixed by retrying:
+                                        } else if let Some(field) = deserialized.missing_field() {
+                                            let error = GeocodingError::MalformedResponse(
+                                                crate::error::sanitize_url(&url),
+                                                field.to_string(),
+                                            );
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
+                                        } else {
+                                            // If Google's response was "Ok" return the
+                                            // struct deserialized from JSON:
+                                            Ok(deserialized)
+                                        } // if
+                                    } // Ok(deserialized)
+                                    Err(error) => {
+                                        tracing::error!("{}", error);
+                                        Err(Permanent(error))
+                                    } // Err
+                                } // match
+                            } // Ok(text)
+                            Err(error) => {
+                                tracing::error!("HTTP client returned: {}", error);
+                                Err(Permanent(GeocodingError::ReqwestMessage(error.to_string())))
+                            } // Err
+                        } // match
+                          // We got a response from the server but it was not OK.
+                          // Only HTTP "500 Server Errors", and HTTP "429 Too Many
+                          // Requests" are eligible for retries.
+                    } else {
+                        let status = response.status();
+                        // Google may respond with an HTML error page, for example
+                        // when the API key is entirely wrong:
+                        let error = crate::error::non_json_body(response, self.client.capture_response_body)
+                            .await
+                            .map_or_else(
+                                || GeocodingError::HttpUnsuccessful(status.to_string()),
+                                |snippet| GeocodingError::NonJsonResponse { status: status.to_string(), snippet },
+                            ); // map_or_else
+                        if error.is_transient() {
+                            tracing::warn!("HTTP client returned: {}", status);
+                            Err(Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        // Not a 500 Server Error or "429 Too Many Requests"
+                        // error. The error is permanent, do not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}", status);
+                            Err(Permanent(error))
+                        } // if
+                    } // if
+                } // case
+                // HTTP client did not get a response from the server. Retry:
+                Err(error) => {
+                    tracing::warn!("HTTP client returned: {}", error);
+                    Err(Transient {
+                        err: GeocodingError::Reqwest(error),
+                        retry_after: None,
+                    })
+                } // case
+            } // match
+        })
+        .await;
+
+        // Record the request in the audit trail, now that retries are over:
+        audit.finish();
+        let response = response?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<GeocodingResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
+} // impl
@@ -82,24 +82,34 @@
 
 pub mod error;
 pub mod forward;
+#[cfg(feature = "reqwest")]
+mod get;
+pub mod ready_request;
 pub mod response;
 pub mod reverse;
 
 // -----------------------------------------------------------------------------
 
 const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/geocode";
-const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 // -----------------------------------------------------------------------------
 
 pub use crate::geocoding::{
     error::Error as GeocodingError,
-    forward::{component::Component as GeocodingComponent, ForwardRequest as GeocodingRequest}, // forward
+    forward::{
+        builder::Builder as GeocodingBuilder, component::Component as GeocodingComponent,
+        parameters::Parameters as GeocodingParameters, ForwardRequest as GeocodingRequest,
+    }, // forward
+    ready_request::ReadyRequest as GeocodingReadyRequest,
     response::{
         geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
         Response as GeocodingResponse,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{
+        builder::Builder as GeocodingReverseBuilder,
+        parameters::Parameters as GeocodingReverseParameters,
+        ReverseRequest as GeocodingReverseRequest,
+    }, // reverse
 }; // geocoding
 
 pub use crate::types::{AddressComponent, Geometry, LocationType};
@@ -1,5 +1,5 @@
 use crate::geocoding::response::plus_code::PlusCode;
-use crate::types::{AddressComponent, Geometry, PlaceType};
+use crate::types::{AddressComponent, Bounds, Geometry, MaybeKnown, PlaceType};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Geocoding {
     /// Array containing the separate components applicable to this address.
-    #[serde(default)]
+    #[serde(default, alias = "address_component")] // `<address_component>` elements in XML
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub address_components: Vec<AddressComponent>,
 
@@ -74,9 +74,9 @@ pub struct Geocoding {
     /// result. For example, a geocode of "Chicago" returns "locality" which
     /// indicates that "Chicago" is a city, and also returns "political" which
     /// indicates it is a political entity.
-    #[serde(default)]
+    #[serde(default, alias = "type")] // `<type>` elements in XML
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub types: Vec<PlaceType>,
+    pub types: Vec<MaybeKnown<PlaceType>>,
 } // struct
 
 impl Geocoding {
@@ -94,6 +94,28 @@ impl Geocoding {
             .map(|plus_code| plus_code.global_code.to_string())
     } // fn
 
+    /// Returns the recommended viewport for displaying the result. This is a
+    /// convenient box for framing the result on a map, or for passing to the
+    /// `Bounds` helpers.
+    ///
+    /// Google always sends a viewport, but it is not validated. If the
+    /// viewport does not contain the result's `location`, a degenerate,
+    /// zero-area `Bounds` at the location is returned instead.
+    /// ```rust
+    /// let (center, zoom) = geocoding.viewport_or_point_bounds().best_fit_zoom(640, 480, 0);
+    /// ```
+    #[must_use]
+    pub fn viewport_or_point_bounds(&self) -> Bounds {
+        if self.geometry.viewport.contains(&self.geometry.location) {
+            self.geometry.viewport
+        } else {
+            Bounds {
+                southwest: self.geometry.location,
+                northeast: self.geometry.location,
+            } // Bounds
+        } // if
+    } // fn
+
     /// A helper function for destructuring the optional `compound_code`
     /// field. If the _`compound_code`_ field is populated, this function will
     /// return the compound plus code. If the _`compound_code`_ field is empty,
@@ -9,6 +9,8 @@ pub mod status;
 // -----------------------------------------------------------------------------
 
 use crate::geocoding::response::{geocoding::Geocoding, status::Status};
+use crate::types::{LocationType, PlaceType};
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -31,7 +33,7 @@ pub struct Response {
     /// When the geocoder returns results, it places them within a results
     /// array. Even if the geocoder returns no results (such as if the address
     /// doesn't exist) it still returns an empty results array.
-    #[serde(default)]
+    #[serde(default, alias = "result")] // `<result>` elements in XML
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub results: Vec<Geocoding>,
 
@@ -43,12 +45,98 @@ pub struct Response {
 
 // -----------------------------------------------------------------------------
 
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.results.is_empty().then_some("results")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// The address-like result types, from most to least preferred, that
+    /// `best_address` uses to rank results. These are also the result types
+    /// that `GoogleMapsClient::reverse_geocode_to_address` filters its request
+    /// by.
+    pub const ADDRESS_TYPES: [PlaceType; 4] = [
+        PlaceType::StreetAddress,
+        PlaceType::Premise,
+        PlaceType::Subpremise,
+        PlaceType::Route,
+    ];
+
+    /// Returns the result that best describes a street address, or `None` if
+    /// there are no results.
+    ///
+    /// Results are ranked by:
+    ///
+    /// 1. Their `location_type`. A `ROOFTOP` result, which is accurate down to
+    ///    the street address, is preferred over all other location types.
+    ///
+    /// 2. Their most preferred result type, in the order of `ADDRESS_TYPES`:
+    ///    `street_address`, then `premise`, then `subpremise`, then `route`.
+    ///    Results with none of these types are ranked last.
+    ///
+    /// If more than one result has the same rank, the first of them is
+    /// returned, because Google orders its results from best to worst match.
+    #[must_use]
+    pub fn best_address(&self) -> Option<&Geocoding> {
+        self.results.iter().min_by_key(|result| {
+            let is_rooftop = result
+                .geometry
+                .location_type
+                .as_ref()
+                .is_some_and(|location_type| *location_type == LocationType::RoofTop);
+
+            let type_rank = Self::ADDRESS_TYPES
+                .iter()
+                .position(|address_type| result.types.iter().any(|t| t == address_type))
+                .unwrap_or(Self::ADDRESS_TYPES.len());
+
+            (!is_rooftop, type_rank)
+        }) // min_by_key
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Geocoding API JSON `String` into a `Response`
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn forward_geocoding_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("geocoding", 0));
+    } // fn
+
+    #[test]
+    fn reverse_geocoding_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("geocoding", 1));
+    } // fn
+} // mod
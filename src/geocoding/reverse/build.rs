@@ -1,29 +1,36 @@
 use crate::geocoding::reverse::ReverseRequest;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     /// Builds the query string for the Google Maps Geocoding API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut ReverseRequest {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
             "key={}&latlng={}",
             self.client.key,
-            String::from(&self.latlng),
+            String::from(&*self.client.coordinates(&self.latlng)),
         ); // format!
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         } // if
@@ -31,35 +38,23 @@ impl<'a> ReverseRequest<'a> {
         // Location type(s) key/value pair:
         if !self.location_types.is_empty() {
             query.push_str("&location_type=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .location_types
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut location_types = self.location_types.iter().map(String::from).collect::<Vec<String>>();
+            location_types.sort();
+            location_types.dedup();
+            query.push_str(&utf8_percent_encode(&location_types.join("|"), NON_ALPHANUMERIC).to_string());
         } // if
 
         // Result type(s) key/value pair:
         if !self.result_types.is_empty() {
             query.push_str("&result_type=");
-            query.push_str(
-                &utf8_percent_encode(
-                    &self
-                        .result_types
-                        .iter()
-                        .map(String::from)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                    NON_ALPHANUMERIC,
-                )
-                .to_string(),
-            ); // push_str
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut result_types = self.result_types.iter().map(String::from).collect::<Vec<String>>();
+            result_types.sort();
+            result_types.dedup();
+            query.push_str(&utf8_percent_encode(&result_types.join("|"), NON_ALPHANUMERIC).to_string());
         } // if
 
         // Set query string in ReverseRequest struct.
@@ -0,0 +1,172 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::{ready_request::ReadyRequest, reverse::ReverseRequest};
+use crate::types::{Language, LatLng, LocationType, PlaceType, ResponseFormat};
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for reverse _Geocoding API_ requests. Each method takes
+/// the builder by value and returns it, so that a request may be built in a
+/// single expression, or returned from a function. The request can only be
+/// sent once `build` has returned a `ReadyRequest`:
+///
+/// ```rust
+/// let location = GeocodingReverseRequest::builder(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(38.89_77), dec!(-77.03_65))?,
+/// )
+/// .with_result_type(PlaceType::StreetAddress)
+/// .build()
+/// .get()
+/// .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let location = GeocodingReverseRequest::builder(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(38.89_77), dec!(-77.03_65))?,
+/// )
+/// .with_result_type(PlaceType::StreetAddress)
+/// .get()
+/// .await?;
+/// ```
+///
+/// The builder produces exactly the same query string as the mutable
+/// `ReverseRequest` builder with the same parameters.
+
+#[derive(Debug)]
+pub struct Builder {
+    request: ReverseRequest,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ReverseRequest {
+    /// Initializes a typestate builder. See `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `latlng` ‧ The latitude and longitude values specifying the location
+    ///   for which you wish to obtain the closest, human-readable address.
+
+    #[must_use]
+    pub fn builder(client: &GoogleMapsClient, latlng: LatLng) -> Builder {
+        Builder {
+            request: Self::new(client, latlng),
+        } // Builder
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Builds the query string. The returned `ReadyRequest` may then be sent
+    /// to the Google Maps Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn build(mut self) -> ReadyRequest {
+        self.request.build();
+        ReadyRequest {
+            client: self.request.client,
+            query: self.request.query.unwrap_or_default(),
+            language: self.request.language,
+            format: self.request.format,
+        } // ReadyRequest
+    } // fn
+
+    /// Specifies the format that Google responds in. See
+    /// `ReverseRequest::with_format`.
+
+    #[must_use]
+    pub fn with_format(mut self, format: impl Into<ResponseFormat>) -> Self {
+        self.request.with_format(format);
+        self
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `ReverseRequest::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.with_language(language);
+        self
+    } // fn
+
+    /// Specifies a location type to filter the results by. See
+    /// `ReverseRequest::with_location_type`.
+
+    #[must_use]
+    pub fn with_location_type(mut self, location_type: impl Into<LocationType>) -> Self {
+        self.request.with_location_type(location_type);
+        self
+    } // fn
+
+    /// Specifies location types to filter the results by. See
+    /// `ReverseRequest::with_location_types`.
+
+    #[must_use]
+    pub fn with_location_types<C, L>(mut self, location_types: C) -> Self
+    where
+        C: IntoIterator<Item = L>,
+        L: Into<LocationType> {
+        self.request.with_location_types(location_types);
+        self
+    } // fn
+
+    /// Specifies an address type to filter the results by. See
+    /// `ReverseRequest::with_result_type`.
+
+    #[must_use]
+    pub fn with_result_type(mut self, result_type: impl Into<PlaceType>) -> Self {
+        self.request.with_result_type(result_type);
+        self
+    } // fn
+
+    /// Specifies address types to filter the results by. See
+    /// `ReverseRequest::with_result_types`.
+
+    #[must_use]
+    pub fn with_result_types<C, P>(mut self, result_types: C) -> Self
+    where
+        C: IntoIterator<Item = P>,
+        P: Into<PlaceType> {
+        self.request.with_result_types(result_types);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::geocoding::reverse::ReverseRequest;
+    use crate::types::{Language, LatLng, PlaceType};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let latlng = LatLng::try_from_f64(40.714_224, -73.961_452).unwrap();
+
+        let consuming = ReverseRequest::builder(&client, latlng)
+            .with_result_type(PlaceType::StreetAddress)
+            .with_language(Language::English)
+            .build();
+
+        let mut mutable = ReverseRequest::new(&client, latlng);
+        mutable
+            .with_result_type(PlaceType::StreetAddress)
+            .with_language(Language::English);
+
+        assert_eq!(consuming.query_url(), mutable.query_url());
+    } // fn
+} // mod
@@ -5,7 +5,7 @@ use crate::geocoding::{
 
 // =============================================================================
 
-impl<'a> GeocodingReverseRequest<'a> {
+impl GeocodingReverseRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> GeocodingReverseRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<GeocodingResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<GeocodingResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
@@ -5,12 +5,15 @@
 //! geocoding converts latitude & longitude coordinates to a street address.
 
 mod build;
+pub mod builder;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
+pub mod parameters;
 mod query_url;
+mod with_format;
 mod with_language;
 mod with_location_types;
 mod with_result_types;
@@ -18,7 +21,7 @@ mod with_result_types;
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
-use crate::types::{Language, LatLng, LocationType, PlaceType};
+use crate::types::{Language, LatLng, LocationType, PlaceType, ResponseFormat};
 
 // -----------------------------------------------------------------------------
 //
@@ -27,13 +30,13 @@ use crate::types::{Language, LatLng, LocationType, PlaceType};
 /// used to build your request. Reverse geocoding looks up a street address
 /// from latitude & longitude coorindates.
 
-#[derive(Debug)]
-pub struct ReverseRequest<'a> {
+#[derive(Clone)]
+pub struct ReverseRequest {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The latitude and longitude values specifying the location for which you
     /// wish to obtain the closest, human-readable address.
@@ -62,8 +65,30 @@ pub struct ReverseRequest<'a> {
     /// the specified address type(s).
     result_types: Vec<PlaceType>,
 
+    /// The format that Google responds in. The response is parsed into the
+    /// same `Response` struct either way. Defaults to JSON.
+    format: ResponseFormat,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for ReverseRequest {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReverseRequest")
+            .field("client", &self.client)
+            .field("latlng", &self.latlng)
+            .field("language", &self.language)
+            .field("location_types", &self.location_types)
+            .field("result_types", &self.result_types)
+            .field("format", &self.format)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
 } // impl
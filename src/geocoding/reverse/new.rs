@@ -1,10 +1,10 @@
 use crate::client::GoogleMapsClient;
 use crate::geocoding::reverse::ReverseRequest;
-use crate::types::LatLng;
+use crate::types::{LatLng, ResponseFormat};
 
 // =============================================================================
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Geolocation API query with the
@@ -18,16 +18,17 @@ impl<'a> ReverseRequest<'a> {
     ///   for which you wish to obtain the closest, human-readable address.
 
     #[must_use]
-    pub const fn new(client: &'a GoogleMapsClient, latlng: LatLng) -> Self {
+    pub fn new(client: &GoogleMapsClient, latlng: LatLng) -> Self {
         // Instantiate struct and return it to caller:
-        ReverseRequest {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             latlng,
             // Optional parameters:
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            format: ResponseFormat::default(),
             // Internal use only:
             query: None,
         } // struct
@@ -50,18 +51,19 @@ impl<'a> ReverseRequest<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_coordinate(
-        client: &'a GoogleMapsClient,
+        client: &GoogleMapsClient,
         coordinate: &geo_types::Coord
     ) -> Result<Self, crate::error::Error> {
         // Instantiate struct and return it to caller:
-        Ok(ReverseRequest {
+        Ok(Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             latlng: LatLng::try_from(coordinate)?,
             // Optional parameters:
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            format: ResponseFormat::default(),
             // Internal use only:
             query: None,
         }) // struct
@@ -84,18 +86,19 @@ impl<'a> ReverseRequest<'a> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_point(
-        client: &'a GoogleMapsClient,
+        client: &GoogleMapsClient,
         point: &geo_types::Point
     ) -> Result<Self, crate::error::Error> {
         // Instantiate struct and return it to caller:
-        Ok(ReverseRequest {
+        Ok(Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             latlng: LatLng::try_from(point)?,
             // Optional parameters:
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            format: ResponseFormat::default(),
             // Internal use only:
             query: None,
         }) // struct
@@ -0,0 +1,113 @@
+//! Contains the `Parameters` struct, which holds the optional parameters of a
+//! reverse _Geocoding API_ request without the client that will send it.
+
+use crate::client::GoogleMapsClient;
+use crate::geocoding::reverse::ReverseRequest;
+use crate::types::{Language, LatLng, LocationType, PlaceType};
+
+// -----------------------------------------------------------------------------
+//
+/// The optional parameters of a reverse _Geocoding API_ request. Simple
+/// requests may be written with struct-update syntax and turned into a request
+/// with `ReverseRequest::from_parameters`:
+///
+/// ```rust
+/// let address = ReverseRequest::from_parameters(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?,
+///     GeocodingReverseParameters {
+///         language: Some(Language::English),
+///         ..Default::default()
+///     },
+/// )
+/// .execute()
+/// .await?;
+/// ```
+///
+/// The `latlng` coordinates are required and have no sensible default, so
+/// they are not part of this struct. They are passed to `from_parameters`
+/// instead, like they are passed to `ReverseRequest::new`.
+///
+/// The fields have the same meaning as the `ReverseRequest` methods of the
+/// same names. See `ReverseRequest::with_language` and so on for more
+/// information.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Parameters {
+    /// The language in which to return results.
+    pub language: Option<Language>,
+
+    /// A filter of one or more location types. The API returns all addresses
+    /// that match any of the types.
+    pub location_types: Vec<LocationType>,
+
+    /// A filter of one or more address types. The API returns all addresses
+    /// that match any of the types.
+    pub result_types: Vec<PlaceType>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ReverseRequest {
+    /// Initializes a request from its coordinates and optional parameters. The
+    /// request is not built until `execute`, `query_url` or `build` is called,
+    /// exactly as if the parameters had been set with the `with_*` methods.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `latlng` ‧ The latitude and longitude values specifying the location
+    ///   for which you wish to obtain the closest, human-readable address.
+    ///
+    /// * `parameters` ‧ The optional parameters of the request. See
+    ///   `Parameters` for more information.
+
+    #[must_use]
+    pub fn from_parameters(
+        client: &GoogleMapsClient,
+        latlng: LatLng,
+        parameters: Parameters
+    ) -> Self {
+        Self {
+            language: parameters.language,
+            location_types: parameters.location_types,
+            result_types: parameters.result_types,
+            ..Self::new(client, latlng)
+        } // ReverseRequest
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Parameters;
+    use crate::geocoding::reverse::ReverseRequest;
+    use crate::types::{Language, LatLng, LocationType};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn struct_update_syntax_matches_the_with_methods() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let latlng = LatLng::try_from_f64(51.503_364, -0.127_625).unwrap();
+
+        let mut from_parameters = ReverseRequest::from_parameters(
+            &client,
+            latlng,
+            Parameters {
+                language: Some(Language::English),
+                location_types: vec![LocationType::RoofTop],
+                ..Default::default()
+            },
+        ); // from_parameters
+
+        let mut with_methods = ReverseRequest::new(&client, latlng);
+        with_methods
+            .with_language(Language::English)
+            .with_location_type(LocationType::RoofTop);
+
+        assert_eq!(from_parameters.query_url(), with_methods.query_url());
+    } // fn
+} // mod
@@ -1,9 +1,9 @@
-use crate::geocoding::{reverse::ReverseRequest, OUTPUT_FORMAT, SERVICE_URL};
+use crate::geocoding::{reverse::ReverseRequest, SERVICE_URL};
 use std::borrow::Cow;
 
 // =============================================================================
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     /// Returns the URL query string that represents the query you've built.
     ///
     /// ## Description
@@ -17,7 +17,7 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn query_url(&'a mut self) -> String {
+    pub fn query_url(&mut self) -> String {
         let query_string = match &self.query {
             // If query string has already been built, return it:
             Some(query_string) => Cow::from(query_string),
@@ -25,6 +25,20 @@ impl<'a> ReverseRequest<'a> {
             None => Cow::from(self.build().query.clone().unwrap_or_default()),
         }; // match
 
-        format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}")
+        format!("{SERVICE_URL}/{}?{query_string}", self.format)
+    } // fn
+
+    /// Returns a `curl` command that sends the request you've built. This
+    /// could be useful for reproducing a problem from a terminal. The API key
+    /// is replaced with a reference to the `GOOGLE_MAPS_API_KEY` environment
+    /// variable, so the command may be safely shared, for example in a bug
+    /// report.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn to_curl(&mut self) -> String {
+        crate::curl::to_curl("GET", &self.query_url(), &[], None)
     } // fn
 } // impl
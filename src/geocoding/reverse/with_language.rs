@@ -1,6 +1,6 @@
 use crate::{geocoding::reverse::ReverseRequest, types::Language};
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     /// Specifies the language in which to return results.
     ///
     /// # Arguments:
@@ -45,9 +45,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set language in ReverseRequest struct.
         self.language = Some(language.into());
         // Return modified ReverseRequest struct to caller.
@@ -3,7 +3,7 @@ use crate::types::location_type::LocationType;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     /// Restricts the results from the geocoder to the specified location
     /// type(s).
     ///
@@ -60,9 +60,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_location_type(
-        &'a mut self,
+        &mut self,
         location_type: impl Into<LocationType>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         self.location_types = vec![location_type.into()];
         // Return modified ReverseRequest struct to caller.
         self
@@ -103,9 +103,9 @@ impl<'a> ReverseRequest<'a> {
     /// type.
 
     pub fn with_location_types<C, L>(
-        &'a mut self,
+        &mut self,
         location_types: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = L>,
         L: Into<LocationType> {
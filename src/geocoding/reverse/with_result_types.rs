@@ -1,6 +1,6 @@
 use crate::{geocoding::reverse::ReverseRequest, types::PlaceType};
 
-impl<'a> ReverseRequest<'a> {
+impl ReverseRequest {
     /// Restricts the results from the geocoder to the specified result type(s).
     ///
     /// # Arguments:
@@ -114,9 +114,9 @@ impl<'a> ReverseRequest<'a> {
     /// ```
 
     pub fn with_result_type(
-        &'a mut self,
+        &mut self,
         result_type: impl Into<PlaceType>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Add result type to ReverseRequest struct.
         self.result_types = vec![result_type.into()];
         // Return modified ReverseRequest struct to caller.
@@ -157,9 +157,9 @@ impl<'a> ReverseRequest<'a> {
     /// type.
 
     pub fn with_result_types<C, P>(
-        &'a mut self,
+        &mut self,
         result_types: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = P>,
         P: Into<PlaceType> {
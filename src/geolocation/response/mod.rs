@@ -21,12 +21,12 @@ pub struct Response {
     pub error: Option<ErrorObject>,
 } // struct
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Geolocation API JSON `String` into a `Response`
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
\ No newline at end of file
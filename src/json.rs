@@ -0,0 +1,244 @@
+//! JSON parsing for Google Maps Platform responses. The parser backend is
+//! selected with cargo features:
+//!
+//! * `simd-json` (default) uses the [simd-json](https://crates.io/crates/simd-json)
+//!   crate.
+//!
+//! * `serde-json` uses the [serde_json](https://crates.io/crates/serde_json)
+//!   crate. This may be preferable on targets where `simd-json` is not a good
+//!   fit, such as `musl` or older CPUs.
+//!
+//! If both features are enabled, `simd-json` is used. The `reqwest` feature
+//! requires one of the backends, so that a client can parse its responses. If
+//! neither backend is enabled, the request and response types are still
+//! available but the `FromStr` implementations of the response types are left
+//! out.
+
+use miette::Diagnostic;
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "simd-json")]
+type Backend = simd_json::Error;
+
+#[cfg(all(feature = "serde-json", not(feature = "simd-json")))]
+type Backend = serde_json::Error;
+
+#[cfg(not(any(feature = "simd-json", feature = "serde-json")))]
+type Backend = std::convert::Infallible;
+
+#[cfg(all(feature = "reqwest", not(any(feature = "simd-json", feature = "serde-json"))))]
+compile_error!(
+    "the `reqwest` feature requires a JSON parser backend: enable either the \
+    `simd-json` or the `serde-json` feature"
+);
+
+// -----------------------------------------------------------------------------
+//
+/// An error produced while parsing a JSON response. This is the same type
+/// regardless of which JSON parser backend has been selected.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::json::error), url(docsrs))]
+pub struct Error(Backend);
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Parses a JSON response into the requested type using the selected JSON
+/// parser backend. The `simd-json` backend parses in-place, which is why a
+/// mutable buffer is required.
+
+#[cfg(feature = "simd-json")]
+pub(crate) fn parse_json<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T, Error> {
+    simd_json::serde::from_slice(bytes).map_err(Error)
+} // fn
+
+/// Parses a JSON response into the requested type using the selected JSON
+/// parser backend.
+
+#[cfg(all(feature = "serde-json", not(feature = "simd-json")))]
+#[allow(clippy::needless_pass_by_ref_mut)] // for parity with `simd-json`
+pub(crate) fn parse_json<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T, Error> {
+    serde_json::from_slice(bytes).map_err(Error)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Parses a JSON response like `parse_json`. If the response can not be parsed
+/// and `capture` is set, the beginning of the response body is returned with
+/// the error. The snippet is only allocated when parsing fails.
+///
+/// The `simd-json` backend may modify the buffer while parsing, so the
+/// beginning of the body is first copied to the stack.
+
+#[cfg(all(feature = "reqwest", feature = "simd-json"))]
+pub(crate) fn parse_json_response<T: DeserializeOwned>(
+    bytes: &mut [u8],
+    capture: bool,
+) -> Result<T, (Error, Option<String>)> {
+    let mut head = [0_u8; crate::error::BODY_SNIPPET_LENGTH];
+    let length = if capture { bytes.len().min(head.len()) } else { 0 };
+    head[..length].copy_from_slice(&bytes[..length]);
+    parse_json(bytes).map_err(|error| (error, capture.then(|| crate::error::body_snippet(&head[..length]))))
+} // fn
+
+/// Parses a JSON response like `parse_json`. If the response can not be parsed
+/// and `capture` is set, the beginning of the response body is returned with
+/// the error. The snippet is only allocated when parsing fails.
+
+#[cfg(all(feature = "reqwest", feature = "serde-json", not(feature = "simd-json")))]
+pub(crate) fn parse_json_response<T: DeserializeOwned>(
+    bytes: &mut [u8],
+    capture: bool,
+) -> Result<T, (Error, Option<String>)> {
+    parse_json(bytes).map_err(|error| (error, capture.then(|| crate::error::body_snippet(bytes))))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Parses a JSON `&str` into the requested type. Used by the `FromStr`
+/// implementations of the response types.
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
+pub(crate) fn parse_json_str<T: DeserializeOwned>(s: &str) -> Result<T, Error> {
+    let mut bytes = s.to_string().into_bytes();
+    parse_json(&mut bytes)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Serializes a value into a compact JSON string using the selected JSON
+/// backend. Used by the audit log.
+
+#[cfg(all(feature = "reqwest", feature = "simd-json"))]
+pub(crate) fn to_json_string<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    simd_json::serde::to_string(value).map_err(Error)
+} // fn
+
+/// Serializes a value into a compact JSON string using the selected JSON
+/// backend. Used by the audit log.
+
+#[cfg(all(feature = "reqwest", feature = "serde-json", not(feature = "simd-json")))]
+pub(crate) fn to_json_string<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(Error)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Serializes a value into an indented JSON string using the selected JSON
+/// backend. Used by the cassette recorder.
+
+#[cfg(all(feature = "vcr", feature = "simd-json"))]
+pub(crate) fn to_json_string_pretty<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    simd_json::serde::to_string_pretty(value).map_err(Error)
+} // fn
+
+/// Serializes a value into an indented JSON string using the selected JSON
+/// backend. Used by the cassette recorder.
+
+#[cfg(all(feature = "vcr", feature = "serde-json", not(feature = "simd-json")))]
+pub(crate) fn to_json_string_pretty<T: serde::Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    serde_json::to_string_pretty(value).map_err(Error)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+pub(crate) mod tests {
+    use super::parse_json_response;
+    use serde::de::{DeserializeOwned, IgnoredAny};
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    /// A JSON value that is compared semantically: every number is compared as
+    /// an `f64`, and object members that are `null` or an empty array are the
+    /// same as missing members.
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(untagged)]
+    enum Value {
+        Null,
+        Bool(bool),
+        Number(f64),
+        String(String),
+        Array(Vec<Self>),
+        Object(BTreeMap<String, Self>),
+    } // enum
+
+    impl Value {
+        fn normalize(self) -> Self {
+            match self {
+                Self::Array(values) => Self::Array(values.into_iter().map(Self::normalize).collect()),
+                Self::Object(members) => Self::Object(
+                    members
+                        .into_iter()
+                        .filter(|(_name, value)| !matches!(value, Self::Null) && value != &Self::Array(Vec::new()))
+                        .map(|(name, value)| (name, value.normalize()))
+                        .collect()
+                ),
+                value => value,
+            } // match
+        } // fn
+    } // impl
+
+    /// Returns the body of a response that is recorded in a cassette in the
+    /// `tests/cassettes` directory.
+
+    pub fn cassette_body(cassette: &str, index: usize) -> String {
+        #[derive(Deserialize)]
+        struct Interaction {
+            body: String,
+        } // struct
+
+        let path = format!("{}/tests/cassettes/{cassette}.json", env!("CARGO_MANIFEST_DIR"));
+        let mut interactions: Vec<Interaction> =
+            super::parse_json_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        interactions.swap_remove(index).body
+    } // fn
+
+    /// Returns a response fixture, written by hand to cover cases that the
+    /// recorded cassettes do not, from the `tests/fixtures` directory.
+
+    pub fn fixture(name: &str) -> String {
+        let path = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+        std::fs::read_to_string(path).unwrap()
+    } // fn
+
+    /// Deserializes a response, serializes it again, and asserts that the
+    /// result is semantically the same JSON as the response.
+
+    pub fn assert_round_trip<T: DeserializeOwned + Serialize>(json: &str) {
+        let response: T = super::parse_json_str(json).unwrap();
+        let serialized = super::to_json_string(&response).unwrap();
+        let original = super::parse_json_str::<Value>(json).unwrap().normalize();
+        let round_tripped = super::parse_json_str::<Value>(&serialized).unwrap().normalize();
+        assert_eq!(round_tripped, original, "{serialized}");
+    } // fn
+
+    #[test]
+    fn returns_the_untouched_body_when_parsing_fails() {
+        let body = r#"{"status": "OKé\n", "results": [}"#;
+        let mut bytes = body.as_bytes().to_vec();
+        let (_error, snippet) = parse_json_response::<IgnoredAny>(&mut bytes, true).unwrap_err();
+        assert_eq!(snippet.as_deref(), Some(body));
+    } // fn
+
+    #[test]
+    fn returns_no_body_when_capture_is_disabled() {
+        let mut bytes = br#"{"status": "#.to_vec();
+        let (_error, snippet) = parse_json_response::<IgnoredAny>(&mut bytes, false).unwrap_err();
+        assert_eq!(snippet, None);
+    } // fn
+} // mod
@@ -19,7 +19,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! google_maps = "3.7"
+//! google_maps = "4.0"
 //! ```
 //!
 //! Optionally, add `rust_decimal = "1"` and `rust_decimal_macros = "1"` for
@@ -50,8 +50,19 @@
 //! * `reqwest-middleware` ‧ uses [reqwest-middleware](https://crates.io/crates/reqwest-middleware)
 //!   for querying the Google Maps API
 //! * `geo` ‧ support for the rust [geo](https://crates.io/crates/geo) ecosystem
+//! * `geojson` ‧ allows `LatLng`, `Bounds`, and snap-to-roads and elevation
+//!   responses to be exported as
+//!   [GeoJSON](https://crates.io/crates/geojson) geometry
 //! * `polyline` ‧ allows easy type conversions from a `Route` or `Step` to a geo
 //!   [LineString](https://docs.rs/geo-types/0.7.13/geo_types/geometry/struct.LineString.html)
+//! * `vcr` ‧ records responses to cassette files and replays them, for
+//!   deterministic tests without an API key
+//! * `cancellation` ‧ adds `get_with_cancellation` methods that take a
+//!   [tokio-util](https://crates.io/crates/tokio-util) `CancellationToken`
+//! * `gpx` ‧ reads `LatLng` paths from the tracks of GPX files, for
+//!   snap-to-roads and elevation requests
+//! * `xml` ‧ allows Geocoding API responses to be requested in XML, which is
+//!   parsed with [quick-xml](https://crates.io/crates/quick-xml)
 //!
 //! Note: the `autocomplete` feature covers the Places API autocomplete-related
 //! services:
@@ -59,6 +70,14 @@
 //! and [Query Autocomplete requests](https://docs.rs/google_maps/latest/google_maps/prelude/struct.ClientSettings.html#method.query_autocomplete).
 //! All other Places API services are covered by the `places` feature.
 //!
+//! Each API can be compiled on its own, for example with `default-features = false`
+//! and `features = ["geocoding", "time_zone", "reqwest", "reqwest-default-tls", "serde-json"]`.
+//! The `reqwest` feature requires a JSON parser backend, either `simd-json` or
+//! `serde-json`. Without `reqwest` or a JSON parser backend,
+//! only the request and response types are available. The shared types (such as `LatLng` and `Bounds`), errors and the client are
+//! always available. `scripts/check-features.sh` checks that every API builds on
+//! its own.
+//!
 //! ### Reqwest Feature Flags
 //!
 //! For use with `reqwest` only.
@@ -95,6 +114,9 @@
 //!     # rust_decimal default features:
 //!     "decimal-serde",
 //!
+//!     # JSON parser backend, `simd-json` or `serde-json`:
+//!     "simd-json",
+//!
 //!     # simd-json default features:
 //!     "simd-json-beef",
 //!     "simd-json-known-key",
@@ -109,13 +131,14 @@
 //!
 //! ```toml
 //! google_maps = {
-//!     version = "3.7",
+//!     version = "4.0",
 //!     default-features = false,
 //!     features = [
 //!         "directions",
 //!         "reqwest",
 //!         "reqwest-rustls",
-//!         "reqwest-brotli"
+//!         "reqwest-brotli",
+//!         "simd-json"
 //!     ]
 //! }
 //! ```
@@ -410,9 +433,12 @@
 
 mod client;
 pub mod error;
+pub mod json;
 pub mod prelude;
 mod serde;
 pub mod types;
+#[cfg(feature = "xml")]
+pub mod xml;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
 // feature flags:
@@ -437,12 +463,14 @@ pub mod time_zone;
 
 pub use crate::{
     client::GoogleMapsClient as ClientSettings, client::GoogleMapsClient,
-    error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
+    error::Error as GoogleMapsError, error::Error, json::Error as JsonError,
+    types::error::Error as TypeError,
 }; // crate
 
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -457,6 +485,8 @@ pub use crate::types::bounds::Bounds;
 pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
+pub use crate::types::google_status::GoogleStatus;
+pub use crate::types::has_status::HasStatus;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -479,6 +509,7 @@ pub use crate::types::language::Language;
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+pub use crate::types::maybe_known::MaybeKnown;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -495,15 +526,65 @@ pub use crate::types::place_type::PlaceType;
     feature = "places"
 ))]
 pub use crate::types::region::Region;
+#[cfg(feature = "geocoding")]
+pub use crate::types::response_format::ResponseFormat;
+
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
+mod curl;
 
 // Optional dependencies:
 
+#[cfg(all(feature = "places", feature = "reqwest"))]
+mod page_stream;
+
+#[cfg(all(feature = "places", feature = "reqwest"))]
+pub use crate::page_stream::PageStream;
+
 #[cfg(feature = "reqwest")]
 mod request_rate;
 
+#[cfg(feature = "reqwest")]
+pub mod circuit_breaker;
+
+#[cfg(feature = "reqwest")]
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+
+#[cfg(feature = "reqwest")]
+pub mod retry_budget;
+
+#[cfg(feature = "reqwest")]
+pub use crate::retry_budget::RetryBudget;
+
+#[cfg(feature = "reqwest")]
+pub mod audit;
+
+#[cfg(feature = "reqwest")]
+pub use crate::audit::{AuditEntry, AuditSink, ChannelAuditSink, FileAuditSink};
+
+#[cfg(feature = "cancellation")]
+mod cancellation;
+
+#[cfg(feature = "vcr")]
+pub mod cassette;
+
+#[cfg(feature = "vcr")]
+pub use crate::cassette::{Cassette, CassetteMode};
+
 #[cfg(feature = "reqwest")]
 pub use crate::request_rate::api::Api;
 
+#[cfg(feature = "reqwest")]
+pub use crate::request_rate::throttle_hook::ThrottleHook;
+
 #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
 type ReqError = reqwest_maybe_middleware::Error;
 
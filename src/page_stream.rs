@@ -0,0 +1,136 @@
+//! Contains the `PageStream` struct, which lazily fetches a paginated result
+//! set from the Google Maps Platform and yields its items one at a time.
+
+use crate::error::Error as GoogleMapsError;
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+// -----------------------------------------------------------------------------
+//
+/// A `futures::Stream` of the items in a paginated result set, such as the
+/// places returned by a _Places API_ _Text Search_.
+///
+/// Pages are fetched lazily: the first page is requested when the stream is
+/// first polled, and the next page is only requested once every item of the
+/// current page has been consumed. Nothing is prefetched, so dropping the
+/// stream early means no further requests are made. Each page is requested
+/// through the client, so the client's rate limits are observed between
+/// pages.
+///
+/// If a page can not be fetched, the error is yielded as an item and the
+/// stream ends.
+///
+/// The stream owns its request and a clone of the client, so it does not
+/// borrow from them and may be moved into a spawned task.
+
+pub struct PageStream<T> {
+    stream: BoxStream<'static, Result<T, GoogleMapsError>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl<T: Send + 'static> PageStream<T> {
+    /// This method is not for public consumption. It is for internal use only.
+    ///
+    /// ## Arguments
+    ///
+    /// * `first` ‧ The state that `fetch` will be called with to request the
+    ///   first page. For example, a request without a page token.
+    ///
+    /// * `fetch` ‧ Requests a page. Returns the page's items, and the state
+    ///   that it should be called with to request the next page, if there is
+    ///   a next page.
+
+    pub(crate) fn new<S, F, Fut>(first: S, mut fetch: F) -> Self
+    where
+        S: Send + 'static,
+        F: FnMut(S) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(Vec<T>, Option<S>), GoogleMapsError>> + Send + 'static {
+        let pages = stream::unfold(Some(first), move |state| {
+            // The request for the page is only created here, when the
+            // previous page has been consumed:
+            let page = state.map(&mut fetch);
+            async move {
+                match page?.await {
+                    Ok((items, next)) => Some((Ok(items), next)),
+                    // Yield the error and end the stream:
+                    Err(error) => Some((Err(error), None)),
+                } // match
+            } // async
+        }); // unfold
+
+        let stream = pages
+            .flat_map(|page| {
+                let items: Vec<Result<T, GoogleMapsError>> = match page {
+                    Ok(items) => items.into_iter().map(Ok).collect(),
+                    Err(error) => vec![Err(error)],
+                }; // match
+                stream::iter(items)
+            }) // flat_map
+            .boxed();
+
+        Self { stream }
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T> Stream for PageStream<T> {
+    type Item = Result<T, GoogleMapsError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    } // fn
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T> std::fmt::Debug for PageStream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PageStream").finish_non_exhaustive()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::PageStream;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn only_fetches_the_pages_that_are_consumed() {
+        // An endless result set of two items per page, which counts how many
+        // pages were requested:
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fetches);
+        let mut stream = PageStream::new(0_usize, move |page| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok((vec![page * 2, page * 2 + 1], Some(page + 1))) }
+        }); // new
+
+        // Nothing is requested until the stream is polled:
+        assert_eq!(fetches.load(Ordering::SeqCst), 0);
+
+        // The second page is only requested once the first one is consumed:
+        assert_eq!(stream.next().await.unwrap().unwrap(), 0);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 3);
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        // Dropping the stream means the third page is never requested:
+        drop(stream);
+        tokio::task::yield_now().await;
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    } // fn
+} // mod
@@ -0,0 +1,278 @@
+//! Contains the `AdrAddress` struct, which is a place's address parsed from
+//! the [adr microformat](http://microformats.org/wiki/adr) snippet in its
+//! `adr_address` field.
+
+use crate::places::Place;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// A place's address, segmented into its parts. This is parsed from the
+/// place's `adr_address` field with `Place::adr_address_parsed`.
+///
+/// Each field is the text of the `<span>` with the matching class in the
+/// `adr_address` snippet, with HTML entities decoded. A field is `None` if
+/// the snippet has no such span.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AdrAddress {
+    /// The post office box, from the `post-office-box` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_office_box: Option<String>,
+
+    /// The extended address, such as a suite or floor, from the
+    /// `extended-address` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extended_address: Option<String>,
+
+    /// The street address, from the `street-address` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub street_address: Option<String>,
+
+    /// The city or town, from the `locality` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+
+    /// The state, province or prefecture, from the `region` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// The postal code, from the `postal-code` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// The country, from the `country-name` span.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for AdrAddress {
+    type Err = std::convert::Infallible;
+
+    /// Parses an adr microformat snippet, such as:
+    ///
+    /// ```text
+    /// <span class="street-address">48 Pirrama Rd</span>,
+    /// <span class="locality">Pyrmont</span> <span class="region">NSW</span>
+    /// <span class="postal-code">2009</span>,
+    /// <span class="country-name">Australia</span>
+    /// ```
+    ///
+    /// Text outside of the spans, and spans with unrecognized classes, are
+    /// ignored. Parsing never fails: a snippet with no recognized spans
+    /// produces an `AdrAddress` with every field set to `None`.
+    fn from_str(adr_address: &str) -> Result<Self, Self::Err> {
+        let mut address = Self::default();
+        let mut rest = adr_address;
+
+        while let Some(start) = rest.find("<span") {
+            rest = &rest[start + "<span".len()..];
+
+            // The opening tag ends at the first `>`:
+            let Some(tag_end) = rest.find('>') else { break };
+            let class = class_attribute(&rest[..tag_end]);
+            rest = &rest[tag_end + 1..];
+
+            // The text ends at the closing tag:
+            let text_end = rest.find("</span>").unwrap_or(rest.len());
+            let text = decode_entities(rest[..text_end].trim());
+            rest = &rest[text_end..];
+
+            let field = match class.as_deref() {
+                Some("post-office-box") => &mut address.post_office_box,
+                Some("extended-address") => &mut address.extended_address,
+                Some("street-address") => &mut address.street_address,
+                Some("locality") => &mut address.locality,
+                Some("region") => &mut address.region,
+                Some("postal-code") => &mut address.postal_code,
+                Some("country-name") => &mut address.country_name,
+                _ => continue,
+            }; // match
+
+            if !text.is_empty() {
+                *field = Some(text);
+            } // if
+        } // while
+
+        Ok(address)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Parses the place's `adr_address` field into its parts. This is often
+    /// the easiest way to get a well-segmented postal address for a place.
+    ///
+    /// Returns `None` if the place has no `adr_address`.
+    /// ```rust
+    /// if let Some(address) = place.adr_address_parsed() {
+    ///     println!("{:?}", address.postal_code);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn adr_address_parsed(&self) -> Option<AdrAddress> {
+        self.adr_address
+            .as_deref()
+            .and_then(|adr_address| adr_address.parse().ok())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the value of the `class` attribute from the attributes of an HTML
+/// tag, such as ` class="locality"`. Either quote character may be used.
+
+fn class_attribute(attributes: &str) -> Option<String> {
+    let value = &attributes[attributes.find("class=")? + "class=".len()..];
+    let quote = value.chars().next().filter(|quote| *quote == '"' || *quote == '\'')?;
+    let value = &value[1..];
+    Some(value[..value.find(quote)?].trim().to_string())
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes the named HTML entities that are common in addresses, and all
+/// numeric character references. Unrecognized entities are left as they are.
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest
+            .find(';')
+            .map(|end| (&rest[1..end], end))
+            .and_then(|(name, end)| decode_entity(name).map(|character| (character, end)));
+
+        if let Some((character, end)) = entity {
+            decoded.push(character);
+            rest = &rest[end + 1..];
+        } else {
+            decoded.push('&');
+            rest = &rest[1..];
+        } // if
+    } // while
+
+    decoded.push_str(rest);
+    decoded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes a single HTML entity, given without its `&` and `;`.
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some('\u{a0}'),
+        _ => {
+            let code = name.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            }; // match
+            char::from_u32(code)
+        },
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::AdrAddress;
+
+    fn parse(adr_address: &str) -> AdrAddress {
+        adr_address.parse().unwrap()
+    } // fn
+
+    #[test]
+    fn parses_a_us_address() {
+        let address = parse(concat!(
+            r#"<span class="street-address">1600 Amphitheatre Pkwy</span>, "#,
+            r#"<span class="locality">Mountain View</span>, "#,
+            r#"<span class="region">CA</span> <span class="postal-code">94043</span>, "#,
+            r#"<span class="country-name">USA</span>"#,
+        ));
+
+        assert_eq!(address, AdrAddress {
+            street_address: Some("1600 Amphitheatre Pkwy".to_string()),
+            locality: Some("Mountain View".to_string()),
+            region: Some("CA".to_string()),
+            postal_code: Some("94043".to_string()),
+            country_name: Some("USA".to_string()),
+            ..AdrAddress::default()
+        });
+    } // fn
+
+    #[test]
+    fn parses_a_uk_address_without_a_region() {
+        let address = parse(concat!(
+            r#"<span class="extended-address">Fortnum &amp; Mason</span>, "#,
+            r#"<span class="street-address">181 Piccadilly, St. James&#39;s</span>, "#,
+            r#"<span class="locality">London</span> <span class="postal-code">W1A 1ER</span>, "#,
+            r#"<span class="country-name">UK</span>"#,
+        ));
+
+        assert_eq!(address, AdrAddress {
+            extended_address: Some("Fortnum & Mason".to_string()),
+            street_address: Some("181 Piccadilly, St. James's".to_string()),
+            locality: Some("London".to_string()),
+            postal_code: Some("W1A 1ER".to_string()),
+            country_name: Some("UK".to_string()),
+            ..AdrAddress::default()
+        });
+    } // fn
+
+    #[test]
+    fn parses_a_japanese_address() {
+        // Japanese addresses run from the largest area to the smallest, and
+        // the spans aren't separated by spaces:
+        let address = parse(concat!(
+            r#"<span class="country-name">日本</span>、〒<span class="postal-code">106-6108</span> "#,
+            r#"<span class="region">東京都</span><span class="locality">港区</span>"#,
+            r#"<span class="street-address">六本木６丁目１０−１</span>"#,
+        ));
+
+        assert_eq!(address, AdrAddress {
+            street_address: Some("六本木６丁目１０−１".to_string()),
+            locality: Some("港区".to_string()),
+            region: Some("東京都".to_string()),
+            postal_code: Some("106-6108".to_string()),
+            country_name: Some("日本".to_string()),
+            ..AdrAddress::default()
+        });
+    } // fn
+
+    #[test]
+    fn leaves_unknown_entities_and_missing_spans_alone() {
+        let address = parse("<span class='locality'>Caf&eacute; &#x26; Bar &amp</span> <span>Nowhere</span>");
+        assert_eq!(address, AdrAddress {
+            locality: Some("Caf&eacute; & Bar &amp".to_string()),
+            ..AdrAddress::default()
+        });
+
+        assert_eq!(parse("Just some text"), AdrAddress::default());
+    } // fn
+
+    #[cfg(any(feature = "simd-json", feature = "serde-json"))]
+    #[test]
+    fn parses_the_adr_address_of_a_place() {
+        let place: crate::places::Place = r#"{
+            "place_id": "ChIJj61dQgK6j4AR4GeTYWZsKWw",
+            "adr_address": "<span class=\"locality\">Mountain View</span>"
+        }"#.parse().unwrap();
+        let address = place.adr_address_parsed().unwrap();
+        assert_eq!(address.locality.as_deref(), Some("Mountain View"));
+    } // fn
+} // mod
@@ -43,6 +43,10 @@ pub enum Error {
     /// sort order type code. See
     /// `google_maps\src\places\place_details\sort_order.rs` for more information.
     InvalidSortOrderCode(String),
+    /// The factor that an expanding Nearby Search multiplies its radius by
+    /// must be a finite number greater than `1.0`. Contains the factor that
+    /// was provided.
+    InvalidExpansionFactor(f64),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
@@ -53,8 +57,23 @@ pub enum Error {
     /// not be passed normally so a `String` representation is passed instead.
     #[cfg(feature = "reqwest")]
     ReqwestMessage(String),
-    /// The dependency library Serde JSON generated an error.
-    SimdJson(simd_json::Error),
+    /// The JSON parser generated an error.
+    Json(crate::json::Error),
+    /// The response from the Google Maps service could not be parsed. Contains
+    /// the sanitized request URL, the error generated by the JSON parser, and
+    /// the beginning of the response body (if response body capture is
+    /// enabled in the client).
+    InvalidJsonResponse(String, crate::json::Error, Option<String>),
+    /// Google responded with an `OK` status, but the response is missing data
+    /// that an `OK` response always contains. Contains the sanitized request
+    /// URL and the name of the missing field.
+    MalformedResponse(String, String),
+    /// Google responded with a body that is not JSON, such as an HTML error
+    /// page. This usually means that the API key is invalid, or that the
+    /// service is misconfigured. Contains the HTTP status, and the beginning
+    /// of the response body (if response body capture is enabled in the
+    /// client).
+    NonJsonResponse { status: String, snippet: String },
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -123,6 +142,9 @@ impl std::fmt::Display for Error {
             Self::InvalidSortOrderCode(sort_order_code) => write!(f, "Google Maps Places API client: \
                 `{sort_order_code}` is not a valid sort order code. \
                 Valid codes are `most_relevant` and `newest`."),
+            Self::InvalidExpansionFactor(factor) => write!(f, "Google Maps Places API client: \
+                `{factor}` is not a valid expansion factor. \
+                The factor must be a finite number greater than `1.0`."),
             Self::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
@@ -130,19 +152,148 @@ impl std::fmt::Display for Error {
             Self::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
-            Self::SimdJson(error) => write!(f, "Google Maps Places API client in the Serde JSON library: {error}"),
+            Self::Json(error) => write!(f, "Google Maps Places API client in the JSON parser: {error}"),
+            Self::InvalidJsonResponse(url, error, body) => match body {
+                Some(body) => write!(f,
+                    "Google Maps Places API client in the JSON parser: {error}. \
+                    Request URL: `{url}`. \
+                    Response body: `{body}`"),
+                None => write!(f,
+                    "Google Maps Places API client in the JSON parser: {error}. \
+                    Request URL: `{url}`."),
+            }, // match
+            Self::MalformedResponse(url, field) => write!(f,
+                "Google Maps Places API client: \
+                Google responded with an `OK` status, but the response has no `{field}`. \
+                Request URL: `{url}`."),
+            Self::NonJsonResponse { status, snippet } => if snippet.is_empty() {
+                write!(f,
+                    "Google Maps Places API client: \
+                    Google responded with a `{status}` status and a body that is not JSON, such as an HTML error page. \
+                    Ensure that the API key is valid, and that the API is enabled for it.")
+            } else {
+                write!(f,
+                    "Google Maps Places API client: \
+                    Google responded with a `{status}` status and a body that is not JSON, such as an HTML error page. \
+                    Ensure that the API key is valid, and that the API is enabled for it. \
+                    Response body: `{snippet}`")
+            }, // if
         } // match
     } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
 
-impl From<simd_json::Error> for Error {
-    /// This trait converts from an Serde JSON (`simd_json::Error`)
+impl From<crate::json::Error> for Error {
+    /// This trait converts from a JSON parser (`crate::json::Error`)
     /// error type into a Google Maps Places API error type
     /// (`google_maps::place::error::Error`) by wrapping it inside. This
     /// function is required to use the `?` operator.
-    fn from(error: simd_json::Error) -> Self {
-        Self::SimdJson(error)
+    fn from(error: crate::json::Error) -> Self {
+        Self::Json(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Error {
+    /// Returns `true` if the error is temporary and the request may succeed if
+    /// it is retried. Network errors, HTTP "500 Server Errors", HTTP "429 Too
+    /// Many Requests", and the Google Maps Places API's `UNKNOWN_ERROR` status
+    /// are transient. All other errors are permanent.
+    ///
+    /// This is the same classification that the client uses when deciding
+    /// whether a request is eligible for automatic retries.
+    #[must_use]
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::GoogleMapsService(status, _) => status == &Status::UnknownError,
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::is_transient_http_status(status),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(_) => true,
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns `true` if the error was caused by exceeding a usage quota or a
+    /// request rate limit.
+    #[must_use]
+    pub fn is_quota_error(&self) -> bool {
+        match self {
+            Self::GoogleMapsService(status, _) => matches!(status, Status::OverQueryLimit),
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::is_quota_http_status(status),
+            _ => false,
+        } // match
+    } // fn
+
+    /// Returns the HTTP status code that the server responded with, if the
+    /// error was caused by an unsuccessful HTTP response.
+    #[cfg(feature = "reqwest")]
+    #[must_use]
+    pub fn http_status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::HttpUnsuccessful(status) | Self::NonJsonResponse { status, .. } => crate::error::http_status(status),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the status that the Google Maps Platform responded with, if the
+    /// error was reported by Google's service. This allows branching on the
+    /// precise status without parsing the error message.
+    #[must_use]
+    pub const fn google_status(&self) -> Option<&Status> {
+        match self {
+            Self::GoogleMapsService(status, _) => Some(status),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Returns the error message that the Google Maps Platform responded with,
+    /// if the error was reported by Google's service and a message was
+    /// included.
+    #[must_use]
+    pub fn google_message(&self) -> Option<&str> {
+        match self {
+            Self::GoogleMapsService(_, message) => message.as_deref(),
+            _ => None,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::{Error, Status};
+    use reqwest::StatusCode;
+
+    fn non_json_response(status: &str) -> Error {
+        Error::NonJsonResponse { status: status.to_string(), snippet: String::new() }
+    } // fn
+
+    // `ReqError` is `reqwest::Error` itself, unless `reqwest-middleware` is
+    // enabled:
+    #[cfg_attr(not(feature = "reqwest-middleware"), allow(clippy::useless_conversion))]
+    #[test]
+    fn classifies_each_error_variant() {
+        // Each error, and whether it is transient, whether it is a quota
+        // error, and its HTTP status:
+        let cases = [
+            (Error::GoogleMapsService(Status::UnknownError, None), true, false, None),
+            (Error::GoogleMapsService(Status::OverQueryLimit, None), false, true, None),
+            (Error::GoogleMapsService(Status::RequestDenied, None), false, false, None),
+            (Error::HttpUnsuccessful("429 Too Many Requests".to_string()), true, true, Some(StatusCode::TOO_MANY_REQUESTS)),
+            (Error::HttpUnsuccessful("503 Service Unavailable".to_string()), true, false, Some(StatusCode::SERVICE_UNAVAILABLE)),
+            (Error::HttpUnsuccessful("404 Not Found".to_string()), false, false, Some(StatusCode::NOT_FOUND)),
+            (non_json_response("503 Service Unavailable"), true, false, Some(StatusCode::SERVICE_UNAVAILABLE)),
+            (non_json_response("403 Forbidden"), false, false, Some(StatusCode::FORBIDDEN)),
+            (Error::Reqwest(crate::error::tests::reqwest_error().into()), true, false, None),
+        ]; // cases
+
+        for (error, is_transient, is_quota_error, http_status) in cases {
+            assert_eq!(error.is_transient(), is_transient, "{error:?}");
+            assert_eq!(error.is_quota_error(), is_quota_error, "{error:?}");
+            assert_eq!(error.http_status(), http_status, "{error:?}");
+        } // for
+    } // fn
+} // mod
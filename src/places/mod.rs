@@ -92,6 +92,8 @@ pub use crate::places::place_autocomplete::request::Request as QueryAutocomplete
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub mod adr_address;
 #[cfg(feature = "places")]
 pub mod business_status;
 #[cfg(feature = "places")]
@@ -125,7 +127,8 @@ pub mod status;
 
 #[cfg(feature = "places")]
 pub use crate::places::{
-    business_status::BusinessStatus, error::Error, place::Place,
+    adr_address::AdrAddress, business_status::BusinessStatus, error::Error,
+    place::Place,
     place_editorial_summary::PlaceEditorialSummary, place_opening_hours::PlaceOpeningHours,
     place_opening_hours_period::PlaceOpeningHoursPeriod,
     place_opening_hours_period_detail::PlaceOpeningHoursPeriodDetail, place_photo::PlacePhoto,
@@ -4,7 +4,9 @@
 use crate::places::{
     BusinessStatus, PlaceEditorialSummary, PlaceOpeningHours, PlacePhoto, PlaceReview,
 };
-use crate::types::{AddressComponent, Geometry, PlaceType};
+use crate::serde::decimal_as_number::option_decimal_as_number;
+use crate::serde::number_or_string::option_number_or_string;
+use crate::types::{AddressComponent, Geometry, MaybeKnown, PlaceType};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -12,7 +14,14 @@ use serde::{Deserialize, Serialize};
 //
 /// Attributes describing a place. Not all attributes will be available for all
 /// place types.
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+///
+/// Equality and hashing are identity by `place_id`: two `Place` structs are
+/// equal if they have the same `place_id`, even if their other attributes
+/// differ. This allows search results from several pages to be deduplicated
+/// with a `HashSet<Place>`. A place without a `place_id` is never equal to a
+/// place with one, and two places without a `place_id` are only equal if all
+/// of their attributes are equal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Place {
     /// An array containing the separate components applicable to this address.
     ///
@@ -33,7 +42,7 @@ pub struct Place {
     /// The allowed values include: `OPERATIONAL`, `CLOSED_TEMPORARILY`, and
     /// `CLOSED_PERMANENTLY`
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub business_status: Option<BusinessStatus>,
+    pub business_status: Option<MaybeKnown<BusinessStatus>>,
 
     /// Specifies if the business supports curbside pickup.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,6 +138,12 @@ pub struct Place {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opening_hours: Option<PlaceOpeningHours>,
 
+    /// Whether the place has shut down permanently or temporarily. This field
+    /// is deprecated in favour of `business_status`, but may still be present
+    /// in older responses. Use `Place::is_operational` to reconcile the two.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permanently_closed: Option<bool>,
+
     /// An array of photo objects, each containing a reference to an image. A
     /// request may return up to ten photos. More information about place photos
     /// and how you can use the images in your application can be found in the
@@ -162,6 +177,8 @@ pub struct Place {
 
     /// Contains the place's rating, from 1.0 to 5.0, based on aggregated user
     /// reviews.
+    #[serde(default, deserialize_with = "option_number_or_string")]
+    #[serde(serialize_with = "option_decimal_as_number")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rating: Option<Decimal>,
 
@@ -240,7 +257,7 @@ pub struct Place {
     /// list of [supported types](https://developers.google.com/maps/documentation/places/web-service/supported_types#table2).
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub types: Vec<PlaceType>,
+    pub types: Vec<MaybeKnown<PlaceType>>,
 
     /// Contains the URL of the official Google page for this place. This will
     /// be the Google-owned page that contains the best available information
@@ -284,14 +301,296 @@ pub struct Place {
     pub wheelchair_accessible_entrance: Option<bool>,
 } // struct Place
 
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Returns a stable key that identifies this place, that is its
+    /// `place_id`. This is the key used for equality and hashing, when it is
+    /// present.
+
+    #[must_use]
+    pub fn key(&self) -> Option<&str> {
+        self.place_id.as_deref()
+    } // fn
+
+    /// Returns the average rating of the reviews that Google returned for
+    /// this place, or `None` if no reviews were returned.
+    ///
+    /// This is not the same as the overall `rating` field. Google returns at
+    /// most five reviews, while the overall rating is aggregated from all of
+    /// the place's user reviews.
+
+    #[must_use]
+    pub fn average_review_rating(&self) -> Option<Decimal> {
+        if self.reviews.is_empty() {
+            None
+        } else {
+            let total: u32 = self.reviews.iter().map(|review| u32::from(review.rating)).sum();
+            Some((Decimal::from(total) / Decimal::from(self.reviews.len())).normalize())
+        } // if
+    } // fn
+
+    /// Returns the operational status of the place, if Google returned one
+    /// that this crate recognizes. An unrecognized status is still available
+    /// in the `business_status` field.
+    /// ```rust
+    /// if place.known_business_status() == Some(&BusinessStatus::ClosedTemporarily) {
+    ///     println!("closed for now");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn known_business_status(&self) -> Option<&BusinessStatus> {
+        self.business_status.as_ref().and_then(MaybeKnown::known)
+    } // fn
+
+    /// Returns the types of the place that this crate recognizes, in the
+    /// order that Google returned them. Unrecognized types are skipped, but
+    /// are still available in the `types` field.
+    /// ```rust
+    /// if place.known_types().contains(&PlaceType::Restaurant) {
+    ///     println!("hungry?");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn known_types(&self) -> Vec<PlaceType> {
+        self.types
+            .iter()
+            .filter_map(MaybeKnown::known)
+            .copied()
+            .collect()
+    } // fn
+
+    /// Returns whether the place is operating, by reconciling the
+    /// `business_status` field with the legacy `permanently_closed` field.
+    ///
+    /// A recognized `business_status` takes precedence: `OPERATIONAL` is
+    /// `Some(true)`, and `CLOSED_TEMPORARILY` or `CLOSED_PERMANENTLY` is
+    /// `Some(false)`. Otherwise, `permanently_closed` is used. Returns `None`
+    /// if neither field tells whether the place is operating.
+
+    #[must_use]
+    pub fn is_operational(&self) -> Option<bool> {
+        self.known_business_status()
+            .map(|business_status| *business_status == BusinessStatus::Operational)
+            .or_else(|| self.permanently_closed.map(|permanently_closed| !permanently_closed))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Place {
+    /// Compares every field of two places, except for their `place_id`. The
+    /// fields are listed exhaustively so that a new field can't be left out.
+
+    fn fields_eq(&self, other: &Self) -> bool {
+        let Self {
+            address_components, adr_address, business_status, curbside_pickup,
+            current_opening_hours, delivery, dine_in, editorial_summary,
+            formatted_address, formatted_phone_number, geometry, icon,
+            icon_background_color, icon_mask_base_uri,
+            international_phone_number, name, opening_hours,
+            permanently_closed, photos, price_level, rating, reservable,
+            reviews, secondary_opening_hours, serves_beer, serves_breakfast,
+            serves_brunch, serves_dinner, serves_lunch, serves_vegetarian_food,
+            serves_wine, takeout, types, url, user_ratings_total, utc_offset,
+            vicinity, website, wheelchair_accessible_entrance, place_id: _,
+        } = self;
+
+        *address_components == other.address_components &&
+            *adr_address == other.adr_address &&
+            *business_status == other.business_status &&
+            *curbside_pickup == other.curbside_pickup &&
+            *current_opening_hours == other.current_opening_hours &&
+            *delivery == other.delivery &&
+            *dine_in == other.dine_in &&
+            *editorial_summary == other.editorial_summary &&
+            *formatted_address == other.formatted_address &&
+            *formatted_phone_number == other.formatted_phone_number &&
+            *geometry == other.geometry &&
+            *icon == other.icon &&
+            *icon_background_color == other.icon_background_color &&
+            *icon_mask_base_uri == other.icon_mask_base_uri &&
+            *international_phone_number == other.international_phone_number &&
+            *name == other.name &&
+            *opening_hours == other.opening_hours &&
+            *permanently_closed == other.permanently_closed &&
+            *photos == other.photos &&
+            *price_level == other.price_level &&
+            *rating == other.rating &&
+            *reservable == other.reservable &&
+            *reviews == other.reviews &&
+            *secondary_opening_hours == other.secondary_opening_hours &&
+            *serves_beer == other.serves_beer &&
+            *serves_breakfast == other.serves_breakfast &&
+            *serves_brunch == other.serves_brunch &&
+            *serves_dinner == other.serves_dinner &&
+            *serves_lunch == other.serves_lunch &&
+            *serves_vegetarian_food == other.serves_vegetarian_food &&
+            *serves_wine == other.serves_wine &&
+            *takeout == other.takeout &&
+            *types == other.types &&
+            *url == other.url &&
+            *user_ratings_total == other.user_ratings_total &&
+            *utc_offset == other.utc_offset &&
+            *vicinity == other.vicinity &&
+            *website == other.website &&
+            *wheelchair_accessible_entrance == other.wheelchair_accessible_entrance
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PartialEq for Place {
+    /// Two places with a `place_id` are equal if they have the same
+    /// `place_id`, even if their other fields differ. This deduplicates a place
+    /// that was returned twice, for example on overlapping result pages.
+    ///
+    /// A place without a `place_id` is never equal to a place with one. Two
+    /// places without a `place_id` are compared field by field instead, so
+    /// that unrelated places aren't mistaken for the same place.
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.place_id, &other.place_id) {
+            (Some(place_id), Some(other_place_id)) => place_id == other_place_id,
+            (None, None) => self.fields_eq(other),
+            _ => false,
+        } // match
+    } // fn
+} // impl
+
+impl Eq for Place {}
+
+// -----------------------------------------------------------------------------
+
+impl std::hash::Hash for Place {
+    /// Only the `place_id` is hashed if there is one. Otherwise, the name,
+    /// address and location are hashed. Places that are equal field by field
+    /// have the same name, address and location, so this is consistent with
+    /// `PartialEq`.
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.place_id.hash(state);
+        if self.place_id.is_none() {
+            self.name.hash(state);
+            self.formatted_address.hash(state);
+            self.vicinity.hash(state);
+            self.geometry.hash(state);
+        } // if
+    } // fn
+} // impl
+
 /// ----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Place {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API JSON response into a usable `Place`
     /// struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Place;
+    use std::collections::HashSet;
+
+    fn place(json: &str) -> Place {
+        json.parse().unwrap()
+    } // fn
+
+    #[test]
+    fn deduplicates_overlapping_pages_by_place_id() {
+        let first_page = [
+            place(r#"{"place_id": "ChIJ-first", "name": "Bakery"}"#),
+            place(r#"{"place_id": "ChIJ-second", "name": "Cafe"}"#),
+        ];
+        // The second page repeats a place, with a more recent rating:
+        let second_page = [
+            place(r#"{"place_id": "ChIJ-second", "name": "Cafe", "rating": 4.5}"#),
+            place(r#"{"place_id": "ChIJ-third", "name": "Diner"}"#),
+        ];
+
+        let places: HashSet<Place> = first_page.into_iter().chain(second_page).collect();
+
+        let mut keys: Vec<&str> = places.iter().filter_map(Place::key).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["ChIJ-first", "ChIJ-second", "ChIJ-third"]);
+    } // fn
+
+    #[test]
+    fn equality_is_identity_by_place_id() {
+        let without_rating = place(r#"{"place_id": "ChIJ-cafe", "name": "Cafe"}"#);
+        let with_rating = place(r#"{"place_id": "ChIJ-cafe", "name": "Cafe", "rating": 4.5}"#);
+        let other_place = place(r#"{"place_id": "ChIJ-diner", "name": "Cafe"}"#);
+
+        assert_eq!(without_rating, with_rating);
+        assert_ne!(without_rating, other_place);
+    } // fn
+
+    #[test]
+    fn places_without_a_place_id_are_compared_field_by_field() {
+        let bakery = place(r#"{"name": "Bakery", "vicinity": "12 Main St"}"#);
+        let cafe = place(r#"{"name": "Cafe", "vicinity": "12 Main St"}"#);
+        let rated_bakery = place(r#"{"name": "Bakery", "vicinity": "12 Main St", "rating": 4.5}"#);
+
+        assert_eq!(bakery, bakery.clone());
+        assert_ne!(bakery, cafe);
+        assert_ne!(bakery, rated_bakery);
+
+        // A place without a `place_id` is never the same as one with it:
+        let identified_bakery = place(r#"{"place_id": "ChIJ-bakery", "name": "Bakery", "vicinity": "12 Main St"}"#);
+        assert_ne!(bakery, identified_bakery);
+        assert_ne!(identified_bakery, bakery);
+
+        let places: HashSet<Place> = [bakery.clone(), cafe, bakery, rated_bakery, identified_bakery]
+            .into_iter()
+            .collect();
+        assert_eq!(places.len(), 4);
+    } // fn
+
+    #[test]
+    fn known_accessors_skip_unrecognized_codes() {
+        use crate::places::BusinessStatus;
+        use crate::types::PlaceType;
+
+        let cafe = place(r#"{
+            "business_status": "CLOSED_TEMPORARILY",
+            "types": ["cafe", "food_hall_of_the_future", "restaurant"]
+        }"#);
+        assert_eq!(cafe.known_business_status(), Some(&BusinessStatus::ClosedTemporarily));
+        assert_eq!(cafe.known_types(), [PlaceType::Cafe, PlaceType::Restaurant]);
+        assert_eq!(cafe.types.len(), 3);
+
+        let relocated = place(r#"{"business_status": "RELOCATED"}"#);
+        assert_eq!(relocated.known_business_status(), None);
+        assert!(relocated.known_types().is_empty());
+        assert_eq!(relocated.business_status.unwrap().unknown(), Some("RELOCATED"));
+    } // fn
+
+    #[test]
+    fn reconciles_the_business_status_with_permanently_closed() {
+        for (json, expected) in [
+            (r#"{"business_status": "OPERATIONAL"}"#, Some(true)),
+            (r#"{"business_status": "CLOSED_TEMPORARILY"}"#, Some(false)),
+            (r#"{"business_status": "CLOSED_PERMANENTLY"}"#, Some(false)),
+            // An unrecognized status says nothing about whether the place is
+            // operating:
+            (r#"{"business_status": "RELOCATED"}"#, None),
+            (r#"{"business_status": "RELOCATED", "permanently_closed": true}"#, Some(false)),
+            // Only the legacy field:
+            (r#"{"permanently_closed": true}"#, Some(false)),
+            (r#"{"permanently_closed": false}"#, Some(true)),
+            // Both fields, where the business status wins:
+            (r#"{"business_status": "OPERATIONAL", "permanently_closed": true}"#, Some(true)),
+            (r#"{"business_status": "CLOSED_TEMPORARILY", "permanently_closed": false}"#, Some(false)),
+            // Neither field:
+            (r#"{"name": "Cafe"}"#, None),
+        ] {
+            assert_eq!(place(json).is_operational(), expected, "{json}");
+        } // for
+    } // fn
+} // mod
@@ -3,15 +3,21 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Places API _Place
     /// Autocomplete_ query based on the input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -27,17 +33,21 @@ impl<'a> Request<'a> {
         // Components key/value pair:
         if !self.components.is_empty() {
             query.push_str("&components=");
-            let components = self
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut components = self
                 .components
                 .iter()
                 .map(|component| format!("country:{}", String::from(component).to_lowercase()))
-                .collect::<Vec<String>>()
-                .join("|");
-            query.push_str(&utf8_percent_encode(&components, NON_ALPHANUMERIC).to_string());
+                .collect::<Vec<String>>();
+            components.sort();
+            components.dedup();
+            query.push_str(&utf8_percent_encode(&components.join("|"), NON_ALPHANUMERIC).to_string());
         } // if
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
@@ -45,7 +55,21 @@ impl<'a> Request<'a> {
         // Location key/value pair:
         if let Some(location) = &self.location {
             query.push_str("&location=");
-            query.push_str(&String::from(location));
+            query.push_str(&String::from(&*self.client.coordinates(location)));
+        }
+
+        // Location Bias key/value pair:
+        if let Some((center, radius)) = &self.locationbias {
+            query.push_str("&locationbias=circle:");
+            query.push_str(&radius.to_string());
+            query.push('@');
+            query.push_str(&String::from(&*self.client.coordinates(center)));
+        }
+
+        // Location Restriction key/value pair:
+        if let Some(locationrestriction) = &self.locationrestriction {
+            query.push_str("&locationrestriction=rectangle:");
+            query.push_str(&String::from(&*self.client.coordinates(locationrestriction)));
         }
 
         // Offset key/value pair:
@@ -57,7 +81,7 @@ impl<'a> Request<'a> {
         // Origin key/value pair:
         if let Some(origin) = &self.origin {
             query.push_str("&origin=");
-            query.push_str(&String::from(origin));
+            query.push_str(&String::from(&*self.client.coordinates(origin)));
         }
 
         // Radius key/value pair:
@@ -66,8 +90,9 @@ impl<'a> Request<'a> {
             query.push_str(&radius.to_string());
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if none
+        // was set for this request:
+        if let Some(region) = self.region.as_ref().or(self.client.default_region.as_ref()) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         }
@@ -87,13 +112,16 @@ impl<'a> Request<'a> {
         // Types key/value pair:
         if !self.types.is_empty() {
             query.push_str("&types=");
-            let types = self
+            // Sorted and deduplicated so that the query string does not depend
+            // on the order that the builder methods were called in:
+            let mut types = self
                 .types
                 .iter()
                 .map(String::from)
-                .collect::<Vec<String>>()
-                .join("|");
-            query.push_str(&types);
+                .collect::<Vec<String>>();
+            types.sort();
+            types.dedup();
+            query.push_str(&types.join("|"));
         }
 
         // Set query string in Request struct.
@@ -5,7 +5,7 @@ use crate::places::place_autocomplete::{
 
 // =============================================================================
 
-impl<'a> PlaceAutocompleteRequest<'a> {
+impl PlaceAutocompleteRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> PlaceAutocompleteRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
-        self.build().get().await
+    pub async fn execute(&mut self) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
+        self.validate()?.build().get().await
     } // fn
 } // impl
@@ -11,20 +11,24 @@ mod execute;
 mod get;
 mod new;
 mod query_url;
+mod validate;
 mod with_components;
 mod with_language;
 mod with_location;
+mod with_location_bias;
+mod with_location_restriction;
 mod with_offset;
 mod with_origin;
 mod with_region;
 mod with_sessiontoken;
+mod with_strict_bounds;
 mod with_types;
 
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
 use crate::places::place_autocomplete::request::autocomplete_type::AutocompleteType;
-use crate::types::{Country, Language, LatLng, Region};
+use crate::types::{Bounds, Country, Language, LatLng, Region};
 
 // -----------------------------------------------------------------------------
 
@@ -32,13 +36,13 @@ use crate::types::{Country, Language, LatLng, Region};
 /// _Place Autocomplete_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The text string on which to search. The Place Autocomplete service will
     /// return candidate matches based on this string and order results based on
@@ -94,6 +98,16 @@ pub struct Request<'a> {
     ///   'Market in Barcelona'.
     location: Option<LatLng>,
 
+    /// Prefers results within a circle, given by its centre and its radius in
+    /// meters. This is a bias, rather than a restriction, meaning that results
+    /// outside this area may still be returned.
+    locationbias: Option<(LatLng, u32)>,
+
+    /// Restricts results to a rectangular area. This is a restriction, rather
+    /// than a bias, meaning that results outside this area will not be
+    /// returned even if they match the user input.
+    locationrestriction: Option<Bounds>,
+
     /// The position, in the input term, of the last character that the service
     /// uses to match predictions. For example, if the input is `Google` and the
     /// offset is 3, the service will match on `Goo`. The string determined by
@@ -102,7 +116,7 @@ pub struct Request<'a> {
     /// service will attempt to match against `Goo abc`. If no offset is
     /// supplied, the service will use the whole term. The offset should
     /// generally be set to the position of the text caret.
-    offset: Option<u8>,
+    offset: Option<usize>,
 
     /// The origin point from which to calculate straight-line distance to the
     /// destination (returned as `distance_meters`). If this value is omitted,
@@ -172,6 +186,9 @@ pub struct Request<'a> {
     /// `location` and `radius`. This is a restriction, rather than a bias,
     /// meaning that results outside this region will not be returned even if
     /// they match the user input.
+    ///
+    /// * Strict bounds require that a `location` and `radius`, or a
+    ///   `locationrestriction`, is set.
     strictbounds: Option<bool>,
 
     /// You may restrict results from a Place Autocomplete request to be of a
@@ -188,3 +205,29 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("input", &self.input)
+            .field("components", &self.components)
+            .field("language", &self.language)
+            .field("location", &self.location)
+            .field("locationbias", &self.locationbias)
+            .field("locationrestriction", &self.locationrestriction)
+            .field("offset", &self.offset)
+            .field("origin", &self.origin)
+            .field("radius", &self.radius)
+            .field("region", &self.region)
+            .field("sessiontoken", &self.sessiontoken)
+            .field("strictbounds", &self.strictbounds)
+            .field("types", &self.types)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
@@ -0,0 +1,43 @@
+use crate::places::place_autocomplete::{error::Error, request::Request};
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Ensures the built query is valid. This function checks the combination
+    /// of parameters to ensure that they make sense together and that Google
+    /// Maps Places API will accept them - i.e. it will not allow strict bounds
+    /// without an area to restrict the results to. This function does not check
+    /// parameter values for validity - i.e. it will not ensure
+    /// Latitudes/Longitudes are valid and well-formed.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn validate(&mut self) -> Result<&mut Self, Error> {
+        // If strict bounds have been requested...
+        if self.strictbounds == Some(true) {
+            // ...an area, either a circle or a rectangle, must be set:
+            let has_circle = self.location.is_some() && self.radius.is_some();
+            if !has_circle && self.locationrestriction.is_none() {
+                return Err(Error::StrictBoundsWithoutArea);
+            } // if
+        } // if
+
+        // A circular location bias must have an area:
+        if let Some((_center, 0)) = &self.locationbias {
+            return Err(Error::ZeroLocationBiasRadius);
+        } // if
+
+        // At most 5 distinct countries may be set:
+        let mut countries: Vec<String> = self.components.iter().map(String::from).collect();
+        countries.sort();
+        countries.dedup();
+        if countries.len() > 5 {
+            return Err(Error::TooManyCountries(countries.len()));
+        } // if
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
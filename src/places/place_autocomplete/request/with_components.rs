@@ -3,7 +3,7 @@ use crate::Country;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the components parameter to the Place API _Place Autocomplete_
     /// query.
     ///
@@ -16,9 +16,9 @@ impl<'a> Request<'a> {
     /// * Multiple components may be stacked together.
 
     pub fn with_component(
-        &'a mut self,
+        &mut self,
         component: impl Into<Country>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set components in Request struct.
         self.components.extend(vec![component.into()]);
         // Return modified Request struct to caller.
@@ -28,7 +28,7 @@ impl<'a> Request<'a> {
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the components parameter to the Place API _Place Autocomplete_
     /// query.
     ///
@@ -48,9 +48,9 @@ impl<'a> Request<'a> {
     /// type.
 
     pub fn with_components<C, O>(
-        &'a mut self,
+        &mut self,
         components: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = O>,
         O: Into<Country> {
@@ -59,4 +59,30 @@ impl<'a> Request<'a> {
         // Return modified Request struct to caller.
         self
     } // fn
+
+    /// Restricts the Place API _Place Autocomplete_ query to up to 5
+    /// countries. This is the same as `with_components`, under a name that
+    /// says what the components are.
+    ///
+    /// ## Arguments
+    ///
+    /// * `countries` ‧ The countries to restrict results to. They are sent as
+    ///   `components=country:us|country:ca`, using lowercase ISO 3166-1
+    ///   alpha-2 codes. Duplicate countries are removed.
+    ///
+    /// Google rejects a request with more than 5 distinct countries, so the
+    /// `validate` method will return an error in that case.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_countries([Country::UnitedStates, Country::Canada])
+    /// ```
+
+    pub fn with_countries(
+        &mut self,
+        countries: impl IntoIterator<Item = Country>
+    ) -> &mut Self {
+        self.with_components(countries)
+    } // fn
 } // impl
@@ -3,7 +3,7 @@ use crate::types::Language;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the language parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -35,9 +35,9 @@ impl<'a> Request<'a> {
     ///       For example, _utca_ and _tér_ are synonyms for street in Hungarian.
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set language in Request struct.
         self.language = Some(language.into());
         // Return modified Request struct to caller.
@@ -0,0 +1,72 @@
+use crate::places::place_autocomplete::request::Request;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Adds a circular location bias parameter to the Place API _Place
+    /// Autocomplete_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The centre of the circle to prefer results within.
+    ///
+    /// * `radius_m` ‧ The radius of the circle, in meters. It must not be zero.
+    ///
+    /// This is a bias, rather than a restriction, meaning that results outside
+    /// of the circle may still be returned. It is sent as
+    /// `locationbias=circle:radius@lat,lng`. To restrict results to a
+    /// rectangular area instead, see `with_location_restriction`.
+    ///
+    /// Of the Places API requests in this crate, only _Place Autocomplete_
+    /// accepts `locationbias`. _Text Search_ is biased with its `location`
+    /// and `radius` parameters instead.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_location_bias_circle(LatLng::try_from_dec(dec!(40), dec!(-75))?, 1_000)
+    /// ```
+
+    pub fn with_location_bias_circle(
+        &mut self,
+        center: impl Into<LatLng>,
+        radius_m: u32
+    ) -> &mut Self {
+        // Set location bias in Request struct.
+        self.locationbias = Some((center.into(), radius_m));
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::places::place_autocomplete::error::Error;
+    use crate::types::LatLng;
+    use crate::GoogleMapsClient;
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap()
+    } // fn
+
+    #[test]
+    fn emits_a_circular_location_bias() {
+        let client = client();
+        let url = client
+            .place_autocomplete("pizza")
+            .with_location_bias_circle(LatLng::try_from_f64(40.0, -75.0).unwrap(), 1_000)
+            .query_url();
+        assert!(url.contains("&locationbias=circle:1000@40,-75"));
+    } // fn
+
+    #[test]
+    fn rejects_a_zero_radius() {
+        let client = client();
+        let mut request = client.place_autocomplete("pizza");
+        request.with_location_bias_circle(LatLng::try_from_f64(40.0, -75.0).unwrap(), 0);
+        assert!(matches!(request.validate(), Err(Error::ZeroLocationBiasRadius)));
+    } // fn
+} // mod
@@ -0,0 +1,27 @@
+use crate::places::place_autocomplete::request::Request;
+use crate::types::Bounds;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Adds the location restriction parameter to the Place API _Place
+    /// Autocomplete_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` ‧ A rectangular area, defined by its south-west and
+    ///   north-east corners, to restrict results to. This is a restriction,
+    ///   rather than a bias, meaning that results outside this area will not be
+    ///   returned even if they match the user input. This may be used, for
+    ///   example, to confine predictions to a single city.
+
+    pub fn with_location_restriction(
+        &mut self,
+        bounds: impl Into<Bounds>
+    ) -> &mut Self {
+        // Set location restriction in Request struct.
+        self.locationrestriction = Some(bounds.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -2,7 +2,7 @@ use crate::places::place_autocomplete::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the offset parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -18,9 +18,9 @@ impl<'a> Request<'a> {
     ///   caret.
 
     pub fn with_offset(
-        &'a mut self,
-        offset: impl Into<u8>
-    ) -> &'a mut Self {
+        &mut self,
+        offset: impl Into<usize>
+    ) -> &mut Self {
         // Set offset in Request struct.
         self.offset = Some(offset.into());
         // Return modified Request struct to caller.
@@ -3,7 +3,7 @@ use crate::types::LatLng;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the origin parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -13,9 +13,9 @@ impl<'a> Request<'a> {
     ///   value is omitted, straight-line distance will not be returned.
 
     pub fn with_origin(
-        &'a mut self,
+        &mut self,
         origin: impl Into<LatLng>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set origin in Request struct.
         self.origin = Some(origin.into());
         // Return modified Request struct to caller.
@@ -3,7 +3,7 @@ use crate::types::Region;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the region parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -17,9 +17,9 @@ impl<'a> Request<'a> {
     ///   Ireland").
 
     pub fn with_region(
-        &'a mut self,
+        &mut self,
         region: impl Into<Region>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set region in Request struct.
         self.region = Some(region.into());
         // Return modified Request struct to caller.
@@ -2,7 +2,7 @@ use crate::places::place_autocomplete::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the session token parameter to the Place API _Place Autocomplete_
     /// query.
     ///
@@ -38,9 +38,9 @@ impl<'a> Request<'a> {
     ///   billed individually.
 
     pub fn with_sessiontoken(
-        &'a mut self,
+        &mut self,
         sessiontoken: impl Into<String>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set session token in Request struct.
         self.sessiontoken = Some(sessiontoken.into());
         // Return modified Request struct to caller.
@@ -0,0 +1,29 @@
+use crate::places::place_autocomplete::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Adds the strict bounds parameter to the Place API _Place Autocomplete_
+    /// query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `strictbounds` ‧ Returns only those places that are strictly within
+    ///   the region defined by `location` and `radius`. This is a restriction,
+    ///   rather than a bias, meaning that results outside this region will not
+    ///   be returned even if they match the user input.
+    ///
+    /// Strict bounds require that an area is also set with the
+    /// `with_location_and_radius` or `with_location_restriction` methods.
+    /// Otherwise, the `validate` method will return an error.
+
+    pub fn with_strict_bounds(
+        &mut self,
+        strictbounds: impl Into<bool>
+    ) -> &mut Self {
+        // Set strictbounds in Request struct.
+        self.strictbounds = Some(strictbounds.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -3,7 +3,7 @@ use crate::places::place_autocomplete::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the types parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -19,8 +19,8 @@ impl<'a> Request<'a> {
     /// * Multiple result type filters may be stacked together.
 
     pub fn with_type(
-        &'a mut self,
-        autocomplete_type: impl Into<AutocompleteType>) -> &'a mut Self {
+        &mut self,
+        autocomplete_type: impl Into<AutocompleteType>) -> &mut Self {
         // Set types in Request struct.
         self.types.extend(vec![autocomplete_type.into()]);
         // Return modified Request struct to caller.
@@ -30,7 +30,7 @@ impl<'a> Request<'a> {
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the types parameter to the Place API _Place Autocomplete_ query.
     ///
     /// ## Arguments
@@ -53,9 +53,9 @@ impl<'a> Request<'a> {
     /// `AutocompleteType` type.
 
     pub fn with_types<C, A>(
-        &'a mut self,
+        &mut self,
         types: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = A>,
         A: Into<AutocompleteType> {
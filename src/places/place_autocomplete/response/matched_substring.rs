@@ -23,12 +23,12 @@ pub struct MatchedSubstring {
 
 /// ----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for MatchedSubstring {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API _Place Autocomplete_ JSON
     /// `MatchedSubstring` response into a usable `MatchedSubstring` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
@@ -12,6 +12,7 @@ pub mod term;
 // -----------------------------------------------------------------------------
 
 use crate::places::place_autocomplete::response::{prediction::Prediction, status::Status}; // crate::places::place_autocomplete::response
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Places API _Place Autocomplete_ request
@@ -64,12 +65,51 @@ pub struct Response {
     pub info_messages: Vec<String>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.predictions.is_empty().then_some("predictions")
+    } // fn
+} // impl
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API _Place Autocomplete_ JSON `String`
     /// response into a usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn place_autocomplete_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("autocomplete", 0));
+    } // fn
+
+    #[test]
+    fn query_autocomplete_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("autocomplete", 1));
+    } // fn
+} // mod
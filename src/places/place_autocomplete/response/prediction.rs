@@ -3,7 +3,7 @@
 use crate::places::place_autocomplete::response::{
     matched_substring::MatchedSubstring, structured_format::StructuredFormat, term::Term,
 }; // crate::places::place_autocomplete::response
-use crate::types::PlaceType;
+use crate::types::{MaybeKnown, PlaceType};
 use serde::{Deserialize, Serialize};
 
 /// ----------------------------------------------------------------------------
@@ -78,17 +78,17 @@ pub struct Prediction {
     #[serde(alias = "types")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub types: Vec<PlaceType>,
+    pub types: Vec<MaybeKnown<PlaceType>>,
 } // struct Prediction
 
 /// ----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Prediction {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API _Place Autocomplete_ JSON
     /// `Prediction` response into a usable `Prediction` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
@@ -71,13 +71,25 @@ impl std::convert::From<&Status> for String {
     /// [status](https://developers.google.com/maps/documentation/timezone/intro#Responses)
     /// code.
     fn from(status: &Status) -> Self {
-        match status {
-            Status::InvalidRequest => Self::from("INVALID_REQUEST"),
-            Status::Ok => Self::from("OK"),
-            Status::OverQueryLimit => Self::from("OVER_QUERY_LIMIT"),
-            Status::RequestDenied => Self::from("REQUEST_DENIED"),
-            Status::UnknownError => Self::from("UNKNOWN_ERROR"),
-            Status::ZeroResults => Self::from("ZERO_RESULTS"),
+        status.as_str().to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns the status code exactly as documented and returned by Google,
+    /// for example `OVER_QUERY_LIMIT`. Unlike `Display`, which is meant for
+    /// people, this is suitable for logs or as a metrics label.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest => "INVALID_REQUEST",
+            Self::Ok => "OK",
+            Self::OverQueryLimit => "OVER_QUERY_LIMIT",
+            Self::RequestDenied => "REQUEST_DENIED",
+            Self::UnknownError => "UNKNOWN_ERROR",
+            Self::ZeroResults => "ZERO_RESULTS",
         } // match
     } // fn
 } // impl
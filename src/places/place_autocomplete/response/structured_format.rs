@@ -49,12 +49,12 @@ pub struct StructuredFormat {
 
 /// ----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for StructuredFormat {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API _Place Autocomplete_ JSON
     /// `StructuredFormat` response into a usable `StructuredFormat` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
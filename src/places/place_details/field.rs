@@ -341,10 +341,14 @@ impl Field {
     /// [field](https://developers.google.com/maps/documentation/places/web-service/details#fields)
     /// codes.
     pub fn vec_to_csv(fields: &[Self]) -> String {
-        fields
+        // Sorted and deduplicated so that the output does not depend on the
+        // order that the fields were given in:
+        let mut fields = fields
             .iter()
             .map(String::from)
-            .collect::<Vec<String>>()
-            .join(",")
+            .collect::<Vec<String>>();
+        fields.sort();
+        fields.dedup();
+        fields.join(",")
     } // fn
 } // impl
@@ -5,7 +5,7 @@ use crate::places::place_details::{
 
 // =============================================================================
 
-impl<'a> PlaceDetailsRequest<'a> {
+impl PlaceDetailsRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> PlaceDetailsRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<PlaceDetailsResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<PlaceDetailsResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
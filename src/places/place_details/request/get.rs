@@ -5,20 +5,28 @@ use crate::places::place_details::{
 };
 use crate::places::status::Status as PlacesStatus;
 use crate::request_rate::api::Api;
-use backoff::future::retry;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> PlaceDetailsRequest<'a> {
+impl PlaceDetailsRequest {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(
+            otel.kind = "client",
+            http.method = "GET",
+            http.url = tracing::field::Empty,
+            google.api = "places",
+            google.status = tracing::field::Empty,
+        )
+    )]
     pub async fn get(&mut self) -> Result<PlaceDetailsResponse, GoogleMapsError> {
         // Build the URL stem for the HTTP get request:
         let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
@@ -30,6 +38,13 @@ impl<'a> PlaceDetailsRequest<'a> {
             None => return Err(PlaceDetailsError::QueryNotBuilt)?,
         } // match
 
+        // Fail fast if the circuit breaker is not letting requests through:
+        self.client.check_circuit_breaker()?;
+
+        // Fail fast if a replaying cassette has no response for the request:
+        #[cfg(feature = "vcr")]
+        self.client.check_cassette(&url)?;
+
         // Observe any rate limiting before executing request:
         tracing::info!("making HTTP GET request to Google Maps Place Details API");
 
@@ -39,15 +54,24 @@ impl<'a> PlaceDetailsRequest<'a> {
             .await;
 
         tracing::debug!("{url}");
+        tracing::Span::current().record("http.url", crate::error::sanitize_url(&url).as_str());
+
+        // The language is also sent in the `Accept-Language` header:
+        let language = self.language.as_ref().or(self.client.default_language.as_ref()).map(<&str>::from);
+
+        // Collect the details of the request for the audit trail:
+        let audit = self.client.audit("places", &url);
 
         // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        // retries is returned, we have reached the maximum retries, or the
+        // circuit breaker has opened. Note: errors wrapped in `Transient()`
+        // will retried by the `backoff` crate while errors wrapped in
+        // `Permanent()` will exit the retry loop.
+        let response = self.client.retry(|| async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+            let response = self.client.get_request_with_language(&url, language).await;
+            audit.attempt(&response);
 
             // Check response from the HTTP client:
             match response {
@@ -55,33 +79,46 @@ impl<'a> PlaceDetailsRequest<'a> {
                     // HTTP client was successful getting a response from the
                     // server. Check the HTTP status code:
                     if response.status().is_success() {
+                        // Capture the status and content type before the
+                        // response is consumed, in case the body is not JSON:
+                        let status = response.status().to_string();
+                        let content_type = crate::error::content_type(&response);
                         // If the HTTP GET request was successful, get the
                         // response text:
                         let bytes = response.text().await.map(String::into_bytes);
+                        audit.response_bytes(&bytes);
                         match bytes {
+                            Ok(bytes) if crate::error::is_non_json(content_type.as_deref(), &bytes) => {
+                                // Google responded with something other than JSON,
+                                // such as an HTML error page. This will not be
+                                // fixed by retrying:
+                                let error = PlaceDetailsError::NonJsonResponse {
+                                    status,
+                                    snippet: crate::error::non_json_snippet(&bytes, self.client.capture_response_body),
+                                };
+                                tracing::error!("{}", error);
+                                Err(Permanent(error))
+                            } // Ok(bytes)
                             Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<PlaceDetailsResponse>(&mut bytes) {
+                                match crate::json::parse_json_response::<PlaceDetailsResponse>(&mut bytes, self.client.capture_response_body) {
                                     Ok(deserialized) => {
+                                        tracing::Span::current().record("google.status", deserialized.status.as_str());
+                                        audit.google_status(deserialized.status.as_str());
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == PlacesStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
+                                        if deserialized.status != PlacesStatus::Ok {
+                                            // Google API returned an error. This
+                                            // indicates an issue with the request.
+                                            // In most cases, retrying will not
+                                            // help:
                                             let error = PlaceDetailsError::GoogleMapsService(
-                                                deserialized.status.clone(),
+                                                deserialized.status,
                                                 None,
                                             );
                                             // Check Google API response status
                                             // for error type:
-                                            if deserialized.status == PlacesStatus::UnknownError {
+                                            if error.is_transient() {
                                                 // Only Google's "Unknown Error"
                                                 // is eligible for retries:
                                                 tracing::warn!("{}", error);
@@ -96,11 +133,29 @@ impl<'a> PlaceDetailsRequest<'a> {
                                                 tracing::error!("{}", error);
                                                 Err(Permanent(error))
                                             } // if
+                                        // Google's response was "Ok" but it is missing
+                                        // data that an "Ok" response always has. This
+                                        // will not be fixed by retrying:
+                                        } else if let Some(field) = deserialized.missing_field() {
+                                            let error = PlaceDetailsError::MalformedResponse(
+                                                crate::error::sanitize_url(&url),
+                                                field.to_string(),
+                                            );
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
+                                        } else {
+                                            // If Google's response was "Ok" return the
+                                            // struct deserialized from JSON:
+                                            Ok(deserialized)
                                         } // if
                                     } // Ok(deserialized)
-                                    Err(error) => {
+                                    Err((error, body)) => {
                                         tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(PlaceDetailsError::SimdJson(error)))
+                                        Err(Permanent(PlaceDetailsError::InvalidJsonResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            body,
+                                        )))
                                     } // Err
                                 } // match
                             } // Ok(text)
@@ -114,19 +169,28 @@ impl<'a> PlaceDetailsRequest<'a> {
                           // We got a response from the server but it was not OK.
                           // Only HTTP "500 Server Errors", and HTTP "429 Too Many
                           // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: PlaceDetailsError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
                     } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(PlaceDetailsError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
+                        let status = response.status();
+                        // Google may respond with an HTML error page, for example
+                        // when the API key is entirely wrong:
+                        let error = crate::error::non_json_body(response, self.client.capture_response_body)
+                            .await
+                            .map_or_else(
+                                || PlaceDetailsError::HttpUnsuccessful(status.to_string()),
+                                |snippet| PlaceDetailsError::NonJsonResponse { status: status.to_string(), snippet },
+                            ); // map_or_else
+                        if error.is_transient() {
+                            tracing::warn!("HTTP client returned: {}", status);
+                            Err(Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        // Not a 500 Server Error or "429 Too Many Requests"
+                        // error. The error is permanent, do not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}", status);
+                            Err(Permanent(error))
+                        } // if
                     } // if
                 } // case
                 // HTTP client did not get a response from the server. Retry:
@@ -139,9 +203,48 @@ impl<'a> PlaceDetailsRequest<'a> {
                 } // case
             } // match
         })
-        .await?;
+        .await;
+
+        // Record the request in the audit trail, now that retries are over:
+        audit.finish();
+        let response = response?;
 
         // Return response to caller:
         Ok(response)
     } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<PlaceDetailsResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+
+    #[tokio::test]
+    async fn replays_place_details() {
+        let client = replay_client("places");
+        let response = client
+            .place_details("ChIJN1t_tDeuEmsRUsoyG83frY4")
+            .execute()
+            .await
+            .unwrap();
+        let place = response.result.unwrap();
+        assert_eq!(place.name.as_deref(), Some("Google Workplace 6"));
+    } // fn
+} // mod
@@ -28,13 +28,13 @@ use crate::{client::GoogleMapsClient, types::Language, types::Region};
 /// _Place Autocomplete_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// A textual identifier that uniquely identifies a place, returned from a
     /// [Place Search](https://developers.google.com/maps/documentation/places/web-service/search).
@@ -162,3 +162,23 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("place_id", &self.place_id)
+            .field("fields", &self.fields)
+            .field("language", &self.language)
+            .field("region", &self.region)
+            .field("reviews_no_translations", &self.reviews_no_translations)
+            .field("reviews_sort", &self.reviews_sort)
+            .field("sessiontoken", &self.sessiontoken)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
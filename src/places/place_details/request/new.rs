@@ -3,7 +3,7 @@ use crate::places::place_details::request::Request;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Places API _Place Details_ query
@@ -20,11 +20,11 @@ impl Request<'_> {
     ///   [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, place_id: impl Into<String>) -> Request {
+    pub fn new(client: &GoogleMapsClient, place_id: impl Into<String>) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             place_id: place_id.into(),
             // Optional parameters:
             fields: Vec::new(),
@@ -3,7 +3,7 @@ use crate::places::place_details::Field;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Sets the requested fields to the Places API _Place Details_ query.
     ///
     /// ## Arguments
@@ -42,9 +42,9 @@ impl<'a> Request<'a> {
     /// `F` generic is for any type that can be converted to the `Field` type.
 
     pub fn with_fields<C, F>(
-        &'a mut self,
+        &mut self,
         fields: C
-    ) -> &'a mut Self
+    ) -> &mut Self
     where
         C: IntoIterator<Item = F>,
         F: Into<Field> {
@@ -2,7 +2,7 @@ use crate::places::place_details::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies whether Google should provide translations of user reviews in
     /// the Places API _Place Details_ response.
     ///
@@ -19,9 +19,9 @@ impl<'a> Request<'a> {
     /// header as the preferred language.
 
     pub fn with_no_review_translations(
-        &'a mut self,
+        &mut self,
         no_translations: impl Into<bool>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set translations setting in Request struct.
         self.reviews_no_translations = Some(no_translations.into());
         // Return modified Request struct to caller.
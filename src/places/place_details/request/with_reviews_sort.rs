@@ -3,7 +3,7 @@ use crate::places::place_details::SortOrder;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the sort order of user reviews in the Places API _Place
     /// Details_ response.
     ///
@@ -23,9 +23,9 @@ impl<'a> Request<'a> {
     /// the end user.
 
     pub fn with_reviews_sort(
-        &'a mut self,
+        &mut self,
         sort_order: impl Into<SortOrder>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set sort order setting in Request struct.
         self.reviews_sort = Some(sort_order.into());
         // Return modified Request struct to caller.
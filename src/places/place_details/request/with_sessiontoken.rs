@@ -2,7 +2,7 @@ use crate::places::place_details::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Specifies the session token for the Places API _Place Details_ request.
     ///
     /// ## Arguments
@@ -37,9 +37,9 @@ impl<'a> Request<'a> {
     ///   billed individually.
 
     pub fn with_sessiontoken(
-        &'a mut self,
+        &mut self,
         sessiontoken: impl Into<String>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set session token in Request struct.
         self.sessiontoken = Some(sessiontoken.into());
         // Return modified Request struct to caller.
@@ -7,6 +7,7 @@
 
 use crate::places::status::Status;
 use crate::places::Place;
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Places API _Place Details_ request
@@ -49,12 +50,55 @@ pub struct Response {
 
 // -----------------------------------------------------------------------------
 
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns a stable key that identifies the place in this response, that
+    /// is its `place_id`. Two responses for the same place have the same key,
+    /// and compare and hash as equal because `Place` does.
+
+    #[must_use]
+    pub fn key(&self) -> Option<&str> {
+        self.result.as_ref().and_then(Place::key)
+    } // fn
+
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn missing_field(&self) -> Option<&'static str> {
+        self.result.is_none().then_some("result")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API _Place Details_ JSON response into a
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn place_details_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("places", 2));
+    } // fn
+} // mod
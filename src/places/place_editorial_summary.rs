@@ -2,7 +2,7 @@
 //! overview, and also includes the language code for these if applicable.
 //! Summary text must be presented as-is and can not be modified or altered.
 
-use crate::types::Language;
+use crate::types::{Language, MaybeKnown};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -15,7 +15,7 @@ use serde::{Deserialize, Serialize};
 pub struct PlaceEditorialSummary {
     /// The language of the previous fields. May not always be present.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<Language>,
+    pub language: Option<MaybeKnown<Language>>,
 
     /// A medium-length textual summary of the place.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -24,12 +24,12 @@ pub struct PlaceEditorialSummary {
 
 // -----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for PlaceEditorialSummary {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API JSON response into a usable
     /// `PlaceEditorialSummary` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
@@ -1,6 +1,7 @@
 //! An object describing the opening hours of a place.
 
 use crate::places::{PlaceOpeningHoursPeriod, PlaceSpecialDay, SecondaryHoursType};
+use crate::types::MaybeKnown;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
@@ -37,7 +38,7 @@ pub struct PlaceOpeningHours {
     /// `BREAKFAST`, `LUNCH`, `DINNER`, `BRUNCH`, `PICKUP`, `SENIOR_HOURS`). Set
     /// for `secondary_opening_hours` only.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub secondary_hours_type: Option<SecondaryHoursType>,
+    pub secondary_hours_type: Option<MaybeKnown<SecondaryHoursType>>,
 
     /// An array of strings describing in human-readable text the hours of the
     /// place.
@@ -48,13 +49,13 @@ pub struct PlaceOpeningHours {
 
 // -----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for PlaceOpeningHours {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API JSON response into a usable
     /// `PlaceOpeningHours` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
 
@@ -1,9 +1,12 @@
 //! A review of the place submitted by a user.
 
-use crate::types::Language;
-use chrono::{DateTime, LocalResult, TimeZone, Utc};
-use serde::de::{Unexpected, Visitor};
-use serde::{Deserialize, Deserializer, Serialize};
+use crate::types::{Language, MaybeKnown};
+use crate::serde::{
+    datetime_to_unix::datetime_to_unix, number_or_string::number_or_string,
+    unix_to_datetime::unix_to_datetime,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
 //
@@ -17,6 +20,7 @@ pub struct PlaceReview {
 
     /// The user's overall rating for this place. This is a whole number,
     /// ranging from 1 to 5.
+    #[serde(deserialize_with = "number_or_string")]
     pub rating: u8,
 
     /// The time that the review was submitted in text, relative to the current
@@ -24,8 +28,12 @@ pub struct PlaceReview {
     pub relative_time_description: String,
 
     /// The time that the review was submitted, measured in the number of
-    /// seconds since since midnight, January 1, 1970 UTC.
-    #[serde(deserialize_with = "integer_as_date_time")]
+    /// seconds since since midnight, January 1, 1970 UTC. The raw Unix
+    /// timestamp is available through the `timestamp` method.
+    #[serde(
+        deserialize_with = "unix_to_datetime",
+        serialize_with = "datetime_to_unix"
+    )]
     pub time: DateTime<Utc>,
 
     /// The URL to the user's Google Maps Local Guides profile, if available.
@@ -38,7 +46,7 @@ pub struct PlaceReview {
     /// are tagged as 'en', and not 'en-AU' or 'en-UK' and so on. This field is
     /// empty if there is only a rating with no review text.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub language: Option<Language>,
+    pub language: Option<MaybeKnown<Language>>,
 
     /// An IETF language code indicating the original language of the review. If
     /// the review has been translated, then `original_language` != language. This
@@ -47,7 +55,7 @@ pub struct PlaceReview {
     /// tagged as 'en', and not 'en-AU' or 'en-UK' and so on. This field is
     /// empty if there is only a rating with no review text.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub original_language: Option<Language>,
+    pub original_language: Option<MaybeKnown<Language>>,
 
     /// The URL to the user's profile photo, if available.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -71,59 +79,24 @@ pub struct PlaceReview {
 
 // -----------------------------------------------------------------------------
 
-fn integer_as_date_time<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    deserializer.deserialize_u64(DateTimeUtcVisitor)
-} // fn integer_as_date_time
-
-struct DateTimeUtcVisitor;
-
-impl Visitor<'_> for DateTimeUtcVisitor {
-    type Value = DateTime<Utc>;
-
-    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("integer representation of seconds since January 1, 1970 UTC")
-        // write_str
-    } // fn expecting
-
-    fn visit_u64<E>(self, value: u64) -> Result<DateTime<Utc>, E>
-    where
-        E: serde::de::Error,
-    {
-        let value: i64 = match value.try_into() {
-            Ok(value_as_i64) => value_as_i64,
-            Err(_error) => {
-                return Err(
-                    E::invalid_value(
-                        Unexpected::Unsigned(value),
-                        &"UNIX timestamp representing seconds since January 1, 1970 UTC",
-                    ), // invalid_value
-                );
-            } // Err
-        }; // match
-
-        match Utc.timestamp_opt(value, 0) {
-            LocalResult::Single(date_time_utc) => Ok(date_time_utc),
-            _ => Err(
-                E::invalid_value(
-                    Unexpected::Signed(value),
-                    &"UNIX timestamp representing seconds since January 1, 1970 UTC",
-                ), // invalid_value
-            ), // Err
-        } // match
-    } // fn visit_i64
-} // impl Visitor
+impl PlaceReview {
+    /// Returns the time that the review was submitted as a raw Unix timestamp,
+    /// that is the number of seconds since midnight, January 1, 1970 UTC. This
+    /// is the value as it was originally returned by Google.
+    #[must_use]
+    pub const fn timestamp(&self) -> i64 {
+        self.time.timestamp()
+    } // fn
+} // impl
 
 // -----------------------------------------------------------------------------
 
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for PlaceReview {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Places API JSON response into a usable
     /// `PlaceReview` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn from_str
 } // impl FromStr
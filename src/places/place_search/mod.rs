@@ -48,3 +48,43 @@
 
 pub mod nearby_search;
 pub mod text_search;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+use crate::{
+    error::Error as GoogleMapsError,
+    places::{error::Error as PlacesError, status::Status as PlacesStatus},
+}; // crate
+
+/// This function is not for public consumption. It is for internal use only.
+///
+/// There is a short delay between when a `next_page_token` is issued, and when
+/// it will become valid. Requesting the next page before it is available will
+/// return an `INVALID_REQUEST` response. This function retries `get` with a
+/// short back-off for as long as Google responds with `INVALID_REQUEST`, for up
+/// to ten seconds.
+
+#[cfg(feature = "reqwest")]
+pub(crate) async fn get_next_page<F, Fut, R>(get: F) -> Result<R, GoogleMapsError>
+where
+    F: Fn() -> Fut + Sync,
+    Fut: std::future::Future<Output = Result<R, GoogleMapsError>> + Send,
+    R: Send {
+    let backoff = backoff::ExponentialBackoff {
+        initial_interval: std::time::Duration::from_millis(500),
+        max_elapsed_time: Some(std::time::Duration::from_secs(10)),
+        ..backoff::ExponentialBackoff::default()
+    }; // ExponentialBackoff
+
+    backoff::future::retry(backoff, || async {
+        get().await.map_err(|error| match error {
+            GoogleMapsError::Places(PlacesError::GoogleMapsService(
+                PlacesStatus::InvalidRequest,
+                _,
+            )) => backoff::Error::transient(error),
+            error => backoff::Error::permanent(error),
+        }) // map_err
+    }) // retry
+    .await
+} // fn
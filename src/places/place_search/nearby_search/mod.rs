@@ -24,3 +24,48 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 pub use crate::places::{error::Error, status::Status}; // place_search
 
 pub use crate::places::place_search::nearby_search::{request::Request, response::Response}; // nearby_search
+
+// -----------------------------------------------------------------------------
+//
+/// Searches for places around `center`, widening the search radius until
+/// places are found. The search starts at `start_radius` meters, and the
+/// radius is multiplied by `factor` after each empty search, up to
+/// `max_radius` meters. See `Request::execute_expanding` for more
+/// information.
+///
+/// To filter the search, for example by place type, build the request with
+/// `GoogleMapsClient::nearby_search` and call `execute_expanding` on it.
+///
+/// ## Example
+///
+/// ```rust
+/// let search_results = nearby_search::search_expanding(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(53.740_989), dec!(-113.493_768))?,
+///     500,
+///     50_000,
+///     2.0,
+/// ).await?;
+/// ```
+///
+/// # Errors
+///
+/// * Returns the error of the last search if no places are found within
+///   `max_radius`, or the error of any search that failed for another reason.
+///
+/// * Returns `Error::InvalidExpansionFactor` without sending a search if
+///   `factor` is not a finite number greater than `1.0`.
+
+#[cfg(feature = "reqwest")]
+pub async fn search_expanding(
+    client: &crate::client::GoogleMapsClient,
+    center: impl Into<crate::LatLng>,
+    start_radius: u32,
+    max_radius: u32,
+    factor: f64,
+) -> Result<Response, crate::error::Error> {
+    client
+        .nearby_search(center, start_radius)
+        .execute_expanding(max_radius, factor)
+        .await
+} // fn
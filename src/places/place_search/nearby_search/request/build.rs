@@ -3,22 +3,28 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Places API _Nearby Search_
     /// query based on the input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
             "key={}&location={}&radius={}",
             self.client.key,
-            String::from(&self.location),
+            String::from(&*self.client.coordinates(&self.location)),
             self.radius,
         );
 
@@ -26,11 +32,13 @@ impl<'a> Request<'a> {
         // string:
 
         if let Some(keyword) = &self.keyword {
-            query.push_str("&keyword={}");
+            query.push_str("&keyword=");
             query.push_str(&utf8_percent_encode(keyword, NON_ALPHANUMERIC).to_string());
         }
 
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
@@ -45,9 +53,14 @@ impl<'a> Request<'a> {
             query.push_str(&minprice.to_string());
         }
 
+        if let Some(name) = &self.name {
+            query.push_str("&name=");
+            query.push_str(&utf8_percent_encode(name, NON_ALPHANUMERIC).to_string());
+        }
+
         if let Some(opennow) = &self.opennow {
             if *opennow {
-                query.push_str("&opennow");
+                query.push_str("&opennow=true");
             }
         }
 
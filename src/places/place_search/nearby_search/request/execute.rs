@@ -5,7 +5,7 @@ use crate::places::place_search::nearby_search::{
 
 // =============================================================================
 
-impl<'a> TextSearchRequest<'a> {
+impl TextSearchRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> TextSearchRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<TextSearchResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<TextSearchResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
@@ -0,0 +1,165 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::error::Error as PlacesError;
+use crate::places::place_search::nearby_search::{request::Request, response::Response};
+use crate::places::{status::Status, RankBy};
+
+// -----------------------------------------------------------------------------
+
+/// The most searches that `execute_expanding` sends for one call, including
+/// the first one. This bounds the cost of a call no matter how close `factor`
+/// is to `1.0`.
+const MAX_SEARCHES: usize = 10;
+
+// =============================================================================
+
+impl Request {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built, widening the search radius until
+    /// places are found.
+    ///
+    /// ## Description
+    ///
+    /// The search is first sent with the request's radius. If Google finds no
+    /// places, the radius is multiplied by `factor` and the search is sent
+    /// again, until places are found or the radius reaches `max_radius`. The
+    /// response of the first search that found places is returned. Each
+    /// search observes the client's rate limiter, like any other request.
+    ///
+    /// If no places are found within `max_radius`, the result of the last
+    /// search is returned. This is usually a `ZERO_RESULTS` error, the same as
+    /// `execute` returns.
+    ///
+    /// Every search is billed, so at most 10 searches are sent. A `factor` of
+    /// `2.0` from `500` meters reaches `50,000` meters, the Nearby Search's
+    /// maximum radius, in 8 searches.
+    ///
+    /// Google ignores the radius when the results are ranked by distance
+    /// (`RankBy::Distance`), so in that case only one search is sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_radius` ‧ The largest radius to search, in meters.
+    ///
+    /// * `factor` ‧ What the radius is multiplied by after each empty search.
+    ///   Must be a finite number greater than `1.0`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let search_results = google_maps_client.nearby_search(location, 500)
+    ///     .with_type(PlaceType::Pharmacy)
+    ///     .execute_expanding(50_000, 2.0)
+    ///     .await?;
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// * Returns `Error::InvalidExpansionFactor` without sending a search if
+    ///   `factor` is not a finite number greater than `1.0`.
+
+    pub async fn execute_expanding(
+        &mut self,
+        max_radius: u32,
+        factor: f64,
+    ) -> Result<Response, GoogleMapsError> {
+        if !factor.is_finite() || factor <= 1.0 {
+            return Err(PlacesError::InvalidExpansionFactor(factor).into());
+        } // if
+
+        let mut searches = 0;
+
+        loop {
+            let response = self.build().get().await;
+            searches += 1;
+
+            let empty = match &response {
+                Ok(response) => response.results.is_empty(),
+                Err(GoogleMapsError::Places(error)) => error.google_status() == Some(&Status::ZeroResults),
+                Err(_) => false,
+            }; // match
+
+            if !empty
+                || self.radius >= max_radius
+                || searches >= MAX_SEARCHES
+                || self.rankby == Some(RankBy::Distance)
+            {
+                return response;
+            } // if
+
+            let radius = (f64::from(self.radius) * factor).ceil();
+            self.radius = if radius < f64::from(max_radius) {
+                // The radius is below `max_radius`, so it fits in a `u32`:
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let radius = radius as u32;
+                radius.max(self.radius + 1)
+            } else {
+                max_radius
+            }; // if
+
+            tracing::info!("no places found, widening the Nearby Search radius to {} meters", self.radius);
+        } // loop
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error as GoogleMapsError;
+    use crate::places::error::Error as PlacesError;
+    use crate::{GoogleMapsClient, LatLng};
+
+    fn center() -> LatLng {
+        LatLng::try_from_f64(-33.867_052_2, 151.195_736_2).unwrap()
+    } // fn
+
+    #[tokio::test]
+    async fn rejects_factors_that_do_not_grow_the_radius() {
+        let client = GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        for factor in [1.0, 0.5, -2.0, f64::NAN, f64::INFINITY] {
+            let result = client
+                .nearby_search(center(), 500_u32)
+                .execute_expanding(50_000, factor)
+                .await;
+            assert!(matches!(
+                result,
+                Err(GoogleMapsError::Places(PlacesError::InvalidExpansionFactor(_)))
+            ));
+        } // for
+    } // fn
+
+    #[cfg(feature = "vcr")]
+    #[tokio::test]
+    async fn searches_once_when_ranked_by_distance() {
+        use crate::places::{RankBy, Status};
+        let client = crate::cassette::tests::replay_client("nearby_search_expanding");
+        let result = client
+            .nearby_search(center(), 500_u32)
+            .with_keyword("pizza")
+            .with_rankby(RankBy::Distance)
+            .execute_expanding(50_000, 2.0)
+            .await;
+        // A second search would not be in the cassette:
+        assert!(matches!(
+            result,
+            Err(GoogleMapsError::Places(error)) if error.google_status() == Some(&Status::ZeroResults)
+        ));
+    } // fn
+
+    #[cfg(feature = "vcr")]
+    #[tokio::test]
+    async fn stops_after_the_maximum_number_of_searches() {
+        use crate::places::Status;
+        let client = crate::cassette::tests::replay_client("nearby_search_expanding");
+        let result = client
+            .nearby_search(center(), 1_u32)
+            .execute_expanding(50_000, 1.000_1)
+            .await;
+        // The cassette has the first 10 searches, from 1 to 10 meters:
+        assert!(matches!(
+            result,
+            Err(GoogleMapsError::Places(error)) if error.google_status() == Some(&Status::ZeroResults)
+        ));
+    } // fn
+} // mod
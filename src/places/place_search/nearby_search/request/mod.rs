@@ -7,14 +7,19 @@ mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
+mod execute_expanding;
+#[cfg(feature = "reqwest")]
 mod get;
 mod is_open_now;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod stream;
 mod with_keyword;
 mod with_language;
 mod with_max_price;
 mod with_min_price;
+mod with_name;
 mod with_pagetoken;
 mod with_rankby;
 mod with_type;
@@ -31,13 +36,13 @@ use crate::{client::GoogleMapsClient, types::Language, types::PlaceType};
 /// _Nearby Search_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The point around which to retrieve place information. This must be
     /// specified as `latitude,longitude`.
@@ -120,6 +125,11 @@ pub struct Request<'a> {
     /// region to region.
     minprice: Option<u8>,
 
+    /// A term to be matched against all content that Google has indexed for
+    /// this place. Equivalent to `keyword`, with which it is combined. Google
+    /// has deprecated this parameter in favour of `keyword`.
+    name: Option<String>,
+
     /// Returns only those places that are open for business at the time the
     /// query is sent. Places that do not specify opening hours in the Google
     /// Places database will not be returned if you include this parameter in
@@ -165,3 +175,27 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("location", &self.location)
+            .field("radius", &self.radius)
+            .field("keyword", &self.keyword)
+            .field("language", &self.language)
+            .field("maxprice", &self.maxprice)
+            .field("minprice", &self.minprice)
+            .field("name", &self.name)
+            .field("opennow", &self.opennow)
+            .field("pagetoken", &self.pagetoken)
+            .field("rankby", &self.rankby)
+            .field("place_type", &self.place_type)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
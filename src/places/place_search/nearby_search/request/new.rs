@@ -4,7 +4,7 @@ use crate::LatLng;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Places API _Nearby Search_ query
@@ -38,11 +38,11 @@ impl Request<'_> {
     /// * Nearby Search: 50,000 meters
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, location: LatLng, radius: u32) -> Request {
+    pub fn new(client: &GoogleMapsClient, location: LatLng, radius: u32) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             location,
             radius,
             // Optional parameters:
@@ -50,6 +50,7 @@ impl Request<'_> {
             language: None,
             maxprice: None,
             minprice: None,
+            name: None,
             opennow: None,
             pagetoken: None,
             rankby: None,
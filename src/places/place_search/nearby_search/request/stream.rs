@@ -0,0 +1,57 @@
+use crate::places::place_search::{get_next_page, nearby_search::request::Request};
+use crate::places::Place;
+use crate::PageStream;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Returns a stream of every place matching the query you've built,
+    /// following the `next_page_token` from one page to the next.
+    ///
+    /// ## Description
+    ///
+    /// The _Nearby Search_ returns up to 20 places per page, and up to 60 places
+    /// in total. The stream requests the first page when it is first polled,
+    /// and only requests the next page once every place of the current page
+    /// has been consumed. Stop consuming the stream, or drop it, and no more
+    /// pages will be requested.
+    ///
+    /// If a page can not be fetched, the error is yielded and the stream ends.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut places = request.stream().take(30);
+    /// while let Some(place) = places.next().await {
+    ///     println!("{}", place?.name.unwrap_or_default());
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn stream(&self) -> PageStream<Place> {
+        let request = self.clone();
+        PageStream::new(None, move |pagetoken: Option<String>| {
+            let mut request = request.clone();
+            async move {
+                let response = match pagetoken {
+                    None => request.build().get().await?,
+                    Some(pagetoken) => {
+                        request.with_pagetoken(pagetoken).build();
+                        get_next_page(|| {
+                            let mut request = request.clone();
+                            async move { request.get().await }
+                        })
+                        .await?
+                    } // Some
+                }; // match
+                Ok((response.results, response.next_page_token.map(Some)))
+            } // async
+        }) // PageStream
+    } // fn
+} // impl
@@ -2,7 +2,7 @@ use crate::places::place_search::nearby_search::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the searched text string to the Places API _Nearby Search_ query.
     ///
     /// ## Arguments
@@ -22,10 +22,10 @@ impl<'a> Request<'a> {
     /// `CLOSED_TEMPORARILY` or `CLOSED_PERMANENTLY` will not be returned.
 
     pub fn with_keyword(
-        &'a mut self,
+        &mut self,
         keyword: impl Into<String>
-    ) -> &'a mut Self {
-        // Set maximum price in Request struct.
+    ) -> &mut Self {
+        // Set keyword in Request struct.
         self.keyword = Some(keyword.into());
         // Return modified Request struct to caller.
         self
@@ -2,7 +2,7 @@ use crate::places::place_search::nearby_search::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the minimum price to the Places API _Nearby Search_ query.
     ///
     /// ## Arguments
@@ -13,9 +13,9 @@ impl<'a> Request<'a> {
     ///   value will vary from region to region.
 
     pub fn with_min_price(
-        &'a mut self,
+        &mut self,
         minprice: impl Into<u8>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set minimum price in Request struct.
         self.minprice = Some(minprice.into());
         // Return modified Request struct to caller.
@@ -0,0 +1,27 @@
+use crate::places::place_search::nearby_search::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Adds the name parameter to the Places API _Nearby Search_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `name` ‧ A term to be matched against all content that Google has
+    ///   indexed for this place. This is equivalent to `keyword`: values in
+    ///   this field are combined with the `keyword` value and passed as part
+    ///   of the same search string.
+    ///
+    /// Google has deprecated this parameter in favour of `keyword`. Prefer
+    /// `with_keyword` in new code.
+
+    pub fn with_name(
+        &mut self,
+        name: impl Into<String>
+    ) -> &mut Self {
+        // Set name in Request struct.
+        self.name = Some(name.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -2,7 +2,7 @@ use crate::places::place_search::nearby_search::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the page token parameter to the Places API _Nearby Search_ query.
     ///
     /// ## Arguments
@@ -13,9 +13,9 @@ impl<'a> Request<'a> {
     ///   be ignored.
 
     pub fn with_pagetoken(
-        &'a mut self,
+        &mut self,
         pagetoken: impl Into<String>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set page token in Request struct.
         self.pagetoken = Some(pagetoken.into());
         // Return modified Request struct to caller.
@@ -3,7 +3,7 @@ use crate::places::RankBy;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the rank-by order parameter to the Places API _Nearby Search_
     /// query.
     ///
@@ -25,9 +25,9 @@ impl<'a> Request<'a> {
     ///   radius is disallowed.
 
     pub fn with_rankby(
-        &'a mut self,
+        &mut self,
         rankby: impl Into<RankBy>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set rannk by order in Request struct.
         self.rankby = Some(rankby.into());
         // Return modified Request struct to caller.
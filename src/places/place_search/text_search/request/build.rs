@@ -3,15 +3,21 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Places API _Text Search_
     /// query based on the input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -24,8 +30,9 @@ impl<'a> Request<'a> {
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
@@ -33,7 +40,7 @@ impl<'a> Request<'a> {
         // Location key/value pair:
         if let Some(location) = &self.location {
             query.push_str("&location=");
-            query.push_str(&String::from(location));
+            query.push_str(&String::from(&*self.client.coordinates(location)));
         }
 
         if let Some(maxprice) = &self.maxprice {
@@ -58,8 +65,9 @@ impl<'a> Request<'a> {
             query.push_str(pagetoken);
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if none
+        // was set for this request:
+        if let Some(region) = self.region.as_ref().or(self.client.default_region.as_ref()) {
             query.push_str("&region=");
             query.push_str(&String::from(region));
         }
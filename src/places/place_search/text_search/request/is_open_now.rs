@@ -2,7 +2,7 @@ use crate::places::place_search::text_search::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds "open now" filter to the Places API _Text Search_ query.
     ///
     /// ## Arguments
@@ -12,7 +12,7 @@ impl<'a> Request<'a> {
     ///   in the Google Places database will not be returned if you include this
     ///   parameter in your query.
 
-    pub fn is_open_now(&'a mut self, opennow: bool) -> &'a mut Self {
+    pub fn is_open_now(&mut self, opennow: bool) -> &mut Self {
         // Set "open now" filter in Request struct.
         self.opennow = Some(opennow);
         // Return modified Request struct to caller.
@@ -11,6 +11,8 @@ mod get;
 mod is_open_now;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod stream;
 mod with_language;
 mod with_location;
 mod with_max_price;
@@ -31,13 +33,13 @@ use crate::{
 /// _Text Search_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The text string on which to search, for example: "restaurant" or "123
     /// Main Street". This must a place name, address, or category of
@@ -154,3 +156,26 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("input", &self.input)
+            .field("radius", &self.radius)
+            .field("language", &self.language)
+            .field("location", &self.location)
+            .field("maxprice", &self.maxprice)
+            .field("minprice", &self.minprice)
+            .field("opennow", &self.opennow)
+            .field("pagetoken", &self.pagetoken)
+            .field("region", &self.region)
+            .field("place_type", &self.place_type)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
@@ -3,7 +3,7 @@ use crate::places::place_search::text_search::request::Request;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Places API _Text Search_ query
@@ -41,11 +41,11 @@ impl Request<'_> {
     /// * Nearby Search: 50,000 meters
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, query: impl Into<String>, radius: u32) -> Request {
+    pub fn new(client: &GoogleMapsClient, query: impl Into<String>, radius: u32) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             input: query.into(),
             radius,
             // Optional parameters:
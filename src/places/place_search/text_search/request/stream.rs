@@ -0,0 +1,94 @@
+use crate::places::place_search::{get_next_page, text_search::request::Request};
+use crate::places::Place;
+use crate::PageStream;
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Returns a stream of every place matching the query you've built,
+    /// following the `next_page_token` from one page to the next.
+    ///
+    /// ## Description
+    ///
+    /// The _Text Search_ returns up to 20 places per page, and up to 60 places
+    /// in total. The stream requests the first page when it is first polled,
+    /// and only requests the next page once every place of the current page
+    /// has been consumed. Stop consuming the stream, or drop it, and no more
+    /// pages will be requested.
+    ///
+    /// If a page can not be fetched, the error is yielded and the stream ends.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut places = request.stream().take(30);
+    /// while let Some(place) = places.next().await {
+    ///     println!("{}", place?.name.unwrap_or_default());
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn stream(&self) -> PageStream<Place> {
+        let request = self.clone();
+        PageStream::new(None, move |pagetoken: Option<String>| {
+            let mut request = request.clone();
+            async move {
+                let response = match pagetoken {
+                    None => request.build().get().await?,
+                    Some(pagetoken) => {
+                        request.with_pagetoken(pagetoken).build();
+                        get_next_page(|| {
+                            let mut request = request.clone();
+                            async move { request.get().await }
+                        })
+                        .await?
+                    } // Some
+                }; // match
+                Ok((response.results, response.next_page_token.map(Some)))
+            } // async
+        }) // PageStream
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn drops_the_stream_without_requesting_the_third_page() {
+        // The cassette has three pages of places, of two, two and one places:
+        let client = replay_client("text_search_pages");
+        let cassette = client.cassette.clone().unwrap();
+
+        let mut places = client.text_search("pizza in New York", 0_u32).stream();
+
+        let mut names = Vec::new();
+        for _place in 0..4 {
+            names.push(places.next().await.unwrap().unwrap().name.unwrap());
+        } // for
+        assert_eq!(
+            names,
+            [
+                "Joe's Pizza",
+                "Prince Street Pizza",
+                "Lombardi's Pizza",
+                "John's of Bleecker Street",
+            ]
+        );
+
+        // Two pages were requested, and the third is never requested once
+        // the stream has been dropped:
+        assert_eq!(cassette.remaining(), 1);
+        drop(places);
+        assert_eq!(cassette.remaining(), 1);
+    } // fn
+} // mod
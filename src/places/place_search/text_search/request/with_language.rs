@@ -3,7 +3,7 @@ use crate::types::Language;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the language parameter to the Places API _Text Search_ query.
     ///
     /// ## Arguments
@@ -36,9 +36,9 @@ impl<'a> Request<'a> {
     ///       Hungarian.
 
     pub fn with_language(
-        &'a mut self,
+        &mut self,
         language: impl Into<Language>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set language in Request struct.
         self.language = Some(language.into());
         // Return modified Request struct to caller.
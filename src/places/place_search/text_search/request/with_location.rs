@@ -3,7 +3,7 @@ use crate::types::LatLng;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the location and radius parameters to the Places API _Text Search_
     /// query.
     ///
@@ -18,9 +18,9 @@ impl<'a> Request<'a> {
     /// `radius`.
 
     pub fn with_location(
-        &'a mut self,
+        &mut self,
         location: impl Into<LatLng>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.location = Some(location.into());
         // Return modified Request struct to caller.
@@ -2,7 +2,7 @@ use crate::places::place_search::text_search::request::Request;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the maximum price to the Places API _Text Search_ query.
     ///
     /// ## Arguments
@@ -13,9 +13,9 @@ impl<'a> Request<'a> {
     ///   value will vary from region to region.
 
     pub fn with_max_price(
-        &'a mut self,
+        &mut self,
         maxprice: impl Into<u8>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set maximum price in Request struct.
         self.maxprice = Some(maxprice.into());
         // Return modified Request struct to caller.
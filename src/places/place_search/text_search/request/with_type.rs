@@ -3,7 +3,7 @@ use crate::types::PlaceType;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the types parameter to the Places API _Text Search_ query.
     ///
     /// ## Arguments
@@ -22,9 +22,9 @@ impl<'a> Request<'a> {
     /// `ZERO_RESULTS`.
 
     pub fn with_type(
-        &'a mut self,
+        &mut self,
         place_type: impl Into<PlaceType>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.place_type = Some(place_type.into());
         // Return modified Request struct to caller.
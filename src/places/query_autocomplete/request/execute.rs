@@ -4,7 +4,7 @@ use crate::places::query_autocomplete::request::Request as QueryAutocompleteRequ
 
 // =============================================================================
 
-impl<'a> QueryAutocompleteRequest<'a> {
+impl QueryAutocompleteRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -19,7 +19,7 @@ impl<'a> QueryAutocompleteRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<PlaceAutocompleteResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
@@ -24,13 +24,13 @@ use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
 /// _Query Autocomplete_ query**. The methods implemented for this struct are
 /// what's used to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The text string on which to search. The Query Autocomplete service will
     /// return candidate matches based on this string and order results based on
@@ -81,7 +81,7 @@ pub struct Request<'a> {
     /// service will attempt to match against `Goo abc`. If no offset is
     /// supplied, the service will use the whole term. The offset should
     /// generally be set to the position of the text caret.
-    offset: Option<u8>,
+    offset: Option<usize>,
 
     /// Defines the distance (in meters) within which to return place results.
     /// You may bias results to a specified circle by passing a location and a
@@ -109,3 +109,21 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("input", &self.input)
+            .field("language", &self.language)
+            .field("location", &self.location)
+            .field("offset", &self.offset)
+            .field("radius", &self.radius)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
@@ -3,7 +3,7 @@ use crate::places::query_autocomplete::request::Request;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Place API _Query Autocomplete_
@@ -16,11 +16,11 @@ impl Request<'_> {
     /// * `input` ‧ The text string on which to search.
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request {
+    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             input: input.into(),
             // Optional parameters:
             language: None,
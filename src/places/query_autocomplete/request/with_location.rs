@@ -3,7 +3,7 @@ use crate::types::LatLng;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> Request<'a> {
+impl Request {
     /// Adds the location and radius parameters to the Place API _Query
     /// Autocomplete_ query.
     ///
@@ -35,10 +35,10 @@ impl<'a> Request<'a> {
     /// * Text Search: 50,000 meters
 
     pub fn with_location_and_radius(
-        &'a mut self,
+        &mut self,
         location: impl Into<LatLng>,
         radius: u32
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set location in Request struct.
         self.location = Some(location.into());
         // Set radius in Request struct.
@@ -22,12 +22,14 @@ pub use rust_decimal_macros::dec;
 
 pub use crate::{
     client::GoogleMapsClient as ClientSettings, client::GoogleMapsClient,
-    error::Error as GoogleMapsError, error::Error, types::error::Error as TypeError,
+    error::Error as GoogleMapsError, error::Error, json::Error as JsonError,
+    types::error::Error as TypeError,
 }; // crate
 
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -42,6 +44,8 @@ pub use crate::types::bounds::Bounds;
 pub use crate::types::country::Country;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
+pub use crate::types::google_status::GoogleStatus;
+pub use crate::types::has_status::HasStatus;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -64,6 +68,7 @@ pub use crate::types::language::Language;
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+pub use crate::types::maybe_known::MaybeKnown;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -80,6 +85,8 @@ pub use crate::types::place_type::PlaceType;
     feature = "places"
 ))]
 pub use crate::types::region::Region;
+#[cfg(feature = "geocoding")]
+pub use crate::types::response_format::ResponseFormat;
 
 // =============================================================================
 //
@@ -87,8 +94,23 @@ pub use crate::types::region::Region;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(all(feature = "places", feature = "reqwest"))]
+pub use crate::page_stream::PageStream;
+
+#[cfg(feature = "reqwest")]
+pub use crate::request_rate::{api::Api, throttle_hook::ThrottleHook};
+
 #[cfg(feature = "reqwest")]
-pub use crate::request_rate::api::Api;
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+
+#[cfg(feature = "reqwest")]
+pub use crate::retry_budget::RetryBudget;
+
+#[cfg(feature = "reqwest")]
+pub use crate::audit::{AuditEntry, AuditSink, ChannelAuditSink, FileAuditSink};
+
+#[cfg(feature = "vcr")]
+pub use crate::cassette::{Cassette, CassetteMode};
 
 // -----------------------------------------------------------------------------
 
@@ -117,7 +139,10 @@ pub use crate::directions::{
 #[cfg(feature = "directions")]
 pub use crate::directions::{
     error::Error as DirectionsError,
-    request::Request as DirectionsRequest,
+    request::{
+        builder::Builder as DirectionsBuilder, ready_request::ReadyRequest as DirectionsReadyRequest,
+        Request as DirectionsRequest,
+    }, // request
     response::{directions_distance::DirectionsDistance, directions_duration::DirectionsDuration}, // response
 }; // crate::directions
 
@@ -125,7 +150,11 @@ pub use crate::directions::{
 
 #[cfg(feature = "distance_matrix")]
 pub use crate::distance_matrix::{
-    error::Error as DistanceMatrixError, request::Request as DistanceMatrixRequest,
+    error::Error as DistanceMatrixError,
+    request::{
+        builder::Builder as DistanceMatrixBuilder,
+        ready_request::ReadyRequest as DistanceMatrixReadyRequest, Request as DistanceMatrixRequest,
+    }, // request
     response::status::Status as DistanceMatrixStatus, response::Response as DistanceMatrixResponse,
 }; // use crate::distance_matrix
 
@@ -134,7 +163,10 @@ pub use crate::distance_matrix::{
 #[cfg(feature = "elevation")]
 pub use crate::elevation::{
     error::Error as ElevationError,
-    request::{locations::Locations as ElevationLocations, Request as ElevationRequest}, // request
+    request::{
+        builder::Builder as ElevationBuilder, locations::Locations as ElevationLocations,
+        ready_request::ReadyRequest as ElevationReadyRequest, Request as ElevationRequest,
+    }, // request
     response::{point::Point, status::Status as ElevationStatus, Response as ElevationResponse}, // response
 }; // crate::elevation
 
@@ -143,12 +175,20 @@ pub use crate::elevation::{
 #[cfg(feature = "geocoding")]
 pub use crate::geocoding::{
     error::Error as GeocodingError,
-    forward::{component::Component as GeocodingComponent, ForwardRequest as GeocodingRequest}, // forward
+    forward::{
+        builder::Builder as GeocodingBuilder, component::Component as GeocodingComponent,
+        parameters::Parameters as GeocodingParameters, ForwardRequest as GeocodingRequest,
+    }, // forward
+    ready_request::ReadyRequest as GeocodingReadyRequest,
     response::{
         geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
         Response as GeocodingResponse,
     }, // response
-    reverse::ReverseRequest as GeocodingReverseRequest,
+    reverse::{
+        builder::Builder as GeocodingReverseBuilder,
+        parameters::Parameters as GeocodingReverseParameters,
+        ReverseRequest as GeocodingReverseRequest,
+    }, // reverse
 }; // crate::geocoding
 
 // -----------------------------------------------------------------------------
@@ -156,8 +196,14 @@ pub use crate::geocoding::{
 #[cfg(feature = "time_zone")]
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
-    request::Request as TimeZoneRequest,
-    response::{status::Status as TimeZoneStatus, Response as TimeZoneResponse}, // reponse
+    request::{
+        builder::Builder as TimeZoneBuilder, ready_request::ReadyRequest as TimeZoneReadyRequest,
+        Request as TimeZoneRequest,
+    }, // request
+    response::{
+        local_time_zone::LocalTimeZone, status::Status as TimeZoneStatus,
+        Response as TimeZoneResponse,
+    }, // reponse
 }; // crate::time_zone
 
 // -----------------------------------------------------------------------------
@@ -182,7 +228,8 @@ pub use crate::places::place_autocomplete::request::Request as QueryAutocomplete
 
 #[cfg(feature = "places")]
 pub use crate::places::{
-    business_status::BusinessStatus, error::Error as PlacesError, place::Place,
+    business_status::BusinessStatus, error::Error as PlacesError,
+    place::Place,
     place_editorial_summary::PlaceEditorialSummary, place_opening_hours::PlaceOpeningHours,
     place_opening_hours_period::PlaceOpeningHoursPeriod,
     place_opening_hours_period_detail::PlaceOpeningHoursPeriodDetail, place_photo::PlacePhoto,
@@ -5,6 +5,7 @@
 use crate::request_rate::target_rate::TargetRate;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 use stream_throttle::ThrottlePool;
 
 /// Contains the user's specified request rate and the effective current request
@@ -13,6 +14,10 @@ use stream_throttle::ThrottlePool;
 pub struct ApiRate {
     pub target_rate: TargetRate,
     pub throttle_pool: Option<ThrottlePool>,
+    /// Hands out turns at the throttle pool in the order that they were
+    /// requested. `tokio`'s mutex is fair, so waiters are served first-in,
+    /// first-out.
+    pub queue: Arc<tokio::sync::Mutex<()>>,
 } // struct
 
 impl std::default::Default for ApiRate {
@@ -21,6 +26,7 @@ impl std::default::Default for ApiRate {
         Self {
             target_rate: TargetRate::default(),
             throttle_pool: None,
+            queue: Arc::default(),
         } // struct
     } // fn
 } // impl
@@ -10,9 +10,14 @@ impl ApiRate {
     /// this method will put the thread to sleep until it is ready for the next
     /// request.
     ///
+    /// Waiters are let through in the order that they called this method, so
+    /// a request can not be starved by newer requests to the same API.
 
     pub async fn limit(&self) {
-        if let Some(wait_pool) = &self.throttle_pool { wait_pool.queue().await }
+        if let Some(wait_pool) = &self.throttle_pool {
+            let _turn = self.queue.lock().await;
+            wait_pool.queue().await;
+        } // if
         /*
         match self.current_rate.first_request {
             // If this is the first request to the API, initialize the
@@ -82,3 +87,39 @@ impl ApiRate {
         */
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::{api::Api, RequestRate};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn lets_waiters_through_in_the_order_that_they_arrived() {
+        // One request every 20 milliseconds, so that the waiters queue up:
+        let mut request_rate = RequestRate::default();
+        request_rate.with_rate(&Api::Directions, 1, Duration::from_millis(20));
+        let api_rate = Arc::new(request_rate.rate_map[&Api::Directions].clone());
+
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let mut waiters = Vec::new();
+        for waiter in 0..20 {
+            let api_rate = Arc::clone(&api_rate);
+            let completed = Arc::clone(&completed);
+            waiters.push(tokio::spawn(async move {
+                api_rate.limit().await;
+                completed.lock().unwrap().push(waiter);
+            }));
+            // Let the waiter start waiting before the next one is spawned:
+            tokio::time::sleep(Duration::from_millis(2)).await;
+        } // for
+
+        for waiter in waiters {
+            waiter.await.unwrap();
+        } // for
+
+        assert_eq!(*completed.lock().unwrap(), (0..20).collect::<Vec<_>>());
+    } // fn
+} // mod
@@ -1,6 +1,6 @@
 use crate::request_rate::{api::Api, duration_to_string::duration_to_string, RequestRate};
 use futures::future;
-use std::time::SystemTime;
+use std::time::{Instant, SystemTime};
 
 impl RequestRate {
     /// This method is not for public consumption. It is for internal use only.
@@ -16,15 +16,30 @@ impl RequestRate {
     /// ## Arguments
     ///
     /// * `apis` ‧ The APIs for which to observe the request rate limit.
+    ///
+    /// Each API's limiter lets waiters through in first-in, first-out order.
+    ///
+    /// If a `ThrottleHook` has been set, it will be called for each API that
+    /// delayed the request.
     pub async fn limit_apis(&self, apis: Vec<&Api>) {
         let mut limit_futures = Vec::new();
         for (key, val) in &self.rate_map {
             if apis.contains(&key) {
-                limit_futures.push(val.limit());
+                limit_futures.push(async move {
+                    let start = Instant::now();
+                    val.limit().await;
+                    (key, start.elapsed())
+                });
             }
         }
         let start = SystemTime::now();
-        future::join_all(limit_futures).await;
+        let waits = future::join_all(limit_futures).await;
+        if let Some(throttle_hook) = &self.throttle_hook {
+            waits
+                .iter()
+                .filter(|(_api, waited)| waited.as_millis() > 10)
+                .for_each(|(api, waited)| throttle_hook.on_throttle(api, *waited));
+        } // if
         let wait_time = SystemTime::now().duration_since(start);
         if let Ok(duration) = wait_time {
             if duration.as_millis() > 10 {
@@ -38,3 +53,68 @@ impl RequestRate {
         }
     }
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::request_rate::{api::Api, RequestRate};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn reports_each_throttled_request_to_the_hook() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let captured = Arc::clone(&events);
+
+        // One request every 50 milliseconds:
+        let mut request_rate = RequestRate::default();
+        request_rate
+            .with_rate(&Api::Directions, 1, Duration::from_millis(50))
+            .with_throttle_hook(move |api: &Api, waited: Duration| {
+                captured.lock().unwrap().push((api.clone(), waited));
+            });
+
+        for _request in 0..3 {
+            request_rate.limit_apis(vec![&Api::All, &Api::Directions]).await;
+        } // for
+
+        // The first request is let through immediately, the next two wait:
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        for (api, waited) in events.iter() {
+            assert_eq!(api, &Api::Directions);
+            assert!(*waited >= Duration::from_millis(25), "{waited:?}");
+        } // for
+    } // fn
+
+    #[tokio::test]
+    async fn does_not_report_requests_that_were_not_throttled() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let captured = Arc::clone(&events);
+
+        let mut request_rate = RequestRate::default();
+        request_rate
+            .with_rate(&Api::Directions, 100, Duration::from_secs(1))
+            .with_throttle_hook(move |api: &Api, waited: Duration| {
+                captured.lock().unwrap().push((api.clone(), waited));
+            });
+
+        request_rate.limit_apis(vec![&Api::All, &Api::Directions]).await;
+
+        assert!(events.lock().unwrap().is_empty());
+    } // fn
+
+    #[tokio::test]
+    async fn a_rate_on_one_api_does_not_throttle_another() {
+        let mut request_rate = RequestRate::default();
+        request_rate.with_rate(&Api::Directions, 1, Duration::from_secs(60));
+
+        // Directions is limited to one request a minute, but Geocoding has no
+        // rate of its own, so back-to-back requests don't wait:
+        let started = std::time::Instant::now();
+        request_rate.limit_apis(vec![&Api::All, &Api::Geocoding]).await;
+        request_rate.limit_apis(vec![&Api::All, &Api::Geocoding]).await;
+        assert!(started.elapsed() < Duration::from_secs(1), "{:?}", started.elapsed());
+    } // fn
+} // mod
@@ -12,26 +12,34 @@ mod duration_unit;
 mod limit;
 mod rate_to_string;
 mod target_rate;
+pub mod throttle_hook;
 mod with_rate;
+mod with_throttle_hook;
 
 // -----------------------------------------------------------------------------
 
 use crate::request_rate::api::Api;
 use crate::request_rate::api_rate::ApiRate;
+use crate::request_rate::throttle_hook::ThrottleHook;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 // -----------------------------------------------------------------------------
 //
 /// Contains the request rates for the Google Maps Platform and the individual
 /// Google Maps APIs.
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone)]
 pub struct RequestRate {
     /// Used to specify the request rate for _all_ APIs in addition to the
     /// per-API request rates. The `Api::All` request rate will be observed
     /// first, then the per-API request rate such as `Api::Directions` will be
     /// observed afterward.
     pub rate_map: HashMap<Api, ApiRate>,
+
+    /// An optional hook that is called each time the rate limiter delays a
+    /// request. See the `ThrottleHook` trait for more information.
+    pub throttle_hook: Option<Arc<dyn ThrottleHook>>,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -41,6 +49,28 @@ impl std::default::Default for RequestRate {
     fn default() -> Self {
         Self {
             rate_map: HashMap::new(),
+            throttle_hook: None,
         } // struct
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for RequestRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("RequestRate")
+            .field("rate_map", &self.rate_map)
+            .field("throttle_hook", &self.throttle_hook.is_some())
+            .finish()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl PartialEq for RequestRate {
+    fn eq(&self, other: &Self) -> bool {
+        self.rate_map == other.rate_map
+    } // fn
+} // impl
+
+impl Eq for RequestRate {}
@@ -0,0 +1,41 @@
+//! Contains the `ThrottleHook` trait. It is used to observe how often, and for
+//! how long, requests are being delayed by the client's rate limiter.
+
+use crate::request_rate::api::Api;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Implement this trait to be notified each time the rate limiter delays a
+/// request. This can be used to emit metrics (for example, to Prometheus) that
+/// show how often and how long requests are being throttled.
+///
+/// The default `on_throttle` implementation does nothing. Any closure with the
+/// signature `Fn(&Api, Duration)` may also be used as a hook:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::try_new(YOUR_GOOGLE_API_KEY_HERE)?
+///     .with_rate(&Api::All, 1, std::time::Duration::from_secs(2))
+///     .with_throttle_hook(|api: &Api, waited: std::time::Duration| {
+///         println!("`{api}` request was throttled for {waited:?}");
+///     })
+///     .build();
+/// ```
+
+pub trait ThrottleHook: Send + Sync {
+    /// Called after the rate limiter has delayed a request to the `api` API.
+    /// `waited` is the amount of time that the request spent waiting.
+    fn on_throttle(&self, _api: &Api, _waited: Duration) {}
+} // trait
+
+// -----------------------------------------------------------------------------
+
+impl<F> ThrottleHook for F
+where
+    F: Fn(&Api, Duration) + Send + Sync,
+{
+    /// Calls the closure with the throttled API and the time spent waiting.
+    fn on_throttle(&self, api: &Api, waited: Duration) {
+        self(api, waited);
+    } // fn
+} // impl
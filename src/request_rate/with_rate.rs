@@ -1,4 +1,5 @@
 use crate::request_rate::{api::Api, api_rate::ApiRate, target_rate::TargetRate, RequestRate}; // use crate::request_rate
+use std::sync::Arc;
 use std::time::Duration;
 use stream_throttle::{ThrottlePool, ThrottleRate};
 
@@ -65,6 +66,7 @@ impl RequestRate {
                     ApiRate {
                         target_rate: TargetRate { requests, duration },
                         throttle_pool,
+                        queue: Arc::default(),
                     },
                 );
             }
@@ -75,6 +77,7 @@ impl RequestRate {
                     // Set new target request rate:
                     target_rate: TargetRate { requests, duration },
                     throttle_pool,
+                    queue: Arc::default(),
                 };
             } // ApiRate
         } // match
@@ -0,0 +1,21 @@
+use crate::request_rate::{throttle_hook::ThrottleHook, RequestRate};
+use std::sync::Arc;
+
+// =============================================================================
+
+impl RequestRate {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the hook that will be called each time the rate limiter delays a
+    /// request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `throttle_hook` ‧ Any type that implements the `ThrottleHook` trait,
+    ///   or a closure with the signature `Fn(&Api, Duration)`.
+
+    pub fn with_throttle_hook(&mut self, throttle_hook: impl ThrottleHook + 'static) -> &mut Self {
+        self.throttle_hook = Some(Arc::new(throttle_hook));
+        self
+    } // fn
+} // impl
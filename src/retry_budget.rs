@@ -0,0 +1,146 @@
+//! Contains the `RetryBudget` struct, which limits how many automatic retries
+//! the client makes in proportion to its successful requests. This stops
+//! retries from multiplying the client's traffic during an outage.
+
+use std::sync::Mutex;
+
+// -----------------------------------------------------------------------------
+//
+/// Limits the automatic retries that the client makes, across every request
+/// and every clone of the client. Use the `GoogleMapsClient::with_retry_budget`
+/// method to enable it.
+///
+/// The budget is a bucket of tokens, in the style of the retry budgets of
+/// [Finagle](https://twitter.github.io/finagle/guide/Clients.html#retries) and
+/// [Linkerd](https://linkerd.io/2/reference/retries/#retry-budgets):
+///
+/// * The bucket starts full, with `capacity` tokens.
+///
+/// * Each retry takes one token. A transient failure that would be retried
+///   when the bucket is empty is not retried. The request fails with a
+///   `RetryBudgetExhausted` error instead.
+///
+/// * Each successful attempt puts `retry_ratio` tokens back, up to
+///   `capacity`.
+///
+/// While Google's servers are healthy, retries are limited to `retry_ratio`
+/// times the number of successful attempts, plus a burst of `capacity`. While
+/// they are failing, nothing refills the bucket, so at most `capacity`
+/// retries are made in total. First attempts are never limited.
+
+#[derive(Debug)]
+pub struct RetryBudget {
+    /// The most tokens that the bucket holds.
+    capacity: f64,
+
+    /// How many tokens each successful attempt puts back.
+    retry_ratio: f64,
+
+    /// The tokens currently in the bucket.
+    tokens: Mutex<f64>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl RetryBudget {
+    /// Creates a full retry budget.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ How many retries may be made in a burst, and in total
+    ///   while every attempt is failing.
+    ///
+    /// * `retry_ratio` ‧ How many retries each successful attempt pays for.
+    ///   For example, `0.1` allows one retry for every 10 successful
+    ///   attempts. Negative values are treated as `0.0`.
+
+    #[must_use]
+    pub fn new(capacity: u32, retry_ratio: f64) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            retry_ratio: retry_ratio.max(0.0),
+            tokens: Mutex::new(f64::from(capacity)),
+        } // struct
+    } // fn
+
+    /// Returns how many retries the budget currently allows.
+
+    #[must_use]
+    pub fn available(&self) -> u32 {
+        // The tokens are kept between `0.0` and `capacity`, which is a `u32`:
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let available = self.lock().floor() as u32;
+        available
+    } // fn
+
+    /// Takes a token for a retry. Returns `false`, and takes nothing, if the
+    /// budget is exhausted.
+
+    pub(crate) fn withdraw(&self) -> bool {
+        let mut tokens = self.lock();
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            true
+        } else {
+            false
+        } // if
+    } // fn
+
+    /// Puts tokens back for a successful attempt.
+
+    pub(crate) fn deposit(&self) {
+        let mut tokens = self.lock();
+        *tokens = (*tokens + self.retry_ratio).min(self.capacity);
+    } // fn
+
+    /// Records the outcome of an attempt. Responses that are not server
+    /// errors or `429 Too Many Requests` are successes, and refill the budget.
+
+    pub(crate) fn record(&self, response: &Result<reqwest::Response, crate::ReqError>) {
+        if matches!(response, Ok(response) if !crate::error::is_transient_http_status(response.status().as_str())) {
+            self.deposit();
+        } // if
+    } // fn
+
+    /// Locks the tokens. A poisoned lock is recovered, because the tokens are
+    /// always left consistent.
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, f64> {
+        self.tokens.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "time_zone", feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use crate::LatLng;
+
+    #[tokio::test]
+    async fn bounds_the_attempts_of_concurrent_failing_requests() {
+        // The cassette has 50 `503 Service Unavailable` responses, which is
+        // more than the requests below may use:
+        let mut client = replay_client("retry_budget");
+        client.with_retry_budget(5, 0.1);
+        let cassette = client.cassette.clone().unwrap();
+        let recorded = cassette.remaining();
+
+        let requests = (0..20).map(|_index| async {
+            client
+                .time_zone(
+                    LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                    chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+                )
+                .execute()
+                .await
+        }); // map
+        let results = futures::future::join_all(requests).await;
+
+        // Every request fails, and only the 5 retries in the budget are made
+        // on top of the 20 first attempts:
+        assert!(results.iter().all(Result::is_err));
+        assert_eq!(recorded - cassette.remaining(), 25);
+        assert_eq!(client.retry_budget.as_ref().unwrap().available(), 0);
+    } // fn
+} // mod
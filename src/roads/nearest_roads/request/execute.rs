@@ -5,7 +5,7 @@ use crate::roads::nearest_roads::{
 
 // =============================================================================
 
-impl<'a> NearestRoadsRequest<'a> {
+impl NearestRoadsRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> NearestRoadsRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<NearestRoadsResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<NearestRoadsResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
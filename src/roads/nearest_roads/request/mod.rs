@@ -23,14 +23,17 @@ use crate::types::LatLng;
 /// **Look at this `Request` struct for documentation on how to build your _Snap
 /// To Roads_ query**. The methods implemented for this struct are what's used
 /// to build your request.
+///
+/// The Roads API does not accept `language` or `region` parameters, so this
+/// request has no `with_language` or `with_region` methods.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The points to be snapped. The points parameter accepts a list of
     /// latitude/longitude pairs. Latitude and longitude values should be
@@ -44,3 +47,17 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("points", &self.points)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
@@ -4,7 +4,7 @@ use crate::types::LatLng;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Nearest Roads query with the
@@ -26,11 +26,11 @@ impl Request<'_> {
     ///   list of latitude/longitude pairs.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, points: Vec<LatLng>) -> Request {
+    pub fn new(client: &GoogleMapsClient, points: Vec<LatLng>) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             points,
             // Internal use only:
             query: None,
@@ -6,6 +6,7 @@
 
 use crate::roads::error_response::ErrorResponse;
 use crate::roads::snapped_point::SnappedPoint;
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -23,13 +24,13 @@ pub struct Response {
     /// An array of snapped points. See
     /// [SnappedPoint](https://developers.google.com/maps/documentation/roads/nearest#SnappedPoint)
     /// for more information.
-    #[serde(alias = "snappedPoints")]
+    #[serde(rename = "snappedPoints", alias = "snapped_points")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub snapped_points: Vec<SnappedPoint>,
 
     /// A string containing a user-visible warning.
-    #[serde(alias = "warningMessage")]
+    #[serde(rename = "warningMessage", alias = "warning_message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub warning_message: Option<String>,
 
@@ -41,12 +42,38 @@ pub struct Response {
 
 // -----------------------------------------------------------------------------
 
+impl HasStatus for Response {
+    /// Returns the status of the response. The _Roads API_ only returns a
+    /// status when the request failed, so a response without an `error` is
+    /// `OK`.
+    fn google_status(&self) -> GoogleStatus {
+        self.error.as_ref().map_or(GoogleStatus::Ok, |error| {
+            GoogleStatus::from(error.status.as_str())
+        }) // map_or
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps _Nearest Roads_ JSON `String` response into a
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn nearest_roads_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("roads", 1));
+    } // fn
+} // mod
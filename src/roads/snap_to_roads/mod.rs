@@ -30,6 +30,8 @@
 
 pub mod request;
 pub mod response;
+#[cfg(feature = "reqwest")]
+mod snap_path;
 
 // -----------------------------------------------------------------------------
 
@@ -39,3 +41,5 @@ const SERVICE_URL: &str = "https://roads.googleapis.com/v1/snapToRoads";
 
 pub use crate::roads::snap_to_roads::request::Request as SnapToRoadsRequest;
 pub use crate::roads::snap_to_roads::response::Response as SnapToRoadsResponse;
+#[cfg(feature = "reqwest")]
+pub use crate::roads::snap_to_roads::snap_path::snap_path;
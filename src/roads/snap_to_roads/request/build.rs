@@ -3,17 +3,23 @@ use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Builds the query string for the Google Maps Snap to Roads request based
     /// on the input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -21,7 +27,7 @@ impl<'a> Request<'a> {
         let path: String = self
             .path
             .iter()
-            .map(String::from)
+            .map(|latlng| String::from(&*self.client.coordinates(latlng)))
             .collect::<Vec<String>>()
             .join("|");
 
@@ -5,7 +5,7 @@ use crate::roads::snap_to_roads::{
 
 // =============================================================================
 
-impl<'a> SnapToRoadsRequest<'a> {
+impl SnapToRoadsRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> SnapToRoadsRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<SnapToRoadsResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
@@ -4,20 +4,28 @@ use crate::roads::error::Error as RoadsError;
 use crate::roads::snap_to_roads::{
     request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse, SERVICE_URL,
 };
-use backoff::future::retry;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> SnapToRoadsRequest<'a> {
+impl SnapToRoadsRequest {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(
+            otel.kind = "client",
+            http.method = "GET",
+            http.url = tracing::field::Empty,
+            google.api = "roads",
+            google.status = tracing::field::Empty,
+        )
+    )]
     pub async fn get(&mut self) -> Result<SnapToRoadsResponse, GoogleMapsError> {
         // Build the URL stem for the HTTP get request:
         let mut url = format!("{SERVICE_URL}/?");
@@ -29,6 +37,13 @@ impl<'a> SnapToRoadsRequest<'a> {
             None => return Err(RoadsError::QueryNotBuilt)?,
         } // match
 
+        // Fail fast if the circuit breaker is not letting requests through:
+        self.client.check_circuit_breaker()?;
+
+        // Fail fast if a replaying cassette has no response for the request:
+        #[cfg(feature = "vcr")]
+        self.client.check_cassette(&url)?;
+
         // Observe any rate limiting before executing request:
         tracing::info!("making HTTP GET request to Google Maps Roads API");
 
@@ -38,15 +53,21 @@ impl<'a> SnapToRoadsRequest<'a> {
             .await;
 
         tracing::debug!("{url}");
+        tracing::Span::current().record("http.url", crate::error::sanitize_url(&url).as_str());
+
+        // Collect the details of the request for the audit trail:
+        let audit = self.client.audit("roads", &url);
 
         // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        // retries is returned, we have reached the maximum retries, or the
+        // circuit breaker has opened. Note: errors wrapped in `Transient()`
+        // will retried by the `backoff` crate while errors wrapped in
+        // `Permanent()` will exit the retry loop.
+        let response = self.client.retry(|| async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
             let response = self.client.get_request(&url).await;
+            audit.attempt(&response);
 
             // Check response from the HTTP client:
             match response {
@@ -54,18 +75,36 @@ impl<'a> SnapToRoadsRequest<'a> {
                     // HTTP client was successful getting a response from the
                     // server. Check the HTTP status code:
                     if response.status().is_success() {
+                        // Capture the status and content type before the
+                        // response is consumed, in case the body is not JSON:
+                        let status = response.status().to_string();
+                        let content_type = crate::error::content_type(&response);
                         // If the HTTP GET request was successful, get the
                         // response text:
                         let bytes = response.text().await.map(String::into_bytes);
+                        audit.response_bytes(&bytes);
                         match bytes {
+                            Ok(bytes) if crate::error::is_non_json(content_type.as_deref(), &bytes) => {
+                                // Google responded with something other than JSON,
+                                // such as an HTML error page. This will not be
+                                // fixed by retrying:
+                                let error = RoadsError::NonJsonResponse {
+                                    status,
+                                    snippet: crate::error::non_json_snippet(&bytes, self.client.capture_response_body),
+                                };
+                                tracing::error!("{}", error);
+                                Err(Permanent(error))
+                            } // Ok(bytes)
                             Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<SnapToRoadsResponse>(&mut bytes) {
+                                match crate::json::parse_json_response::<SnapToRoadsResponse>(&mut bytes, self.client.capture_response_body) {
                                     Ok(deserialized) => {
                                         // Google API returned an error. This
                                         // indicates an issue with the request.
                                         // In most cases, retrying will not
                                         // help:
                                         if let Some(error) = deserialized.error {
+                                            tracing::Span::current().record("google.status", error.status.as_str());
+                                            audit.google_status(error.status.as_str());
                                             let error = RoadsError::GoogleMapsService(
                                                 error.status.clone(),
                                                 Some(error.message),
@@ -80,12 +119,18 @@ impl<'a> SnapToRoadsRequest<'a> {
                                             // contain an `ErrorResponse`
                                             // struct, return the struct
                                             // deserialized from JSON:
+                                            tracing::Span::current().record("google.status", "OK");
+                                            audit.google_status("OK");
                                             Ok(deserialized)
                                         } // if
                                     } // Ok(deserialized)
-                                    Err(error) => {
+                                    Err((error, body)) => {
                                         tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(RoadsError::SimdJson(error)))
+                                        Err(Permanent(RoadsError::InvalidJsonResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            body,
+                                        )))
                                     } // Err
                                 } // match
                             } // Ok(text)
@@ -97,19 +142,28 @@ impl<'a> SnapToRoadsRequest<'a> {
                           // We got a response from the server but it was not OK.
                           // Only HTTP "500 Server Errors", and HTTP "429 Too Many
                           // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: RoadsError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
                     } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(RoadsError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
+                        let status = response.status();
+                        // Google may respond with an HTML error page, for example
+                        // when the API key is entirely wrong:
+                        let error = crate::error::non_json_body(response, self.client.capture_response_body)
+                            .await
+                            .map_or_else(
+                                || RoadsError::HttpUnsuccessful(status.to_string()),
+                                |snippet| RoadsError::NonJsonResponse { status: status.to_string(), snippet },
+                            ); // map_or_else
+                        if error.is_transient() {
+                            tracing::warn!("HTTP client returned: {}", status);
+                            Err(Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        // Not a 500 Server Error or "429 Too Many Requests"
+                        // error. The error is permanent, do not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}", status);
+                            Err(Permanent(error))
+                        } // if
                     } // if
                 } // case
                 // HTTP client did not get a response from the server. Retry:
@@ -122,9 +176,70 @@ impl<'a> SnapToRoadsRequest<'a> {
                 } // case
             } // match
         })
-        .await?;
+        .await;
+
+        // Record the request in the audit trail, now that retries are over:
+        audit.finish();
+        let response = response?;
 
         // Return response to caller:
         Ok(response)
     } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use crate::roads::{snap_to_roads::response::Response, snapped_point::SnappedPoint};
+    use crate::LatLng;
+
+    #[tokio::test]
+    async fn replays_snap_to_roads() {
+        let client = replay_client("roads");
+        let response = client
+            .snap_to_roads(vec![
+                LatLng::try_from_f64(-35.278_01, 149.129_58).unwrap(),
+                LatLng::try_from_f64(-35.280_32, 149.129_07).unwrap(),
+            ])
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                snapped_points: vec![
+                    SnappedPoint {
+                        location: LatLng::try_from_f64(-35.278_416_7, 149.129_469_2).unwrap(),
+                        place_id: Some("ChIJoR7CemhNFmsRQB9QbW7qABM".to_string()),
+                        origin_index: Some(0),
+                    },
+                    SnappedPoint {
+                        location: LatLng::try_from_f64(-35.280_316_7, 149.129_087_9).unwrap(),
+                        place_id: Some("ChIJiy6YT2hNFmsRkHZAbW7qABM".to_string()),
+                        origin_index: Some(1),
+                    },
+                ],
+                warning_message: None,
+                error: None,
+            }
+        );
+    } // fn
+} // mod
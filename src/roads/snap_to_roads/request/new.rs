@@ -4,7 +4,7 @@ use crate::types::LatLng;
 
 // =============================================================================
 
-impl Request<'_> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Snap To Roads query with the
@@ -23,11 +23,11 @@ impl Request<'_> {
     ///   between consecutive points caused by GPS signal loss, or noise.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, path: Vec<LatLng>) -> Request {
+    pub fn new(client: &GoogleMapsClient, path: Vec<LatLng>) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             path,
             // Optional parameters:
             interpolate: None,
@@ -2,7 +2,7 @@ use crate::roads::snap_to_roads::request::Request;
 
 // =============================================================================
 
-impl<'a> Request<'a> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Whether to interpolate a path to include all points forming the full
@@ -24,9 +24,9 @@ impl<'a> Request<'a> {
     /// ```
 
     pub fn with_interpolation(
-        &'a mut self,
+        &mut self,
         interpolate: impl Into<bool>
-    ) -> &'a mut Self {
+    ) -> &mut Self {
         // Set language in Request struct.
         self.interpolate = Some(interpolate.into());
         // Return modified Request struct to caller.
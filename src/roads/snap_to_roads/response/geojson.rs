@@ -0,0 +1,112 @@
+//! Provides `GeoJSON` export for the _Snap To Roads_ response, using the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::roads::snap_to_roads::response::Response;
+use ::geojson::{Feature, Geometry, JsonObject, JsonValue, Value};
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the snapped path as a `GeoJSON` `Feature` with a `LineString`
+    /// geometry. Each position is ordered `[longitude, latitude]`.
+    ///
+    /// The feature has two properties, with one entry for each snapped point:
+    ///
+    /// * `placeIds` ‧ The place ID of the road segment that the point was
+    ///   snapped to, or `null` if Google did not return one.
+    ///
+    /// * `originalIndices` ‧ The index of the point in the request's path, or
+    ///   `null` for a point that was added by interpolation.
+    ///
+    /// A `GeoJSON` `LineString` should have at least two positions. If fewer
+    /// than two points were snapped, the geometry has fewer positions.
+    ///
+    /// ```rust
+    /// let feature = snapped.to_feature();
+    /// ```
+
+    #[must_use]
+    pub fn to_feature(&self) -> Feature {
+        let line_string = self
+            .snapped_points
+            .iter()
+            .map(|point| point.location.to_geojson_position())
+            .collect();
+
+        let place_ids = self
+            .snapped_points
+            .iter()
+            .map(|point| point.place_id.clone().map_or(JsonValue::Null, JsonValue::from))
+            .collect();
+
+        let original_indices = self
+            .snapped_points
+            .iter()
+            .map(|point| point.origin_index.map_or(JsonValue::Null, JsonValue::from))
+            .collect();
+
+        let mut properties = JsonObject::new();
+        properties.insert("placeIds".to_string(), JsonValue::Array(place_ids));
+        properties.insert("originalIndices".to_string(), JsonValue::Array(original_indices));
+
+        Feature {
+            bbox: None,
+            geometry: Some(Geometry::new(Value::LineString(line_string))),
+            id: None,
+            properties: Some(properties),
+            foreign_members: None,
+        } // Feature
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::roads::{snap_to_roads::response::Response, snapped_point::SnappedPoint};
+    use crate::types::LatLng;
+    use ::geojson::GeoJson;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn exports_the_snapped_path_as_a_line_string() {
+        let snapped_point = |lat, lng, place_id: &str, origin_index| SnappedPoint {
+            location: LatLng::try_from_dec(lat, lng).unwrap(),
+            place_id: Some(place_id.to_string()),
+            origin_index,
+        }; // closure
+
+        let response = Response {
+            snapped_points: vec![
+                snapped_point(dec!(-35.2784167), dec!(149.1294692), "ChIJoR7CemhNFmsRQB9QbW7qABM", Some(0)),
+                // A point added by interpolation, without an original index:
+                snapped_point(dec!(-35.2794167), dec!(149.1292891), "ChIJoR7CemhNFmsRQB9QbW7qABM", None),
+                snapped_point(dec!(-35.2803167), dec!(149.1290879), "ChIJiy6YT2hNFmsRkHZAbW7qABM", Some(1)),
+            ],
+            warning_message: None,
+            error: None,
+        }; // Response
+
+        let expected: GeoJson = r#"{
+            "type": "Feature",
+            "geometry": {
+                "type": "LineString",
+                "coordinates": [
+                    [149.1294692, -35.2784167],
+                    [149.1292891, -35.2794167],
+                    [149.1290879, -35.2803167]
+                ]
+            },
+            "properties": {
+                "placeIds": [
+                    "ChIJoR7CemhNFmsRQB9QbW7qABM",
+                    "ChIJoR7CemhNFmsRQB9QbW7qABM",
+                    "ChIJiy6YT2hNFmsRkHZAbW7qABM"
+                ],
+                "originalIndices": [0, null, 1]
+            }
+        }"#.parse().unwrap();
+
+        assert_eq!(GeoJson::from(response.to_feature()), expected);
+    } // fn
+} // mod
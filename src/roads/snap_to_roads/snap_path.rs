@@ -0,0 +1,176 @@
+//! Contains the `snap_path` function. It is used to snap GPS traces that are
+//! longer than the _Snap To Roads_ API accepts in a single request.
+
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snapped_point::SnappedPoint;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+/// The maximum number of points that Google accepts in a single _Snap To
+/// Roads_ request.
+const MAX_POINTS_PER_REQUEST: usize = 100;
+
+/// The number of points shared between consecutive requests. The overlap
+/// gives each request some context on either side of the chunk boundary, so
+/// that the snapped path stays continuous.
+const OVERLAP: usize = 10;
+
+// -----------------------------------------------------------------------------
+
+/// Snaps a path of any length to the roads most likely travelled along.
+///
+/// The _Snap To Roads_ API accepts at most 100 points per request. This
+/// function splits `points` into chunks of up to 100 points, each overlapping
+/// the previous chunk by a few points. The chunks are requested one at a time,
+/// observing the client's rate limits, and the results are stitched back
+/// together with the overlap de-duplicated.
+///
+/// The `origin_index` of each returned `SnappedPoint` refers to the index in
+/// `points`, not the index within a chunk.
+///
+/// ## Arguments
+///
+/// * `client` ‧ The client that will be used to send the requests.
+///
+/// * `points` ‧ The path to be snapped. An empty path returns an empty `Vec`
+///   without making any requests.
+///
+/// * `interpolate` ‧ Whether to interpolate the path to include all points
+///   forming the full road-geometry. See `Request::with_interpolation`.
+///
+/// ## Example
+///
+/// ```rust
+/// let snapped_points = snap_path(&google_maps_client, &gps_trace, true).await?;
+/// ```
+///
+/// # Errors
+///
+/// * Returns the first error returned by any of the requests. Results from
+///   the chunks that had already been snapped are discarded.
+
+pub async fn snap_path(
+    client: &GoogleMapsClient,
+    points: &[LatLng],
+    interpolate: bool,
+) -> Result<Vec<SnappedPoint>, GoogleMapsError> {
+    let mut snapped_points: Vec<SnappedPoint> = Vec::new();
+    let mut start = 0;
+
+    while start < points.len() {
+        let end = (start + MAX_POINTS_PER_REQUEST).min(points.len());
+
+        let response = client
+            .snap_to_roads(points[start..end].iter().copied())
+            .with_interpolation(interpolate)
+            .execute()
+            .await?;
+
+        // Re-number the points so that their original index refers to the
+        // caller's path rather than to this chunk:
+        let chunk = response.snapped_points.into_iter().map(|mut snapped_point| {
+            snapped_point.origin_index = snapped_point.origin_index.map(|index| index + start);
+            snapped_point
+        }); // map
+
+        if start == 0 {
+            snapped_points.extend(chunk);
+        } else {
+            // The points in the middle of the overlap are where the two chunks
+            // are joined. Points before the join come from the previous chunk,
+            // and points from the join onward come from this chunk. This way
+            // both sides of the join were snapped with some context:
+            let join = start + OVERLAP / 2;
+            let is_after_join =
+                |snapped_point: &SnappedPoint| snapped_point.origin_index.is_some_and(|index| index >= join);
+
+            if let Some(position) = snapped_points.iter().position(is_after_join) {
+                snapped_points.truncate(position);
+            } // if
+
+            snapped_points.extend(chunk.skip_while(|snapped_point| !is_after_join(snapped_point)));
+        } // if
+
+        if end == points.len() {
+            break;
+        } // if
+
+        start = end - OVERLAP;
+    } // while
+
+    Ok(snapped_points)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use super::snap_path;
+    use crate::cassette::tests::replay_client;
+    use crate::types::LatLng;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn stitches_three_chunks_of_an_interpolated_path() {
+        // 250 points are sent as three requests, for points 0..100, 90..190
+        // and 180..250. Each recorded response has an interpolated point
+        // between every pair of original points, and place IDs that name the
+        // chunk each point was snapped in:
+        let client = replay_client("snap_path");
+        let cassette = client.cassette.clone().unwrap();
+        let path: Vec<LatLng> = (0..250)
+            .map(|index| {
+                LatLng::try_from_dec(dec!(40) + Decimal::new(index, 4), dec!(-74)).unwrap()
+            }) // map
+            .collect();
+
+        let snapped_points = snap_path(&client, &path, true).await.unwrap();
+        assert_eq!(cassette.remaining(), 0);
+
+        // Every original point appears once, in order, numbered by its index
+        // in `path` rather than its index within a chunk:
+        let origin_indices: Vec<usize> = snapped_points
+            .iter()
+            .filter_map(|snapped_point| snapped_point.origin_index)
+            .collect();
+        assert_eq!(origin_indices, (0..250).collect::<Vec<usize>>());
+
+        // ...with one interpolated point between each of them:
+        assert_eq!(snapped_points.len(), 250 + 249);
+
+        let place_ids: Vec<&str> = snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.place_id.as_deref().unwrap())
+            .collect();
+
+        // The chunks are joined in the middle of each overlap, at points 95
+        // and 185. The interpolated point leading up to a join comes from the
+        // earlier chunk, and has no `origin_index`:
+        assert_eq!(
+            place_ids[187..192],
+            [
+                "CHUNK_0_BETWEEN_93_94",
+                "CHUNK_0_POINT_94",
+                "CHUNK_0_BETWEEN_94_95",
+                "CHUNK_1_POINT_95",
+                "CHUNK_1_BETWEEN_95_96",
+            ]
+        );
+        assert_eq!(snapped_points[189].origin_index, None);
+        assert_eq!(
+            place_ids[367..372],
+            [
+                "CHUNK_1_BETWEEN_183_184",
+                "CHUNK_1_POINT_184",
+                "CHUNK_1_BETWEEN_184_185",
+                "CHUNK_2_POINT_185",
+                "CHUNK_2_BETWEEN_185_186",
+            ]
+        );
+        assert_eq!(snapped_points[369].origin_index, None);
+        assert_eq!(place_ids.last(), Some(&"CHUNK_2_POINT_249"));
+    } // fn
+} // mod
@@ -4,8 +4,10 @@
 
 // -----------------------------------------------------------------------------
 
+use crate::serde::decimal_as_number::decimal_as_number;
 use crate::types::LatLng;
-use serde::{Deserialize, Serialize};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize, Serializer};
 
 // -----------------------------------------------------------------------------
 //
@@ -15,11 +17,12 @@ use serde::{Deserialize, Serialize};
 pub struct SnappedPoint {
     /// See [LatitudeLongitudeLiteral](https://developers.google.com/maps/documentation/roads/snap#LatitudeLongitudeLiteral)
     /// for more information.
+    #[serde(serialize_with = "serialize_location")]
     pub location: LatLng,
 
     /// A unique identifier for a place. **All place IDs returned by the Roads
     /// API correspond to road segments.**
-    #[serde(alias = "placeId")]
+    #[serde(rename = "placeId", alias = "place_id")]
     pub place_id: Option<String>,
 
     /// An integer that indicates the corresponding value in the original
@@ -30,6 +33,27 @@ pub struct SnappedPoint {
     /// indexed from `0`, so a point with an originalIndex of `4` will be the
     /// snapped value of the 5th latitude/longitude passed to the path
     /// parameter.
-    #[serde(alias = "originalIndex")]
+    #[serde(rename = "originalIndex", alias = "origin_index")]
     pub origin_index: Option<usize>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+/// The Roads API names the coordinates of a
+/// [LatitudeLongitudeLiteral](https://developers.google.com/maps/documentation/roads/snap#LatitudeLongitudeLiteral)
+/// `latitude` and `longitude` rather than `lat` and `lng`.
+
+fn serialize_location<S: Serializer>(location: &LatLng, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct LatitudeLongitudeLiteral<'a> {
+        #[serde(serialize_with = "decimal_as_number")]
+        latitude: &'a Decimal,
+        #[serde(serialize_with = "decimal_as_number")]
+        longitude: &'a Decimal,
+    } // struct
+
+    LatitudeLongitudeLiteral {
+        latitude: &location.lat,
+        longitude: &location.lng,
+    }.serialize(serializer)
+} // fn
@@ -86,11 +86,23 @@ impl std::convert::From<&Status> for String {
     /// Converts a `Status` enum to a `String` that contains a status
     /// code.
     fn from(status: &Status) -> Self {
-        match status {
-            Status::InvalidArgument => Self::from("INVALID_ARGUMENT"),
-            Status::PermissionDenied => Self::from("PERMISSION_DENIED"),
-            Status::NotFound => Self::from("NOT_FOUND"),
-            Status::ResourceExhausted => Self::from("RESOURCE_EXHAUSTED"),
+        status.as_str().to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Status {
+    /// Returns the status code exactly as documented and returned by Google,
+    /// for example `RESOURCE_EXHAUSTED`. Unlike `Display`, which is meant for
+    /// people, this is suitable for logs or as a metrics label.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidArgument => "INVALID_ARGUMENT",
+            Self::PermissionDenied => "PERMISSION_DENIED",
+            Self::NotFound => "NOT_FOUND",
+            Self::ResourceExhausted => "RESOURCE_EXHAUSTED",
         } // match
     } // fn
 } // impl
@@ -0,0 +1,16 @@
+//! Contains Serde serializer for converting a `chrono::DateTime<Utc>` struct
+//! into a Unix Timestamp in integer format.
+
+use chrono::{DateTime, Utc};
+use serde::Serializer;
+
+/// This function converts a `chrono::DateTime<Utc>` struct back into a Unix
+/// Timestamp in integer format. This is the same format that the Google Maps
+/// Platform uses, so a serialized response can be deserialized again.
+
+pub fn datetime_to_unix<S>(data: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_i64(data.timestamp())
+} // fn
@@ -0,0 +1,208 @@
+//! Contains Serde serializers that write `Decimal` fields, and the coordinates
+//! of `LatLng` and `Bounds` fields, as JSON numbers.
+
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places"
+))]
+use crate::types::Bounds;
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places"
+))]
+use crate::types::LatLng;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::ser::{Error, Serializer};
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places"
+))]
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// The Google Maps Platform returns coordinates, ratings and fares as JSON
+/// numbers. Depending on the `decimal-serde-*` feature that is enabled,
+/// `rust_decimal` serializes a `Decimal` as a JSON string instead. This
+/// function always writes a JSON number so that a re-serialized response is in
+/// Google's wire format.
+///
+/// ```rust
+/// #[serde(serialize_with = "decimal_as_number")]
+/// pub lat: Decimal,
+/// ```
+///
+/// # Errors
+///
+/// * Returns an error if the decimal can not be represented as an `f64`.
+
+pub fn decimal_as_number<S: Serializer>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error> {
+    value
+        .to_f64()
+        .ok_or_else(|| S::Error::custom(format!("{value} can not be represented as an `f64`")))
+        .and_then(|value| serializer.serialize_f64(value))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Same as `decimal_as_number` but for optional fields. `None` is written as
+/// `null`. Fields using this serializer will usually also be annotated with
+/// `#[serde(skip_serializing_if = "Option::is_none")]`.
+///
+/// # Errors
+///
+/// * Returns an error if the decimal can not be represented as an `f64`.
+
+#[cfg(feature = "places")]
+#[allow(clippy::ref_option)]
+pub fn option_decimal_as_number<S: Serializer>(
+    value: &Option<Decimal>,
+    serializer: S
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => decimal_as_number(value, serializer),
+        None => serializer.serialize_none(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// `LatLng` keeps the serializer selected by the `decimal-serde-*` features so
+/// that it round-trips through any format without losing precision. Response
+/// structs use this function instead so that a re-serialized response writes
+/// its coordinates as JSON numbers, like Google does.
+///
+/// ```rust
+/// #[serde(serialize_with = "latlng_as_numbers")]
+/// pub location: LatLng,
+/// ```
+///
+/// # Errors
+///
+/// * Returns an error if a coordinate can not be represented as an `f64`.
+
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places"
+))]
+pub fn latlng_as_numbers<S: Serializer>(value: &LatLng, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct LatLngLiteral<'a> {
+        #[serde(serialize_with = "decimal_as_number")]
+        lat: &'a Decimal,
+        #[serde(serialize_with = "decimal_as_number")]
+        lng: &'a Decimal,
+    } // struct
+
+    LatLngLiteral {
+        lat: &value.lat,
+        lng: &value.lng,
+    }.serialize(serializer)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Same as `latlng_as_numbers` but for both corners of a `Bounds`.
+///
+/// # Errors
+///
+/// * Returns an error if a coordinate can not be represented as an `f64`.
+
+#[cfg(any(
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places"
+))]
+pub fn bounds_as_numbers<S: Serializer>(value: &Bounds, serializer: S) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct BoundsLiteral<'a> {
+        #[serde(serialize_with = "latlng_as_numbers")]
+        southwest: &'a LatLng,
+        #[serde(serialize_with = "latlng_as_numbers")]
+        northeast: &'a LatLng,
+    } // struct
+
+    BoundsLiteral {
+        southwest: &value.southwest,
+        northeast: &value.northeast,
+    }.serialize(serializer)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Same as `bounds_as_numbers` but for optional fields. `None` is written as
+/// `null`.
+///
+/// # Errors
+///
+/// * Returns an error if a coordinate can not be represented as an `f64`.
+
+#[cfg(any(feature = "geocoding", feature = "places"))]
+#[allow(clippy::ref_option)]
+pub fn option_bounds_as_numbers<S: Serializer>(
+    value: &Option<Bounds>,
+    serializer: S
+) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => bounds_as_numbers(value, serializer),
+        None => serializer.serialize_none(),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::decimal_as_number;
+    use rust_decimal_macros::dec;
+    use rust_decimal::Decimal;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Coordinate {
+        #[serde(serialize_with = "decimal_as_number")]
+        value: Decimal,
+    } // struct
+
+    #[test]
+    fn writes_decimals_as_json_numbers() {
+        for (value, expected) in [
+            (dec!(39.7391536), r#"{"value":39.7391536}"#),
+            (dec!(-104.9847034), r#"{"value":-104.9847034}"#),
+            (dec!(4.5), r#"{"value":4.5}"#),
+        ] {
+            let json = crate::json::to_json_string(&Coordinate { value }).unwrap();
+            assert_eq!(json, expected);
+        } // for
+    } // fn
+
+    #[cfg(feature = "directions")]
+    #[test]
+    fn writes_coordinates_as_json_numbers() {
+        use super::latlng_as_numbers;
+        use crate::types::LatLng;
+
+        #[derive(Serialize)]
+        struct Stop {
+            #[serde(serialize_with = "latlng_as_numbers")]
+            location: LatLng,
+        } // struct
+
+        let location = LatLng::try_from_dec(dec!(45.4215306), dec!(-75.6971933)).unwrap();
+        let json = crate::json::to_json_string(&Stop { location }).unwrap();
+        assert_eq!(json, r#"{"location":{"lat":45.4215306,"lng":-75.6971933}}"#);
+    } // fn
+} // mod
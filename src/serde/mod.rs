@@ -4,3 +4,9 @@
 pub mod duration_to_seconds;
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod seconds_to_duration;
+#[cfg(feature = "places")]
+pub mod datetime_to_unix;
+pub mod decimal_as_number;
+pub mod number_or_string;
+#[cfg(feature = "places")]
+pub mod unix_to_datetime;
@@ -0,0 +1,238 @@
+//! Contains Serde deserializers for numeric fields that may be represented
+//! either as a JSON number or as a JSON string containing a number.
+
+use rust_decimal::Decimal;
+use serde::de::{Deserializer, Error, MapAccess, Unexpected, Visitor};
+use serde::Deserialize;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+
+/// The Google Maps Platform occasionally returns numeric fields as strings
+/// (for example, `"value": "1234"` rather than `"value": 1234`). Fixtures
+/// written by other tools may also quote numbers. This function accepts either
+/// representation. Anything that isn't numeric is rejected.
+///
+/// ```rust
+/// #[serde(deserialize_with = "number_or_string")]
+/// pub value: u32,
+/// ```
+
+pub fn number_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    deserializer.deserialize_any(NumberOrStringVisitor(PhantomData))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Same as `number_or_string` but only for human-readable formats such as JSON
+/// and XML, where a `Decimal` may have been written by Google or by another
+/// tool. Formats that aren't self-describing, such as `bincode` or `postcard`,
+/// can't be asked for "any" value. For these, the `Decimal` is read back as
+/// whatever `rust_decimal` wrote: an `f64` with the `decimal-serde-float`
+/// feature, and a string otherwise. If `decimal-serde-float` is combined with
+/// `decimal-serde-arbitrary-precision`, `Decimal`'s own deserializer is used.
+///
+/// ```rust
+/// #[serde(deserialize_with = "decimal_number_or_string")]
+/// pub lat: Decimal,
+/// ```
+
+pub fn decimal_number_or_string<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        number_or_string(deserializer)
+    } else if cfg!(all(feature = "decimal-serde-float", feature = "decimal-serde-arbitrary-precision")) {
+        <Decimal as Deserialize>::deserialize(deserializer)
+    } else if cfg!(feature = "decimal-serde-float") {
+        deserializer.deserialize_f64(NumberOrStringVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_str(NumberOrStringVisitor(PhantomData))
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Same as `number_or_string` but for optional fields. Fields using this
+/// deserializer should also be annotated with `#[serde(default)]` so that a
+/// missing field is deserialized as `None`.
+///
+/// ```rust
+/// #[serde(default, deserialize_with = "option_number_or_string")]
+/// pub rating: Option<Decimal>,
+/// ```
+
+#[cfg(feature = "places")]
+pub fn option_number_or_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    Ok(Option::<NumberOrString<T>>::deserialize(deserializer)?
+        .map(|NumberOrString(value)| value))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+/// Wraps a value so that `Option<T>` can be deserialized leniently.
+
+#[cfg(feature = "places")]
+struct NumberOrString<T>(T);
+
+#[cfg(feature = "places")]
+impl<'de, T: FromStr> Deserialize<'de> for NumberOrString<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        number_or_string(deserializer).map(Self)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Visits a JSON number or a JSON string and parses it into `T` using its
+/// `FromStr` implementation.
+
+struct NumberOrStringVisitor<T>(PhantomData<T>);
+
+impl<T: FromStr> NumberOrStringVisitor<T> {
+    fn parse<E: Error>(value: &str, unexpected: Unexpected<'_>) -> Result<T, E> {
+        value
+            .trim()
+            .parse()
+            .map_err(|_| E::invalid_value(unexpected, &"a number"))
+    } // fn
+} // impl
+
+impl<'de, T: FromStr> Visitor<'de> for NumberOrStringVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a number, or a string containing a number")
+    } // fn
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<T, E> {
+        Self::parse(&value.to_string(), Unexpected::Signed(value))
+    } // fn
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<T, E> {
+        Self::parse(&value.to_string(), Unexpected::Unsigned(value))
+    } // fn
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<T, E> {
+        // `f64` is formatted using its shortest round-trip representation,
+        // which is never in scientific notation:
+        Self::parse(&value.to_string(), Unexpected::Float(value))
+    } // fn
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        Self::parse(value, Unexpected::Str(value))
+    } // fn
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<T, A::Error> {
+        // The XML parser presents an element such as `<lat>51.5</lat>` as a
+        // map with a single `$text` entry:
+        match map.next_entry::<String, String>()? {
+            Some((key, value)) if key == "$text" && map.next_key::<String>()?.is_none() =>
+                Self::parse(&value, Unexpected::Str(&value)),
+            _ => Err(A::Error::invalid_type(Unexpected::Map, &self)),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::number_or_string;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Distance {
+        #[serde(deserialize_with = "number_or_string")]
+        value: u32,
+    } // struct
+
+    #[derive(Debug, Deserialize)]
+    struct Rating {
+        #[serde(deserialize_with = "number_or_string")]
+        rating: Decimal,
+    } // struct
+
+    fn distance(json: &str) -> Result<u32, crate::json::Error> {
+        crate::json::parse_json_str::<Distance>(json).map(|distance| distance.value)
+    } // fn
+
+    fn rating(json: &str) -> Result<Decimal, crate::json::Error> {
+        crate::json::parse_json_str::<Rating>(json).map(|rating| rating.rating)
+    } // fn
+
+    #[test]
+    fn accepts_numbers() {
+        assert_eq!(distance(r#"{"value": 1234}"#).unwrap(), 1234);
+        assert_eq!(rating(r#"{"rating": 4.5}"#).unwrap(), dec!(4.5));
+        assert_eq!(rating(r#"{"rating": -73.9612903}"#).unwrap(), dec!(-73.9612903));
+    } // fn
+
+    #[test]
+    fn accepts_quoted_numbers() {
+        assert_eq!(distance(r#"{"value": "1234"}"#).unwrap(), 1234);
+        assert_eq!(rating(r#"{"rating": "4.5"}"#).unwrap(), dec!(4.5));
+    } // fn
+
+    #[test]
+    fn trims_whitespace_around_quoted_numbers() {
+        assert_eq!(distance(r#"{"value": " 1234\n"}"#).unwrap(), 1234);
+        assert_eq!(rating(r#"{"rating": "\t4.5 "}"#).unwrap(), dec!(4.5));
+    } // fn
+
+    #[test]
+    fn rejects_values_that_are_not_numeric() {
+        assert!(distance(r#"{"value": "1,234"}"#).is_err());
+        assert!(distance(r#"{"value": "twelve"}"#).is_err());
+        assert!(distance(r#"{"value": ""}"#).is_err());
+        assert!(distance(r#"{"value": -1}"#).is_err());
+        assert!(distance(r#"{"value": true}"#).is_err());
+        assert!(distance(r#"{"value": [1234]}"#).is_err());
+        assert!(rating(r#"{"rating": "4.5 stars"}"#).is_err());
+    } // fn
+
+    #[test]
+    fn accepts_an_xml_text_node() {
+        use serde::de::value::{Error, MapDeserializer};
+
+        // The XML parser presents an element such as `<value>1234</value>` as
+        // a map with a single `$text` entry:
+        let text = MapDeserializer::<_, Error>::new(std::iter::once(("$text", " 1234 ")));
+        assert_eq!(number_or_string::<_, u32>(text).unwrap(), 1234);
+
+        // A map with other entries is not a number:
+        let attributes = MapDeserializer::<_, Error>::new([("$text", "1234"), ("unit", "m")].into_iter());
+        assert!(number_or_string::<_, u32>(attributes).is_err());
+        let element = MapDeserializer::<_, Error>::new(std::iter::once(("value", "1234")));
+        assert!(number_or_string::<_, u32>(element).is_err());
+    } // fn
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn accepts_xml_elements() {
+        #[derive(Deserialize)]
+        struct Element {
+            #[serde(deserialize_with = "number_or_string")]
+            value: u32,
+            #[serde(deserialize_with = "number_or_string")]
+            rating: Decimal,
+        } // struct
+
+        let xml = b"<result><value> 1234 </value><rating>4.5</rating></result>";
+        let element: Element = crate::xml::parse_xml(xml).unwrap();
+        assert_eq!(element.value, 1234);
+        assert_eq!(element.rating, dec!(4.5));
+    } // fn
+} // mod
@@ -1,8 +1,9 @@
 //! Contains Serde serializer/deserializer for converting a quantity of seconds
 //! in `String` format into a `time::Duration` struct.
 
+use crate::serde::number_or_string::number_or_string;
 use chrono::Duration;
-use serde::{Deserialize, Deserializer};
+use serde::Deserializer;
 
 /// This trait converts a quantity of seconds in `String` format into a
 /// `time::Duration` struct. The Google Maps Platform returns duration fields in
@@ -12,9 +13,9 @@ pub fn seconds_to_duration<'de, D>(deserializer: D) -> Result<Duration, D::Error
 where
     D: Deserializer<'de>,
 {
-    // Deserialize the field (from a `String`) into an `i64`. This is what the
-    // `time::Duration::seconds()` method expects:
-    let seconds: i64 = Deserialize::deserialize(deserializer)?;
+    // Deserialize the field (from a number or a `String`) into an `i64`. This
+    // is what the `time::Duration::seconds()` method expects:
+    let seconds: i64 = number_or_string(deserializer)?;
     // This handy-dandy method converts from the seconds count in `i64` format
     // into a `Duration` struct:
     Duration::try_seconds(seconds).ok_or_else(|| {
@@ -0,0 +1,64 @@
+//! Contains Serde deserializer for converting a Unix Timestamp in integer
+//! format into a `chrono::DateTime<Utc>` struct.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+
+/// This function converts a Unix Timestamp in integer format into a
+/// `chrono::DateTime<Utc>` struct. The Unix Timestamp is the number of seconds
+/// that have elapsed since the Unix epoch, that is the time `00:00:00 UTC on 1
+/// January 1970`, minus leap seconds. The Google Maps Platform returns some
+/// fields (for example, the `time` of a place review) in the Unix Timestamp
+/// format and it's handier to be able to use them as a `DateTime`.
+
+pub fn unix_to_datetime<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // Deserialize the field into an `i64`. This is what the
+    // `chrono::DateTime::from_timestamp()` method expects:
+    let unix_timestamp: i64 = Deserialize::deserialize(deserializer)?;
+    // This handy-dandy method converts from the Unix Timestamp in `i64` format
+    // into a `DateTime<Utc>` struct:
+    DateTime::from_timestamp(unix_timestamp, 0).ok_or_else(|| {
+        serde::de::Error::custom(format!(
+            "could not deserialize a date & time from Unix timestamp: {unix_timestamp}"
+        ))
+    })
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::places::PlaceReview;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    #[test]
+    fn converts_an_epoch_into_a_datetime_and_back() {
+        let json = r#"{"author_name":"Ada","rating":5,"relative_time_description":"a week ago","time":1331161200}"#;
+        let review: PlaceReview = crate::json::parse_json_str(json).unwrap();
+        assert_eq!(review.time, Utc.with_ymd_and_hms(2012, 3, 7, 23, 0, 0).unwrap());
+
+        let serialized = crate::json::to_json_string(&review).unwrap();
+        assert!(serialized.contains(r#""time":1331161200"#), "{serialized}");
+        let deserialized: PlaceReview = crate::json::parse_json_str(&serialized).unwrap();
+        assert_eq!(deserialized.time, review.time);
+    } // fn
+
+    #[test]
+    fn converts_the_epoch_itself() {
+        let review: PlaceReview = crate::json::parse_json_str(
+            r#"{"author_name":"Ada","rating":5,"relative_time_description":"","time":0}"#
+        ).unwrap();
+        assert_eq!(review.time, DateTime::UNIX_EPOCH);
+    } // fn
+
+    #[test]
+    fn rejects_timestamps_that_are_out_of_range() {
+        let result = crate::json::parse_json_str::<PlaceReview>(
+            r#"{"author_name":"Ada","rating":5,"relative_time_description":"","time":9223372036854775807}"#
+        );
+        assert!(result.is_err());
+    } // fn
+} // mod
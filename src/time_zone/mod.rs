@@ -77,6 +77,12 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 
 pub use crate::time_zone::{
     error::Error as TimeZoneError,
-    request::Request as TimeZoneRequest,
-    response::{status::Status as TimeZoneStatus, Response as TimeZoneResponse}, // reponse
+    request::{
+        builder::Builder as TimeZoneBuilder, ready_request::ReadyRequest as TimeZoneReadyRequest,
+        Request as TimeZoneRequest,
+    }, // request
+    response::{
+        local_time_zone::LocalTimeZone, status::Status as TimeZoneStatus,
+        Response as TimeZoneResponse,
+    }, // reponse
 }; // crate::time_zone
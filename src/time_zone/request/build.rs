@@ -1,29 +1,36 @@
 use crate::time_zone::request::Request;
 
-impl<'a> Request<'a> {
+impl Request {
     /// Builds the query string for the Google Maps Time Zone API based on the
     /// input provided by the client.
     ///
+    /// Parameters are always emitted in the same order: the `key`, then the
+    /// required parameters, then the optional parameters in alphabetical
+    /// order. Multi-valued parameters whose order is not significant are
+    /// sorted. The query string therefore does not depend on the order that
+    /// the builder methods were called in.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &mut Self {
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
             "key={}&location={}&timestamp={}",
             self.client.key,
-            String::from(&self.location),
+            String::from(&*self.client.coordinates(&self.location)),
             self.timestamp.timestamp(),
         );
 
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // none was set for this request:
+        if let Some(language) = self.language.as_ref().or(self.client.default_language.as_ref()) {
             query.push_str("&language=");
             query.push_str(&String::from(language));
         }
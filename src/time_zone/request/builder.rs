@@ -0,0 +1,127 @@
+use crate::client::GoogleMapsClient;
+use crate::time_zone::request::{ready_request::ReadyRequest, Request};
+use crate::types::{Language, LatLng};
+use chrono::{DateTime, Utc};
+
+// -----------------------------------------------------------------------------
+//
+/// A typestate builder for _Time Zone API_ requests. Each method takes the
+/// builder by value and returns it, so that a request may be built in a single
+/// expression, or returned from a function. The request can only be sent once
+/// `build` has returned a `ReadyRequest`:
+///
+/// ```rust
+/// let time_zone = TimeZoneRequest::builder(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512))?,
+///     Utc::now(),
+/// )
+/// .with_language(Language::Czech)
+/// .build()
+/// .get()
+/// .await?;
+/// ```
+///
+/// Forgetting to call `build` does not compile, because a `Builder` cannot be
+/// sent:
+///
+/// ```compile_fail
+/// let time_zone = TimeZoneRequest::builder(
+///     &google_maps_client,
+///     LatLng::try_from_dec(dec!(50.090_903), dec!(14.400_512))?,
+///     Utc::now(),
+/// )
+/// .with_language(Language::Czech)
+/// .get()
+/// .await?;
+/// ```
+///
+/// The builder produces exactly the same query string as the mutable
+/// `Request` builder with the same parameters.
+
+#[derive(Debug)]
+pub struct Builder {
+    request: Request,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Request {
+    /// Initializes a typestate builder. See `Builder` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that will be used to send the request.
+    ///
+    /// * `location` ‧ Latitude & longitude of the desired time zone location.
+    ///
+    /// * `timestamp` ‧ Time is used to determine if Daylight Savings is
+    ///   applicable.
+
+    #[must_use]
+    pub fn builder(
+        client: &GoogleMapsClient,
+        location: LatLng,
+        timestamp: DateTime<Utc>
+    ) -> Builder {
+        Builder {
+            request: Self::new(client, location, timestamp),
+        } // Builder
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Builder {
+    /// Builds the query string. The returned `ReadyRequest` may then be sent
+    /// to the Google Maps Platform.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[must_use]
+    pub fn build(mut self) -> ReadyRequest {
+        self.request.build();
+        ReadyRequest {
+            client: self.request.client,
+            query: self.request.query.unwrap_or_default(),
+            language: self.request.language,
+        } // ReadyRequest
+    } // fn
+
+    /// Specifies the language in which to return results. See
+    /// `Request::with_language`.
+
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<Language>) -> Self {
+        self.request.with_language(language);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::time_zone::request::Request;
+    use crate::types::{Language, LatLng};
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn both_builder_styles_produce_the_same_url() {
+        let client =
+            GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let location = LatLng::try_from_f64(39.603_481, -119.682_251).unwrap();
+        let timestamp = chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap();
+
+        let consuming = Request::builder(&client, location, timestamp)
+            .with_language(Language::French)
+            .build();
+
+        let mut mutable = Request::new(&client, location, timestamp);
+        mutable.with_language(Language::French);
+
+        assert_eq!(consuming.query_url(), mutable.query_url());
+    } // fn
+} // mod
@@ -5,7 +5,7 @@ use crate::time_zone::{
 
 // =============================================================================
 
-impl<'a> TimeZoneRequest<'a> {
+impl TimeZoneRequest {
     // -------------------------------------------------------------------------
     //
     /// Executes the query you've built.
@@ -20,7 +20,7 @@ impl<'a> TimeZoneRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub async fn execute(&'a mut self) -> Result<TimeZoneResponse, GoogleMapsError> {
+    pub async fn execute(&mut self) -> Result<TimeZoneResponse, GoogleMapsError> {
         self.build().get().await
     } // fn
 } // impl
@@ -1,34 +1,88 @@
 use crate::error::Error as GoogleMapsError;
 use crate::request_rate::api::Api;
 use crate::time_zone::{
-    error::Error as TimeZoneError, request::Request as TimeZoneRequest,
+    error::Error as TimeZoneError, request::ready_request::ReadyRequest,
+    request::Request as TimeZoneRequest,
     response::status::Status as TimeZoneStatus, response::Response as TimeZoneResponse,
     OUTPUT_FORMAT, SERVICE_URL,
 };
-use backoff::future::retry;
 use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> TimeZoneRequest<'a> {
+impl TimeZoneRequest {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
     pub async fn get(&mut self) -> Result<TimeZoneResponse, GoogleMapsError> {
-        // Build the URL stem for the HTTP get request:
-        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+        self.ready()?.get().await
+    } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &mut self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<TimeZoneResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
+
+    /// Converts the built request into a `ReadyRequest`, which is used to
+    /// perform the HTTP get request.
+
+    fn ready(&self) -> Result<ReadyRequest, TimeZoneError> {
+        // If the query string has not been built, return an error:
+        self.query
+            .as_ref()
+            .map(|query| ReadyRequest {
+                client: self.client.clone(),
+                query: query.clone(),
+                language: self.language.clone(),
+            }) // map
+            .ok_or(TimeZoneError::QueryNotBuilt)
+    } // fn
+} // impl
 
-        match &self.query {
-            // If query string built, append it to the URL stem.
-            Some(query) => url.push_str(query.as_ref()),
-            // If query string not built, return an error.
-            None => return Err(TimeZoneError::QueryNotBuilt)?,
-        } // match
+// -----------------------------------------------------------------------------
+
+impl ReadyRequest {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(
+            otel.kind = "client",
+            http.method = "GET",
+            http.url = tracing::field::Empty,
+            google.api = "time_zone",
+            google.status = tracing::field::Empty,
+        )
+    )]
+    pub async fn get(&self) -> Result<TimeZoneResponse, GoogleMapsError> {
+        // Build the URL for the HTTP get request:
+        let url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{}", self.query);
+
+        // Fail fast if the circuit breaker is not letting requests through:
+        self.client.check_circuit_breaker()?;
+
+        // Fail fast if a replaying cassette has no response for the request:
+        #[cfg(feature = "vcr")]
+        self.client.check_cassette(&url)?;
 
         // Observe any rate limiting before executing request:
         tracing::info!("making HTTP GET request to Google Maps Time Zone API");
@@ -39,15 +93,24 @@ impl<'a> TimeZoneRequest<'a> {
             .await;
 
         tracing::debug!("{url}");
+        tracing::Span::current().record("http.url", crate::error::sanitize_url(&url).as_str());
+
+        // The language is also sent in the `Accept-Language` header:
+        let language = self.language.as_ref().or(self.client.default_language.as_ref()).map(<&str>::from);
+
+        // Collect the details of the request for the audit trail:
+        let audit = self.client.audit("time_zone", &url);
 
         // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
+        // retries is returned, we have reached the maximum retries, or the
+        // circuit breaker has opened. Note: errors wrapped in `Transient()`
+        // will retried by the `backoff` crate while errors wrapped in
+        // `Permanent()` will exit the retry loop.
+        let response = self.client.retry(|| async {
             // Query the Google Cloud Maps Platform using using an HTTP get
             // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+            let response = self.client.get_request_with_language(&url, language).await;
+            audit.attempt(&response);
 
             // Check response from the HTTP client:
             match response {
@@ -55,33 +118,46 @@ impl<'a> TimeZoneRequest<'a> {
                     // HTTP client was successful getting a response from the
                     // server. Check the HTTP status code:
                     if response.status().is_success() {
+                        // Capture the status and content type before the
+                        // response is consumed, in case the body is not JSON:
+                        let status = response.status().to_string();
+                        let content_type = crate::error::content_type(&response);
                         // If the HTTP GET request was successful, get the
                         // response text:
                         let bytes = response.text().await.map(String::into_bytes);
+                        audit.response_bytes(&bytes);
                         match bytes {
+                            Ok(bytes) if crate::error::is_non_json(content_type.as_deref(), &bytes) => {
+                                // Google responded with something other than JSON,
+                                // such as an HTML error page. This will not be
+                                // fixed by retrying:
+                                let error = TimeZoneError::NonJsonResponse {
+                                    status,
+                                    snippet: crate::error::non_json_snippet(&bytes, self.client.capture_response_body),
+                                };
+                                tracing::error!("{}", error);
+                                Err(Permanent(error))
+                            } // Ok(bytes)
                             Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<TimeZoneResponse>(&mut bytes) {
+                                match crate::json::parse_json_response::<TimeZoneResponse>(&mut bytes, self.client.capture_response_body) {
                                     Ok(deserialized) => {
+                                        tracing::Span::current().record("google.status", deserialized.status.as_str());
+                                        audit.google_status(deserialized.status.as_str());
                                         // If the response JSON was successfully
                                         // parsed, check the Google API status
                                         // before returning it to the caller:
-                                        if deserialized.status == TimeZoneStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
+                                        if deserialized.status != TimeZoneStatus::Ok {
+                                            // Google API returned an error. This
+                                            // indicates an issue with the request.
+                                            // In most cases, retrying will not
+                                            // help:
                                             let error = TimeZoneError::GoogleMapsService(
                                                 deserialized.status.clone(),
                                                 deserialized.error_message,
                                             );
                                             // Check Google API response status
                                             // for error type:
-                                            if deserialized.status == TimeZoneStatus::UnknownError {
+                                            if error.is_transient() {
                                                 // Only Google's "Unknown Error"
                                                 // is eligible for retries:
                                                 tracing::warn!("{}", error);
@@ -96,11 +172,29 @@ impl<'a> TimeZoneRequest<'a> {
                                                 tracing::error!("{}", error);
                                                 Err(Permanent(error))
                                             } // if
+                                        // Google's response was "Ok" but it is missing
+                                        // data that an "Ok" response always has. This
+                                        // will not be fixed by retrying:
+                                        } else if let Some(field) = deserialized.missing_field() {
+                                            let error = TimeZoneError::MalformedResponse(
+                                                crate::error::sanitize_url(&url),
+                                                field.to_string(),
+                                            );
+                                            tracing::error!("{}", error);
+                                            Err(Permanent(error))
+                                        } else {
+                                            // If Google's response was "Ok" return the
+                                            // struct deserialized from JSON:
+                                            Ok(deserialized)
                                         } // if
                                     } // Ok(deserialized)
-                                    Err(error) => {
+                                    Err((error, body)) => {
                                         tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(TimeZoneError::SimdJson(error)))
+                                        Err(Permanent(TimeZoneError::InvalidJsonResponse(
+                                            crate::error::sanitize_url(&url),
+                                            error,
+                                            body,
+                                        )))
                                     } // Err
                                 } // match
                             } // Ok(text)
@@ -112,19 +206,28 @@ impl<'a> TimeZoneRequest<'a> {
                           // We got a response from the server but it was not OK.
                           // Only HTTP "500 Server Errors", and HTTP "429 Too Many
                           // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: TimeZoneError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
                     } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(TimeZoneError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
+                        let status = response.status();
+                        // Google may respond with an HTML error page, for example
+                        // when the API key is entirely wrong:
+                        let error = crate::error::non_json_body(response, self.client.capture_response_body)
+                            .await
+                            .map_or_else(
+                                || TimeZoneError::HttpUnsuccessful(status.to_string()),
+                                |snippet| TimeZoneError::NonJsonResponse { status: status.to_string(), snippet },
+                            ); // map_or_else
+                        if error.is_transient() {
+                            tracing::warn!("HTTP client returned: {}", status);
+                            Err(Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        // Not a 500 Server Error or "429 Too Many Requests"
+                        // error. The error is permanent, do not retry:
+                        } else {
+                            tracing::error!("HTTP client returned: {}", status);
+                            Err(Permanent(error))
+                        } // if
                     } // if
                 } // case
                 // HTTP client did not get a response from the server. Retry:
@@ -137,9 +240,99 @@ impl<'a> TimeZoneRequest<'a> {
                 } // case
             } // match
         })
-        .await?;
+        .await;
+
+        // Record the request in the audit trail, now that retries are over:
+        audit.finish();
+        let response = response?;
 
         // Return response to caller:
         Ok(response)
     } // fn
+
+    /// Performs the HTTP get request like `get`, but gives up as soon as the
+    /// `token` is cancelled. The request in flight is aborted, and a
+    /// `Cancelled` error is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token` ‧ Cancels the request when it is cancelled.
+
+    #[cfg(feature = "cancellation")]
+    pub async fn get_with_cancellation(
+        &self,
+        token: tokio_util::sync::CancellationToken,
+    ) -> Result<TimeZoneResponse, GoogleMapsError> {
+        crate::cancellation::until_cancelled(&token, self.get()).await
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "vcr"))]
+mod tests {
+    use crate::cassette::tests::replay_client;
+    use crate::time_zone::error::Error as TimeZoneError;
+    use crate::time_zone::response::{status::Status, Response};
+    use crate::LatLng;
+
+    #[tokio::test]
+    async fn replays_time_zone() {
+        let client = replay_client("time_zone");
+        let response = client
+            .time_zone(
+                LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                chrono::DateTime::from_timestamp(1_331_161_200, 0).unwrap()
+            )
+            .execute()
+            .await
+            .unwrap();
+        assert_eq!(
+            response,
+            Response {
+                dst_offset: Some(0),
+                error_message: None,
+                raw_offset: Some(-28_800),
+                status: Status::Ok,
+                time_zone_id: Some(chrono_tz::America::Los_Angeles),
+                time_zone_name: Some("Pacific Standard Time".to_string()),
+            }
+        );
+    } // fn
+
+    #[tokio::test]
+    async fn rejects_a_response_that_is_not_json() {
+        let client = replay_client("time_zone_non_json");
+        let time_zone = |timestamp| {
+            let mut request = client.time_zone(
+                LatLng::try_from_f64(39.603_481, -119.682_251).unwrap(),
+                chrono::DateTime::from_timestamp(timestamp, 0).unwrap()
+            );
+            async move { request.execute().await }
+        }; // closure
+
+        // An HTML page, even though the content type claims that it's JSON:
+        let result = time_zone(1_331_161_200).await;
+        let Err(crate::error::Error::TimeZone(TimeZoneError::NonJsonResponse { status, snippet })) = result else {
+            panic!("expected a non-JSON response, got {result:?}");
+        };
+        assert_eq!(status, "200 OK");
+        assert!(snippet.starts_with("<html><head><title>Error 404"), "{snippet}");
+
+        // A `text/html` content type, even though the body isn't markup:
+        let result = time_zone(1_331_164_800).await;
+        let Err(crate::error::Error::TimeZone(TimeZoneError::NonJsonResponse { status, snippet })) = result else {
+            panic!("expected a non-JSON response, got {result:?}");
+        };
+        assert_eq!(status, "200 OK");
+        assert_eq!(snippet, "Service misconfigured");
+
+        // An unsuccessful HTTP status with an HTML error page:
+        let result = time_zone(1_331_168_400).await;
+        let Err(crate::error::Error::TimeZone(TimeZoneError::NonJsonResponse { status, snippet })) = result else {
+            panic!("expected a non-JSON response, got {result:?}");
+        };
+        assert_eq!(status, "403 Forbidden");
+        assert!(snippet.starts_with("<!DOCTYPE html>"), "{snippet}");
+    } // fn
+} // mod
@@ -4,12 +4,14 @@
 //! for building your Google Maps Platform request.
 
 mod build;
+pub mod builder;
 #[cfg(feature = "reqwest")]
 mod execute;
 #[cfg(feature = "reqwest")]
 mod get;
 mod new;
 mod query_url;
+pub mod ready_request;
 mod with_language;
 
 use crate::{client::GoogleMapsClient, types::Language, types::LatLng};
@@ -21,13 +23,13 @@ use chrono::{DateTime, Utc};
 /// Zone API_ query**. The methods implemented for this struct are what's used
 /// to build your request.
 
-#[derive(Debug)]
-pub struct Request<'a> {
+#[derive(Clone)]
+pub struct Request {
     // Required parameters:
     // --------------------
     /// This structure contains the application's API key and other
     /// user-definable settings such as "maximum retries."
-    client: &'a GoogleMapsClient,
+    client: GoogleMapsClient,
 
     /// The location to look up.
     location: LatLng,
@@ -53,3 +55,19 @@ pub struct Request<'a> {
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
     query: Option<String>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for Request {
+    /// Formats the request for debugging. The API key is redacted from the
+    /// query string, and from the client.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("client", &self.client)
+            .field("location", &self.location)
+            .field("timestamp", &self.timestamp)
+            .field("language", &self.language)
+            .field("query", &self.query.as_deref().map(crate::error::sanitize_url))
+            .finish()
+    } // fn
+} // impl
@@ -7,7 +7,7 @@ use chrono::{DateTime, Utc};
 
 // Explict lifetime required for `geo` feature
 #[allow(clippy::needless_lifetimes)]
-impl<'r> Request<'r> {
+impl Request {
     // -------------------------------------------------------------------------
     //
     /// Initializes the builder pattern for a Time Zone API query with the
@@ -38,15 +38,15 @@ impl<'r> Request<'r> {
     /// ```
 
     #[must_use]
-    pub const fn new(
+    pub fn new(
         client: &GoogleMapsClient,
         location: LatLng,
         timestamp: DateTime<Utc>
-    ) -> Request {
+    ) -> Self {
         // Instantiate struct and return it to caller:
-        Request {
+        Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             location,
             timestamp,
             // Optional parameters:
@@ -74,14 +74,14 @@ impl<'r> Request<'r> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_coordinate<'g>(
-        client: &'r GoogleMapsClient,
+        client: &GoogleMapsClient,
         coordinate: &'g geo_types::Coord,
         timestamp: DateTime<Utc>
     ) -> Result<Self, crate::error::Error> {
         // Instantiate struct and return it to caller:
-        Ok(Request {
+        Ok(Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             location: LatLng::try_from(coordinate)?,
             timestamp,
             // Optional parameters:
@@ -109,14 +109,14 @@ impl<'r> Request<'r> {
 
     #[cfg(feature = "geo")]
     pub fn try_new_point<'g>(
-        client: &'r GoogleMapsClient,
+        client: &GoogleMapsClient,
         point: &'g geo_types::Point,
         timestamp: DateTime<Utc>
     ) -> Result<Self, crate::error::Error> {
         // Instantiate struct and return it to caller:
-        Ok(Request {
+        Ok(Self {
             // Required parameters:
-            client,
+            client: client.clone(),
             location: LatLng::try_from(point)?,
             timestamp,
             // Optional parameters:
@@ -0,0 +1,47 @@
+//! Contains the `LocalTimeZone` struct, a condensed form of a successful
+//! _Time Zone API_ response.
+
+use crate::time_zone::response::Response;
+use chrono::Duration;
+use chrono_tz::Tz;
+
+// -----------------------------------------------------------------------------
+//
+/// The time zone in effect at a location, at a specific time. This is
+/// returned by `GoogleMapsClient::time_zone_at`, and may be taken from a
+/// successful `Response` with `Response::local_time_zone`.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocalTimeZone {
+    /// The IANA time zone ID, such as `America/Los_Angeles` or
+    /// `Australia/Sydney`.
+    pub time_zone_id: String,
+
+    /// The total offset from UTC at the requested time. This is the sum of
+    /// the time zone's raw offset and the daylight savings offset.
+    pub offset: Duration,
+
+    /// The parsed time zone, which may be used to convert times to the
+    /// location's local time with `chrono`.
+    pub tz: Tz,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Condenses the response into a `LocalTimeZone`. Returns `None` if the
+    /// response is missing the time zone ID or either of the offsets, which
+    /// is the case when the request was unsuccessful - for example, when the
+    /// location is over water and Google responded with `ZERO_RESULTS`.
+    #[must_use]
+    pub fn local_time_zone(&self) -> Option<LocalTimeZone> {
+        let tz = self.time_zone_id?;
+        let offset = i64::from(self.raw_offset?) + i64::from(self.dst_offset?);
+
+        Some(LocalTimeZone {
+            time_zone_id: tz.name().to_string(),
+            offset: Duration::seconds(offset),
+            tz,
+        }) // LocalTimeZone
+    } // fn
+} // impl
@@ -2,10 +2,12 @@
 //! the Google Maps Platform. Look in here for more information about the data
 //! returned from Google's server and how to parse it with your program.
 
+pub mod local_time_zone;
 pub mod status;
 
 use crate::time_zone::response::status::Status;
 use chrono_tz::Tz;
+use crate::types::{GoogleStatus, HasStatus};
 use serde::{Deserialize, Serialize};
 
 /// The response from the Google Maps Time Zone API will be stored in this
@@ -26,7 +28,7 @@ pub struct Response {
     /// The offset for daylight-savings time in seconds. This will be zero if
     /// the time zone is not in Daylight Savings Time during the specified
     /// `time`.
-    #[serde(alias = "dstOffset")]
+    #[serde(rename = "dstOffset", alias = "dst_offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dst_offset: Option<i16>,
 
@@ -35,13 +37,13 @@ pub struct Response {
     ///
     /// **Note**: This field is not guaranteed to be always present, and its
     /// content is subject to change.
-    #[serde(alias = "errorMessage")]
+    #[serde(rename = "errorMessage", alias = "error_message")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error_message: Option<String>,
 
     /// The offset from UTC (in seconds) for the given location. This does not
     /// take into effect daylight savings.
-    #[serde(alias = "rawOffset")]
+    #[serde(rename = "rawOffset", alias = "raw_offset")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub raw_offset: Option<i16>,
 
@@ -56,24 +58,66 @@ pub struct Response {
     /// When a timezone has several IDs, the canonical one is returned. In
     /// timezone.xml, this is the first alias of each timezone. For example,
     /// `Asia/Calcutta` is returned, not `Asia/Kolkata`.
-    #[serde(alias = "timeZoneId")]
+    #[serde(rename = "timeZoneId", alias = "time_zone_id")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone_id: Option<Tz>,
 
     /// A string containing the long form name of the time zone. This field will
     /// be localized if the language parameter is set. eg. "Pacific Daylight
     /// Time" or "Australian Eastern Daylight Time"
-    #[serde(alias = "timeZoneName")]
+    #[serde(rename = "timeZoneName", alias = "time_zone_name")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone_name: Option<String>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl HasStatus for Response {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus {
+        GoogleStatus::from(self.status.as_str())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the name of a field that Google always includes in an `OK`
+    /// response, if it is missing from this response. An `OK` response that
+    /// is missing such a field is malformed.
+    #[cfg(feature = "reqwest")]
+    pub(crate) const fn missing_field(&self) -> Option<&'static str> {
+        if self.time_zone_id.is_none() {
+            Some("timeZoneId")
+        } else if self.raw_offset.is_none() {
+            Some("rawOffset")
+        } else if self.dst_offset.is_none() {
+            Some("dstOffset")
+        } else {
+            None
+        } // if
+    } // fn
+} // impl
+
+#[cfg(any(feature = "simd-json", feature = "serde-json"))]
 impl std::str::FromStr for Response {
-    type Err = simd_json::Error;
+    type Err = crate::json::Error;
     /// Parse a Google Maps Time Zone API JSON `String` response into a
     /// usable `Response` struct.
-    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
-        let mut bytes = s.to_string().into_bytes();
-        simd_json::serde::from_slice(&mut bytes)
+    fn from_str(s: &str) -> Result<Self, crate::json::Error> {
+        crate::json::parse_json_str(s)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use super::Response;
+    use crate::json::tests::{assert_round_trip, cassette_body};
+
+    #[test]
+    fn time_zone_response_round_trips() {
+        assert_round_trip::<Response>(&cassette_body("time_zone", 0));
+    } // fn
+} // mod
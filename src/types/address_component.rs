@@ -1,6 +1,6 @@
 //! Contains the separate components applicable to an address.
 
-use crate::types::PlaceType;
+use crate::types::{MaybeKnown, PlaceType};
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -43,7 +43,7 @@ pub struct AddressComponent {
     /// An array indicating the type of the address component. See the list of
     /// [supported
     /// types](https://developers.google.com/places/web-service/supported_types).
-    #[serde(default)]
+    #[serde(default, alias = "type")] // `<type>` elements in XML
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub types: Vec<PlaceType>,
+    pub types: Vec<MaybeKnown<PlaceType>>,
 } // struct
@@ -0,0 +1,111 @@
+//! Provides `Bounds` conversion methods for the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::types::{bounds::Bounds, latlng::LatLng};
+use ::geojson::{Geometry, Value};
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns the bounding box as a `GeoJSON` `Polygon` geometry.
+    ///
+    /// The polygon has a single, closed ring of 5 positions: the south-west,
+    /// south-east, north-east and north-west corners, then the south-west
+    /// corner again. This is counter-clockwise, as `GeoJSON` requires for an
+    /// exterior ring. Each position is ordered `[longitude, latitude]`.
+    ///
+    /// Bounds that cross the antimeridian are not split into two polygons, so
+    /// the ring's eastern edge is west of its western edge.
+    ///
+    /// ```rust
+    /// let polygon = bounds.to_geojson_polygon();
+    /// ```
+
+    #[must_use]
+    pub fn to_geojson_polygon(&self) -> Geometry {
+        let southeast = LatLng {
+            lat: self.southwest.lat,
+            lng: self.northeast.lng,
+        }; // LatLng
+
+        let northwest = LatLng {
+            lat: self.northeast.lat,
+            lng: self.southwest.lng,
+        }; // LatLng
+
+        let ring = vec![
+            self.southwest.to_geojson_position(),
+            southeast.to_geojson_position(),
+            self.northeast.to_geojson_position(),
+            northwest.to_geojson_position(),
+            self.southwest.to_geojson_position(),
+        ]; // vec!
+
+        Geometry::new(Value::Polygon(vec![ring]))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&Bounds> for Geometry {
+    /// Converts a borrowed `&google_maps::Bounds` struct to a `GeoJSON`
+    /// `Polygon` geometry.
+    fn from(bounds: &Bounds) -> Self {
+        bounds.to_geojson_polygon()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{bounds::Bounds, latlng::LatLng};
+    use ::geojson::{Geometry, Value};
+    use rust_decimal_macros::dec;
+
+    /// Returns the positions of a polygon's exterior ring.
+    fn exterior_ring(geometry: Geometry) -> Vec<Vec<f64>> {
+        let Value::Polygon(mut rings) = geometry.value else {
+            panic!("expected a polygon, got {:?}", geometry.value);
+        };
+        assert_eq!(rings.len(), 1);
+        rings.remove(0)
+    } // fn
+
+    #[test]
+    fn converts_bounds_to_a_closed_ring() {
+        let bounds = Bounds {
+            southwest: LatLng::try_from_dec(dec!(43.5), dec!(-79.5)).unwrap(),
+            northeast: LatLng::try_from_dec(dec!(45.5), dec!(-73.5)).unwrap(),
+        }; // Bounds
+
+        let ring = exterior_ring(bounds.to_geojson_polygon());
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+
+        // Counter-clockwise from the south-west corner, longitude first:
+        assert_eq!(ring, [
+            vec![-79.5, 43.5],
+            vec![-73.5, 43.5],
+            vec![-73.5, 45.5],
+            vec![-79.5, 45.5],
+            vec![-79.5, 43.5],
+        ]);
+
+        assert_eq!(Geometry::from(&bounds), bounds.to_geojson_polygon());
+    } // fn
+
+    #[test]
+    fn keeps_the_corners_of_bounds_that_cross_the_antimeridian() {
+        let bounds = Bounds {
+            southwest: LatLng::try_from_dec(dec!(-20), dec!(170)).unwrap(),
+            northeast: LatLng::try_from_dec(dec!(-10), dec!(-170)).unwrap(),
+        }; // Bounds
+
+        let ring = exterior_ring(bounds.to_geojson_polygon());
+        assert_eq!(ring.len(), 5);
+        assert_eq!(ring.first(), ring.last());
+        assert_eq!(ring[1], vec![-170.0, -20.0]);
+        assert_eq!(ring[3], vec![170.0, -10.0]);
+    } // fn
+} // mod
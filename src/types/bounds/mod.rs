@@ -4,16 +4,32 @@
 
 #[cfg(feature = "geo")]
 mod geo;
+#[cfg(feature = "geojson")]
+mod geojson;
 
 // -----------------------------------------------------------------------------
 
 use crate::types::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
-use crate::types::latlng::LatLng;
+use crate::types::latlng::{LatLng, EARTH_RADIUS_METERS};
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
 
+/// The width & height of the whole world, in pixels, at zoom level 0 of a Web
+/// Mercator map.
+const WORLD_SIZE_PIXELS: f64 = 256.0;
+
+/// The highest zoom level that Google Maps supports.
+const MAX_ZOOM: u8 = 21;
+
+/// The latitude beyond which Web Mercator maps are cut off, in degrees.
+const MAX_MERCATOR_LATITUDE: f64 = 85.051_128_78;
+
+// -----------------------------------------------------------------------------
+
 /// Contains the recommended viewport for displaying the returned result,
 /// specified as two latitude & longitude pairs defining the southwest and
 /// northeast corner of the viewport bounding box. Generally the viewport is
@@ -68,9 +84,20 @@ impl std::str::FromStr for Bounds {
     type Err = GoogleMapsError;
     /// Gets a `Bounds` struct from a `String` that contains two pipe-delimited
     /// latitude & longitude pairs.
+    ///
+    /// A single latitude & longitude pair (with no pipe) is also accepted. It
+    /// is converted into a degenerate, zero-area `Bounds` where the south-west
+    /// and north-east corners are the same point.
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         let corner: Vec<&str> = value.trim().split('|').collect();
-        if corner.len() == 2 {
+        if corner.len() == 1 {
+            let point = LatLng::from_str(corner[0].trim());
+            let point = point.map_err(|_| TypeError::InvalidBoundsString(value.to_owned()))?;
+            Ok(Self {
+                southwest: point,
+                northeast: point,
+            })
+        } else if corner.len() == 2 {
             let southwest = LatLng::from_str(corner[0].trim());
             let southwest =
                 southwest.map_err(|_| TypeError::InvalidBoundsString(value.to_owned()))?;
@@ -86,3 +113,524 @@ impl std::str::FromStr for Bounds {
         } // if
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Returns the bounding box that encloses a circle of `radius_meters`
+    /// metres around `center`. This is useful for "within 5 km of this point"
+    /// searches, for example to bias geocoding results with a viewport.
+    ///
+    /// The latitude delta is the radius's angle on the earth's surface. The
+    /// longitude delta is scaled by `1 / cos(latitude)`, because meridians
+    /// converge towards the poles. The corners are rounded to 7 decimal
+    /// places, which is roughly one centimetre of precision.
+    ///
+    /// Latitudes are clamped to the -90° to 90° range. If the box reaches a
+    /// pole, or would be wider than the entire globe, it is widened to -180°
+    /// to 180°. Otherwise a box that crosses the antimeridian wraps around it,
+    /// so that its south-west longitude is greater than its north-east
+    /// longitude. A negative, `NaN` or infinite radius is treated as zero.
+    ///
+    /// ```rust
+    /// let bounds = Bounds::from_center_and_radius(latlng, 5_000.0);
+    /// ```
+
+    #[must_use]
+    pub fn from_center_and_radius(center: LatLng, radius_meters: f64) -> Self {
+        let radius = if radius_meters.is_finite() && radius_meters > 0.0 {
+            radius_meters
+        } else {
+            0.0
+        }; // if
+
+        let lat = center.lat_f64();
+        let lng = center.lng_f64();
+
+        let lat_delta = (radius / EARTH_RADIUS_METERS).to_degrees();
+        let lng_delta = lat_delta / lat.to_radians().cos();
+
+        let south = (lat - lat_delta).max(-90.0);
+        let north = (lat + lat_delta).min(90.0);
+
+        let (west, east) = if south <= -90.0 || north >= 90.0 || lng_delta >= 180.0 {
+            (-180.0, 180.0)
+        } else {
+            let west = lng - lng_delta;
+            let east = lng + lng_delta;
+            (
+                if west < -180.0 { west + 360.0 } else { west },
+                if east > 180.0 { east - 360.0 } else { east },
+            )
+        }; // if
+
+        // Every coordinate is finite, so the conversions can not fail:
+        let degrees = |degrees: f64| Decimal::from_f64(degrees).unwrap_or_default();
+
+        Self {
+            southwest: LatLng { lat: degrees(south), lng: degrees(west) },
+            northeast: LatLng { lat: degrees(north), lng: degrees(east) },
+        } // Bounds
+        .rounded(7)
+    } // fn
+
+    /// Splits the bounding box into an evenly-sized grid of `rows` × `cols`
+    /// smaller bounding boxes. This can be used to parallelize a scan over a
+    /// large region, for example with multiple _Nearby Search_ requests.
+    ///
+    /// Tiles are returned row-by-row, starting from the south-west corner.
+    /// Bounding boxes that cross the antimeridian (where the south-west
+    /// longitude is greater than the north-east longitude) are supported.
+    ///
+    /// If either `rows` or `cols` is zero, an empty `Vec` is returned.
+    ///
+    /// ```rust
+    /// let tiles = bounds.subdivide(2, 2);
+    /// assert_eq!(tiles.len(), 4);
+    /// ```
+
+    #[must_use]
+    pub fn subdivide(&self, rows: usize, cols: usize) -> Vec<Self> {
+        if rows == 0 || cols == 0 {
+            return Vec::new();
+        } // if
+
+        let south = self.southwest.lat;
+        let north = self.northeast.lat;
+        let west = self.southwest.lng;
+
+        // Unwrap the eastern longitude for boxes that cross the antimeridian,
+        // so that the box's width can be evenly divided:
+        let east = if self.northeast.lng < west {
+            self.northeast.lng + dec!(360.0)
+        } else {
+            self.northeast.lng
+        }; // if
+
+        let lat_step = (north - south) / Decimal::from(rows);
+        let lng_step = (east - west) / Decimal::from(cols);
+
+        // Wrap an unwrapped longitude back into the -180° to 180° range. The
+        // western edge of a tile uses -180° rather than 180°:
+        let wrap_west = |lng: Decimal| if lng >= dec!(180.0) { lng - dec!(360.0) } else { lng };
+        let wrap_east = |lng: Decimal| if lng > dec!(180.0) { lng - dec!(360.0) } else { lng };
+
+        (0..rows)
+            .flat_map(|row| {
+                // The last row & column use the original edges, to avoid any
+                // rounding errors from the division:
+                let tile_south = south + lat_step * Decimal::from(row);
+                let tile_north = if row + 1 == rows {
+                    north
+                } else {
+                    south + lat_step * Decimal::from(row + 1)
+                }; // if
+
+                (0..cols).map(move |col| {
+                    let tile_west = wrap_west(west + lng_step * Decimal::from(col));
+                    let tile_east = if col + 1 == cols {
+                        self.northeast.lng
+                    } else {
+                        wrap_east(west + lng_step * Decimal::from(col + 1))
+                    }; // if
+
+                    Self {
+                        southwest: LatLng { lat: tile_south, lng: tile_west },
+                        northeast: LatLng { lat: tile_north, lng: tile_east },
+                    } // Bounds
+                }) // map
+            }) // flat_map
+            .collect()
+    } // fn
+
+    /// Returns `true` if `point` is inside the bounding box or on its edge.
+    /// Bounding boxes that cross the antimeridian (where the south-west
+    /// longitude is greater than the north-east longitude) are supported.
+    ///
+    /// ```rust
+    /// if geocoding.geometry.viewport.contains(&latlng) {
+    ///     println!("{latlng} is in view");
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn contains(&self, point: &LatLng) -> bool {
+        let within_lat = self.southwest.lat <= point.lat && point.lat <= self.northeast.lat;
+
+        let within_lng = if self.southwest.lng <= self.northeast.lng {
+            self.southwest.lng <= point.lng && point.lng <= self.northeast.lng
+        } else {
+            self.southwest.lng <= point.lng || point.lng <= self.northeast.lng
+        }; // if
+
+        within_lat && within_lng
+    } // fn
+
+    /// Returns a copy of the bounding box grown by `padding_degrees` on every
+    /// side. This is useful for framing a result on a map with some margin
+    /// around it.
+    ///
+    /// Latitudes are clamped to the -90° to 90° range. Longitudes wrap around
+    /// the antimeridian, and if the padded box would span the entire globe, it
+    /// is widened to -180° to 180°. A negative, `NaN` or infinite padding is
+    /// treated as zero.
+    ///
+    /// ```rust
+    /// let padded = bounds.expand(0.01);
+    /// ```
+
+    #[must_use]
+    pub fn expand(&self, padding_degrees: f64) -> Self {
+        let padding = Decimal::from_f64(padding_degrees)
+            .filter(Decimal::is_sign_positive)
+            .unwrap_or_default();
+
+        let south = (self.southwest.lat - padding).max(dec!(-90.0));
+        let north = (self.northeast.lat + padding).min(dec!(90.0));
+
+        // Measure the box's width, taking into account boxes that cross the
+        // antimeridian:
+        let width = if self.northeast.lng < self.southwest.lng {
+            self.northeast.lng + dec!(360.0) - self.southwest.lng
+        } else {
+            self.northeast.lng - self.southwest.lng
+        }; // if
+
+        let (west, east) = if width + padding * dec!(2.0) >= dec!(360.0) {
+            (dec!(-180.0), dec!(180.0))
+        } else {
+            let west = self.southwest.lng - padding;
+            let east = self.northeast.lng + padding;
+            (
+                if west < dec!(-180.0) { west + dec!(360.0) } else { west },
+                if east > dec!(180.0) { east - dec!(360.0) } else { east },
+            )
+        }; // if
+
+        Self {
+            southwest: LatLng { lat: south, lng: west },
+            northeast: LatLng { lat: north, lng: east },
+        } // Bounds
+    } // fn
+
+    /// Returns the center and the zoom level that fit the bounding box in a
+    /// `width_px` × `height_px` map viewport, such as a _Static Maps_ image or
+    /// a front-end map, with `padding_px` pixels of margin on every side.
+    ///
+    /// This uses the Web Mercator projection of Google Maps. The zoom level
+    /// is calculated separately for each axis, and the lower of the two is
+    /// returned, so that a tall & narrow or a short & wide box fits both ways.
+    /// The zoom level is clamped to the 0 to 21 range. A zero-area box gets
+    /// the maximum zoom level, and a viewport that is entirely padding gets
+    /// zoom level 0. Boxes that cross the antimeridian are supported.
+    ///
+    /// The center is the middle of the box on the map, so its latitude is
+    /// taken halfway between the corners in Mercator space rather than in
+    /// degrees. Latitudes beyond ±85.05°, which Web Mercator maps do not show,
+    /// are clamped.
+    ///
+    /// ```rust
+    /// let (center, zoom) = bounds.best_fit_zoom(640, 640, 20);
+    /// ```
+
+    #[must_use]
+    pub fn best_fit_zoom(&self, width_px: u32, height_px: u32, padding_px: u32) -> (LatLng, u8) {
+        // The latitude's position on a Mercator map, in radians:
+        let mercator_y = |lat: f64| {
+            let lat = lat.clamp(-MAX_MERCATOR_LATITUDE, MAX_MERCATOR_LATITUDE).to_radians();
+            (std::f64::consts::FRAC_PI_4 + lat / 2.0).tan().ln()
+        }; // closure
+
+        let south = mercator_y(self.southwest.lat_f64());
+        let north = mercator_y(self.northeast.lat_f64());
+        let west = self.southwest.lng_f64();
+
+        // Measure the box's width, taking into account boxes that cross the
+        // antimeridian:
+        let mut width_degrees = self.northeast.lng_f64() - west;
+        if width_degrees < 0.0 {
+            width_degrees += 360.0;
+        } // if
+
+        // The fraction of the whole world that the box covers on each axis:
+        let lat_fraction = (north - south).abs() / std::f64::consts::TAU;
+        let lng_fraction = width_degrees / 360.0;
+
+        // The zoom level at which a fraction of the world fills the pixels:
+        let zoom = |pixels: u32, fraction: f64| {
+            let pixels = f64::from(pixels.saturating_sub(padding_px.saturating_mul(2)));
+            if pixels <= 0.0 {
+                0.0
+            } else {
+                (pixels / WORLD_SIZE_PIXELS / fraction).log2().floor()
+            } // if
+        }; // closure
+
+        // The `clamp` and the `NaN` check keep the conversion within `u8`:
+        let zoom = zoom(width_px, lng_fraction).min(zoom(height_px, lat_fraction));
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let zoom = if zoom.is_nan() { 0 } else { zoom.clamp(0.0, f64::from(MAX_ZOOM)) as u8 };
+
+        // Find the center in Mercator space, and convert it back to degrees:
+        let center_lat = ((south + north) / 2.0).exp().atan().mul_add(2.0, -std::f64::consts::FRAC_PI_2).to_degrees();
+        let mut center_lng = width_degrees.mul_add(0.5, west);
+        if center_lng > 180.0 {
+            center_lng -= 360.0;
+        } // if
+
+        // Every coordinate is finite, so the conversions can not fail:
+        let degrees = |degrees: f64| Decimal::from_f64(degrees).unwrap_or_default();
+        let center = LatLng { lat: degrees(center_lat), lng: degrees(center_lng) }.rounded(7);
+
+        (center, zoom)
+    } // fn
+
+    /// Returns a copy of the bounding box, with the coordinates of both
+    /// corners rounded to `decimals` decimal places. See `LatLng::rounded`.
+
+    #[must_use]
+    pub fn rounded(&self, decimals: u8) -> Self {
+        Self {
+            southwest: self.southwest.rounded(decimals),
+            northeast: self.northeast.rounded(decimals),
+        } // Bounds
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::client::Coordinates for Bounds {
+    fn rounded(&self, decimals: u8) -> Self {
+        Self::rounded(self, decimals)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::Bounds;
+    use crate::types::LatLng;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    /// Returns the height and width of a bounding box that doesn't cross the
+    /// antimeridian, in degrees.
+    fn size(bounds: &Bounds) -> (f64, f64) {
+        (
+            bounds.northeast.lat_f64() - bounds.southwest.lat_f64(),
+            bounds.northeast.lng_f64() - bounds.southwest.lng_f64(),
+        )
+    } // fn
+
+    #[test]
+    fn widens_the_box_away_from_the_equator() {
+        let equator = Bounds::from_center_and_radius(LatLng::from_f64(0.0, 10.0).unwrap(), 5_000.0);
+        let north = Bounds::from_center_and_radius(LatLng::from_f64(70.0, 10.0).unwrap(), 5_000.0);
+        let (equator_height, equator_width) = size(&equator);
+        let (north_height, north_width) = size(&north);
+
+        // 5 km is about 0.045° of latitude, anywhere on the globe:
+        assert!((equator_height - 0.089_932).abs() < 1e-5, "{equator_height}");
+        assert!((north_height - equator_height).abs() < 1e-6, "{north_height}");
+
+        // At the equator the box is square, but at 70°N each degree of
+        // longitude is only cos(70°) as wide, so the box is wider in degrees:
+        assert!((equator_width - equator_height).abs() < 1e-6, "{equator_width}");
+        let ratio = north_width / equator_width;
+        assert!((ratio - 1.0 / 70_f64.to_radians().cos()).abs() < 1e-4, "{ratio}");
+    } // fn
+
+    #[test]
+    fn clamps_a_box_that_reaches_a_pole() {
+        let north = Bounds::from_center_and_radius(LatLng::from_f64(89.99, 10.0).unwrap(), 5_000.0);
+        assert_eq!(north.northeast.lat, dec!(90));
+        assert!((north.southwest.lat_f64() - 89.945_034).abs() < 1e-5, "{north:?}");
+        assert_eq!(north.southwest.lng, dec!(-180));
+        assert_eq!(north.northeast.lng, dec!(180));
+
+        let south = Bounds::from_center_and_radius(LatLng::from_f64(-89.99, 10.0).unwrap(), 5_000.0);
+        assert_eq!(south.southwest.lat, dec!(-90));
+        assert_eq!(south.southwest.lng, dec!(-180));
+        assert_eq!(south.northeast.lng, dec!(180));
+    } // fn
+
+    #[test]
+    fn wraps_a_box_around_the_antimeridian() {
+        let bounds = Bounds::from_center_and_radius(LatLng::from_f64(0.0, 179.99).unwrap(), 5_000.0);
+        assert!(bounds.southwest.lng > bounds.northeast.lng, "{bounds:?}");
+        assert!((bounds.northeast.lng_f64() - -179.965_034).abs() < 1e-5, "{bounds:?}");
+    } // fn
+
+    #[test]
+    fn fits_a_one_degree_box_at_the_equator() {
+        let bounds: Bounds = "-0.5,0|0.5,1".parse().unwrap();
+
+        // The box is 1/360 of the world's width. At zoom level `z` the world
+        // is 256 × 2^z pixels wide, so the box fills 640 pixels at
+        // 2^z = 640 × 360 / 256 = 900, or z ≈ 9.8. Near the equator the
+        // Mercator height of 1° is about the same as its width, so zoom
+        // level 9 is the deepest that shows the entire box:
+        let (center, zoom) = bounds.best_fit_zoom(640, 640, 0);
+        assert_eq!(zoom, 9);
+        assert_eq!(center, LatLng::from_f64(0.0, 0.5).unwrap());
+
+        // With 200 pixels of padding on each side, only 240 pixels are left,
+        // so 2^z = 240 × 360 / 256 = 337.5, or z ≈ 8.4:
+        assert_eq!(bounds.best_fit_zoom(640, 640, 200).1, 8);
+    } // fn
+
+    #[test]
+    fn fits_the_narrower_axis_of_a_tall_or_wide_box() {
+        // 8° tall & 1° wide: 2^z = 640 × 360 / 256 / 8 = 112.5, or z ≈ 6.8:
+        let tall: Bounds = "-4,0|4,1".parse().unwrap();
+        assert_eq!(tall.best_fit_zoom(640, 640, 0).1, 6);
+
+        // 1° tall & 8° wide, in a viewport that's twice as wide as it is
+        // tall: 2^z = 1280 × 360 / 256 / 8 = 225, or z ≈ 7.8:
+        let wide: Bounds = "-0.5,0|0.5,8".parse().unwrap();
+        assert_eq!(wide.best_fit_zoom(1280, 640, 0).1, 7);
+    } // fn
+
+    #[test]
+    fn clamps_the_zoom_level() {
+        let point: Bounds = "45.5,-73.5".parse().unwrap();
+        let (center, zoom) = point.best_fit_zoom(640, 640, 0);
+        assert_eq!(zoom, 21);
+        assert_eq!(center, LatLng::from_f64(45.5, -73.5).unwrap());
+
+        let world: Bounds = "-85,-180|85,180".parse().unwrap();
+        assert_eq!(world.best_fit_zoom(640, 640, 0).1, 1);
+        assert_eq!(world.best_fit_zoom(640, 640, 320).1, 0);
+    } // fn
+
+    #[test]
+    fn fits_a_box_that_crosses_the_antimeridian() {
+        let bounds: Bounds = "-0.5,179.5|0.5,-179".parse().unwrap();
+        let (center, zoom) = bounds.best_fit_zoom(640, 640, 0);
+        // 1.5° wide: 2^z = 640 × 360 / 256 / 1.5 = 600, or z ≈ 9.2:
+        assert_eq!(zoom, 9);
+        assert_eq!(center, LatLng::from_f64(0.0, -179.75).unwrap());
+    } // fn
+
+    /// Returns the area of a bounding box in square degrees, unwrapping the
+    /// longitude of a box that crosses the antimeridian.
+    fn area(bounds: &Bounds) -> Decimal {
+        let mut width = bounds.northeast.lng - bounds.southwest.lng;
+        if width < Decimal::ZERO {
+            width += dec!(360);
+        } // if
+        width * (bounds.northeast.lat - bounds.southwest.lat)
+    } // fn
+
+    /// Asserts that the tiles cover the original bounding box exactly: every
+    /// tile is inside it, no two tiles overlap, and their areas add up to the
+    /// original's area.
+    fn assert_tiles_cover(bounds: &Bounds, tiles: &[Bounds]) {
+        // Longitudes are unwrapped to increase eastwards from the original
+        // box's western edge, so that intervals can be compared:
+        let unwrap = |lng: Decimal| if lng < bounds.southwest.lng { lng + dec!(360) } else { lng };
+        let overlaps = |a: (Decimal, Decimal), b: (Decimal, Decimal)| a.0.max(b.0) < a.1.min(b.1);
+
+        for (index, tile) in tiles.iter().enumerate() {
+            assert!(bounds.contains(&tile.southwest) && bounds.contains(&tile.northeast), "{tile:?}");
+            for other in &tiles[index + 1..] {
+                // Two tiles may share an edge, but not an area:
+                let lats = overlaps(
+                    (tile.southwest.lat, tile.northeast.lat),
+                    (other.southwest.lat, other.northeast.lat),
+                );
+                let lngs = overlaps(
+                    (unwrap(tile.southwest.lng), unwrap(tile.northeast.lng)),
+                    (unwrap(other.southwest.lng), unwrap(other.northeast.lng)),
+                );
+                assert!(!(lats && lngs), "{tile:?} overlaps {other:?}");
+            } // for
+        } // for
+        assert_eq!(tiles.iter().map(area).sum::<Decimal>(), area(bounds));
+    } // fn
+
+    #[test]
+    fn subdivides_a_box_into_a_grid() {
+        let bounds: Bounds = "10,20|12,24".parse().unwrap();
+        let tiles = bounds.subdivide(2, 2);
+
+        let expected: Vec<Bounds> = ["10,20|11,22", "10,22|11,24", "11,20|12,22", "11,22|12,24"]
+            .iter()
+            .map(|tile| tile.parse().unwrap())
+            .collect();
+        assert_eq!(tiles, expected);
+        assert_tiles_cover(&bounds, &tiles);
+
+        // The outer edges of the grid are the original box's edges:
+        assert_eq!(tiles[0].southwest, bounds.southwest);
+        assert_eq!(tiles[3].northeast, bounds.northeast);
+    } // fn
+
+    #[test]
+    fn subdivides_a_box_that_crosses_the_antimeridian() {
+        let bounds: Bounds = "-1,170|1,-170".parse().unwrap();
+        let tiles = bounds.subdivide(2, 2);
+
+        // The western column ends at 180°, and the eastern column starts at
+        // -180°, rather than either column spanning the whole globe:
+        let expected: Vec<Bounds> = ["-1,170|0,180", "-1,-180|0,-170", "0,170|1,180", "0,-180|1,-170"]
+            .iter()
+            .map(|tile| tile.parse().unwrap())
+            .collect();
+        assert_eq!(tiles, expected);
+        assert_tiles_cover(&bounds, &tiles);
+    } // fn
+
+    #[test]
+    fn subdivides_into_nothing_without_rows_or_columns() {
+        let bounds: Bounds = "10,20|12,24".parse().unwrap();
+        assert!(bounds.subdivide(0, 2).is_empty());
+        assert!(bounds.subdivide(2, 0).is_empty());
+    } // fn
+
+    /// Returns a bounding box from its southwest and northeast corners.
+    fn bounds(south: Decimal, west: Decimal, north: Decimal, east: Decimal) -> Bounds {
+        Bounds {
+            southwest: LatLng::try_from_dec(south, west).unwrap(),
+            northeast: LatLng::try_from_dec(north, east).unwrap(),
+        } // Bounds
+    } // fn
+
+    #[test]
+    fn expands_a_box_on_every_side() {
+        let expanded = bounds(dec!(10), dec!(20), dec!(11), dec!(22)).expand(0.25);
+        assert_eq!(expanded, bounds(dec!(9.75), dec!(19.75), dec!(11.25), dec!(22.25)));
+    } // fn
+
+    #[test]
+    fn clamps_an_expanded_box_at_the_poles() {
+        let expanded = bounds(dec!(-89.5), dec!(0), dec!(89.5), dec!(10)).expand(1.0);
+        assert_eq!(expanded, bounds(dec!(-90), dec!(-1), dec!(90), dec!(11)));
+    } // fn
+
+    #[test]
+    fn wraps_an_expanded_box_around_the_antimeridian() {
+        let expanded = bounds(dec!(0), dec!(170), dec!(10), dec!(179.5)).expand(1.0);
+        assert_eq!(expanded, bounds(dec!(-1), dec!(169), dec!(11), dec!(-179.5)));
+        assert!(expanded.contains(&LatLng::try_from_dec(dec!(5), dec!(-179.8)).unwrap()));
+
+        // A box that already crosses the antimeridian:
+        let expanded = bounds(dec!(0), dec!(179), dec!(10), dec!(-179)).expand(1.0);
+        assert_eq!(expanded, bounds(dec!(-1), dec!(178), dec!(11), dec!(-178)));
+    } // fn
+
+    #[test]
+    fn widens_an_expanded_box_to_the_whole_globe() {
+        let expanded = bounds(dec!(0), dec!(-170), dec!(10), dec!(170)).expand(15.0);
+        assert_eq!(expanded, bounds(dec!(-15), dec!(-180), dec!(25), dec!(180)));
+    } // fn
+
+    #[test]
+    fn ignores_negative_or_invalid_padding() {
+        let original = bounds(dec!(10), dec!(20), dec!(11), dec!(22));
+        assert_eq!(original.expand(-1.0), original);
+        assert_eq!(original.expand(f64::NAN), original);
+        assert_eq!(original.expand(f64::INFINITY), original);
+    } // fn
+} // mod
@@ -3,6 +3,7 @@
 //! codes](https://en.wikipedia.org/wiki/ISO_3166-1).
 
 use crate::error::Error as GoogleMapsError;
+use crate::types::error::Error as TypeError;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -331,258 +332,7 @@ impl std::convert::From<&Country> for &str {
     /// Alpha-2](https://en.wikipedia.org/wiki/List_of_ISO_3166_country_codes)
     /// country code.
     fn from(country: &Country) -> Self {
-        match country {
-            Country::Afghanistan => "AF",
-            Country::AlandIslands => "AX",
-            Country::Albania => "AL",
-            Country::Algeria => "DZ",
-            Country::AmericanSamoa => "AS",
-            Country::Andorra => "AD",
-            Country::Angola => "AO",
-            Country::Anguilla => "AI",
-            Country::Antarctica => "AQ",
-            Country::AntiguaAndBarbuda => "AG",
-            Country::Argentina => "AR",
-            Country::Armenia => "AM",
-            Country::Aruba => "AW",
-            Country::Australia => "AU",
-            Country::Austria => "AT",
-            Country::Azerbaijan => "AZ",
-            Country::Bahamas => "BS",
-            Country::Bahrain => "BH",
-            Country::Bangladesh => "BD",
-            Country::Barbados => "BB",
-            Country::Belarus => "BY",
-            Country::Belgium => "BE",
-            Country::Belize => "BZ",
-            Country::Benin => "BJ",
-            Country::Bermuda => "BM",
-            Country::Bhutan => "BT",
-            Country::Bolivia => "BO",
-            Country::BonaireSintEustatiusAndSaba => "BQ",
-            Country::BosniaAndHerzegovina => "BA",
-            Country::Botswana => "BW",
-            Country::BouvetIsland => "BV",
-            Country::Brazil => "BR",
-            Country::BritishIndianOceanTerritory => "IO",
-            Country::BritishVirginIslands => "VG",
-            Country::Brunei => "BN",
-            Country::Bulgaria => "BG",
-            Country::BurkinaFaso => "BF",
-            Country::Burundi => "BI",
-            Country::CaboVerde => "CV",
-            Country::Cambodia => "KH",
-            Country::Cameroon => "CM",
-            Country::Canada => "CA",
-            Country::CaymanIslands => "KY",
-            Country::CentralAfricanRepublic => "CF",
-            Country::Chad => "TD",
-            Country::Chile => "CL",
-            Country::China => "CN",
-            Country::ChristmasIsland => "CX",
-            Country::CocosKeelingIslands => "CC",
-            Country::Colombia => "CO",
-            Country::Comoros => "KM",
-            Country::CongoBraXXaville => "CG",
-            Country::CongoKinshasa => "CD",
-            Country::CookIslands => "CK",
-            Country::CostaRica => "CR",
-            Country::CotedIvoire => "CI",
-            Country::Croatia => "HR",
-            Country::Cuba => "CU",
-            Country::Curacao => "CW",
-            Country::Cyprus => "CY",
-            Country::Czechia => "CZ",
-            Country::Denmark => "DK",
-            Country::Djibouti => "DJ",
-            Country::Dominica => "DM",
-            Country::DominicanRepublic => "DO",
-            Country::Ecuador => "EC",
-            Country::Egypt => "EG",
-            Country::ElSalvador => "SV",
-            Country::EquatorialGuinea => "GQ",
-            Country::Eritrea => "ER",
-            Country::Estonia => "EE",
-            Country::Eswatini => "SZ",
-            Country::Ethiopia => "ET",
-            Country::FalklandIslands => "FK",
-            Country::FaroeIslands => "FO",
-            Country::Fiji => "FJ",
-            Country::Finland => "FI",
-            Country::France => "FR",
-            Country::FrenchGuiana => "GF",
-            Country::FrenchPolynesia => "PF",
-            Country::FrenchSouthernTerritories => "TF",
-            Country::Gabon => "GA",
-            Country::Gambia => "GM",
-            Country::Georgia => "GE",
-            Country::Germany => "DE",
-            Country::Ghana => "GH",
-            Country::Gibraltar => "GI",
-            Country::Greece => "GR",
-            Country::Greenland => "GL",
-            Country::Grenada => "GD",
-            Country::Guadeloupe => "GP",
-            Country::Guam => "GU",
-            Country::Guatemala => "GT",
-            Country::Guernsey => "GG",
-            Country::Guinea => "GN",
-            Country::GuineaBissau => "GW",
-            Country::Guyana => "GY",
-            Country::Haiti => "HT",
-            Country::HeardAndMcDonaldIslands => "HM",
-            Country::HolySee => "VA",
-            Country::Honduras => "HN",
-            Country::HongKong => "HK",
-            Country::Hungary => "HU",
-            Country::Iceland => "IS",
-            Country::India => "IN",
-            Country::Indonesia => "ID",
-            Country::Iran => "IR",
-            Country::Iraq => "IQ",
-            Country::Ireland => "IE",
-            Country::IsleOfMan => "IM",
-            Country::Israel => "IL",
-            Country::Italy => "IT",
-            Country::Jamaica => "JM",
-            Country::Japan => "JP",
-            Country::Jersey => "JE",
-            Country::Jordan => "JO",
-            Country::Kazakhstan => "KZ",
-            Country::Kenya => "KE",
-            Country::Kiribati => "KI",
-            Country::Kuwait => "KW",
-            Country::Kyrgyzstan => "KG",
-            Country::Laos => "LA",
-            Country::Latvia => "LV",
-            Country::Lebanon => "LB",
-            Country::Lesotho => "LS",
-            Country::Liberia => "LR",
-            Country::Libya => "LY",
-            Country::Liechtenstein => "LI",
-            Country::Lithuania => "LT",
-            Country::Luxembourg => "LU",
-            Country::Macao => "MO",
-            Country::Madagascar => "MG",
-            Country::Malawi => "MW",
-            Country::Malaysia => "MY",
-            Country::Maldives => "MV",
-            Country::Mali => "ML",
-            Country::Malta => "MT",
-            Country::MarshallIslands => "MH",
-            Country::Martinique => "MQ",
-            Country::Mauritania => "MR",
-            Country::Mauritius => "MU",
-            Country::Mayotte => "YT",
-            Country::Mexico => "MX",
-            Country::Micronesia => "FM",
-            Country::Moldova => "MD",
-            Country::Monaco => "MC",
-            Country::Mongolia => "MN",
-            Country::Montenegro => "ME",
-            Country::Montserrat => "MS",
-            Country::Morocco => "MA",
-            Country::Mozambique => "MZ",
-            Country::Myanmar => "MM",
-            Country::Namibia => "NA",
-            Country::Nauru => "NR",
-            Country::Nepal => "NP",
-            Country::Netherlands => "NL",
-            Country::NewCaledonia => "NC",
-            Country::NewZealand => "NZ",
-            Country::Nicaragua => "NI",
-            Country::Niger => "NE",
-            Country::Nigeria => "NG",
-            Country::Niue => "NU",
-            Country::NorfolkIsland => "NF",
-            Country::NorthernMarianaIslands => "MP",
-            Country::NorthKorea => "KP",
-            Country::NorthMacedonia => "MK",
-            Country::Norway => "NO",
-            Country::Oman => "OM",
-            Country::Pakistan => "PK",
-            Country::Palau => "PW",
-            Country::Palestine => "PS",
-            Country::Panama => "PA",
-            Country::PapuaNewGuinea => "PG",
-            Country::Paraguay => "PY",
-            Country::Peru => "PE",
-            Country::Philippines => "PH",
-            Country::Pitcairn => "PN",
-            Country::Poland => "PL",
-            Country::Portugal => "PT",
-            Country::PuertoRico => "PR",
-            Country::Qatar => "QA",
-            Country::Reunion => "RE",
-            Country::Romania => "RO",
-            Country::Russia => "RU",
-            Country::Rwanda => "RW",
-            Country::Samoa => "WS",
-            Country::SanMarino => "SM",
-            Country::SaoTomeAndPrincipe => "ST",
-            Country::SaudiArabia => "SA",
-            Country::Senegal => "SN",
-            Country::Serbia => "RS",
-            Country::Seychelles => "SC",
-            Country::SierraLeone => "SL",
-            Country::Singapore => "SG",
-            Country::SintMaarten => "SX",
-            Country::Slovakia => "SK",
-            Country::Slovenia => "SI",
-            Country::SolomonIslands => "SB",
-            Country::Somalia => "SO",
-            Country::SouthAfrica => "ZA",
-            Country::SouthGeorgiaAndSouthSandwichIslands => "GS",
-            Country::SouthKorea => "KR",
-            Country::SouthSudan => "SS",
-            Country::Spain => "ES",
-            Country::SriLanka => "LK",
-            Country::StBarthelemy => "BL",
-            Country::StHelena => "SH",
-            Country::StKittsAndNevis => "KN",
-            Country::StLucia => "LC",
-            Country::StMartin => "MF",
-            Country::StPierreAndMiquelon => "PM",
-            Country::StVincentAndGrenadines => "VC",
-            Country::Sudan => "SD",
-            Country::Suriname => "SR",
-            Country::SvalbardAndJanMayen => "SJ",
-            Country::Sweden => "SE",
-            Country::Switzerland => "CH",
-            Country::Syria => "SY",
-            Country::Taiwan => "TW",
-            Country::Tajikistan => "TJ",
-            Country::Tanzania => "TZ",
-            Country::Thailand => "TH",
-            Country::TimorLeste => "TL",
-            Country::Togo => "TG",
-            Country::Tokelau => "TK",
-            Country::Tonga => "TO",
-            Country::TrinidadAndTobago => "TT",
-            Country::Tunisia => "TN",
-            Country::Turkey => "TR",
-            Country::Turkmenistan => "TM",
-            Country::TurksAndCaicosIslands => "TC",
-            Country::Tuvalu => "TV",
-            Country::Uganda => "UG",
-            Country::Ukraine => "UA",
-            Country::UnitedArabEmirates => "AE",
-            Country::UnitedKingdom => "GB",
-            Country::UnitedStates => "US",
-            Country::UnitedStatesMinorOutlyingIslands => "UM",
-            Country::Uruguay => "UY",
-            Country::USVirginIslands => "VI",
-            Country::Uzbekistan => "UZ",
-            Country::Vanuatu => "VU",
-            Country::Venezuela => "VE",
-            Country::Vietnam => "VN",
-            Country::WallisAndFutuna => "WF",
-            Country::WesternSahara => "EH",
-            Country::Yemen => "YE",
-            Country::Zambia => "ZM",
-            Country::Zimbabwe => "ZW",
-            Country::Other => "XX",
-        } // match
+        country.to_alpha2()
     } // fn
 } // impl
 
@@ -884,16 +634,23 @@ impl std::convert::TryFrom<&str> for Country {
 
 impl std::str::FromStr for Country {
     // Error definitions are contained in the
-    // `google_maps\src\geocoding\error.rs` module.
+    // `google_maps\src\types\error.rs` module.
     type Err = GoogleMapsError;
-    /// Gets a `Country` enum from a `String` that contains a valid [ISO 3166-1
-    /// Alpha-2](https://en.wikipedia.org/wiki/List_of_ISO_3166_country_codes)
-    /// country code.
-    fn from_str(country_code: &str) -> Result<Self, Self::Err> {
-        Ok(COUNTRIES_BY_CODE
-            .get(country_code)
-            .copied()
-            .unwrap_or(Self::Other))
+    /// Gets a `Country` enum from a `String` that contains either an [ISO
+    /// 3166-1 Alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)
+    /// country code (`DE`), an [ISO 3166-1
+    /// Alpha-3](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-3) country code
+    /// (`DEU`), or the country's English name (`Germany`). Matching is
+    /// case-insensitive.
+    ///
+    /// Unlike `TryFrom<&str>`, which returns `Country::Other` for unrecognized
+    /// codes so that new countries in Google's responses do not cause errors,
+    /// this returns an error.
+    fn from_str(country: &str) -> Result<Self, Self::Err> {
+        let country = country.trim();
+        Ok(Self::from_alpha2(country)
+            .or_else(|_| Self::from_alpha3(country))
+            .or_else(|_| Self::from_name(country))?)
     } // fn
 } // impl
 
@@ -904,259 +661,400 @@ impl Country {
     /// user.
     #[must_use]
     pub const fn display(&self) -> &str {
-        match self {
-            Self::Afghanistan => "Afghanistan",
-            Self::AlandIslands => "Åland Islands",
-            Self::Albania => "Albania",
-            Self::Algeria => "Algeria",
-            Self::AmericanSamoa => "American Samoa",
-            Self::Andorra => "Andorra",
-            Self::Angola => "Angola",
-            Self::Anguilla => "Anguilla",
-            Self::Antarctica => "Antarctica",
-            Self::AntiguaAndBarbuda => "Antigua and Barbuda",
-            Self::Argentina => "Argentina",
-            Self::Armenia => "Armenia",
-            Self::Aruba => "Aruba",
-            Self::Australia => "Australia",
-            Self::Austria => "Austria",
-            Self::Azerbaijan => "Azerbaijan",
-            Self::Bahamas => "Bahamas",
-            Self::Bahrain => "Bahrain",
-            Self::Bangladesh => "Bangladesh",
-            Self::Barbados => "Barbados",
-            Self::Belarus => "Belarus",
-            Self::Belgium => "Belgium",
-            Self::Belize => "Belize",
-            Self::Benin => "Benin",
-            Self::Bermuda => "Bermuda",
-            Self::Bhutan => "Bhutan",
-            Self::Bolivia => "Bolivia",
-            Self::BonaireSintEustatiusAndSaba => "Bonaire, Sint Eustatius and Saba",
-            Self::BosniaAndHerzegovina => "Bosnia and Herzegovina",
-            Self::Botswana => "Botswana",
-            Self::BouvetIsland => "Bouvet Island",
-            Self::Brazil => "Brazil",
-            Self::BritishIndianOceanTerritory => "British Indian Ocean Territory",
-            Self::BritishVirginIslands => "British Virgin Islands",
-            Self::Brunei => "Brunei",
-            Self::Bulgaria => "Bulgaria",
-            Self::BurkinaFaso => "Burkina Faso",
-            Self::Burundi => "Burundi",
-            Self::CaboVerde => "Cabo Verde",
-            Self::Cambodia => "Cambodia",
-            Self::Cameroon => "Cameroon",
-            Self::Canada => "Canada",
-            Self::CaymanIslands => "Cayman Islands",
-            Self::CentralAfricanRepublic => "Central African Republic",
-            Self::Chad => "Chad",
-            Self::Chile => "Chile",
-            Self::China => "China",
-            Self::ChristmasIsland => "Christmas Island",
-            Self::CocosKeelingIslands => "Cocos (Keeling) Islands",
-            Self::Colombia => "Colombia",
-            Self::Comoros => "Comoros",
-            Self::CongoBraXXaville => "Congo",
-            Self::CongoKinshasa => "Democratic Republic of the Congo",
-            Self::CookIslands => "Cook Islands",
-            Self::CostaRica => "Costa Rica",
-            Self::CotedIvoire => "Côte d'Ivoire",
-            Self::Croatia => "Croatia",
-            Self::Cuba => "Cuba",
-            Self::Curacao => "Curaçao",
-            Self::Cyprus => "Cyprus",
-            Self::Czechia => "Czechia",
-            Self::Denmark => "Denmark",
-            Self::Djibouti => "Djibouti",
-            Self::Dominica => "Dominica",
-            Self::DominicanRepublic => "Dominican Republic",
-            Self::Ecuador => "Ecuador",
-            Self::Egypt => "Egypt",
-            Self::ElSalvador => "El Salvador",
-            Self::EquatorialGuinea => "Equatorial Guinea",
-            Self::Eritrea => "Eritrea",
-            Self::Estonia => "Estonia",
-            Self::Eswatini => "Eswatini",
-            Self::Ethiopia => "Ethiopia",
-            Self::FalklandIslands => "Falkland Islands",
-            Self::FaroeIslands => "Faroe Islands",
-            Self::Fiji => "Fiji",
-            Self::Finland => "Finland",
-            Self::France => "France",
-            Self::FrenchGuiana => "French Guiana",
-            Self::FrenchPolynesia => "French Polynesia",
-            Self::FrenchSouthernTerritories => "French Southern Territories",
-            Self::Gabon => "Gabon",
-            Self::Gambia => "Gambia",
-            Self::Georgia => "Georgia",
-            Self::Germany => "Germany",
-            Self::Ghana => "Ghana",
-            Self::Gibraltar => "Gibraltar",
-            Self::Greece => "Greece",
-            Self::Greenland => "Greenland",
-            Self::Grenada => "Grenada",
-            Self::Guadeloupe => "Guadeloupe",
-            Self::Guam => "Guam",
-            Self::Guatemala => "Guatemala",
-            Self::Guernsey => "Guernsey",
-            Self::Guinea => "Guinea",
-            Self::GuineaBissau => "Guinea-Bissau",
-            Self::Guyana => "Guyana",
-            Self::Haiti => "Haiti",
-            Self::HeardAndMcDonaldIslands => "Heard Island and McDonald Islands",
-            Self::HolySee => "Holy See",
-            Self::Honduras => "Honduras",
-            Self::HongKong => "Hong Kong",
-            Self::Hungary => "Hungary",
-            Self::Iceland => "Iceland",
-            Self::India => "India",
-            Self::Indonesia => "Indonesia",
-            Self::Iran => "Iran",
-            Self::Iraq => "Iraq",
-            Self::Ireland => "Ireland",
-            Self::IsleOfMan => "Isle of Man",
-            Self::Israel => "Israel",
-            Self::Italy => "Italy",
-            Self::Jamaica => "Jamaica",
-            Self::Japan => "Japan",
-            Self::Jersey => "Jersey",
-            Self::Jordan => "Jordan",
-            Self::Kazakhstan => "Kazakhstan",
-            Self::Kenya => "Kenya",
-            Self::Kiribati => "Kiribati",
-            Self::Kuwait => "Kuwait",
-            Self::Kyrgyzstan => "Kyrgyzstan",
-            Self::Laos => "Laos",
-            Self::Latvia => "Latvia",
-            Self::Lebanon => "Lebanon",
-            Self::Lesotho => "Lesotho",
-            Self::Liberia => "Liberia",
-            Self::Libya => "Libya",
-            Self::Liechtenstein => "Liechtenstein",
-            Self::Lithuania => "Lithuania",
-            Self::Luxembourg => "Luxembourg",
-            Self::Macao => "Macao",
-            Self::Madagascar => "Madagascar",
-            Self::Malawi => "Malawi",
-            Self::Malaysia => "Malaysia",
-            Self::Maldives => "Maldives",
-            Self::Mali => "Mali",
-            Self::Malta => "Malta",
-            Self::MarshallIslands => "Marshall Islands",
-            Self::Martinique => "Martinique",
-            Self::Mauritania => "Mauritania",
-            Self::Mauritius => "Mauritius",
-            Self::Mayotte => "Mayotte",
-            Self::Mexico => "Mexico",
-            Self::Micronesia => "Micronesia",
-            Self::Moldova => "Moldova",
-            Self::Monaco => "Monaco",
-            Self::Mongolia => "Mongolia",
-            Self::Montenegro => "Montenegro",
-            Self::Montserrat => "Montserrat",
-            Self::Morocco => "Morocco",
-            Self::Mozambique => "Mozambique",
-            Self::Myanmar => "Myanmar",
-            Self::Namibia => "Namibia",
-            Self::Nauru => "Nauru",
-            Self::Nepal => "Nepal",
-            Self::Netherlands => "Netherlands",
-            Self::NewCaledonia => "New Caledonia",
-            Self::NewZealand => "New Zealand",
-            Self::Nicaragua => "Nicaragua",
-            Self::Niger => "Niger",
-            Self::Nigeria => "Nigeria",
-            Self::Niue => "Niue",
-            Self::NorfolkIsland => "Norfolk Island",
-            Self::NorthernMarianaIslands => "Northern Mariana Islands",
-            Self::NorthKorea => "North Korea",
-            Self::NorthMacedonia => "North Macedonia",
-            Self::Norway => "Norway",
-            Self::Oman => "Oman",
-            Self::Pakistan => "Pakistan",
-            Self::Palau => "Palau",
-            Self::Palestine => "Palestine",
-            Self::Panama => "Panama",
-            Self::PapuaNewGuinea => "Papua New Guinea",
-            Self::Paraguay => "Paraguay",
-            Self::Peru => "Peru",
-            Self::Philippines => "Philippines",
-            Self::Pitcairn => "Pitcairn",
-            Self::Poland => "Poland",
-            Self::Portugal => "Portugal",
-            Self::PuertoRico => "Puerto Rico",
-            Self::Qatar => "Qatar",
-            Self::Reunion => "Réunion",
-            Self::Romania => "Romania",
-            Self::Russia => "Russian Federation",
-            Self::Rwanda => "Rwanda",
-            Self::Samoa => "Samoa",
-            Self::SanMarino => "San Marino",
-            Self::SaoTomeAndPrincipe => "Sao Tome and Principe",
-            Self::SaudiArabia => "Saudi Arabia",
-            Self::Senegal => "Senegal",
-            Self::Serbia => "Serbia",
-            Self::Seychelles => "Seychelles",
-            Self::SierraLeone => "Sierra Leone",
-            Self::Singapore => "Singapore",
-            Self::SintMaarten => "Sint Maarten",
-            Self::Slovakia => "Slovakia",
-            Self::Slovenia => "Slovenia",
-            Self::SolomonIslands => "Solomon Islands",
-            Self::Somalia => "Somalia",
-            Self::SouthAfrica => "SouthA frica",
-            Self::SouthGeorgiaAndSouthSandwichIslands => {
-                "South Georgia and the South Sandwich Islands"
-            }
-            Self::SouthKorea => "South Korea",
-            Self::SouthSudan => "South Sudan",
-            Self::Spain => "Spain",
-            Self::SriLanka => "Sri Lanka",
-            Self::StBarthelemy => "Saint Barthélemy",
-            Self::StHelena => "Saint Helena, Ascension and Tristan da Cunha",
-            Self::StKittsAndNevis => "Saint Kitts and Nevis",
-            Self::StLucia => "Saint Lucia",
-            Self::StMartin => "Saint Martin",
-            Self::StPierreAndMiquelon => "Saint Pierre and Miquelon",
-            Self::StVincentAndGrenadines => "Saint Vincent and the Grenadines",
-            Self::Sudan => "Sudan",
-            Self::Suriname => "Suriname",
-            Self::SvalbardAndJanMayen => "Svalbard and Jan Mayen",
-            Self::Sweden => "Sweden",
-            Self::Switzerland => "Switzerland",
-            Self::Syria => "Syria",
-            Self::Taiwan => "Taiwan, Province of China",
-            Self::Tajikistan => "Tajikistan",
-            Self::Tanzania => "Tanzania",
-            Self::Thailand => "Thailand",
-            Self::TimorLeste => "Timor-Leste",
-            Self::Togo => "Togo",
-            Self::Tokelau => "Tokelau",
-            Self::Tonga => "Tonga",
-            Self::TrinidadAndTobago => "Trinidad and Tobago",
-            Self::Tunisia => "Tunisia",
-            Self::Turkey => "Turkey",
-            Self::Turkmenistan => "Turkmenistan",
-            Self::TurksAndCaicosIslands => "Turks and Caicos Islands",
-            Self::Tuvalu => "Tuvalu",
-            Self::Uganda => "Uganda",
-            Self::Ukraine => "Ukraine",
-            Self::UnitedArabEmirates => "United Arab Emirates",
-            Self::UnitedKingdom => "United Kingdom",
-            Self::UnitedStates => "United States",
-            Self::UnitedStatesMinorOutlyingIslands => "United States Minor Outlying Islands",
-            Self::Uruguay => "Uruguay",
-            Self::USVirginIslands => "US Virgin ISlands",
-            Self::Uzbekistan => "Uzbekistan",
-            Self::Vanuatu => "Vanuatu",
-            Self::Venezuela => "Venezuela",
-            Self::Vietnam => "Viet Nam",
-            Self::WallisAndFutuna => "Wallis and Futuna",
-            Self::WesternSahara => "Western Sahara",
-            Self::Yemen => "Yemen",
-            Self::Zambia => "Zambia",
-            Self::Zimbabwe => "Zimbabwe",
-            Self::Other => "Other",
-        } // match
+        self.name()
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+/// The ISO 3166-1 alpha-2 code, ISO 3166-1 alpha-3 code, and English name of
+/// each country. There is one row per variant, in the same order as the
+/// variants' discriminants, so that a row can be found by indexing with the
+/// discriminant.
+
+const COUNTRIES: [(Country, &str, &str, &str); 250] = [
+    (Country::Afghanistan, "AF", "AFG", "Afghanistan"),
+    (Country::AlandIslands, "AX", "ALA", "Åland Islands"),
+    (Country::Albania, "AL", "ALB", "Albania"),
+    (Country::Algeria, "DZ", "DZA", "Algeria"),
+    (Country::AmericanSamoa, "AS", "ASM", "American Samoa"),
+    (Country::Andorra, "AD", "AND", "Andorra"),
+    (Country::Angola, "AO", "AGO", "Angola"),
+    (Country::Anguilla, "AI", "AIA", "Anguilla"),
+    (Country::Antarctica, "AQ", "ATA", "Antarctica"),
+    (Country::AntiguaAndBarbuda, "AG", "ATG", "Antigua and Barbuda"),
+    (Country::Argentina, "AR", "ARG", "Argentina"),
+    (Country::Armenia, "AM", "ARM", "Armenia"),
+    (Country::Aruba, "AW", "ABW", "Aruba"),
+    (Country::Australia, "AU", "AUS", "Australia"),
+    (Country::Austria, "AT", "AUT", "Austria"),
+    (Country::Azerbaijan, "AZ", "AZE", "Azerbaijan"),
+    (Country::Bahamas, "BS", "BHS", "Bahamas"),
+    (Country::Bahrain, "BH", "BHR", "Bahrain"),
+    (Country::Bangladesh, "BD", "BGD", "Bangladesh"),
+    (Country::Barbados, "BB", "BRB", "Barbados"),
+    (Country::Belarus, "BY", "BLR", "Belarus"),
+    (Country::Belgium, "BE", "BEL", "Belgium"),
+    (Country::Belize, "BZ", "BLZ", "Belize"),
+    (Country::Benin, "BJ", "BEN", "Benin"),
+    (Country::Bermuda, "BM", "BMU", "Bermuda"),
+    (Country::Bhutan, "BT", "BTN", "Bhutan"),
+    (Country::Bolivia, "BO", "BOL", "Bolivia"),
+    (Country::BonaireSintEustatiusAndSaba, "BQ", "BES", "Bonaire, Sint Eustatius and Saba"),
+    (Country::BosniaAndHerzegovina, "BA", "BIH", "Bosnia and Herzegovina"),
+    (Country::Botswana, "BW", "BWA", "Botswana"),
+    (Country::BouvetIsland, "BV", "BVT", "Bouvet Island"),
+    (Country::Brazil, "BR", "BRA", "Brazil"),
+    (Country::BritishIndianOceanTerritory, "IO", "IOT", "British Indian Ocean Territory"),
+    (Country::BritishVirginIslands, "VG", "VGB", "British Virgin Islands"),
+    (Country::Brunei, "BN", "BRN", "Brunei"),
+    (Country::Bulgaria, "BG", "BGR", "Bulgaria"),
+    (Country::BurkinaFaso, "BF", "BFA", "Burkina Faso"),
+    (Country::Burundi, "BI", "BDI", "Burundi"),
+    (Country::CaboVerde, "CV", "CPV", "Cabo Verde"),
+    (Country::Cambodia, "KH", "KHM", "Cambodia"),
+    (Country::Cameroon, "CM", "CMR", "Cameroon"),
+    (Country::Canada, "CA", "CAN", "Canada"),
+    (Country::CaymanIslands, "KY", "CYM", "Cayman Islands"),
+    (Country::CentralAfricanRepublic, "CF", "CAF", "Central African Republic"),
+    (Country::Chad, "TD", "TCD", "Chad"),
+    (Country::Chile, "CL", "CHL", "Chile"),
+    (Country::China, "CN", "CHN", "China"),
+    (Country::ChristmasIsland, "CX", "CXR", "Christmas Island"),
+    (Country::CocosKeelingIslands, "CC", "CCK", "Cocos (Keeling) Islands"),
+    (Country::Colombia, "CO", "COL", "Colombia"),
+    (Country::Comoros, "KM", "COM", "Comoros"),
+    (Country::CongoBraXXaville, "CG", "COG", "Congo"),
+    (Country::CongoKinshasa, "CD", "COD", "Democratic Republic of the Congo"),
+    (Country::CookIslands, "CK", "COK", "Cook Islands"),
+    (Country::CostaRica, "CR", "CRI", "Costa Rica"),
+    (Country::CotedIvoire, "CI", "CIV", "Côte d'Ivoire"),
+    (Country::Croatia, "HR", "HRV", "Croatia"),
+    (Country::Cuba, "CU", "CUB", "Cuba"),
+    (Country::Curacao, "CW", "CUW", "Curaçao"),
+    (Country::Cyprus, "CY", "CYP", "Cyprus"),
+    (Country::Czechia, "CZ", "CZE", "Czechia"),
+    (Country::Denmark, "DK", "DNK", "Denmark"),
+    (Country::Djibouti, "DJ", "DJI", "Djibouti"),
+    (Country::Dominica, "DM", "DMA", "Dominica"),
+    (Country::DominicanRepublic, "DO", "DOM", "Dominican Republic"),
+    (Country::Ecuador, "EC", "ECU", "Ecuador"),
+    (Country::Egypt, "EG", "EGY", "Egypt"),
+    (Country::ElSalvador, "SV", "SLV", "El Salvador"),
+    (Country::EquatorialGuinea, "GQ", "GNQ", "Equatorial Guinea"),
+    (Country::Eritrea, "ER", "ERI", "Eritrea"),
+    (Country::Estonia, "EE", "EST", "Estonia"),
+    (Country::Eswatini, "SZ", "SWZ", "Eswatini"),
+    (Country::Ethiopia, "ET", "ETH", "Ethiopia"),
+    (Country::FalklandIslands, "FK", "FLK", "Falkland Islands"),
+    (Country::FaroeIslands, "FO", "FRO", "Faroe Islands"),
+    (Country::Fiji, "FJ", "FJI", "Fiji"),
+    (Country::Finland, "FI", "FIN", "Finland"),
+    (Country::France, "FR", "FRA", "France"),
+    (Country::FrenchGuiana, "GF", "GUF", "French Guiana"),
+    (Country::FrenchPolynesia, "PF", "PYF", "French Polynesia"),
+    (Country::FrenchSouthernTerritories, "TF", "ATF", "French Southern Territories"),
+    (Country::Gabon, "GA", "GAB", "Gabon"),
+    (Country::Gambia, "GM", "GMB", "Gambia"),
+    (Country::Georgia, "GE", "GEO", "Georgia"),
+    (Country::Germany, "DE", "DEU", "Germany"),
+    (Country::Ghana, "GH", "GHA", "Ghana"),
+    (Country::Gibraltar, "GI", "GIB", "Gibraltar"),
+    (Country::Greece, "GR", "GRC", "Greece"),
+    (Country::Greenland, "GL", "GRL", "Greenland"),
+    (Country::Grenada, "GD", "GRD", "Grenada"),
+    (Country::Guadeloupe, "GP", "GLP", "Guadeloupe"),
+    (Country::Guam, "GU", "GUM", "Guam"),
+    (Country::Guatemala, "GT", "GTM", "Guatemala"),
+    (Country::Guernsey, "GG", "GGY", "Guernsey"),
+    (Country::Guinea, "GN", "GIN", "Guinea"),
+    (Country::GuineaBissau, "GW", "GNB", "Guinea-Bissau"),
+    (Country::Guyana, "GY", "GUY", "Guyana"),
+    (Country::Haiti, "HT", "HTI", "Haiti"),
+    (Country::HeardAndMcDonaldIslands, "HM", "HMD", "Heard Island and McDonald Islands"),
+    (Country::HolySee, "VA", "VAT", "Holy See"),
+    (Country::Honduras, "HN", "HND", "Honduras"),
+    (Country::HongKong, "HK", "HKG", "Hong Kong"),
+    (Country::Hungary, "HU", "HUN", "Hungary"),
+    (Country::Iceland, "IS", "ISL", "Iceland"),
+    (Country::India, "IN", "IND", "India"),
+    (Country::Indonesia, "ID", "IDN", "Indonesia"),
+    (Country::Iran, "IR", "IRN", "Iran"),
+    (Country::Iraq, "IQ", "IRQ", "Iraq"),
+    (Country::Ireland, "IE", "IRL", "Ireland"),
+    (Country::IsleOfMan, "IM", "IMN", "Isle of Man"),
+    (Country::Israel, "IL", "ISR", "Israel"),
+    (Country::Italy, "IT", "ITA", "Italy"),
+    (Country::Jamaica, "JM", "JAM", "Jamaica"),
+    (Country::Japan, "JP", "JPN", "Japan"),
+    (Country::Jersey, "JE", "JEY", "Jersey"),
+    (Country::Jordan, "JO", "JOR", "Jordan"),
+    (Country::Kazakhstan, "KZ", "KAZ", "Kazakhstan"),
+    (Country::Kenya, "KE", "KEN", "Kenya"),
+    (Country::Kiribati, "KI", "KIR", "Kiribati"),
+    (Country::Kuwait, "KW", "KWT", "Kuwait"),
+    (Country::Kyrgyzstan, "KG", "KGZ", "Kyrgyzstan"),
+    (Country::Laos, "LA", "LAO", "Laos"),
+    (Country::Latvia, "LV", "LVA", "Latvia"),
+    (Country::Lebanon, "LB", "LBN", "Lebanon"),
+    (Country::Lesotho, "LS", "LSO", "Lesotho"),
+    (Country::Liberia, "LR", "LBR", "Liberia"),
+    (Country::Libya, "LY", "LBY", "Libya"),
+    (Country::Liechtenstein, "LI", "LIE", "Liechtenstein"),
+    (Country::Lithuania, "LT", "LTU", "Lithuania"),
+    (Country::Luxembourg, "LU", "LUX", "Luxembourg"),
+    (Country::Macao, "MO", "MAC", "Macao"),
+    (Country::Madagascar, "MG", "MDG", "Madagascar"),
+    (Country::Malawi, "MW", "MWI", "Malawi"),
+    (Country::Malaysia, "MY", "MYS", "Malaysia"),
+    (Country::Maldives, "MV", "MDV", "Maldives"),
+    (Country::Mali, "ML", "MLI", "Mali"),
+    (Country::Malta, "MT", "MLT", "Malta"),
+    (Country::MarshallIslands, "MH", "MHL", "Marshall Islands"),
+    (Country::Martinique, "MQ", "MTQ", "Martinique"),
+    (Country::Mauritania, "MR", "MRT", "Mauritania"),
+    (Country::Mauritius, "MU", "MUS", "Mauritius"),
+    (Country::Mayotte, "YT", "MYT", "Mayotte"),
+    (Country::Mexico, "MX", "MEX", "Mexico"),
+    (Country::Micronesia, "FM", "FSM", "Micronesia"),
+    (Country::Moldova, "MD", "MDA", "Moldova"),
+    (Country::Monaco, "MC", "MCO", "Monaco"),
+    (Country::Mongolia, "MN", "MNG", "Mongolia"),
+    (Country::Montenegro, "ME", "MNE", "Montenegro"),
+    (Country::Montserrat, "MS", "MSR", "Montserrat"),
+    (Country::Morocco, "MA", "MAR", "Morocco"),
+    (Country::Mozambique, "MZ", "MOZ", "Mozambique"),
+    (Country::Myanmar, "MM", "MMR", "Myanmar"),
+    (Country::Namibia, "NA", "NAM", "Namibia"),
+    (Country::Nauru, "NR", "NRU", "Nauru"),
+    (Country::Nepal, "NP", "NPL", "Nepal"),
+    (Country::Netherlands, "NL", "NLD", "Netherlands"),
+    (Country::NewCaledonia, "NC", "NCL", "New Caledonia"),
+    (Country::NewZealand, "NZ", "NZL", "New Zealand"),
+    (Country::Nicaragua, "NI", "NIC", "Nicaragua"),
+    (Country::Niger, "NE", "NER", "Niger"),
+    (Country::Nigeria, "NG", "NGA", "Nigeria"),
+    (Country::Niue, "NU", "NIU", "Niue"),
+    (Country::NorfolkIsland, "NF", "NFK", "Norfolk Island"),
+    (Country::NorthernMarianaIslands, "MP", "MNP", "Northern Mariana Islands"),
+    (Country::NorthKorea, "KP", "PRK", "North Korea"),
+    (Country::NorthMacedonia, "MK", "MKD", "North Macedonia"),
+    (Country::Norway, "NO", "NOR", "Norway"),
+    (Country::Oman, "OM", "OMN", "Oman"),
+    (Country::Pakistan, "PK", "PAK", "Pakistan"),
+    (Country::Palau, "PW", "PLW", "Palau"),
+    (Country::Palestine, "PS", "PSE", "Palestine"),
+    (Country::Panama, "PA", "PAN", "Panama"),
+    (Country::PapuaNewGuinea, "PG", "PNG", "Papua New Guinea"),
+    (Country::Paraguay, "PY", "PRY", "Paraguay"),
+    (Country::Peru, "PE", "PER", "Peru"),
+    (Country::Philippines, "PH", "PHL", "Philippines"),
+    (Country::Pitcairn, "PN", "PCN", "Pitcairn"),
+    (Country::Poland, "PL", "POL", "Poland"),
+    (Country::Portugal, "PT", "PRT", "Portugal"),
+    (Country::PuertoRico, "PR", "PRI", "Puerto Rico"),
+    (Country::Qatar, "QA", "QAT", "Qatar"),
+    (Country::Reunion, "RE", "REU", "Réunion"),
+    (Country::Romania, "RO", "ROU", "Romania"),
+    (Country::Russia, "RU", "RUS", "Russian Federation"),
+    (Country::Rwanda, "RW", "RWA", "Rwanda"),
+    (Country::Samoa, "WS", "WSM", "Samoa"),
+    (Country::SanMarino, "SM", "SMR", "San Marino"),
+    (Country::SaoTomeAndPrincipe, "ST", "STP", "Sao Tome and Principe"),
+    (Country::SaudiArabia, "SA", "SAU", "Saudi Arabia"),
+    (Country::Senegal, "SN", "SEN", "Senegal"),
+    (Country::Serbia, "RS", "SRB", "Serbia"),
+    (Country::Seychelles, "SC", "SYC", "Seychelles"),
+    (Country::SierraLeone, "SL", "SLE", "Sierra Leone"),
+    (Country::Singapore, "SG", "SGP", "Singapore"),
+    (Country::SintMaarten, "SX", "SXM", "Sint Maarten"),
+    (Country::Slovakia, "SK", "SVK", "Slovakia"),
+    (Country::Slovenia, "SI", "SVN", "Slovenia"),
+    (Country::SolomonIslands, "SB", "SLB", "Solomon Islands"),
+    (Country::Somalia, "SO", "SOM", "Somalia"),
+    (Country::SouthAfrica, "ZA", "ZAF", "South Africa"),
+    (Country::SouthGeorgiaAndSouthSandwichIslands, "GS", "SGS", "South Georgia and the South Sandwich Islands"),
+    (Country::SouthKorea, "KR", "KOR", "South Korea"),
+    (Country::SouthSudan, "SS", "SSD", "South Sudan"),
+    (Country::Spain, "ES", "ESP", "Spain"),
+    (Country::SriLanka, "LK", "LKA", "Sri Lanka"),
+    (Country::StBarthelemy, "BL", "BLM", "Saint Barthélemy"),
+    (Country::StHelena, "SH", "SHN", "Saint Helena, Ascension and Tristan da Cunha"),
+    (Country::StKittsAndNevis, "KN", "KNA", "Saint Kitts and Nevis"),
+    (Country::StLucia, "LC", "LCA", "Saint Lucia"),
+    (Country::StMartin, "MF", "MAF", "Saint Martin"),
+    (Country::StPierreAndMiquelon, "PM", "SPM", "Saint Pierre and Miquelon"),
+    (Country::StVincentAndGrenadines, "VC", "VCT", "Saint Vincent and the Grenadines"),
+    (Country::Sudan, "SD", "SDN", "Sudan"),
+    (Country::Suriname, "SR", "SUR", "Suriname"),
+    (Country::SvalbardAndJanMayen, "SJ", "SJM", "Svalbard and Jan Mayen"),
+    (Country::Sweden, "SE", "SWE", "Sweden"),
+    (Country::Switzerland, "CH", "CHE", "Switzerland"),
+    (Country::Syria, "SY", "SYR", "Syria"),
+    (Country::Taiwan, "TW", "TWN", "Taiwan, Province of China"),
+    (Country::Tajikistan, "TJ", "TJK", "Tajikistan"),
+    (Country::Tanzania, "TZ", "TZA", "Tanzania"),
+    (Country::Thailand, "TH", "THA", "Thailand"),
+    (Country::TimorLeste, "TL", "TLS", "Timor-Leste"),
+    (Country::Togo, "TG", "TGO", "Togo"),
+    (Country::Tokelau, "TK", "TKL", "Tokelau"),
+    (Country::Tonga, "TO", "TON", "Tonga"),
+    (Country::TrinidadAndTobago, "TT", "TTO", "Trinidad and Tobago"),
+    (Country::Tunisia, "TN", "TUN", "Tunisia"),
+    (Country::Turkey, "TR", "TUR", "Turkey"),
+    (Country::Turkmenistan, "TM", "TKM", "Turkmenistan"),
+    (Country::TurksAndCaicosIslands, "TC", "TCA", "Turks and Caicos Islands"),
+    (Country::Tuvalu, "TV", "TUV", "Tuvalu"),
+    (Country::Uganda, "UG", "UGA", "Uganda"),
+    (Country::Ukraine, "UA", "UKR", "Ukraine"),
+    (Country::UnitedArabEmirates, "AE", "ARE", "United Arab Emirates"),
+    (Country::UnitedKingdom, "GB", "GBR", "United Kingdom"),
+    (Country::UnitedStates, "US", "USA", "United States"),
+    (Country::UnitedStatesMinorOutlyingIslands, "UM", "UMI", "United States Minor Outlying Islands"),
+    (Country::Uruguay, "UY", "URY", "Uruguay"),
+    (Country::USVirginIslands, "VI", "VIR", "US Virgin Islands"),
+    (Country::Uzbekistan, "UZ", "UZB", "Uzbekistan"),
+    (Country::Vanuatu, "VU", "VUT", "Vanuatu"),
+    (Country::Venezuela, "VE", "VEN", "Venezuela"),
+    (Country::Vietnam, "VN", "VNM", "Viet Nam"),
+    (Country::WallisAndFutuna, "WF", "WLF", "Wallis and Futuna"),
+    (Country::WesternSahara, "EH", "ESH", "Western Sahara"),
+    (Country::Yemen, "YE", "YEM", "Yemen"),
+    (Country::Zambia, "ZM", "ZMB", "Zambia"),
+    (Country::Zimbabwe, "ZW", "ZWE", "Zimbabwe"),
+    (Country::Other, "XX", "XXX", "Other"),
+]; // COUNTRIES
+
+// Ensures at compile-time that every row is at the index of its variant's
+// discriminant, and that every code is the correct length:
+const _: () = {
+    let mut index = 0;
+    while index < COUNTRIES.len() {
+        let (country, alpha2, alpha3, _name) = COUNTRIES[index];
+        assert!(country as usize == index);
+        assert!(alpha2.len() == 2);
+        assert!(alpha3.len() == 3);
+        index += 1;
+    } // while
+}; // const
+
+// -----------------------------------------------------------------------------
+
+impl Country {
+    /// Returns the country's [ISO 3166-1
+    /// alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code, for
+    /// example `DE`. `Country::Other` returns `XX`.
+    #[must_use]
+    pub const fn to_alpha2(&self) -> &'static str {
+        COUNTRIES[*self as usize].1
+    } // fn
+
+    /// Returns the country's [ISO 3166-1
+    /// alpha-3](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-3) code, for
+    /// example `DEU`. `Country::Other` returns `XXX`.
+    #[must_use]
+    pub const fn to_alpha3(&self) -> &'static str {
+        COUNTRIES[*self as usize].2
+    } // fn
+
+    /// Returns the country's English name, for example `Germany`.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        COUNTRIES[*self as usize].3
+    } // fn
+
+    /// Gets a `Country` enum from an [ISO 3166-1
+    /// alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code, such as
+    /// `DE`. Matching is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the code is not a recognized alpha-2 code.
+    pub fn from_alpha2(code: &str) -> Result<Self, TypeError> {
+        COUNTRIES_BY_CODE
+            .get(code.to_ascii_uppercase().as_str())
+            .copied()
+            .ok_or_else(|| TypeError::InvalidCountryCode(code.to_string()))
+    } // fn
+
+    /// Gets a `Country` enum from an [ISO 3166-1
+    /// alpha-3](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-3) code, such as
+    /// `DEU`. Matching is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the code is not a recognized alpha-3 code.
+    pub fn from_alpha3(code: &str) -> Result<Self, TypeError> {
+        COUNTRIES
+            .iter()
+            .find(|(_country, _alpha2, alpha3, _name)| alpha3.eq_ignore_ascii_case(code))
+            .map(|(country, _alpha2, _alpha3, _name)| *country)
+            .ok_or_else(|| TypeError::InvalidCountryCode(code.to_string()))
+    } // fn
+
+    /// Gets a `Country` enum from its English name, as returned by `name`,
+    /// such as `Germany`. Matching is case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the name is not recognized.
+    pub fn from_name(name: &str) -> Result<Self, TypeError> {
+        let name_lowercase = name.to_lowercase();
+        COUNTRIES
+            .iter()
+            .find(|(_country, _alpha2, _alpha3, country_name)| {
+                country_name.to_lowercase() == name_lowercase
+            }) // find
+            .map(|(country, _alpha2, _alpha3, _name)| *country)
+            .ok_or_else(|| TypeError::InvalidCountryCode(name.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::{Country, COUNTRIES, COUNTRIES_BY_CODE};
+    use crate::error::Error;
+    use crate::types::error::Error as TypeError;
+
+    #[test]
+    fn every_country_round_trips_through_both_codes() {
+        // `Other` has the highest discriminant, so the table has a row for
+        // every variant, and the alpha-2 map has an entry for every row:
+        assert_eq!(COUNTRIES.len(), Country::Other as usize + 1);
+        assert_eq!(COUNTRIES_BY_CODE.len(), COUNTRIES.len());
+
+        for (country, alpha2, alpha3, name) in COUNTRIES {
+            assert_eq!(country.to_alpha2(), alpha2);
+            assert_eq!(country.to_alpha3(), alpha3);
+            assert_eq!(Country::from_alpha2(alpha2).unwrap(), country, "{alpha2}");
+            assert_eq!(Country::from_alpha3(alpha3).unwrap(), country, "{alpha3}");
+            assert_eq!(Country::from_name(name).unwrap(), country, "{name}");
+            assert_eq!(alpha3.parse::<Country>().unwrap(), country, "{alpha3}");
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_any_form_in_any_case() {
+        for form in ["DE", "de", "DEU", "deu", "Germany", "GERMANY", " DE "] {
+            assert_eq!(form.parse::<Country>().unwrap(), Country::Germany, "{form}");
+        } // for
+    } // fn
+
+    #[test]
+    fn rejects_unknown_codes_with_a_typed_error() {
+        assert!(matches!(Country::from_alpha2("ZZ"), Err(TypeError::InvalidCountryCode(code)) if code == "ZZ"));
+        assert!(matches!(Country::from_alpha3("ZZZ"), Err(TypeError::InvalidCountryCode(code)) if code == "ZZZ"));
+        // An alpha-3 code is not an alpha-2 code:
+        assert!(Country::from_alpha2("DEU").is_err());
+        assert!(matches!(
+            "Atlantis".parse::<Country>(),
+            Err(Error::Type(TypeError::InvalidCountryCode(name))) if name == "Atlantis",
+        ));
+
+        // `TryFrom` still maps unknown codes from Google's responses to
+        // `Other`:
+        assert_eq!(Country::try_from("ZZ").unwrap(), Country::Other);
+    } // fn
+} // mod
@@ -31,6 +31,10 @@ pub enum Error {
     /// that is invalid.
     InvalidLatLongString(String),
 
+    /// API client library attempted to convert a latitude/longitude pair string
+    /// in degrees, minutes and seconds that is invalid.
+    InvalidDmsString(String),
+
     /// API client library attempted to convert a latitude/longitude pair tuple
     /// that is invalid.
     InvalidLatLongTuple,
@@ -57,6 +61,17 @@ pub enum Error {
     /// API client library attempted to parse a string that contained an invalid
     /// location type code.
     InvalidLocationTypeCode(String),
+
+    /// API client library attempted to read a GPX file that could not be
+    /// read, or that is not valid XML.
+    #[cfg(feature = "gpx")]
+    InvalidGpx(String),
+
+    /// API client library attempted to read a GPX track point that has a
+    /// missing or invalid coordinate. Contains the index of the track point
+    /// in the file, and the reason.
+    #[cfg(feature = "gpx")]
+    InvalidGpxPoint(usize, String),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -90,6 +105,14 @@ impl std::fmt::Display for Error {
                 where the latitude is specified first \
                 and the longitude is specified second."
             ),
+            Self::InvalidDmsString(value) => write!(
+                f,
+                "`{value}` is an invalid degrees, minutes & seconds coordinate string. \
+                The string must consist of a latitude and a longitude, \
+                each with degrees, optional minutes and seconds, \
+                and a hemisphere letter of `N`, `S`, `E` or `W`. \
+                For example: `40°42'46\"N 74°00'21\"W`."
+            ),
             Self::InvalidLatLongTuple => write!(
                 f,
                 "Invalid latitude & longitude tuple coordinates. \
@@ -108,8 +131,8 @@ impl std::fmt::Display for Error {
             ),
             Self::InvalidCountryCode(country_code) => write!(f,
                 "Google Maps Geocoding API client: \
-                `{country_code}` is not a valid ISO 3166-1 Alpha-2 country code. \
-                Note that the country code must be in uppercase. \
+                `{country_code}` is not a valid ISO 3166-1 Alpha-2 or Alpha-3 country code, \
+                or English country name. \
                 For a list of country codes see \
                 https://en.wikipedia.org/wiki/List_of_ISO_3166_country_codes"),
             Self::InvalidPlaceTypeCode(place_type_code) => write!(
@@ -131,6 +154,18 @@ impl std::fmt::Display for Error {
                 `{location_type_code}` is not a known location type code. \
                 Valid codes are `APPROXIMATE`, `GEOMETRIC_CENTER`, \
                 `RANGE_INTERPOLATED`, and `ROOFTOP`."),
+            #[cfg(feature = "gpx")]
+            Self::InvalidGpx(error) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the GPX file could not be read: {error}"
+            ),
+            #[cfg(feature = "gpx")]
+            Self::InvalidGpxPoint(index, reason) => write!(
+                f,
+                "Google Maps Platform API client: \
+                track point {index} of the GPX file is invalid: {reason}"
+            ),
         } // match
     } // fn
 } // impl
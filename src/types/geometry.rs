@@ -2,7 +2,8 @@
 //! displaying the returned result, the bounding box, and other additional
 //! data.
 
-use crate::types::{Bounds, LatLng, LocationType};
+use crate::serde::decimal_as_number::{bounds_as_numbers, latlng_as_numbers, option_bounds_as_numbers};
+use crate::types::{Bounds, LatLng, LocationType, MaybeKnown};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -12,20 +13,22 @@ use serde::{Deserialize, Serialize};
 /// displaying the returned result, the bounding box, and other additional
 /// data.
 
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Geometry {
     /// Contains the geocoded latitude, longitude value. For normal address
     /// lookups, this field is typically the most important.
+    #[serde(serialize_with = "latlng_as_numbers")]
     pub location: LatLng,
 
     /// Stores additional data about the specified location.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub location_type: Option<LocationType>,
+    pub location_type: Option<MaybeKnown<LocationType>>,
 
     /// Contains the recommended viewport for displaying the returned result,
     /// specified as two latitude/longitude values defining the southwest and
     /// northeast corner of the viewport bounding box. Generally the viewport is
     /// used to frame a result when displaying it to a user.
+    #[serde(serialize_with = "bounds_as_numbers")]
     pub viewport: Bounds,
 
     /// Stores the bounding box which can fully contain the returned result.
@@ -35,6 +38,7 @@ pub struct Geometry {
     /// technically part of the city, but probably should not be returned in the
     /// viewport.)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "option_bounds_as_numbers")]
     pub bounds: Option<Bounds>,
 } // struct
 
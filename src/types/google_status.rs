@@ -0,0 +1,157 @@
+//! Contains the `GoogleStatus` enum, which is a service-independent form of the
+//! status that Google returns in every response.
+
+// -----------------------------------------------------------------------------
+//
+/// The status of a response from any Google Maps Platform service. Each
+/// service has its own `Status` enum with only the codes that it may return;
+/// this enum has the codes of all of them, so that responses from different
+/// services may be handled uniformly. See the `HasStatus` trait.
+///
+/// Codes that are not recognized by this crate are kept in the `Other`
+/// variant.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GoogleStatus {
+    /// The request was successful. The _Roads API_ does not return a status
+    /// for successful requests, so its successful responses have this status.
+    Ok,
+    /// The request was successful, but returned no results.
+    ZeroResults,
+    /// A location or place referenced in the request could not be found.
+    NotFound,
+    /// The request was malformed.
+    InvalidRequest,
+    /// An argument of a _Roads API_ request was invalid.
+    InvalidArgument,
+    /// The request had too many waypoints.
+    MaxWaypointsExceeded,
+    /// The request had too many origins and destinations.
+    MaxElementsExceeded,
+    /// The requested route is too long to be processed.
+    MaxRouteLengthExceeded,
+    /// The daily quota was exceeded, or billing is not set up.
+    OverDailyLimit,
+    /// The requestor has exceeded their quota.
+    OverQueryLimit,
+    /// The _Roads API_ quota or rate limit was exceeded.
+    ResourceExhausted,
+    /// The service denied the request.
+    RequestDenied,
+    /// The _Roads API_ denied the request.
+    PermissionDenied,
+    /// The request could not be processed due to a server error. The request
+    /// may succeed if it is retried.
+    UnknownError,
+    /// A status code that is not recognized by this crate.
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&str> for GoogleStatus {
+    /// Gets a `GoogleStatus` enum from a status code, such as `OK` or
+    /// `ZERO_RESULTS`. Unrecognized codes are kept in the `Other` variant.
+    fn from(status_code: &str) -> Self {
+        match status_code {
+            "OK" => Self::Ok,
+            "ZERO_RESULTS" => Self::ZeroResults,
+            "NOT_FOUND" => Self::NotFound,
+            "INVALID_REQUEST" => Self::InvalidRequest,
+            "INVALID_ARGUMENT" => Self::InvalidArgument,
+            "MAX_WAYPOINTS_EXCEEDED" => Self::MaxWaypointsExceeded,
+            "MAX_ELEMENTS_EXCEEDED" => Self::MaxElementsExceeded,
+            "MAX_ROUTE_LENGTH_EXCEEDED" => Self::MaxRouteLengthExceeded,
+            "OVER_DAILY_LIMIT" => Self::OverDailyLimit,
+            "OVER_QUERY_LIMIT" => Self::OverQueryLimit,
+            "RESOURCE_EXHAUSTED" => Self::ResourceExhausted,
+            "REQUEST_DENIED" => Self::RequestDenied,
+            "PERMISSION_DENIED" => Self::PermissionDenied,
+            "UNKNOWN_ERROR" => Self::UnknownError,
+            other => Self::Other(other.to_string()),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&GoogleStatus> for String {
+    /// Converts a `GoogleStatus` enum to a `String` that contains its status
+    /// code.
+    fn from(status: &GoogleStatus) -> Self {
+        match status {
+            GoogleStatus::Ok => Self::from("OK"),
+            GoogleStatus::ZeroResults => Self::from("ZERO_RESULTS"),
+            GoogleStatus::NotFound => Self::from("NOT_FOUND"),
+            GoogleStatus::InvalidRequest => Self::from("INVALID_REQUEST"),
+            GoogleStatus::InvalidArgument => Self::from("INVALID_ARGUMENT"),
+            GoogleStatus::MaxWaypointsExceeded => Self::from("MAX_WAYPOINTS_EXCEEDED"),
+            GoogleStatus::MaxElementsExceeded => Self::from("MAX_ELEMENTS_EXCEEDED"),
+            GoogleStatus::MaxRouteLengthExceeded => Self::from("MAX_ROUTE_LENGTH_EXCEEDED"),
+            GoogleStatus::OverDailyLimit => Self::from("OVER_DAILY_LIMIT"),
+            GoogleStatus::OverQueryLimit => Self::from("OVER_QUERY_LIMIT"),
+            GoogleStatus::ResourceExhausted => Self::from("RESOURCE_EXHAUSTED"),
+            GoogleStatus::RequestDenied => Self::from("REQUEST_DENIED"),
+            GoogleStatus::PermissionDenied => Self::from("PERMISSION_DENIED"),
+            GoogleStatus::UnknownError => Self::from("UNKNOWN_ERROR"),
+            GoogleStatus::Other(status_code) => status_code.clone(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for GoogleStatus {
+    /// Formats a `GoogleStatus` enum as its status code.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::GoogleStatus;
+
+    #[test]
+    fn parses_and_formats_every_status_code() {
+        for (status_code, status) in [
+            ("OK", GoogleStatus::Ok),
+            ("ZERO_RESULTS", GoogleStatus::ZeroResults),
+            ("NOT_FOUND", GoogleStatus::NotFound),
+            ("INVALID_REQUEST", GoogleStatus::InvalidRequest),
+            ("INVALID_ARGUMENT", GoogleStatus::InvalidArgument),
+            ("MAX_WAYPOINTS_EXCEEDED", GoogleStatus::MaxWaypointsExceeded),
+            ("MAX_ELEMENTS_EXCEEDED", GoogleStatus::MaxElementsExceeded),
+            (
+                "MAX_ROUTE_LENGTH_EXCEEDED",
+                GoogleStatus::MaxRouteLengthExceeded,
+            ),
+            ("OVER_DAILY_LIMIT", GoogleStatus::OverDailyLimit),
+            ("OVER_QUERY_LIMIT", GoogleStatus::OverQueryLimit),
+            ("RESOURCE_EXHAUSTED", GoogleStatus::ResourceExhausted),
+            ("REQUEST_DENIED", GoogleStatus::RequestDenied),
+            ("PERMISSION_DENIED", GoogleStatus::PermissionDenied),
+            ("UNKNOWN_ERROR", GoogleStatus::UnknownError),
+        ] {
+            assert_eq!(GoogleStatus::from(status_code), status, "{status_code}");
+            assert_eq!(String::from(&status), status_code);
+            assert_eq!(status.to_string(), status_code);
+        } // for
+    } // fn
+
+    #[test]
+    fn keeps_unrecognized_status_codes() {
+        let status = GoogleStatus::from("QUOTA_PAUSED");
+        assert_eq!(status, GoogleStatus::Other("QUOTA_PAUSED".to_string()));
+        assert_eq!(status.to_string(), "QUOTA_PAUSED");
+
+        // Status codes are matched exactly:
+        assert_eq!(
+            GoogleStatus::from("ok"),
+            GoogleStatus::Other("ok".to_string())
+        );
+        assert_eq!(GoogleStatus::from(""), GoogleStatus::Other(String::new()));
+    } // fn
+} // mod
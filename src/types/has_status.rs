@@ -0,0 +1,87 @@
+//! Contains the `HasStatus` trait, which gives uniform access to the status of
+//! a response from any service.
+
+use crate::types::google_status::GoogleStatus;
+
+// -----------------------------------------------------------------------------
+//
+/// Implemented by the `Response` struct of each service, so that generic code
+/// may check the status of any response. Services store their status
+/// differently: most have a `status` field, while the _Roads API_ has an
+/// `error` object that is only present when the request failed.
+///
+/// ```rust
+/// fn log_status(response: &impl HasStatus) {
+///     if !response.is_ok() {
+///         tracing::warn!("Google responded with `{}`", response.google_status());
+///     }
+/// }
+/// ```
+
+pub trait HasStatus {
+    /// Returns the status of the response.
+    fn google_status(&self) -> GoogleStatus;
+
+    /// Returns `true` if the status of the response is `OK`.
+    fn is_ok(&self) -> bool {
+        self.google_status() == GoogleStatus::Ok
+    } // fn
+} // trait
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(
+    test,
+    feature = "reqwest",
+    feature = "directions",
+    feature = "geocoding",
+    feature = "roads"
+))]
+mod tests {
+    use super::HasStatus;
+    use crate::types::GoogleStatus;
+
+    /// Returns the status of any response, as generic code would.
+    fn status_of(response: &impl HasStatus) -> (GoogleStatus, bool) {
+        (response.google_status(), response.is_ok())
+    } // fn
+
+    #[test]
+    fn reads_the_status_of_any_response() {
+        let directions: crate::directions::response::Response =
+            crate::json::tests::fixture("directions_zero_results")
+                .parse()
+                .unwrap();
+        assert_eq!(status_of(&directions), (GoogleStatus::ZeroResults, false));
+
+        let geocoding: crate::geocoding::response::Response =
+            r#"{"results": [], "status": "OVER_QUERY_LIMIT"}"#.parse().unwrap();
+        assert_eq!(status_of(&geocoding), (GoogleStatus::OverQueryLimit, false));
+
+        let geocoding: crate::geocoding::response::Response =
+            crate::json::tests::cassette_body("geocoding", 0)
+                .parse()
+                .unwrap();
+        assert_eq!(status_of(&geocoding), (GoogleStatus::Ok, true));
+    } // fn
+
+    #[test]
+    fn reads_the_status_of_a_roads_response_from_its_error() {
+        // The Roads API has no status when the request succeeds:
+        let snapped: crate::roads::snap_to_roads::response::Response =
+            r#"{"snappedPoints": []}"#.parse().unwrap();
+        assert_eq!(status_of(&snapped), (GoogleStatus::Ok, true));
+
+        let exhausted: crate::roads::snap_to_roads::response::Response = r#"{"error": {
+            "code": 429,
+            "message": "Quota exceeded for quota metric 'Requests'.",
+            "status": "RESOURCE_EXHAUSTED"
+        }}"#
+        .parse()
+        .unwrap();
+        assert_eq!(
+            status_of(&exhausted),
+            (GoogleStatus::ResourceExhausted, false)
+        );
+    } // fn
+} // mod
@@ -3,6 +3,7 @@
 //! of languages, it is a list of languages that Google Maps supports._
 
 use crate::error::Error as GoogleMapsError;
+use crate::types::error::Error as TypeError;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -57,7 +58,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// You can see what the map will look like in any of the languages listed above
 /// in this [sample application](https://developers.google.com/maps/documentation/javascript/demos/localization/).
 
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u16)]
 #[non_exhaustive]
 pub enum Language {
@@ -154,7 +155,11 @@ pub enum Language {
     /// crate. However, in the meantime, the `Other` catch-all variant allows
     /// `serde` to read data from Google without producing an error until the
     /// new variant added to this `enum`.
-    Other = 84,
+    ///
+    /// The variant holds the raw language tag. When used in a request, the
+    /// tag is passed through to Google as-is, so languages that this crate
+    /// does not know about yet may still be requested.
+    Other(String) = 84,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -187,18 +192,18 @@ impl Serialize for Language {
 
 impl std::convert::From<&Self> for Language {
     /// Converts a borrowed `&Language` enum into an owned `Language` enum by
-    /// copying it.
+    /// cloning it.
     fn from(language: &Self) -> Self {
-        *language
+        language.clone()
     } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
 
-impl std::convert::From<&Language> for &str {
+impl<'a> std::convert::From<&'a Language> for &'a str {
     /// Converts a `Language` enum to a `String` that contains a
     /// [language](https://developers.google.com/maps/faq#languagesupport) code.
-    fn from(language: &Language) -> Self {
+    fn from(language: &'a Language) -> Self {
         match language {
             Language::Afrikaans => "af",
             Language::Albanian => "sq",
@@ -262,7 +267,7 @@ impl std::convert::From<&Language> for &str {
             Language::Norwegian => "no",
             Language::Polish => "pl",
             Language::Portuguese => "pt",
-            Language::PortugueseBrazil => "pr-BR",
+            Language::PortugueseBrazil => "pt-BR",
             Language::PortuguesePortugal => "pt-PT",
             Language::Punjabi => "pa",
             Language::Romanian => "ro",
@@ -284,7 +289,7 @@ impl std::convert::From<&Language> for &str {
             Language::Uzbek => "uz",
             Language::Vietnamese => "vi",
             Language::Zulu => "zu",
-            Language::Other => "xx",
+            Language::Other(language_code) => language_code,
         } // match
     } // fn
 } // impl
@@ -374,6 +379,9 @@ static LANGUAGES_BY_CODE: phf::Map<&'static str, Language> = phf_map! {
     "no" => Language::Norwegian,
     "pl" => Language::Polish,
     "pt" => Language::Portuguese,
+    "pt-BR" => Language::PortugueseBrazil,
+    // Previous versions of this crate sent an incorrect code for Brazilian
+    // Portuguese. It is still accepted for compatibility:
     "pr-BR" => Language::PortugueseBrazil,
     "pt-PT" => Language::PortuguesePortugal,
     "pa" => Language::Punjabi,
@@ -396,7 +404,6 @@ static LANGUAGES_BY_CODE: phf::Map<&'static str, Language> = phf_map! {
     "uz" => Language::Uzbek,
     "vi" => Language::Vietnamese,
     "zu" => Language::Zulu,
-    "xx" => Language::Other,
 };
 
 // -----------------------------------------------------------------------------
@@ -409,8 +416,8 @@ impl std::convert::TryFrom<&str> for Language {
     fn try_from(language_code: &str) -> Result<Self, Self::Error> {
         Ok(LANGUAGES_BY_CODE
             .get(language_code)
-            .copied()
-            .unwrap_or(Self::Other))
+            .cloned()
+            .unwrap_or_else(|| Self::Other(language_code.to_string())))
     } // fn
 } // impl
 
@@ -424,8 +431,8 @@ impl std::str::FromStr for Language {
     fn from_str(language_code: &str) -> Result<Self, Self::Err> {
         Ok(LANGUAGES_BY_CODE
             .get(language_code)
-            .copied()
-            .unwrap_or(Self::Other))
+            .cloned()
+            .unwrap_or_else(|| Self::Other(language_code.to_string())))
     } // fn
 } // impl
 
@@ -521,7 +528,136 @@ impl Language {
             Self::Uzbek => "Uzbek",
             Self::Vietnamese => "Vietnamese",
             Self::Zulu => "Zulu",
-            Self::Other => "Other",
+            Self::Other(_) => "Other",
         } // match
     } // fn
+
+    /// Gets a `Language` enum from a [BCP-47](https://www.rfc-editor.org/info/bcp47)
+    /// language tag, such as `en`, `pt-BR` or `es-419`.
+    ///
+    /// Unlike `TryFrom`, the tag does not need to be in Google's exact form:
+    /// case is ignored and subtags may be separated by `_` as well as `-`, so
+    /// `PT_br` is read as `pt-BR`. The standard `he` tag is also accepted for
+    /// Hebrew, which Google identifies as `iw`.
+    ///
+    /// Well-formed tags that are not supported by this crate are returned as
+    /// `Language::Other`, holding the normalized tag, so they may still be
+    /// passed through to Google.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `InvalidLanguageCode` if the tag is not well-formed. For
+    ///   example, if it is empty or contains a subtag longer than 8
+    ///   characters.
+    pub fn from_bcp47(tag: &str) -> Result<Self, TypeError> {
+        let subtags = tag
+            .trim()
+            .split(['-', '_'])
+            .enumerate()
+            .map(|(index, subtag)| {
+                let is_valid = subtag.chars().all(|c| c.is_ascii_alphanumeric())
+                    && if index == 0 {
+                        (2..=3).contains(&subtag.len())
+                            && subtag.chars().all(|c| c.is_ascii_alphabetic())
+                    } else {
+                        (1..=8).contains(&subtag.len())
+                    }; // if
+                if !is_valid {
+                    return Err(TypeError::InvalidLanguageCode(tag.to_string()));
+                } // if
+                // Normalize the subtag's case: the language is lowercase,
+                // regions are uppercase and scripts are title-case:
+                let is_alphabetic = subtag.chars().all(|c| c.is_ascii_alphabetic());
+                Ok(match (index, subtag.len()) {
+                    (1.., 2) if is_alphabetic => subtag.to_ascii_uppercase(),
+                    (1.., 4) if is_alphabetic => {
+                        let (first, rest) = subtag.split_at(1);
+                        first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+                    } // script
+                    _ => subtag.to_ascii_lowercase(),
+                }) // match
+            }) // map
+            .collect::<Result<Vec<String>, TypeError>>()?;
+
+        let normalized = subtags.join("-");
+
+        let language = match normalized.as_str() {
+            "he" => Some(Self::Hebrew),
+            "zh-Hans" => Some(Self::ChineseSimplified),
+            code => LANGUAGES_BY_CODE.get(code).cloned(),
+        }; // match
+
+        Ok(language.unwrap_or(Self::Other(normalized)))
+    } // fn
+
+    /// Returns the language tag that is sent to Google for this language. For
+    /// `Language::Other`, this is the tag that it holds.
+    ///
+    /// Note that Google uses the deprecated `iw` tag for Hebrew rather than
+    /// `he`.
+    #[must_use]
+    pub fn to_bcp47(&self) -> &str {
+        self.into()
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::Language;
+    use crate::types::error::Error as TypeError;
+
+    #[test]
+    fn regional_variants_round_trip() {
+        let variants = [
+            ("pt-BR", Language::PortugueseBrazil),
+            ("pt-PT", Language::PortuguesePortugal),
+            ("zh-CN", Language::ChineseSimplified),
+            ("zh-TW", Language::ChineseTaiwan),
+            ("en-GB", Language::EnglishGreatBritain),
+            ("es-419", Language::SpanishLatinAmerica),
+            ("zh-Hant", Language::ChineseTraditional),
+        ]; // variants
+
+        for (tag, language) in variants {
+            assert_eq!(Language::from_bcp47(tag).unwrap(), language, "{tag}");
+            assert_eq!(language.to_bcp47(), tag);
+        } // for
+
+        // A regional variant is not its base language:
+        assert_eq!(Language::from_bcp47("pt").unwrap(), Language::Portuguese);
+        assert_ne!(Language::from_bcp47("pt-BR").unwrap(), Language::from_bcp47("pt-PT").unwrap());
+    } // fn
+
+    #[test]
+    fn ignores_case_and_underscores() {
+        for tag in ["pt-BR", "pt_BR", "PT-br", "pt_br", " Pt_Br "] {
+            assert_eq!(Language::from_bcp47(tag).unwrap(), Language::PortugueseBrazil, "{tag}");
+        } // for
+        assert_eq!(Language::from_bcp47("ZH_hant").unwrap(), Language::ChineseTraditional);
+        assert_eq!(Language::from_bcp47("he").unwrap(), Language::Hebrew);
+        assert_eq!(Language::Hebrew.to_bcp47(), "iw");
+    } // fn
+
+    #[test]
+    fn passes_unsupported_tags_through_as_other() {
+        let language = Language::from_bcp47("gsw_ch").unwrap();
+        assert_eq!(language, Language::Other("gsw-CH".to_string()));
+        assert_eq!(language.to_bcp47(), "gsw-CH");
+        assert_eq!(language.to_string(), "gsw-CH");
+        assert_eq!(Language::from_bcp47(language.to_bcp47()).unwrap(), language);
+
+        // Codes from Google's responses are kept exactly as they are:
+        let language = Language::try_from("sr-Latn").unwrap();
+        assert_eq!(language, Language::Other("sr-Latn".to_string()));
+        assert_eq!(language.to_bcp47(), "sr-Latn");
+    } // fn
+
+    #[test]
+    fn rejects_malformed_tags() {
+        for tag in ["", "p", "pt--BR", "pt-toolongsubtag", "12", "pt-B@"] {
+            assert!(matches!(Language::from_bcp47(tag), Err(TypeError::InvalidLanguageCode(_))), "{tag}");
+        } // for
+    } // fn
+} // mod
@@ -0,0 +1,261 @@
+//! Parses latitude & longitude coordinates written in degrees, minutes and
+//! seconds, such as `40°42'46"N 74°00'21"W`.
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::{error::Error as TypeError, latlng::LatLng};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+
+/// The part of a DMS coordinate string that is being read.
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    /// A degrees, minutes or seconds value.
+    Number(Decimal),
+    /// A hemisphere letter: `N`, `S`, `E` or `W`.
+    Hemisphere(char),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Takes a string of latitude & longitude coordinates in degrees, minutes
+    /// and seconds, and attempts to convert it to a `LatLng` struct.
+    ///
+    /// Each coordinate has a degrees value, optionally followed by minutes and
+    /// seconds values, and a hemisphere letter (`N`, `S`, `E` or `W`) either
+    /// before or after the values. The last value of a coordinate may have a
+    /// decimal part. Values may be separated by whitespace or marked with the
+    /// usual symbols, and the coordinates may be separated by a comma. The
+    /// latitude may be given first or second. For example:
+    ///
+    /// * `40°42'46"N 74°00'21"W`
+    /// * `40 42 46 N 74 00 21 W`
+    /// * `40°42'46.5"N, 74°0'21.3"W`
+    /// * `N 40°42.767' W 74°0.35'`
+    ///
+    /// Southern latitudes and western longitudes are converted to negative
+    /// decimal degrees, which are rounded to 7 decimal places.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the string is not in one of the forms above, if a
+    ///   hemisphere letter is missing or unrecognized, if minutes or seconds
+    ///   are not less than 60, or if the resulting latitude or longitude is
+    ///   out of range.
+
+    pub fn from_dms(str: &str) -> Result<Self, GoogleMapsError> {
+        let invalid = || TypeError::InvalidDmsString(str.to_owned());
+
+        let tokens = tokenize(str).ok_or_else(invalid)?;
+
+        // Group the values of each coordinate with its hemisphere letter. The
+        // letter may come before or after the values:
+        let mut coordinates: Vec<(char, Vec<Decimal>)> = Vec::new();
+        let mut values: Vec<Decimal> = Vec::new();
+        let mut leading: Option<char> = None;
+
+        for token in tokens {
+            match token {
+                Token::Number(value) => values.push(value),
+                Token::Hemisphere(hemisphere) => match leading.take() {
+                    // A letter before the values - the next letter begins the
+                    // next coordinate:
+                    Some(previous) if !values.is_empty() => {
+                        coordinates.push((previous, std::mem::take(&mut values)));
+                        leading = Some(hemisphere);
+                    }, // Some
+                    Some(_previous) => return Err(invalid().into()),
+                    // A letter after the values:
+                    None if !values.is_empty() =>
+                        coordinates.push((hemisphere, std::mem::take(&mut values))),
+                    None => leading = Some(hemisphere),
+                }, // match
+            } // match
+        } // for
+
+        match leading {
+            Some(hemisphere) if !values.is_empty() => coordinates.push((hemisphere, values)),
+            None if values.is_empty() => {},
+            _ => return Err(invalid().into()),
+        } // match
+
+        // There must be exactly one latitude and one longitude, in either
+        // order:
+        let [first, second] = <[(char, Vec<Decimal>); 2]>::try_from(coordinates)
+            .map_err(|_| invalid())?;
+        let (latitude, longitude) = match (first.0, second.0) {
+            ('N' | 'S', 'E' | 'W') => (first, second),
+            ('E' | 'W', 'N' | 'S') => (second, first),
+            _ => return Err(invalid().into()),
+        }; // match
+
+        let latitude = to_decimal_degrees(&latitude).ok_or_else(invalid)?;
+        let longitude = to_decimal_degrees(&longitude).ok_or_else(invalid)?;
+
+        Self::try_from_dec(latitude, longitude)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Splits a DMS coordinate string into its values and hemisphere letters.
+/// Whitespace, commas, and degree, minute and second symbols separate the
+/// tokens. Returns `None` if the string contains any other character, or a
+/// word that is not a single hemisphere letter.
+
+fn tokenize(str: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = str.chars().peekable();
+
+    while let Some(&char) = chars.peek() {
+        if char.is_ascii_digit() || char == '.' {
+            let mut number = String::new();
+            while let Some(&char) = chars.peek().filter(|char| char.is_ascii_digit() || **char == '.') {
+                number.push(char);
+                chars.next();
+            } // while
+            tokens.push(Token::Number(Decimal::from_str(&number).ok()?));
+        } else if char.is_alphabetic() {
+            let mut word = String::new();
+            while let Some(&char) = chars.peek().filter(|char| char.is_alphabetic()) {
+                word.push(char);
+                chars.next();
+            } // while
+            match word.to_ascii_uppercase().as_str() {
+                "N" => tokens.push(Token::Hemisphere('N')),
+                "S" => tokens.push(Token::Hemisphere('S')),
+                "E" => tokens.push(Token::Hemisphere('E')),
+                "W" => tokens.push(Token::Hemisphere('W')),
+                _ => return None,
+            } // match
+        } else if char.is_whitespace() || matches!(char, ',' | '°' | 'º' | '\'' | '′' | '’' | '"' | '″' | '”') {
+            chars.next();
+        } else {
+            return None;
+        } // if
+    } // while
+
+    Some(tokens)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Converts a coordinate's hemisphere and its degrees, minutes and seconds
+/// values to signed decimal degrees. Returns `None` if there are no values or
+/// more than three, if a value other than the last has a decimal part, or if
+/// the minutes or seconds are not less than 60.
+
+fn to_decimal_degrees((hemisphere, values): &(char, Vec<Decimal>)) -> Option<Decimal> {
+    if values.is_empty() || values.len() > 3 {
+        return None;
+    } // if
+
+    if values[..values.len() - 1].iter().any(|value| !value.fract().is_zero()) {
+        return None;
+    } // if
+
+    if values.iter().skip(1).any(|value| *value >= dec!(60)) {
+        return None;
+    } // if
+
+    let degrees = values
+        .iter()
+        .zip([dec!(1), dec!(60), dec!(3600)])
+        .try_fold(Decimal::ZERO, |degrees, (value, divisor)| {
+            degrees.checked_add(value.checked_div(divisor)?)
+        })? // try_fold
+        .round_dp(7)
+        .normalize();
+
+    match hemisphere {
+        'S' | 'W' => Some(-degrees),
+        _ => Some(degrees),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::types::{error::Error as TypeError, LatLng};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn parses_degrees_minutes_and_seconds() {
+        let expected = LatLng::try_from_dec(dec!(40.7127778), dec!(-74.0058333)).unwrap();
+        let forms = [
+            r#"40°42'46"N 74°00'21"W"#,
+            "40 42 46 N 74 00 21 W",
+            "40 42 46 n, 74 0 21 w",
+            "N 40°42′46″ W 74°0′21″",
+            // The longitude may be given first:
+            r#"74°00'21"W 40°42'46"N"#,
+        ]; // forms
+
+        for form in forms {
+            assert_eq!(LatLng::from_dms(form).unwrap(), expected, "{form}");
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_a_decimal_part_on_the_last_value() {
+        let seconds = LatLng::from_dms(r#"40°42'46.5"N, 74°0'21.3"W"#).unwrap();
+        assert_eq!(seconds, LatLng::try_from_dec(dec!(40.7129167), dec!(-74.0059167)).unwrap());
+
+        let minutes = LatLng::from_dms("N 40°42.767' W 74°0.35'").unwrap();
+        assert_eq!(minutes, LatLng::try_from_dec(dec!(40.7127833), dec!(-74.0058333)).unwrap());
+
+        let south_east = LatLng::from_dms("33.8568° S 151.2153° E").unwrap();
+        assert_eq!(south_east, LatLng::try_from_dec(dec!(-33.8568), dec!(151.2153)).unwrap());
+    } // fn
+
+    #[test]
+    fn rejects_malformed_coordinates() {
+        let malformed = [
+            // Unrecognized hemisphere letters:
+            r#"40°42'46"X 74°00'21"W"#,
+            r#"40°42'46"N 74°00'21"Q"#,
+            r#"40°42'46"NE 74°00'21"W"#,
+            // Missing hemisphere letter:
+            r#"40°42'46" 74°00'21"W"#,
+            // Two latitudes:
+            r#"40°42'46"N 74°00'21"S"#,
+            // Minutes or seconds of 60 or more:
+            r#"40°60'46"N 74°00'21"W"#,
+            r#"40°42'60"N 74°00'21"W"#,
+            // A decimal part before the last value:
+            r#"40.5°42'46"N 74°00'21"W"#,
+            // Out of range:
+            r#"91°00'00"N 74°00'21"W"#,
+        ]; // malformed
+
+        for str in malformed {
+            assert!(
+                matches!(LatLng::from_dms(str), Err(Error::Type(TypeError::InvalidDmsString(_) | TypeError::InvalidLatitude(..)))),
+                "{str}",
+            );
+        } // for
+    } // fn
+
+    #[test]
+    fn parses_decimal_coordinates_and_falls_back_to_dms() {
+        // `FromStr` still reads decimal degrees first:
+        let decimal: LatLng = "40.7127778, -74.0058333".parse().unwrap();
+        assert_eq!(decimal, LatLng::try_from_dec(dec!(40.7127778), dec!(-74.0058333)).unwrap());
+
+        // and falls back to degrees, minutes and seconds:
+        let dms: LatLng = r#"40°42'46"N 74°00'21"W"#.parse().unwrap();
+        assert_eq!(dms, decimal);
+
+        // A string that is neither is still an invalid decimal pair:
+        assert!(matches!(
+            "forty, seventy".parse::<LatLng>(),
+            Err(Error::Type(TypeError::InvalidLatLongString(_))),
+        ));
+    } // fn
+} // mod
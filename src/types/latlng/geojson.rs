@@ -0,0 +1,57 @@
+//! Provides `LatLng` conversion methods for the
+//! [geojson](https://crates.io/crates/geojson) crate.
+
+use crate::types::latlng::LatLng;
+use ::geojson::{Geometry, Position, Value};
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the coordinate as a `GeoJSON` position. `GeoJSON` orders a
+    /// position's values as `[longitude, latitude]`, the opposite of the usual
+    /// latitude & longitude order.
+
+    #[must_use]
+    pub fn to_geojson_position(&self) -> Position {
+        vec![self.lng_f64(), self.lat_f64()]
+    } // fn
+
+    /// Returns the coordinate as a `GeoJSON` `Point` geometry.
+    ///
+    /// ```rust
+    /// let point = LatLng::try_from_dec(dec!(51.5), dec!(-0.12))?.to_geojson_point();
+    /// // {"type":"Point","coordinates":[-0.12,51.5]}
+    /// ```
+
+    #[must_use]
+    pub fn to_geojson_point(&self) -> Geometry {
+        Geometry::new(Value::Point(self.to_geojson_position()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<&LatLng> for Geometry {
+    /// Converts a borrowed `&google_maps::LatLng` struct to a `GeoJSON` `Point`
+    /// geometry.
+    fn from(latlng: &LatLng) -> Self {
+        latlng.to_geojson_point()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::types::latlng::LatLng;
+    use ::geojson::{Geometry, Value};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn orders_a_position_longitude_first() {
+        let london = LatLng::try_from_dec(dec!(51.5), dec!(-0.12)).unwrap();
+
+        assert_eq!(london.to_geojson_position(), vec![-0.12, 51.5]);
+        assert_eq!(Geometry::from(&london).value, Value::Point(vec![-0.12, 51.5]));
+    } // fn
+} // mod
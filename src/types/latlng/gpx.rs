@@ -0,0 +1,245 @@
+//! Reads `LatLng` coordinates from the tracks of a
+//! [GPX](https://www.topografix.com/gpx.asp) file, such as a GPS trace. The
+//! file is read with the [quick-xml](https://crates.io/crates/quick-xml)
+//! crate.
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::{error::Error as TypeError, latlng::LatLng};
+use quick_xml::events::{BytesStart, Event};
+use rust_decimal::Decimal;
+use std::io::BufRead;
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Reads the track points of a GPX file. The points of every track
+    /// segment, of every track, are returned in the order that they appear in
+    /// the file. Waypoints and route points are ignored.
+    ///
+    /// The points may be used as the path of a _Roads API_ snap-to-roads
+    /// request, or of an _Elevation API_ sampled path request:
+    ///
+    /// ```rust
+    /// let path = LatLng::vec_from_gpx_track(BufReader::new(File::open("trace.gpx")?))?;
+    /// let snapped = google_maps_client.snap_to_roads(path).execute().await?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the file can not be read or is not valid XML.
+    /// * Returns an error with the point's index if a track point is missing
+    ///   its `lat` or `lon` attribute, or if either is not a valid coordinate.
+
+    pub fn vec_from_gpx_track(reader: impl BufRead) -> Result<Vec<Self>, GoogleMapsError> {
+        let mut reader = quick_xml::Reader::from_reader(reader);
+        let mut buffer = Vec::new();
+        let mut points = Vec::new();
+
+        loop {
+            let event = reader
+                .read_event_into(&mut buffer)
+                .map_err(|error| TypeError::InvalidGpx(error.to_string()))?;
+            match event {
+                Event::Start(element) | Event::Empty(element) if element.local_name().as_ref() == b"trkpt" =>
+                    points.push(track_point(&element, points.len())?),
+                Event::Eof => break,
+                _ => {},
+            } // match
+            buffer.clear();
+        } // loop
+
+        Ok(points)
+    } // fn
+
+    /// Reads the track points of a GPX file, like `vec_from_gpx_track`, but
+    /// keeps only every `n`th point. The first and last points are always
+    /// kept. An `n` of `0` is treated as `1`, which keeps every point.
+    ///
+    /// This reduces a densely sampled trace to fewer points, for example to
+    /// fit within the _Roads API_'s limit of 100 points per request.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the same errors as `vec_from_gpx_track`.
+
+    pub fn vec_from_gpx_track_every_nth(
+        reader: impl BufRead,
+        n: usize,
+    ) -> Result<Vec<Self>, GoogleMapsError> {
+        let points = Self::vec_from_gpx_track(reader)?;
+        let last = points.len().saturating_sub(1);
+        Ok(points
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| index % n.max(1) == 0 || *index == last)
+            .map(|(_, point)| point)
+            .collect())
+    } // fn
+
+    /// Reads the track points of a GPX file, like `vec_from_gpx_track`, but
+    /// skips points that are closer than `meters` to the previous point that
+    /// was kept. The first and last points are always kept. Distances are
+    /// great circle distances, see `LatLng::haversine_distance`.
+    ///
+    /// This removes the clusters of points that a GPS receiver records while
+    /// it is standing still.
+    ///
+    /// # Errors
+    ///
+    /// * Returns the same errors as `vec_from_gpx_track`.
+
+    pub fn vec_from_gpx_track_min_spacing(
+        reader: impl BufRead,
+        meters: f64,
+    ) -> Result<Vec<Self>, GoogleMapsError> {
+        let points = Self::vec_from_gpx_track(reader)?;
+        let mut kept: Vec<Self> = Vec::with_capacity(points.len());
+
+        for point in &points {
+            match kept.last() {
+                Some(previous) if previous.haversine_distance(point) < meters => {},
+                _ => kept.push(*point),
+            } // match
+        } // for
+
+        // Keep the end of the track, even if it is close to the last point
+        // that was kept:
+        if let (Some(last), Some(previous)) = (points.last(), kept.last()) {
+            if last != previous {
+                kept.push(*last);
+            } // if
+        } // if
+
+        Ok(kept)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the `lat` and `lon` attributes of a `<trkpt>` element. `index` is the
+/// position of the point in the file, counting from `0`, and is used in error
+/// messages.
+
+fn track_point(element: &BytesStart<'_>, index: usize) -> Result<LatLng, GoogleMapsError> {
+    let invalid = |reason: String| TypeError::InvalidGpxPoint(index, reason);
+
+    let coordinate = |name: &str| -> Result<Decimal, TypeError> {
+        let value = element
+            .try_get_attribute(name)
+            .map_err(|error| invalid(error.to_string()))?
+            .ok_or_else(|| invalid(format!("the `{name}` attribute is missing")))?
+            .unescape_value()
+            .map_err(|error| invalid(error.to_string()))?;
+        Decimal::from_str(value.trim())
+            .or_else(|_| Decimal::from_scientific(value.trim()))
+            .map_err(|_| invalid(format!("`{value}` is not a valid `{name}` value")))
+    }; // closure
+
+    let (lat, lon) = (coordinate("lat")?, coordinate("lon")?);
+
+    LatLng::try_from_dec(lat, lon).map_err(|error| invalid(error.to_string()).into())
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error as GoogleMapsError;
+    use crate::types::{error::Error as TypeError, latlng::LatLng};
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    /// Reads the `trace.gpx` fixture: a walk of 7 track points, in two tracks
+    /// of three segments, along with a waypoint and a route that are ignored.
+    fn trace() -> Vec<u8> {
+        std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/trace.gpx")).unwrap()
+    } // fn
+
+    /// Returns the coordinates of a point, as they are written in the file.
+    fn point(lat: &str, lng: &str) -> LatLng {
+        LatLng::try_from_dec(Decimal::from_str(lat).unwrap(), Decimal::from_str(lng).unwrap()).unwrap()
+    } // fn
+
+    #[test]
+    fn reads_every_track_point_in_order() {
+        let points = LatLng::vec_from_gpx_track(trace().as_slice()).unwrap();
+
+        assert_eq!(points, [
+            point("-35.27801", "149.12958"),
+            point("-35.28032", "149.12907"),
+            point("-35.28099", "149.12929"),
+            point("-35.28144", "149.12984"),
+            point("-35.28146", "149.12986"),
+            point("-35.28282", "149.12956"),
+            point("-35.28302", "149.12881"),
+        ]);
+    } // fn
+
+    #[test]
+    fn keeps_every_nth_track_point_and_the_last() {
+        let points = LatLng::vec_from_gpx_track_every_nth(trace().as_slice(), 4).unwrap();
+        assert_eq!(points, [
+            point("-35.27801", "149.12958"),
+            point("-35.28146", "149.12986"),
+            point("-35.28302", "149.12881"),
+        ]);
+
+        let points = LatLng::vec_from_gpx_track_every_nth(trace().as_slice(), 0).unwrap();
+        assert_eq!(points.len(), 7);
+    } // fn
+
+    #[test]
+    fn skips_track_points_that_are_close_together() {
+        // The fourth and fifth points are about 3 meters apart:
+        let points = LatLng::vec_from_gpx_track_min_spacing(trace().as_slice(), 5.0).unwrap();
+        assert_eq!(points.len(), 6);
+        assert!(!points.contains(&point("-35.28146", "149.12986")));
+
+        // The first and last points are always kept:
+        let points = LatLng::vec_from_gpx_track_min_spacing(trace().as_slice(), 10_000.0).unwrap();
+        assert_eq!(points, [point("-35.27801", "149.12958"), point("-35.28302", "149.12881")]);
+    } // fn
+
+    #[test]
+    fn reports_the_index_of_an_invalid_track_point() {
+        let gpx = r#"<gpx><trk><trkseg>
+            <trkpt lat="-35.27801" lon="149.12958"/>
+            <trkpt lat="-35.28032" lon="149.12907"/>
+            <trkpt lat="-95.28099" lon="149.12929"/>
+        </trkseg></trk></gpx>"#;
+        let result = LatLng::vec_from_gpx_track(gpx.as_bytes());
+        assert!(
+            matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidGpxPoint(2, _)))),
+            "{result:?}"
+        );
+
+        let gpx = r#"<gpx><trk><trkseg>
+            <trkpt lat="-35.27801" lon="149.12958"/>
+            <trkpt lat="-35.28032"/>
+        </trkseg></trk></gpx>"#;
+        let result = LatLng::vec_from_gpx_track(gpx.as_bytes());
+        assert!(
+            matches!(&result, Err(GoogleMapsError::Type(TypeError::InvalidGpxPoint(1, reason))) if reason.contains("`lon`")),
+            "{result:?}"
+        );
+
+        let result = LatLng::vec_from_gpx_track(&b"<gpx><trk></trkseg></gpx>"[..]);
+        assert!(matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidGpx(_)))), "{result:?}");
+    } // fn
+
+    #[cfg(all(feature = "reqwest", feature = "roads"))]
+    #[test]
+    fn snaps_a_gpx_track_to_roads() {
+        let client = crate::GoogleMapsClient::try_new("AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000").unwrap();
+        let path = LatLng::vec_from_gpx_track_every_nth(trace().as_slice(), 3).unwrap();
+
+        let url = client.snap_to_roads(path).query_url();
+        assert_eq!(
+            url,
+            "https://roads.googleapis.com/v1/snapToRoads/\
+            ?key=AIzaSyD-FAKE-KEY-FOR-UNIT-TESTS-000000000\
+            &path=%2D35%2E27801%2C149%2E12958%7C%2D35%2E28144%2C149%2E12984%7C%2D35%2E28302%2C149%2E12881"
+        );
+    } // fn
+} // mod
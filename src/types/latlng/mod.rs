@@ -2,21 +2,33 @@
 //! latitude & longitude coorindate system is used to specify a position or
 //! location on the Earth's surface.
 
+mod dms;
+mod great_circle;
 #[cfg(feature = "geo")]
 mod geo;
+#[cfg(feature = "geojson")]
+mod geojson;
+#[cfg(feature = "gpx")]
+mod gpx;
 
 // -----------------------------------------------------------------------------
 
 use crate::error::Error as GoogleMapsError;
+use crate::serde::number_or_string::decimal_number_or_string;
 use crate::types::error::Error as TypeError;
-use rust_decimal::prelude::FromPrimitive;
-use rust_decimal::Decimal;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::{Decimal, RoundingStrategy};
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::cmp::{Ord, Ordering};
 
 // -----------------------------------------------------------------------------
 
+/// The mean radius of the earth, in metres.
+pub(super) const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+// -----------------------------------------------------------------------------
+
 /// Latitude and longitude values must correspond to a valid location on the
 /// face of the earth. Latitudes can take any value between -90 and 90 while
 /// longitude values can take any value between -180 and 180. If you specify an
@@ -29,12 +41,14 @@ pub struct LatLng {
     #[serde(alias = "y")]
     #[serde(alias = "lat")]
     #[serde(alias = "latitude")]
+    #[serde(deserialize_with = "decimal_number_or_string")]
     pub lat: Decimal,
     /// Longitude. A value between -180.0° and 180.0°.
     #[serde(alias = "x")]
     #[serde(alias = "lon")]
     #[serde(alias = "long")]
     #[serde(alias = "longitude")]
+    #[serde(deserialize_with = "decimal_number_or_string")]
     pub lng: Decimal,
 } // struct
 
@@ -118,6 +132,66 @@ impl LatLng {
 
 // -----------------------------------------------------------------------------
 
+impl LatLng {
+    /// Takes individual longitude & latitude `f64` coordinates, in that order,
+    /// and converts them into a `LatLng` structure. This is the ordering used
+    /// by `GeoJSON`, and by the `x` & `y` of most geometry libraries. If either
+    /// the latitude (-90.0 to +90.0) or longitude (-180.0 to +180.0) are out
+    /// of range, this function will return an error.
+    ///
+    /// ```rust
+    /// // A GeoJSON position is `[longitude, latitude]`:
+    /// let latlng = LatLng::from_lng_lat(-0.127_625, 51.503_364)?;
+    /// assert_eq!(latlng, LatLng::try_from_f64(51.503_364, -0.127_625)?);
+    /// ```
+
+    pub fn from_lng_lat(longitude: f64, latitude: f64) -> Result<Self, GoogleMapsError> {
+        Self::try_from_f64(latitude, longitude)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns a copy of the latitude & longitude pair, with both coordinates
+    /// rounded to `decimals` decimal places. Midpoints are rounded away from
+    /// zero.
+    ///
+    /// Rounding coordinates before they are used in a query keeps URLs short,
+    /// and makes requests for nearby points identical, which increases cache
+    /// hits. Five decimal places is roughly one metre of precision. See also
+    /// `GoogleMapsClient::with_coordinate_precision`.
+    ///
+    /// ```rust
+    /// let latlng = LatLng::try_from_dec(dec!(51.501_364_2), dec!(-0.141_890_1))?;
+    /// assert_eq!(latlng.rounded(4).to_string(), "51.5014,-0.1419");
+    /// ```
+
+    #[must_use]
+    pub fn rounded(&self, decimals: u8) -> Self {
+        let round = |coordinate: Decimal| {
+            coordinate
+                .round_dp_with_strategy(decimals.into(), RoundingStrategy::MidpointAwayFromZero)
+                .normalize()
+        }; // closure
+
+        Self {
+            lat: round(self.lat),
+            lng: round(self.lng),
+        } // LatLng
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl crate::client::Coordinates for LatLng {
+    fn rounded(&self, decimals: u8) -> Self {
+        Self::rounded(self, decimals)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for LatLng {
     // Error definitions are contained in the
     // `google_maps\src\geocoding\error.rs` module.
@@ -125,18 +199,29 @@ impl std::str::FromStr for LatLng {
 
     /// Attempts to get a `LatLng` struct from a borrowed `&str` that contains a
     /// comma-delimited latitude & longitude pair.
+    ///
+    /// If the string is not a pair of decimal numbers, but contains a
+    /// hemisphere letter, it is parsed in degrees, minutes and seconds with
+    /// `LatLng::from_dms`.
     fn from_str(str: &str) -> Result<Self, Self::Err> {
         let coordinates: Vec<&str> = str.trim().split(',').collect();
 
-        if coordinates.len() == 2 {
-            let lat = Decimal::from_str(coordinates[0].trim());
-            let lat = lat.map_err(|_| TypeError::InvalidLatLongString(str.to_owned()))?;
-            let lon = Decimal::from_str(coordinates[1].trim());
-            let lon = lon.map_err(|_| TypeError::InvalidLatLongString(str.to_owned()))?;
-            Self::try_from_dec(lat, lon)
-        } else {
-            Err(TypeError::InvalidLatLongString(str.to_owned()))?
+        if let [lat, lon] = coordinates.as_slice() {
+            if let (Ok(lat), Ok(lon)) = (Decimal::from_str(lat.trim()), Decimal::from_str(lon.trim())) {
+                return Self::try_from_dec(lat, lon);
+            } // if
+        } // if
+
+        // Coordinates such as `40°42'46"N 74°00'21"W`:
+        let has_digit = str.chars().any(|char| char.is_ascii_digit());
+        let has_hemisphere = str
+            .chars()
+            .any(|char| matches!(char.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W'));
+        if has_digit && has_hemisphere {
+            return Self::from_dms(str);
         } // if
+
+        Err(TypeError::InvalidLatLongString(str.to_owned()))?
     } // fn
 } // impl
 
@@ -189,6 +274,23 @@ impl<V: TryInto<Decimal>> TryFrom<(V, V)> for LatLng {
 
 // -----------------------------------------------------------------------------
 
+impl<V: TryInto<Decimal>> TryFrom<[V; 2]> for LatLng {
+    type Error = GoogleMapsError;
+    /// Attempts to get a `LatLng` struct from a `[lat, lng]` array that
+    /// contains a `0` latitude and a `1` longitude (in that order). For the
+    /// `[lng, lat]` ordering used by `GeoJSON`, see `LatLng::from_lng_lat`.
+    ///
+    /// ```rust
+    /// let latlng = LatLng::try_from([51.503_364, -0.127_625])?;
+    /// ```
+    fn try_from(coordinates: [V; 2]) -> Result<Self, Self::Error> {
+        let [latitude, longitude] = coordinates;
+        Self::try_from((latitude, longitude))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::convert::From<&Self> for LatLng {
     /// Converts a borrowed `&LatLng` enum into an owned `LatLng` enum by
     /// copying it.
@@ -328,3 +430,169 @@ impl LatLng {
         (&self.lat, &self.lng)
     }
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Takes individual latitude & longitude `f64` coordinates and converts
+    /// them into a `LatLng` structure. This is the counterpart of the
+    /// `lat_f64` & `lng_f64` accessors, and behaves like `try_from_f64`:
+    /// coordinates that are out of range, or that are not finite, are
+    /// rejected.
+    ///
+    /// A coordinate converted to `LatLng` and back to `f64` round-trips within
+    /// `1e-9`, which is far below the `1e-7` degrees (about 1 cm) that Google
+    /// Maps works in.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the latitude is outside of -90.0 to +90.0, if the
+    ///   longitude is outside of -180.0 to +180.0, or if either can not be
+    ///   represented as a `Decimal`.
+
+    pub fn from_f64(latitude: f64, longitude: f64) -> Result<Self, GoogleMapsError> {
+        Self::try_from_f64(latitude, longitude)
+    } // fn
+
+    /// Returns the north-south latitudinal (or vertical) coordinate as an
+    /// `f64`. This is useful for geometry that is done natively in `f64`.
+    #[must_use]
+    pub fn lat_f64(&self) -> f64 {
+        // A `Decimal` in the valid latitude range always converts to `f64`:
+        self.lat.to_f64().unwrap_or_default()
+    }
+    /// Returns the east-west longitudinal (or horizontal) coordinate as an
+    /// `f64`. This is useful for geometry that is done natively in `f64`.
+    #[must_use]
+    pub fn lng_f64(&self) -> f64 {
+        // A `Decimal` in the valid longitude range always converts to `f64`:
+        self.lng.to_f64().unwrap_or_default()
+    }
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&LatLng> for (f64, f64) {
+    /// Converts a borrowed `&LatLng` struct into a tuple containing 1. the
+    /// latitude (y) coordinate, and then 2. the longitude (x) coordinate, as
+    /// `f64` values.
+    fn from(latlng: &LatLng) -> Self {
+        (latlng.lat_f64(), latlng.lng_f64())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for (f64, f64) {
+    /// Converts an owned `LatLng` struct into a tuple containing 1. the
+    /// latitude (y) coordinate, and then 2. the longitude (x) coordinate, as
+    /// `f64` values.
+    fn from(latlng: LatLng) -> Self {
+        Self::from(&latlng)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::LatLng;
+    use crate::error::Error as GoogleMapsError;
+    use crate::types::error::Error as TypeError;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn f64_coordinates_round_trip() {
+        let coordinates = [
+            (45.421_530_6, -75.697_193_3),
+            (-33.856_784, 151.215_296_6),
+            (0.000_000_1, -0.000_000_1),
+            (90.0, 180.0),
+            (-90.0, -180.0),
+        ]; // coordinates
+
+        for (lat, lng) in coordinates {
+            let latlng = LatLng::from_f64(lat, lng).unwrap();
+            let (lat_f64, lng_f64): (f64, f64) = latlng.into();
+            assert!((lat_f64 - lat).abs() < 1e-9, "{lat} became {lat_f64}");
+            assert!((lng_f64 - lng).abs() < 1e-9, "{lng} became {lng_f64}");
+        } // for
+    } // fn
+
+    #[test]
+    fn keeps_six_and_seven_decimal_places() {
+        let latlng = LatLng::from_f64(45.421_530_6, -75.697_193).unwrap();
+        assert_eq!(latlng.to_string(), "45.4215306,-75.697193");
+        assert_eq!(latlng.lat, dec!(45.4215306));
+        assert_eq!(latlng.lng, dec!(-75.697193));
+    } // fn
+
+    #[test]
+    fn rejects_invalid_f64_coordinates() {
+        assert!(LatLng::from_f64(90.000_000_1, 0.0).is_err());
+        assert!(LatLng::from_f64(0.0, -180.000_000_1).is_err());
+        assert!(LatLng::from_f64(f64::NAN, 0.0).is_err());
+        assert!(LatLng::from_f64(0.0, f64::INFINITY).is_err());
+    } // fn
+
+    #[test]
+    fn reads_coordinates_latitude_first() {
+        let sydney = LatLng::try_from_dec(dec!(-33.856784), dec!(151.2152966)).unwrap();
+
+        assert_eq!(LatLng::from_f64(-33.856_784, 151.215_296_6).unwrap(), sydney);
+        assert_eq!(LatLng::try_from((-33.856_784, 151.215_296_6)).unwrap(), sydney);
+        assert_eq!(LatLng::try_from([-33.856_784, 151.215_296_6]).unwrap(), sydney);
+        assert_eq!(<(f64, f64)>::from(&sydney), (-33.856_784, 151.215_296_6));
+    } // fn
+
+    #[test]
+    fn reads_coordinates_longitude_first() {
+        let sydney = LatLng::try_from_dec(dec!(-33.856784), dec!(151.2152966)).unwrap();
+
+        // A GeoJSON position is `[longitude, latitude]`:
+        assert_eq!(LatLng::from_lng_lat(151.215_296_6, -33.856_784).unwrap(), sydney);
+        assert_eq!(LatLng::from_lng_lat(0.0, 90.0).unwrap(), LatLng::from_f64(90.0, 0.0).unwrap());
+    } // fn
+
+    #[test]
+    fn rejects_coordinates_in_the_wrong_order() {
+        // A longitude of 151° is not a valid latitude:
+        let result = LatLng::from_f64(151.215_296_6, -33.856_784);
+        assert!(matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidLatitude(..)))), "{result:?}");
+
+        let result = LatLng::from_lng_lat(-33.856_784, 151.215_296_6);
+        assert!(matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidLatitude(..)))), "{result:?}");
+
+        // Both orderings reject an out of range longitude the same way:
+        let result = LatLng::from_lng_lat(180.5, 0.0);
+        assert!(matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidLongitude(..)))), "{result:?}");
+        let result = LatLng::try_from((0.0, 180.5));
+        assert!(matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidLongitude(..)))), "{result:?}");
+    } // fn
+
+    #[test]
+    fn reads_coordinates_from_a_format_that_is_not_self_describing() {
+        // `bincode` can't be asked for "any" value, so the coordinates are
+        // read back as the type that `Decimal` was written as:
+        let bytes = bincode::serialize(&(dec!(-33.856784), dec!(151.2152966))).unwrap();
+        let sydney: LatLng = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(sydney, LatLng::try_from_dec(dec!(-33.856784), dec!(151.2152966)).unwrap());
+    } // fn
+
+    #[cfg(not(feature = "decimal-serde-float"))]
+    #[test]
+    fn round_trips_without_losing_precision() {
+        // More digits than an `f64` can hold:
+        let latlng = LatLng::try_from_dec(dec!(45.42153060000000000001), dec!(-75.6971933)).unwrap();
+
+        let bytes = bincode::serialize(&latlng).unwrap();
+        assert_eq!(bincode::deserialize::<LatLng>(&bytes).unwrap(), latlng);
+
+        #[cfg(feature = "reqwest")]
+        {
+            let json = crate::json::to_json_string(&latlng).unwrap();
+            assert_eq!(json, r#"{"lat":"45.42153060000000000001","lng":"-75.6971933"}"#);
+            assert_eq!(crate::json::parse_json_str::<LatLng>(&json).unwrap(), latlng);
+        }
+    } // fn
+} // mod
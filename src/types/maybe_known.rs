@@ -0,0 +1,215 @@
+//! Contains the `MaybeKnown` enum and its associated traits. It is used to
+//! tolerate unrecognized enum codes in Google Maps Platform responses.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// Google occasionally adds new codes to the enumerations it returns (for
+/// example, new place types, new maneuvers, or new vehicle types). Response
+/// fields that contain a string-backed enum are wrapped in `MaybeKnown` so that
+/// an unrecognized code doesn't cause the entire response to fail
+/// deserialization.
+///
+/// * If the code is recognized by this crate, it will be stored in the `Known`
+///   variant.
+///
+/// * If the code is not recognized, the original string will be stored in the
+///   `Unknown` variant.
+///
+/// Request-side enums are not wrapped. Parsing them remains strict.
+///
+/// ```rust
+/// match step.maneuver {
+///     Some(MaybeKnown::Known(DrivingManeuver::TurnLeft)) => println!("Turn left"),
+///     Some(MaybeKnown::Unknown(maneuver)) => println!("New maneuver: {maneuver}"),
+///     _ => (),
+/// }
+///
+/// if step.travel_mode == TravelMode::Walking {
+///     println!("Walk");
+/// }
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MaybeKnown<T> {
+    /// The code was recognized by this crate.
+    Known(T),
+    /// The code was not recognized by this crate. The original string, as sent
+    /// by Google, is kept.
+    Unknown(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<T> MaybeKnown<T> {
+    /// Returns a reference to the recognized value, or `None` if the code was
+    /// not recognized.
+    #[must_use]
+    pub const fn known(&self) -> Option<&T> {
+        match self {
+            Self::Known(value) => Some(value),
+            Self::Unknown(_) => None,
+        } // match
+    } // fn
+
+    /// Returns the recognized value, or `None` if the code was not recognized.
+    #[must_use]
+    pub fn into_known(self) -> Option<T> {
+        match self {
+            Self::Known(value) => Some(value),
+            Self::Unknown(_) => None,
+        } // match
+    } // fn
+
+    /// Returns the original string if the code was not recognized, or `None`
+    /// if the code was recognized.
+    #[must_use]
+    pub fn unknown(&self) -> Option<&str> {
+        match self {
+            Self::Known(_) => None,
+            Self::Unknown(code) => Some(code),
+        } // match
+    } // fn
+
+    /// Returns `true` if the code was recognized by this crate.
+    #[must_use]
+    pub const fn is_known(&self) -> bool {
+        matches!(self, Self::Known(_))
+    } // fn
+
+    /// Returns `true` if the code was not recognized by this crate.
+    #[must_use]
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T> std::convert::From<T> for MaybeKnown<T> {
+    /// Wraps a recognized value in the `Known` variant.
+    fn from(value: T) -> Self {
+        Self::Known(value)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: PartialEq> PartialEq<T> for MaybeKnown<T> {
+    /// Allows a `MaybeKnown` to be compared directly against a recognized
+    /// value. An `Unknown` code is never equal to a recognized value.
+    fn eq(&self, other: &T) -> bool {
+        self.known() == Some(other)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: std::fmt::Display> std::fmt::Display for MaybeKnown<T> {
+    /// Formats the recognized value, or the original string if the code was
+    /// not recognized.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Known(value) => value.fmt(f),
+            Self::Unknown(code) => write!(f, "{code}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: std::str::FromStr> std::str::FromStr for MaybeKnown<T> {
+    type Err = std::convert::Infallible;
+    /// Parses a code into a `MaybeKnown`. This never fails: unrecognized codes
+    /// are stored in the `Unknown` variant.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Ok(code
+            .parse()
+            .map_or_else(|_| Self::Unknown(code.to_string()), Self::Known))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'de, T: std::str::FromStr> Deserialize<'de> for MaybeKnown<T> {
+    /// Manual implementation of `Deserialize` for `serde`. This will use the
+    /// `FromStr` implementation of the wrapped type, and fall back to the
+    /// `Unknown` variant if the code is not recognized.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Ok(string
+            .parse()
+            .map_or_else(|_| Self::Unknown(string), Self::Known))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<T: Serialize> Serialize for MaybeKnown<T> {
+    /// Manual implementation of `Serialize` for `serde`. Unrecognized codes are
+    /// serialized back into their original string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Known(value) => value.serialize(serializer),
+            Self::Unknown(code) => serializer.serialize_str(code),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::json::tests::fixture;
+
+    #[cfg(feature = "directions")]
+    #[test]
+    fn parses_unknown_codes_in_a_directions_response() {
+        use super::MaybeKnown;
+        use crate::directions::response::Response;
+        let response: Response = fixture("directions_unknown_codes").parse().unwrap();
+
+        assert_eq!(response.available_travel_modes[0], MaybeKnown::Unknown("TELEPORTING".to_string()));
+        assert_eq!(response.geocoded_waypoints[0].types[2].unknown(), Some("space_elevator"));
+
+        let steps = &response.routes[0].legs[0].steps;
+        let maneuver = steps[0].maneuver.as_ref().unwrap();
+        assert_eq!(maneuver.unknown(), Some("barrel-roll-left"));
+        assert_eq!(steps[0].travel_mode.unknown(), Some("HOVERBOARDING"));
+
+        // Known codes are still recognized next to the unknown ones:
+        assert!(steps[1].travel_mode.is_known());
+        let vehicle = &steps[1].transit_details.as_ref().unwrap().line.vehicle;
+        assert_eq!(vehicle.vehicle_type.unknown(), Some("AIRSHIP"));
+    } // fn
+
+    #[cfg(feature = "geocoding")]
+    #[test]
+    fn parses_unknown_codes_in_a_geocoding_response() {
+        use crate::geocoding::response::Response;
+        let response: Response = fixture("geocoding_unknown_codes").parse().unwrap();
+
+        let result = &response.results[0];
+        assert!(result.types[0].is_known());
+        assert_eq!(result.types[1].unknown(), Some("floating_island"));
+        assert_eq!(result.address_components[0].types[1].unknown(), Some("moon_base"));
+        let location_type = result.geometry.location_type.as_ref().unwrap();
+        assert_eq!(location_type.unknown(), Some("TRIANGULATED"));
+    } // fn
+
+    #[cfg(feature = "places")]
+    #[test]
+    fn parses_unknown_codes_in_a_places_response() {
+        use crate::places::place_search::text_search::response::Response;
+        let response: Response = fixture("places_unknown_codes").parse().unwrap();
+
+        let place = &response.results[0];
+        let business_status = place.business_status.as_ref().unwrap();
+        assert_eq!(business_status.unknown(), Some("CLOSED_FOR_HIBERNATION"));
+        assert_eq!(place.types[0].unknown(), Some("cat_cafe_for_dragons"));
+        assert!(place.types[1].is_known());
+        // The other places in the page are unaffected:
+        assert!(response.results[1].business_status.as_ref().unwrap().is_known());
+    } // fn
+} // mod
@@ -3,6 +3,7 @@
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod address_component;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -18,6 +19,8 @@ pub(super) mod country;
 pub(super) mod error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod geometry;
+pub(super) mod google_status;
+pub(super) mod has_status;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -40,6 +43,7 @@ pub(super) mod language;
 pub(super) mod latlng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod location_type;
+pub(super) mod maybe_known;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -56,12 +60,15 @@ pub(super) mod place_type;
     feature = "places"
 ))]
 pub(super) mod region;
+#[cfg(feature = "geocoding")]
+pub(super) mod response_format;
 
 // -----------------------------------------------------------------------------
 
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -77,6 +84,8 @@ pub use crate::types::country::Country;
 pub use crate::types::error::Error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
+pub use crate::types::google_status::GoogleStatus;
+pub use crate::types::has_status::HasStatus;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -99,6 +108,7 @@ pub use crate::types::language::Language;
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+pub use crate::types::maybe_known::MaybeKnown;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -115,3 +125,5 @@ pub use crate::types::place_type::PlaceType;
     feature = "places"
 ))]
 pub use crate::types::region::Region;
+#[cfg(feature = "geocoding")]
+pub use crate::types::response_format::ResponseFormat;
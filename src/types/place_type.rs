@@ -3,6 +3,7 @@
 //! "country" (as in a nation) or it could be a "shopping mall."
 
 use crate::error::Error as GoogleMapsError;
+use crate::types::Error as TypeError;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -219,14 +220,12 @@ pub enum PlaceType {
     Regions = 140,
     Cities = 141,
     Landmark = 142,
-    /// If the place type is not recognized by
-    /// [serde](https://crates.io/crates/serde) when reading data from
-    /// Google it will be assigned to this `Other` variant.
+    /// The `other` place type code.
     ///
     /// As new types are added to Google Maps, they must also be added to this
-    /// crate. However, in the meantime, the `Other` catch-all variant allows
-    /// `serde` to read data from Google without producing an error until the
-    /// new variant added to this `enum`.
+    /// crate. In the meantime, place types in responses are wrapped in
+    /// `MaybeKnown`, which keeps an unrecognized code as a `MaybeKnown::Unknown`
+    /// string rather than producing an error.
     Other = 143,
 } // enum
 
@@ -605,7 +604,7 @@ impl std::convert::TryFrom<&str> for PlaceType {
         Ok(PLACE_TYPES_BY_CODE
             .get(place_type_code)
             .copied()
-            .unwrap_or(Self::Other))
+            .ok_or_else(|| TypeError::InvalidPlaceTypeCode(place_type_code.to_string()))?)
     } // fn
 } // impl
 
@@ -621,7 +620,7 @@ impl std::str::FromStr for PlaceType {
         Ok(PLACE_TYPES_BY_CODE
             .get(place_type_code)
             .copied()
-            .unwrap_or(Self::Other))
+            .ok_or_else(|| TypeError::InvalidPlaceTypeCode(place_type_code.to_string()))?)
     } // fn
 } // impl
 
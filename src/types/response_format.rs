@@ -0,0 +1,50 @@
+//! Contains the `ResponseFormat` enum and its associated traits. It is used to
+//! choose whether Google responds in JSON or XML.
+
+// -----------------------------------------------------------------------------
+//
+/// The [output
+/// format](https://developers.google.com/maps/documentation/geocoding/requests-geocoding#request)
+/// that Google responds in. Either format is parsed into the same `Response`
+/// struct.
+///
+/// JSON is recommended, and is the default. XML may be requested for
+/// integrations that require it, and is only available with the `xml` feature.
+/// The enum is non-exhaustive so that enabling the feature does not break
+/// `match` expressions in other crates.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ResponseFormat {
+    /// Google responds in JavaScript Object Notation.
+    #[default]
+    Json,
+    /// Google responds in XML. The response is parsed with the
+    /// [quick-xml](https://crates.io/crates/quick-xml) crate.
+    #[cfg(feature = "xml")]
+    Xml,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ResponseFormat> for &str {
+    /// Converts a `ResponseFormat` enum to the output format segment of a
+    /// request URL.
+    fn from(response_format: &ResponseFormat) -> Self {
+        match response_format {
+            ResponseFormat::Json => "json",
+            #[cfg(feature = "xml")]
+            ResponseFormat::Xml => "xml",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ResponseFormat {
+    /// Converts a `ResponseFormat` enum to the output format segment of a
+    /// request URL.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.into())
+    } // fmt
+} // impl
@@ -0,0 +1,62 @@
+//! XML parsing for Google Maps Platform responses, for services that have been
+//! asked to respond in XML. Uses the [quick-xml](https://crates.io/crates/quick-xml)
+//! crate.
+
+use miette::Diagnostic;
+#[cfg(feature = "reqwest")]
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// An error produced while parsing an XML response.
+
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(google_maps::xml::error), url(docsrs))]
+pub struct Error(quick_xml::DeError);
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Parses an XML response into the requested type. The root element, such as
+/// `<GeocodeResponse>`, is not checked. XML responses repeat an element for
+/// each item of a list, so the response types accept the singular element
+/// name (for example `result`) as an alias of the JSON list's name (for
+/// example `results`).
+
+#[cfg(feature = "reqwest")]
+pub(crate) fn parse_xml<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    quick_xml::de::from_reader(bytes).map_err(Error)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "geocoding", feature = "reqwest"))]
+mod tests {
+    use crate::geocoding::{response::Response, GeocodingStatus};
+
+    #[test]
+    fn parses_an_xml_geocoding_response() {
+        let xml = std::fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/geocoding.xml")).unwrap();
+        let response: Response = super::parse_xml(&xml).unwrap();
+
+        assert_eq!(response.status, GeocodingStatus::Ok);
+        assert_eq!(response.results.len(), 1);
+
+        let result = &response.results[0];
+        assert_eq!(result.formatted_address, "1600 Amphitheatre Pkwy, Mountain View, CA 94043, USA");
+        assert_eq!(result.place_id, "ChIJeRpOeF67j4AR9ydy_PIzPuM");
+        assert_eq!(result.address_components.len(), 7);
+        assert_eq!(result.address_components[2].types.len(), 2);
+        assert_eq!(result.geometry.location.to_string(), "37.4224428,-122.0842467");
+    } // fn
+} // mod
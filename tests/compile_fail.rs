@@ -0,0 +1,18 @@
+//! Checks that the typestate builders reject misuse at compile-time. Each file
+//! in the `tests/compile_fail` directory must fail to compile with the error in
+//! the `.stderr` file next to it.
+
+#![cfg(all(
+    feature = "reqwest",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "time_zone"
+))]
+
+#[test]
+fn typestate_builders_reject_misuse() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/compile_fail/*.rs");
+} // fn
@@ -0,0 +1,14 @@
+use google_maps::prelude::*;
+
+async fn directions(google_maps_client: &GoogleMapsClient) {
+    let _directions = DirectionsRequest::builder(
+        google_maps_client,
+        Location::from_address("240 McLeod St, Ottawa, ON K2P 2R1"),
+        Location::from_address("1867 St Laurent Blvd, Ottawa, ON K1G 5A3"),
+    )
+    .with_travel_mode(TravelMode::Transit)
+    .get()
+    .await;
+}
+
+fn main() {}
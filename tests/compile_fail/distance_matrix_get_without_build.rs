@@ -0,0 +1,14 @@
+use google_maps::prelude::*;
+
+async fn distance_matrix(google_maps_client: &GoogleMapsClient) {
+    let _distance_matrix = DistanceMatrixRequest::builder(
+        google_maps_client,
+        vec![Waypoint::from_address("Ottawa, ON")],
+        vec![Waypoint::from_address("Montréal, QC")],
+    )
+    .with_travel_mode(TravelMode::Driving)
+    .get()
+    .await;
+}
+
+fn main() {}
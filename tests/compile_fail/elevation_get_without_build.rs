@@ -0,0 +1,12 @@
+use google_maps::prelude::*;
+
+async fn elevation(google_maps_client: &GoogleMapsClient, denver: LatLng) {
+    let _elevation = ElevationRequest::positional_builder(
+        google_maps_client,
+        ElevationLocations::LatLngs(vec![denver]),
+    )
+    .get()
+    .await;
+}
+
+fn main() {}
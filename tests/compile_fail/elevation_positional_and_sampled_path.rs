@@ -0,0 +1,12 @@
+use google_maps::prelude::*;
+
+fn elevation(google_maps_client: &GoogleMapsClient, denver: LatLng, death_valley: LatLng) {
+    let _elevation = ElevationRequest::positional_builder(
+        google_maps_client,
+        ElevationLocations::LatLngs(vec![denver]),
+    )
+    .for_sampled_path_request(ElevationLocations::LatLngs(vec![denver, death_valley]), 4)
+    .build();
+}
+
+fn main() {}
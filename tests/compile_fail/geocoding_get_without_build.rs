@@ -0,0 +1,10 @@
+use google_maps::prelude::*;
+
+async fn geocoding(google_maps_client: &GoogleMapsClient) {
+    let _location = GeocodingRequest::builder(google_maps_client)
+        .with_address("10 Downing Street London")
+        .get()
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use google_maps::prelude::*;
+
+async fn reverse_geocoding(google_maps_client: &GoogleMapsClient, white_house: LatLng) {
+    let _location = GeocodingReverseRequest::builder(google_maps_client, white_house)
+        .with_result_type(PlaceType::StreetAddress)
+        .get()
+        .await;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use google_maps::prelude::*;
+
+async fn time_zone(google_maps_client: &GoogleMapsClient, prague: LatLng) {
+    let _time_zone = TimeZoneRequest::builder(google_maps_client, prague, Utc::now())
+        .with_language(Language::Czech)
+        .get()
+        .await;
+}
+
+fn main() {}